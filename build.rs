@@ -0,0 +1,65 @@
+/*! Build script for Tokay.
+
+When the `bootstrap` feature is enabled, the parser's abstract syntax tree is regenerated from
+`src/compiler/tokay.tok` at build time. This is done by a stage 0 build of Tokay, using the
+checked-in parser from `src/compiler/parser.rs`, which parses the grammar and emits the AST as
+`value!()` code into `OUT_DIR`. In case the bootstrap fails, the checked-in parser is used.
+*/
+use std::env;
+use std::path::Path;
+use std::process::Command;
+
+fn main() {
+    println!("cargo:rustc-check-cfg=cfg(tokay_bootstrap)");
+
+    // The stage 0 build must not bootstrap itself.
+    if env::var_os("CARGO_FEATURE_BOOTSTRAP").is_none()
+        || env::var_os("TOKAY_BOOTSTRAP_STAGE0").is_some()
+    {
+        return;
+    }
+
+    println!("cargo:rerun-if-changed=src/compiler/tokay.tok");
+
+    match bootstrap() {
+        Ok(()) => println!("cargo:rustc-cfg=tokay_bootstrap"),
+        Err(err) => println!(
+            "cargo:warning=Bootstrapping parser failed, using checked-in parser: {}",
+            err
+        ),
+    }
+}
+
+// Generate the parser's AST from tokay.tok using a stage 0 build of Tokay.
+fn bootstrap() -> Result<(), String> {
+    let out_dir = env::var("OUT_DIR").map_err(|err| err.to_string())?;
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").map_err(|err| err.to_string())?;
+    let grammar = Path::new(&manifest_dir).join("src/compiler/tokay.tok");
+
+    // Same substitution as performed by `make parser`
+    let program = std::fs::read_to_string(&grammar)
+        .map_err(|err| err.to_string())?
+        .replace("ast(\"main\")", "ast2rust(ast(\"main\"), level=3)");
+
+    let output = Command::new(env::var("CARGO").unwrap_or("cargo".to_string()))
+        .args(["run", "--quiet", "--bin", "tokay", "--manifest-path"])
+        .arg(Path::new(&manifest_dir).join("Cargo.toml"))
+        .arg("--target-dir")
+        .arg(Path::new(&out_dir).join("stage0"))
+        .arg("--")
+        .arg(program)
+        .arg("--")
+        .arg(&grammar)
+        .env("TOKAY_BOOTSTRAP_STAGE0", "1")
+        .env_remove("CARGO_FEATURE_BOOTSTRAP")
+        .output()
+        .map_err(|err| err.to_string())?;
+
+    // Tokay reports errors on stderr, but compiler warnings are also printed there.
+    if !output.status.success() || !output.stdout.trim_ascii_start().starts_with(b"value!(") {
+        return Err(String::from_utf8_lossy(&output.stderr).to_string());
+    }
+
+    std::fs::write(Path::new(&out_dir).join("parser.rs"), output.stdout)
+        .map_err(|err| err.to_string())
+}
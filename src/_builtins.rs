@@ -6,7 +6,15 @@
 use crate::builtin::Builtin;
 
 /*GENERATE cargo run -- _builtins.tok -- `find . -name "*.rs"` */
-pub static BUILTINS: [Builtin; 64] = [
+pub static BUILTINS: [Builtin; 148] = [
+    Builtin {
+        name: "Bytes",
+        func: crate::value::bytes::tokay_token_bytes,
+    },
+    Builtin {
+        name: "Email",
+        func: crate::builtin::net::tokay_token_email,
+    },
     Builtin {
         name: "Float",
         func: crate::value::token::tokay_token_float,
@@ -19,10 +27,58 @@ pub static BUILTINS: [Builtin; 64] = [
         name: "Int",
         func: crate::value::token::tokay_token_int,
     },
+    Builtin {
+        name: "Ip",
+        func: crate::builtin::net::tokay_token_ip,
+    },
+    Builtin {
+        name: "Ipv4",
+        func: crate::builtin::net::tokay_token_ipv4,
+    },
+    Builtin {
+        name: "Ipv6",
+        func: crate::builtin::net::tokay_token_ipv6,
+    },
+    Builtin {
+        name: "Kind",
+        func: crate::value::token::tokay_token_kind,
+    },
+    Builtin {
+        name: "Mac",
+        func: crate::builtin::net::tokay_token_mac,
+    },
+    Builtin {
+        name: "Number",
+        func: crate::value::token::tokay_token_number,
+    },
+    Builtin {
+        name: "QuotedString",
+        func: crate::value::token::tokay_token_quotedstring,
+    },
+    Builtin {
+        name: "Timestamp",
+        func: crate::builtin::timestamp::tokay_token_timestamp,
+    },
+    Builtin {
+        name: "Url",
+        func: crate::builtin::net::tokay_token_url,
+    },
     Builtin {
         name: "Word",
         func: crate::value::token::tokay_token_word,
     },
+    Builtin {
+        name: "abs",
+        func: crate::builtin::math::tokay_function_abs,
+    },
+    Builtin {
+        name: "accept_if",
+        func: crate::builtin::tokay_function_accept_if,
+    },
+    Builtin {
+        name: "args",
+        func: crate::builtin::process::tokay_function_args,
+    },
     Builtin {
         name: "ast",
         func: crate::compiler::ast::tokay_function_ast,
@@ -35,14 +91,62 @@ pub static BUILTINS: [Builtin; 64] = [
         name: "ast_print",
         func: crate::compiler::ast::tokay_function_ast_print,
     },
+    Builtin {
+        name: "behind",
+        func: crate::builtin::tokay_function_behind,
+    },
     Builtin {
         name: "bool",
         func: crate::value::value::Value::tokay_method_bool,
     },
+    Builtin {
+        name: "bytes",
+        func: crate::value::bytes::Bytes::tokay_method_bytes,
+    },
+    Builtin {
+        name: "bytes_add",
+        func: crate::value::bytes::Bytes::tokay_method_bytes_add,
+    },
+    Builtin {
+        name: "bytes_decode",
+        func: crate::value::bytes::Bytes::tokay_method_bytes_decode,
+    },
+    Builtin {
+        name: "bytes_get_item",
+        func: crate::value::bytes::Bytes::tokay_method_bytes_get_item,
+    },
+    Builtin {
+        name: "bytes_hex",
+        func: crate::value::bytes::Bytes::tokay_method_bytes_hex,
+    },
+    Builtin {
+        name: "bytes_int",
+        func: crate::value::bytes::Bytes::tokay_method_bytes_int,
+    },
+    Builtin {
+        name: "bytes_len",
+        func: crate::value::bytes::Bytes::tokay_method_bytes_len,
+    },
+    Builtin {
+        name: "ceil",
+        func: crate::builtin::math::tokay_function_ceil,
+    },
     Builtin {
         name: "chr",
         func: crate::builtin::tokay_function_chr,
     },
+    Builtin {
+        name: "clock",
+        func: crate::value::time::tokay_function_clock,
+    },
+    Builtin {
+        name: "csv_format",
+        func: crate::builtin::csv::tokay_function_csv_format,
+    },
+    Builtin {
+        name: "csv_parse",
+        func: crate::builtin::csv::tokay_function_csv_parse,
+    },
     Builtin {
         name: "debug",
         func: crate::builtin::tokay_function_debug,
@@ -55,6 +159,14 @@ pub static BUILTINS: [Builtin; 64] = [
         name: "dict_clone",
         func: crate::value::dict::Dict::tokay_method_dict_clone,
     },
+    Builtin {
+        name: "dict_delete",
+        func: crate::value::dict::Dict::tokay_method_dict_delete,
+    },
+    Builtin {
+        name: "dict_get",
+        func: crate::value::dict::Dict::tokay_method_dict_get,
+    },
     Builtin {
         name: "dict_get_item",
         func: crate::value::dict::Dict::tokay_method_dict_get_item,
@@ -63,6 +175,10 @@ pub static BUILTINS: [Builtin; 64] = [
         name: "dict_items",
         func: crate::value::dict::Dict::tokay_method_dict_items,
     },
+    Builtin {
+        name: "dict_iter",
+        func: crate::value::dict::Dict::tokay_method_dict_iter,
+    },
     Builtin {
         name: "dict_keys",
         func: crate::value::dict::Dict::tokay_method_dict_keys,
@@ -87,6 +203,26 @@ pub static BUILTINS: [Builtin; 64] = [
         name: "dict_set_item",
         func: crate::value::dict::Dict::tokay_method_dict_set_item,
     },
+    Builtin {
+        name: "dict_update",
+        func: crate::value::dict::Dict::tokay_method_dict_update,
+    },
+    Builtin {
+        name: "dict_values",
+        func: crate::value::dict::Dict::tokay_method_dict_values,
+    },
+    Builtin {
+        name: "dump",
+        func: crate::builtin::tokay_function_dump,
+    },
+    Builtin {
+        name: "emit",
+        func: crate::builtin::tokay_function_emit,
+    },
+    Builtin {
+        name: "env",
+        func: crate::builtin::process::tokay_function_env,
+    },
     Builtin {
         name: "eof",
         func: crate::builtin::tokay_function_eof,
@@ -95,6 +231,14 @@ pub static BUILTINS: [Builtin; 64] = [
         name: "error",
         func: crate::error::tokay_function_error,
     },
+    Builtin {
+        name: "escape",
+        func: crate::builtin::escape::tokay_function_escape,
+    },
+    Builtin {
+        name: "fappend",
+        func: crate::builtin::file::tokay_function_fappend,
+    },
     Builtin {
         name: "float",
         func: crate::value::value::Value::tokay_method_float,
@@ -107,14 +251,42 @@ pub static BUILTINS: [Builtin; 64] = [
         name: "float_fract",
         func: crate::value::value::Value::tokay_method_float_fract,
     },
+    Builtin {
+        name: "float_loose",
+        func: crate::builtin::number::tokay_function_float_loose,
+    },
     Builtin {
         name: "float_trunc",
         func: crate::value::value::Value::tokay_method_float_trunc,
     },
+    Builtin {
+        name: "floor",
+        func: crate::builtin::math::tokay_function_floor,
+    },
+    Builtin {
+        name: "format",
+        func: crate::builtin::tokay_function_format,
+    },
+    Builtin {
+        name: "format_thousands",
+        func: crate::builtin::number::tokay_function_format_thousands,
+    },
+    Builtin {
+        name: "fread",
+        func: crate::builtin::file::tokay_function_fread,
+    },
+    Builtin {
+        name: "fwrite",
+        func: crate::builtin::file::tokay_function_fwrite,
+    },
     Builtin {
         name: "int",
         func: crate::value::value::Value::tokay_method_int,
     },
+    Builtin {
+        name: "int_loose",
+        func: crate::builtin::number::tokay_function_int_loose,
+    },
     Builtin {
         name: "iter",
         func: crate::value::iter::iter::Iter::tokay_method_iter,
@@ -143,6 +315,26 @@ pub static BUILTINS: [Builtin; 64] = [
         name: "iter_rev",
         func: crate::value::iter::iter::Iter::tokay_method_iter_rev,
     },
+    Builtin {
+        name: "json_decode",
+        func: crate::builtin::json::tokay_function_json_decode,
+    },
+    Builtin {
+        name: "json_encode",
+        func: crate::builtin::json::tokay_function_json_encode,
+    },
+    Builtin {
+        name: "len",
+        func: crate::builtin::tokay_function_len,
+    },
+    Builtin {
+        name: "line_number",
+        func: crate::builtin::tokay_function_line_number,
+    },
+    Builtin {
+        name: "line_text",
+        func: crate::builtin::tokay_function_line_text,
+    },
     Builtin {
         name: "list",
         func: crate::value::list::List::tokay_method_list,
@@ -151,6 +343,10 @@ pub static BUILTINS: [Builtin; 64] = [
         name: "list_add",
         func: crate::value::list::List::tokay_method_list_add,
     },
+    Builtin {
+        name: "list_contains",
+        func: crate::value::list::List::tokay_method_list_contains,
+    },
     Builtin {
         name: "list_flatten",
         func: crate::value::list::List::tokay_method_list_flatten,
@@ -163,6 +359,18 @@ pub static BUILTINS: [Builtin; 64] = [
         name: "list_iadd",
         func: crate::value::list::List::tokay_method_list_iadd,
     },
+    Builtin {
+        name: "list_index",
+        func: crate::value::list::List::tokay_method_list_index,
+    },
+    Builtin {
+        name: "list_insert",
+        func: crate::value::list::List::tokay_method_list_insert,
+    },
+    Builtin {
+        name: "list_join",
+        func: crate::value::list::List::tokay_method_list_join,
+    },
     Builtin {
         name: "list_len",
         func: crate::value::list::List::tokay_method_list_len,
@@ -175,6 +383,14 @@ pub static BUILTINS: [Builtin; 64] = [
         name: "list_push",
         func: crate::value::list::List::tokay_method_list_push,
     },
+    Builtin {
+        name: "list_remove",
+        func: crate::value::list::List::tokay_method_list_remove,
+    },
+    Builtin {
+        name: "list_reverse",
+        func: crate::value::list::List::tokay_method_list_reverse,
+    },
     Builtin {
         name: "list_set_item",
         func: crate::value::list::List::tokay_method_list_set_item,
@@ -183,6 +399,18 @@ pub static BUILTINS: [Builtin; 64] = [
         name: "list_sort",
         func: crate::value::list::List::tokay_method_list_sort,
     },
+    Builtin {
+        name: "max",
+        func: crate::builtin::math::tokay_function_max,
+    },
+    Builtin {
+        name: "min",
+        func: crate::builtin::math::tokay_function_min,
+    },
+    Builtin {
+        name: "nocache",
+        func: crate::builtin::tokay_function_nocache,
+    },
     Builtin {
         name: "offset",
         func: crate::builtin::tokay_function_offset,
@@ -191,6 +419,22 @@ pub static BUILTINS: [Builtin; 64] = [
         name: "ord",
         func: crate::builtin::tokay_function_ord,
     },
+    Builtin {
+        name: "parse",
+        func: crate::builtin::tokay_function_parse,
+    },
+    Builtin {
+        name: "parse_unit",
+        func: crate::builtin::number::tokay_function_parse_unit,
+    },
+    Builtin {
+        name: "parse_value",
+        func: crate::builtin::tokay_function_parse_value,
+    },
+    Builtin {
+        name: "pow",
+        func: crate::builtin::math::tokay_function_pow,
+    },
     Builtin {
         name: "print",
         func: crate::builtin::tokay_function_print,
@@ -199,10 +443,46 @@ pub static BUILTINS: [Builtin; 64] = [
         name: "range",
         func: crate::builtin::range::tokay_function_range,
     },
+    Builtin {
+        name: "range_get_item",
+        func: crate::builtin::range::Range::tokay_method_range_get_item,
+    },
+    Builtin {
+        name: "range_iter",
+        func: crate::builtin::range::Range::tokay_method_range_iter,
+    },
+    Builtin {
+        name: "range_len",
+        func: crate::builtin::range::Range::tokay_method_range_len,
+    },
+    Builtin {
+        name: "range_map",
+        func: crate::builtin::range::Range::tokay_method_range_map,
+    },
+    Builtin {
+        name: "range_rev",
+        func: crate::builtin::range::Range::tokay_method_range_rev,
+    },
+    Builtin {
+        name: "reject_if",
+        func: crate::builtin::tokay_function_reject_if,
+    },
     Builtin {
         name: "repr",
         func: crate::builtin::tokay_function_repr,
     },
+    Builtin {
+        name: "round",
+        func: crate::builtin::math::tokay_function_round,
+    },
+    Builtin {
+        name: "snapshot",
+        func: crate::builtin::tokay_function_snapshot,
+    },
+    Builtin {
+        name: "sqrt",
+        func: crate::builtin::math::tokay_function_sqrt,
+    },
     Builtin {
         name: "str",
         func: crate::value::str::Str::tokay_method_str,
@@ -219,10 +499,22 @@ pub static BUILTINS: [Builtin; 64] = [
         name: "str_endswith",
         func: crate::value::str::Str::tokay_method_str_endswith,
     },
+    Builtin {
+        name: "str_find",
+        func: crate::value::str::Str::tokay_method_str_find,
+    },
+    Builtin {
+        name: "str_format",
+        func: crate::value::str::Str::tokay_method_str_format,
+    },
     Builtin {
         name: "str_get_item",
         func: crate::value::str::Str::tokay_method_str_get_item,
     },
+    Builtin {
+        name: "str_iadd",
+        func: crate::value::str::Str::tokay_method_str_iadd,
+    },
     Builtin {
         name: "str_join",
         func: crate::value::str::Str::tokay_method_str_join,
@@ -235,6 +527,10 @@ pub static BUILTINS: [Builtin; 64] = [
         name: "str_lower",
         func: crate::value::str::Str::tokay_method_str_lower,
     },
+    Builtin {
+        name: "str_ltrim",
+        func: crate::value::str::Str::tokay_method_str_ltrim,
+    },
     Builtin {
         name: "str_mul",
         func: crate::value::str::Str::tokay_method_str_mul,
@@ -243,6 +539,10 @@ pub static BUILTINS: [Builtin; 64] = [
         name: "str_replace",
         func: crate::value::str::Str::tokay_method_str_replace,
     },
+    Builtin {
+        name: "str_rtrim",
+        func: crate::value::str::Str::tokay_method_str_rtrim,
+    },
     Builtin {
         name: "str_split",
         func: crate::value::str::Str::tokay_method_str_split,
@@ -255,13 +555,49 @@ pub static BUILTINS: [Builtin; 64] = [
         name: "str_substr",
         func: crate::value::str::Str::tokay_method_str_substr,
     },
+    Builtin {
+        name: "str_trim",
+        func: crate::value::str::Str::tokay_method_str_trim,
+    },
     Builtin {
         name: "str_upper",
         func: crate::value::str::Str::tokay_method_str_upper,
     },
+    Builtin {
+        name: "time",
+        func: crate::value::time::Time::tokay_method_time,
+    },
+    Builtin {
+        name: "time_add",
+        func: crate::value::time::Time::tokay_method_time_add,
+    },
+    Builtin {
+        name: "time_format",
+        func: crate::value::time::Time::tokay_method_time_format,
+    },
+    Builtin {
+        name: "time_get_item",
+        func: crate::value::time::Time::tokay_method_time_get_item,
+    },
+    Builtin {
+        name: "time_sub",
+        func: crate::value::time::Time::tokay_method_time_sub,
+    },
+    Builtin {
+        name: "time_timestamp",
+        func: crate::value::time::Time::tokay_method_time_timestamp,
+    },
+    Builtin {
+        name: "time_utc",
+        func: crate::value::time::Time::tokay_method_time_utc,
+    },
     Builtin {
         name: "type",
         func: crate::builtin::tokay_function_type,
     },
+    Builtin {
+        name: "unescape",
+        func: crate::builtin::escape::tokay_function_unescape,
+    },
 ];
 /*ETARENEG*/
@@ -0,0 +1,135 @@
+//! Reading and writing of delimiter-separated values
+use crate::value::{List, Object, RefValue, Value};
+use crate::Error;
+use tokay_macros::tokay_function;
+extern crate self as tokay;
+
+/** Parses delimiter-separated values into rows of fields.
+
+Fields enclosed by double quotes may contain the delimiter, line breaks and double quotes, which
+are written twice. Rows are separated by "\n" or "\r\n", a line break at the end doesn't start
+another row. */
+pub fn parse(s: &str, delimiter: char) -> Result<Vec<Vec<String>>, String> {
+    let mut rows = Vec::new();
+    let mut row = Vec::new();
+    let mut field = String::new();
+    let mut chars = s.chars().peekable();
+
+    while let Some(ch) = chars.next() {
+        match ch {
+            '"' if field.is_empty() => loop {
+                match chars.next() {
+                    Some('"') if chars.peek() == Some(&'"') => {
+                        chars.next();
+                        field.push('"');
+                    }
+                    Some('"') => break,
+                    Some(ch) => field.push(ch),
+                    None => {
+                        return Err(format!("row {} has an unterminated quote", rows.len() + 1))
+                    }
+                }
+            },
+            ch if ch == delimiter => row.push(std::mem::take(&mut field)),
+            '\r' if chars.peek() == Some(&'\n') => {}
+            '\n' => {
+                row.push(std::mem::take(&mut field));
+                rows.push(std::mem::take(&mut row));
+            }
+            ch => field.push(ch),
+        }
+    }
+
+    if !row.is_empty() || !field.is_empty() {
+        row.push(field);
+        rows.push(row);
+    }
+
+    Ok(rows)
+}
+
+/// Formats rows of fields as delimiter-separated values, quoting fields where required.
+pub fn format(rows: &[Vec<String>], delimiter: char) -> String {
+    let mut ret = String::new();
+
+    for row in rows {
+        for (i, field) in row.iter().enumerate() {
+            if i > 0 {
+                ret.push(delimiter);
+            }
+
+            if field.contains([delimiter, '"', '\r', '\n']) {
+                ret.push('"');
+                ret.push_str(&field.replace('"', "\"\""));
+                ret.push('"');
+            } else {
+                ret.push_str(field);
+            }
+        }
+
+        ret.push('\n');
+    }
+
+    ret
+}
+
+// Retrieves the delimiter argument, which must be a single character
+fn get_delimiter(delimiter: RefValue) -> Result<char, String> {
+    if delimiter.is_void() {
+        return Ok(',');
+    }
+
+    let delimiter = delimiter.to_string();
+    let mut chars = delimiter.chars();
+
+    match (chars.next(), chars.next()) {
+        (Some(ch), None) if !matches!(ch, '"' | '\r' | '\n') => Ok(ch),
+        _ => Err(format!(
+            "delimiter '{}' must be a single character",
+            delimiter
+        )),
+    }
+}
+
+tokay_function!("csv_parse : @s, delimiter=void", {
+    let rows = get_delimiter(delimiter).and_then(|delimiter| parse(&s.to_string(), delimiter));
+
+    match rows {
+        Ok(rows) => RefValue::from(
+            rows.into_iter()
+                .map(|row| RefValue::from(row.into_iter().map(RefValue::from).collect::<Vec<_>>()))
+                .collect::<Vec<_>>(),
+        )
+        .into(),
+        Err(msg) => Error::from(format!("{} {}", __function, msg)).into(),
+    }
+});
+
+tokay_function!("csv_format : @rows, delimiter=void", {
+    let delimiter = match get_delimiter(delimiter) {
+        Ok(delimiter) => delimiter,
+        Err(msg) => return Error::from(format!("{} {}", __function, msg)).into(),
+    };
+
+    // Void and null become empty fields, any other values their string representation
+    let field = |value: &RefValue| {
+        if matches!(&*value.borrow(), Value::Void | Value::Null) {
+            String::new()
+        } else {
+            value.to_string()
+        }
+    };
+
+    // Values which aren't lists are rows with a single field
+    let fields = |value: &RefValue| match value.borrow().object::<List>() {
+        Some(list) => list.iter().map(field).collect(),
+        None => vec![field(value)],
+    };
+
+    let rows: Vec<Vec<String>> = match rows.borrow().object::<List>() {
+        Some(rows) => rows.iter().map(fields).collect(),
+        None => vec![fields(&rows)],
+    };
+
+    RefValue::from(format(&rows, delimiter)).into()
+});
@@ -0,0 +1,155 @@
+//! Processing of escape sequences, and escaping of strings for different targets
+use crate::value::{Object, RefValue};
+use crate::Error;
+use tokay_macros::tokay_function;
+extern crate self as tokay;
+
+/** Resolves the escape sequence at the start of `s`, which follows a backslash.
+
+Named sequences are `\a`, `\b`, `\f`, `\n`, `\r`, `\t` and `\v`. Encoded sequences are three
+octal digits `\ooo`, `\xHH`, `\uHHHH` and `\UHHHHHHHH`. Any other character stands for itself.
+Returns the resolved character and the length of the sequence within `s`. */
+fn unescape_sequence(s: &str) -> Option<(char, usize)> {
+    // Decodes exactly `count` digits of `radix` following a prefix of `skip` bytes
+    let encoded = |skip: usize, count: usize, radix: u32| {
+        let digits = s.get(skip..skip + count)?;
+
+        if !digits.chars().all(|ch| ch.is_digit(radix)) {
+            return None;
+        }
+
+        u32::from_str_radix(digits, radix)
+            .ok()
+            .and_then(char::from_u32)
+            .map(|ch| (ch, skip + count))
+    };
+
+    let ch = s.chars().next()?;
+
+    Some(match ch {
+        'a' => ('\x07', 1),
+        'b' => ('\x08', 1),
+        'f' => ('\x0c', 1),
+        'n' => ('\n', 1),
+        'r' => ('\r', 1),
+        't' => ('\t', 1),
+        'v' => ('\x0b', 1),
+        '0'..='7' => encoded(0, 3, 8).unwrap_or((ch, 1)),
+        'x' => encoded(1, 2, 16).unwrap_or((ch, 1)),
+        'u' => encoded(1, 4, 16).unwrap_or((ch, 1)),
+        'U' => encoded(1, 8, 16).unwrap_or((ch, 1)),
+        ch => (ch, ch.len_utf8()),
+    })
+}
+
+/// Resolves all escape sequences in `s`, as they are written in Tokay strings.
+pub fn unescape(s: &str) -> String {
+    let mut ret = String::with_capacity(s.len());
+    let mut rest = s;
+
+    while let Some(pos) = rest.find('\\') {
+        ret.push_str(&rest[..pos]);
+        rest = &rest[pos + 1..];
+
+        match unescape_sequence(rest) {
+            Some((ch, len)) => {
+                ret.push(ch);
+                rest = &rest[len..];
+            }
+            // A trailing backslash is kept
+            None => ret.push('\\'),
+        }
+    }
+
+    ret.push_str(rest);
+    ret
+}
+
+/** Escapes `s` in a given style, which defaults to "tokay" when called as builtin.
+
+- "tokay" escapes backslashes, double quotes and control characters, so that the result can be
+  placed between double quotes in Tokay code and resolved by `unescape()` again.
+- "json" escapes the string for a JSON string literal.
+- "shell" quotes the string for a POSIX shell, when it contains any characters the shell treats
+  specially. */
+pub fn escape(s: &str, style: &str) -> Result<String, String> {
+    let mut ret = String::with_capacity(s.len());
+
+    match style {
+        "tokay" => {
+            for ch in s.chars() {
+                match ch {
+                    '\\' => ret.push_str("\\\\"),
+                    '"' => ret.push_str("\\\""),
+                    '\x07' => ret.push_str("\\a"),
+                    '\x08' => ret.push_str("\\b"),
+                    '\x0c' => ret.push_str("\\f"),
+                    '\n' => ret.push_str("\\n"),
+                    '\r' => ret.push_str("\\r"),
+                    '\t' => ret.push_str("\\t"),
+                    '\x0b' => ret.push_str("\\v"),
+                    ch if ch.is_control() => ret.push_str(&format!("\\x{:02x}", ch as u32)),
+                    ch => ret.push(ch),
+                }
+            }
+        }
+        "json" => {
+            for ch in s.chars() {
+                match ch {
+                    '\\' => ret.push_str("\\\\"),
+                    '"' => ret.push_str("\\\""),
+                    '\x08' => ret.push_str("\\b"),
+                    '\x0c' => ret.push_str("\\f"),
+                    '\n' => ret.push_str("\\n"),
+                    '\r' => ret.push_str("\\r"),
+                    '\t' => ret.push_str("\\t"),
+                    ch if (ch as u32) < 0x20 => ret.push_str(&format!("\\u{:04x}", ch as u32)),
+                    ch => ret.push(ch),
+                }
+            }
+        }
+        "shell" => {
+            if !s.is_empty()
+                && s.chars().all(|ch| {
+                    ch.is_ascii_alphanumeric()
+                        || matches!(
+                            ch,
+                            '_' | '-' | '.' | '/' | ':' | '=' | '@' | '%' | '+' | ','
+                        )
+                })
+            {
+                return Ok(s.to_string());
+            }
+
+            ret.push('\'');
+            ret.push_str(&s.replace('\'', "'\\''"));
+            ret.push('\'');
+        }
+        style => {
+            return Err(format!(
+                "style '{}' is unknown, use 'tokay', 'json' or 'shell'",
+                style
+            ))
+        }
+    }
+
+    Ok(ret)
+}
+
+tokay_function!(
+    "unescape : @s",
+    RefValue::from(unescape(&s.to_string())).into()
+);
+
+tokay_function!("escape : @s, style=void", {
+    let style = if style.is_void() {
+        "tokay".to_string()
+    } else {
+        style.to_string()
+    };
+
+    match escape(&s.to_string(), &style) {
+        Ok(s) => RefValue::from(s).into(),
+        Err(msg) => Error::from(format!("{} {}", __function, msg)).into(),
+    }
+});
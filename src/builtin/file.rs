@@ -0,0 +1,59 @@
+//! Reading and writing of files
+use crate::value;
+use crate::value::{Object, RefValue};
+use crate::Error;
+use std::fs::OpenOptions;
+use std::io::Write;
+use tokay_macros::tokay_function;
+extern crate self as tokay;
+
+// Writes the string of a value into a file, which is either truncated or appended
+fn write(path: &str, value: &RefValue, append: bool) -> std::io::Result<()> {
+    let mut file = OpenOptions::new()
+        .create(true)
+        .write(true)
+        .append(append)
+        .truncate(!append)
+        .open(path)?;
+
+    file.write_all(value.to_string().as_bytes())
+}
+
+tokay_function!("fread : @path", {
+    let path = path.to_string();
+
+    match std::fs::read_to_string(&path) {
+        Ok(content) => RefValue::from(content).into(),
+        Err(err) => Error::from(format!("{} can't read '{}': {}", __function, path, err)).into(),
+    }
+});
+
+tokay_function!("fwrite : @path, value", {
+    if let Some(context) = context {
+        context.thread.side_effects += 1;
+    }
+
+    let path = path.to_string();
+
+    match write(&path, &value, false) {
+        Ok(()) => value!(void).into(),
+        Err(err) => Error::from(format!("{} can't write '{}': {}", __function, path, err)).into(),
+    }
+});
+
+tokay_function!("fappend : @path, value", {
+    if let Some(context) = context {
+        context.thread.side_effects += 1;
+    }
+
+    let path = path.to_string();
+
+    match write(&path, &value, true) {
+        Ok(()) => value!(void).into(),
+        Err(err) => Error::from(format!(
+            "{} can't append to '{}': {}",
+            __function, path, err
+        ))
+        .into(),
+    }
+});
@@ -0,0 +1,55 @@
+//! Conversion of values into JSON text and back
+use crate::value::{Object, RefValue};
+use crate::Error;
+use serde::Serialize;
+use serde_json::ser::PrettyFormatter;
+use tokay_macros::tokay_function;
+extern crate self as tokay;
+
+/** Encodes a value as JSON text, indented by a number of spaces per level when given.
+
+Dicts become objects, lists become arrays, and void and null become null. Integers exceeding
+64 bits are encoded as strings, any other objects like parselets by their repr. */
+pub fn encode(value: &RefValue, indent: Option<usize>) -> Result<String, String> {
+    let json = match indent {
+        None => serde_json::to_vec(value),
+        Some(indent) => {
+            let indent = " ".repeat(indent);
+            let mut json = Vec::new();
+            let mut serializer = serde_json::Serializer::with_formatter(
+                &mut json,
+                PrettyFormatter::with_indent(indent.as_bytes()),
+            );
+
+            value.serialize(&mut serializer).map(|_| json)
+        }
+    };
+
+    json.map(|json| String::from_utf8(json).expect("JSON is valid UTF-8"))
+        .map_err(|err| err.to_string())
+}
+
+/// Decodes JSON text into a value, where objects become dicts and arrays become lists.
+pub fn decode(json: &str) -> Result<RefValue, String> {
+    serde_json::from_str(json).map_err(|err| err.to_string())
+}
+
+tokay_function!("json_encode : @value, indent=void", {
+    let indent = if indent.is_void() {
+        None
+    } else {
+        Some(indent.to_usize()?)
+    };
+
+    match encode(&value, indent) {
+        Ok(json) => RefValue::from(json).into(),
+        Err(msg) => Error::from(format!("{} {}", __function, msg)).into(),
+    }
+});
+
+tokay_function!("json_decode : @json", {
+    match decode(&json.to_string()) {
+        Ok(value) => value.into(),
+        Err(msg) => Error::from(format!("{} {}", __function, msg)).into(),
+    }
+});
@@ -0,0 +1,146 @@
+//! Mathematical functions on integers and floats
+use crate::value::{ConversionError, List, Object, Overflow, RefValue, Value};
+use crate::Error;
+use num::{FromPrimitive, Signed, ToPrimitive};
+use num_bigint::BigInt;
+use tokay_macros::tokay_function;
+extern crate self as tokay;
+
+// A value coerced into a number
+enum Number {
+    Int(BigInt),
+    Float(f64),
+}
+
+/** Coerces a value into a number.
+
+Floats stay floats, and strings are parsed into an integer or a float. Any other values are
+converted into integers like by the arithmetic operators, so `true` becomes 1. */
+fn number(value: &RefValue) -> Result<Number, ConversionError> {
+    match &*value.borrow() {
+        Value::Float(f) => Ok(Number::Float(*f)),
+        Value::Object(object) if object.is("str") => {
+            let s = object.to_string();
+
+            match s.trim().parse::<BigInt>() {
+                Ok(int) => Ok(Number::Int(int)),
+                Err(_) => Ok(Number::Float(object.to_f64()?)),
+            }
+        }
+        value => Ok(Number::Int(value.to_bigint()?)),
+    }
+}
+
+// Converts a float into an integer, which must be finite
+fn float_to_int(f: f64) -> Result<BigInt, ConversionError> {
+    BigInt::from_f64(f).ok_or_else(|| ConversionError::out_of_range("float", "int", f.to_string()))
+}
+
+// Selects the least or greatest of the arguments, or of the items of a single list argument
+fn select(args: Vec<RefValue>, greatest: bool) -> Option<RefValue> {
+    let items = match args.first() {
+        Some(list) if args.len() == 1 && list.is("list") => list
+            .borrow()
+            .object::<List>()
+            .unwrap()
+            .iter()
+            .cloned()
+            .collect(),
+        _ => args,
+    };
+
+    items.into_iter().reduce(|selected, item| {
+        if (greatest && item > selected) || (!greatest && item < selected) {
+            item
+        } else {
+            selected
+        }
+    })
+}
+
+tokay_function!("abs : @x", {
+    match number(&x)? {
+        Number::Int(int) => RefValue::from(int.abs()).into(),
+        Number::Float(f) => RefValue::from(f.abs()).into(),
+    }
+});
+
+tokay_function!("min : @*args", {
+    match select(args, false) {
+        Some(value) => value.into(),
+        None => Error::from(format!("{} expects at least one value", __function)).into(),
+    }
+});
+
+tokay_function!("max : @*args", {
+    match select(args, true) {
+        Some(value) => value.into(),
+        None => Error::from(format!("{} expects at least one value", __function)).into(),
+    }
+});
+
+tokay_function!("pow : @base, exp", {
+    match (number(&base)?, number(&exp)?) {
+        // Integer powers with a non-negative integer exponent stay integers
+        (Number::Int(base), Number::Int(exp)) if !exp.is_negative() => {
+            let Some(exp) = exp.to_u32() else {
+                return Error::from(format!("{} exponent {} is too large", __function, exp)).into();
+            };
+
+            let overflow =
+                context.map_or(Overflow::Promote, |context| context.thread.options.overflow);
+            RefValue::from(overflow.apply(base.pow(exp))?).into()
+        }
+        (base, exp) => {
+            let float = |number| match number {
+                Number::Int(int) => int
+                    .to_f64()
+                    .ok_or_else(|| ConversionError::out_of_range("int", "f64", int.to_string())),
+                Number::Float(f) => Ok(f),
+            };
+
+            RefValue::from(float(base)?.powf(float(exp)?)).into()
+        }
+    }
+});
+
+tokay_function!("sqrt : @x", {
+    let f = match number(&x)? {
+        Number::Int(int) => int
+            .to_f64()
+            .ok_or_else(|| ConversionError::out_of_range("int", "f64", int.to_string()))?,
+        Number::Float(f) => f,
+    };
+
+    if f < 0.0 {
+        return Error::from(format!("{} of negative number {}", __function, x.repr())).into();
+    }
+
+    RefValue::from(f.sqrt()).into()
+});
+
+tokay_function!("floor : @x", {
+    match number(&x)? {
+        Number::Int(int) => RefValue::from(int).into(),
+        Number::Float(f) => RefValue::from(float_to_int(f.floor())?).into(),
+    }
+});
+
+tokay_function!("ceil : @x", {
+    match number(&x)? {
+        Number::Int(int) => RefValue::from(int).into(),
+        Number::Float(f) => RefValue::from(float_to_int(f.ceil())?).into(),
+    }
+});
+
+tokay_function!("round : @x, digits=void", {
+    match number(&x)? {
+        Number::Int(int) => RefValue::from(int).into(),
+        // Without digits, floats are rounded half away from zero into an integer
+        Number::Float(f) if digits.is_void() => RefValue::from(float_to_int(f.round())?).into(),
+        Number::Float(f) => {
+            let factor = 10f64.powi(digits.to_i64()?.clamp(-308, 308) as i32);
+            RefValue::from((f * factor).round() / factor).into()
+        }
+    }
+});
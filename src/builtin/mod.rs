@@ -1,29 +1,68 @@
 //! Tokay built-in functions
 use crate::_builtins::BUILTINS;
 use crate::value;
-use crate::value::{Dict, Object, RefValue, Value};
-use crate::{Accept, Context, Reject};
+use crate::value::{Dict, List, Object, ParseletRef, RefValue, Str, Value};
+use crate::vm::Thread;
+use crate::{Accept, Capture, Context, Error, Reader, Reject};
 use std::io::{self, Write};
 extern crate self as tokay;
 use tokay_macros::tokay_function;
+pub mod csv;
+pub mod escape;
+pub mod file;
+pub mod json;
+pub mod math;
+pub mod net;
+pub mod number;
+pub mod process;
 pub mod range;
+pub mod timestamp;
+
+// Signature of a built-in function
+pub type BuiltinFn =
+    fn(Option<&mut Context>, Vec<RefValue>, Option<Dict>) -> Result<Accept, Reject>;
+
+// Names of built-in functions touching the environment, which aren't available in sandbox mode
+const ENVIRONMENT: &[&str] = &[
+    "ast_print",
+    "debug",
+    "dump",
+    "env",
+    "fappend",
+    "fread",
+    "fwrite",
+    "print",
+];
 
 // Abstraction of a built-in function
 pub struct Builtin {
     pub name: &'static str, // Function's external name
-    pub func: fn(Option<&mut Context>, Vec<RefValue>, Option<Dict>) -> Result<Accept, Reject>, // Function
+    pub func: BuiltinFn,    // Function
 }
 
 impl Builtin {
     /// Retrieve builtin by name
     pub fn get(ident: &str) -> Option<&'static Builtin> {
-        for builtin in &BUILTINS {
-            if builtin.name == ident {
-                return Some(builtin);
+        // The registry is generated sorted by name
+        BUILTINS
+            .binary_search_by(|builtin| builtin.name.cmp(ident))
+            .ok()
+            .map(|index| &BUILTINS[index])
+    }
+
+    /// Checks if the builtin touches the environment, like by writing to stdout.
+    pub fn touches_environment(&self) -> bool {
+        ENVIRONMENT.contains(&self.name)
+    }
+
+    /// Fails when the builtin touches the environment, but the program runs in sandbox mode.
+    fn check_sandbox(&self, context: Option<&Context>) -> Result<(), Reject> {
+        match context {
+            Some(context) if context.thread.program.sandbox && self.touches_environment() => {
+                Err(format!("{}() isn't available in sandbox mode", self.name).into())
             }
+            _ => Ok(()),
         }
-
-        None
     }
 
     /** Checks for a method on a value given by value type and method name.
@@ -35,19 +74,18 @@ impl Builtin {
     same function.
     */
     pub fn get_method(type_name: &str, method_name: &str) -> Result<&'static Builtin, String> {
-        for builtin in &BUILTINS {
-            // todo: This stupid stuff finds the method name without allocating a string.
-            // I'm sure this could be done better in some way...
-            if builtin.name.starts_with(type_name)
-                && builtin.name.ends_with(method_name)
-                && builtin.name.len() == type_name.len() + method_name.len() + 1
-                && builtin.name.chars().nth(type_name.len()) == Some('_')
-            {
-                return Ok(builtin);
-            }
+        // Compares the names bytewise to `<type>_<method>`, without allocating a string
+        let name = || {
+            type_name
+                .bytes()
+                .chain(std::iter::once(b'_'))
+                .chain(method_name.bytes())
+        };
+
+        match BUILTINS.binary_search_by(|builtin| builtin.name.bytes().cmp(name())) {
+            Ok(index) => Ok(&BUILTINS[index]),
+            Err(_) => Err(format!("Method '{}_{}' not found", type_name, method_name)),
         }
-
-        Err(format!("Method '{}_{}' not found", type_name, method_name))
     }
 
     /// Directly call builtin without context and specified parameters.
@@ -92,6 +130,7 @@ impl Object for BuiltinRef {
         args: Vec<RefValue>,
         nargs: Option<Dict>,
     ) -> Result<Accept, Reject> {
+        self.0.check_sandbox(context.as_deref())?;
         (self.0.func)(context, args, nargs)
     }
 
@@ -101,6 +140,7 @@ impl Object for BuiltinRef {
         args: usize,
         nargs: Option<Dict>,
     ) -> Result<Accept, Reject> {
+        self.0.check_sandbox(Some(context))?;
         let args = context.drain(args);
         (self.0.func)(Some(context), args, nargs)
     }
@@ -134,11 +174,12 @@ impl From<&'static Builtin> for RefValue {
 // Global built-ins
 
 tokay_function!("chr : @i", {
-    RefValue::from(format!(
-        "{}",
-        std::char::from_u32(i.to_usize()? as u32).unwrap()
-    ))
-    .into()
+    let code = i.to_usize()?;
+
+    match u32::try_from(code).ok().and_then(std::char::from_u32) {
+        Some(ch) => RefValue::from(ch).into(),
+        None => Err(format!("{} invalid character code {}", __function, code).into()),
+    }
 });
 
 tokay_function!("ord : @c", {
@@ -156,6 +197,12 @@ tokay_function!("ord : @c", {
 });
 
 tokay_function!("print : @*args", {
+    let mut context = context;
+
+    if let Some(context) = context.as_mut() {
+        context.thread.side_effects += 1;
+    }
+
     if args.len() == 0 && context.is_some() {
         let context = context.unwrap();
 
@@ -179,27 +226,107 @@ tokay_function!("print : @*args", {
     value!(void).into() // need to push a void with high severity
 });
 
-tokay_function!("repr : @value", value!(value.repr()).into());
+tokay_function!("parse_value : @s", {
+    match RefValue::from_literal(&s.to_string()) {
+        Ok(value) => value.into(),
+        Err(msg) => Err(format!("{} {}", __function, msg).into()),
+    }
+});
+
+tokay_function!("repr : @value", {
+    // Prefer the re-parseable literal syntax, and fall back to repr() for values without one
+    match value.to_literal(None) {
+        Ok(literal) => value!(literal).into(),
+        Err(_) => value!(value.repr()).into(),
+    }
+});
+
+tokay_function!("format : @fmt, *args, **nargs", {
+    match Str::from(fmt.to_string()).format(&args, nargs.as_ref()) {
+        Ok(string) => value!(string).into(),
+        Err(msg) => Error::from(format!("{} {}", __function, msg)).into(),
+    }
+});
+
+tokay_function!(
+    "snapshot : @",
+    RefValue::from(&context.unwrap().snapshot()).into()
+);
+
+tokay_function!("dump : @", {
+    eprintln!("{}", context.unwrap().snapshot());
+    value!(void).into()
+});
 tokay_function!("type : @value", value!(value.name()).into());
 
-tokay_function!("debug : @level", {
-    if let Ok(level) = level.to_usize() {
-        if level < u8::MAX as usize {
-            let context = context.unwrap();
-            context.debug = level as u8;
-            //context.thread.debug = level as u8;
-            return Ok(Accept::Next);
+tokay_function!("len : @value", {
+    // Dispatch to the len-method of the value's type, like str_len or list_len
+    match value.call_method("len", context, Vec::new()) {
+        Ok(Some(len)) => len.into(),
+        Ok(None) | Err(_) => Error::from(format!(
+            "{} value of type '{}' has no length",
+            __function,
+            value.name()
+        ))
+        .into(),
+    }
+});
+
+/** Renders a value for debugging, where every value is prefixed by its type.
+
+Lists and dicts are rendered recursively, like `list (int 1, str "a")`, so that the types of
+nested values are visible. Values without a literal syntax use their `repr()`. */
+fn debug_repr(value: &RefValue) -> String {
+    let inner = value.borrow();
+
+    if let Some(list) = inner.object::<List>() {
+        let items: Vec<String> = list.iter().map(debug_repr).collect();
+
+        if items.len() < 2 {
+            format!("list ({}, )", items.join(""))
+        } else {
+            format!("list ({})", items.join(", "))
+        }
+    } else if let Some(dict) = inner.object::<Dict>() {
+        let items: Vec<String> = dict
+            .iter()
+            .map(|(key, value)| format!("{} => {}", key.repr(), debug_repr(value)))
+            .collect();
+
+        format!("dict ({})", items.join(" "))
+    } else if let Value::Void | Value::Null = &*inner {
+        inner.name().to_string()
+    } else {
+        let literal = value.to_literal(None).unwrap_or_else(|_| inner.repr());
+        format!("{} {}", inner.name(), literal)
+    }
+}
+
+tokay_function!("debug : @value=void, level=void", {
+    // With a level, the debug level of the current parselet is set
+    if !level.is_void() {
+        if let Ok(level) = level.to_usize() {
+            if level < u8::MAX as usize {
+                let context = context.unwrap();
+                context.debug = level as u8;
+                //context.thread.debug = level as u8;
+                return Ok(Accept::Next);
+            }
         }
+
+        return Err(Reject::from(format!(
+            "{}: Invalid setting level={:?}",
+            __function, level
+        )));
     }
 
-    Err(Reject::from(format!(
-        "{}: Invalid setting level={:?}",
-        __function, level
-    )))
+    eprintln!("{}", debug_repr(&value));
+    value!(void).into()
 });
 
 tokay_function!("offset : @", {
     let reader = &context.unwrap().thread.reader;
+    let start = reader.start();
     let offset = reader.tell();
     let filename = if let Some(filename) = &reader.filename {
         value!(filename.clone())
@@ -209,13 +336,161 @@ tokay_function!("offset : @", {
 
     value!([
         "filename" => filename,
-        "offset" => (offset.offset),
+        "offset" => (start.offset + offset.offset),
         "row" => (offset.row),
         "col" => (offset.col)
     ])
     .into()
 });
 
+tokay_function!("line_number : @", {
+    let reader = &context.unwrap().thread.reader;
+
+    if reader.separator.is_some() {
+        value!(reader.record).into()
+    } else {
+        value!(reader.tell().row).into()
+    }
+});
+
+tokay_function!("line_text : @", {
+    value!(context.unwrap().thread.reader.current_record().to_string()).into()
+});
+
 tokay_function!("eof : @", {
     value!(context.unwrap().thread.reader.eof()).into()
 });
+
+tokay_function!("emit : @key, value", {
+    // Accumulate value into the thread's list for key, which is undone when the parselet rejects
+    let context = context.unwrap();
+    context.thread.side_effects += 1;
+    context.thread.emits.push((key, value));
+    Ok(Accept::Push(Capture::Empty))
+});
+
+tokay_function!("parse : @parselet, input", {
+    // Run a parselet on a str, or on a list of values like the tokens produced by another parselet
+    let context = context.unwrap();
+
+    let parselet = if let Some(parselet) = parselet.borrow().object::<ParseletRef>() {
+        parselet.clone()
+    } else {
+        return Error::new(
+            None,
+            format!("parse() expects a parselet, got '{}'", parselet.name()),
+        )
+        .into();
+    };
+
+    let mut reader = if let Some(list) = input.borrow().object::<List>() {
+        Reader::from_values(list.iter().cloned().collect())
+    } else {
+        Reader::new(None, Box::new(io::Cursor::new(input.to_string())))
+    };
+
+    let mut thread = Thread::new(context.thread.program, vec![&mut reader]);
+    thread.debug = context.thread.debug;
+    thread.globals = std::mem::take(&mut context.thread.globals);
+
+    let result = parselet
+        .0
+        .borrow()
+        .run(&mut thread, Vec::new(), None, false, 0);
+
+    context.thread.globals = std::mem::take(&mut thread.globals);
+
+    match result {
+        Ok(Accept::Push(mut capture)) => Ok(Accept::Push(Capture::Value(
+            capture.extract(thread.reader),
+            None,
+            10,
+        ))),
+        Err(Reject::Error(error)) => Err(Reject::Error(error)),
+        _ => value!(void).into(),
+    }
+});
+
+tokay_function!("behind : @p, max=1", {
+    // Check whether p matches the input consumed before the reader's offset, up to max characters
+    let context = context.unwrap();
+
+    if !p.is_callable(true) {
+        return Error::new(
+            None,
+            format!("behind() expects a callable, got '{}'", p.name()),
+        )
+        .into();
+    }
+
+    let max = max.to_usize()?;
+
+    // Keep at least max characters on commit, so consumed input is available on streamed input
+    let reader = &mut context.thread.reader;
+    reader.lookbehind = reader.lookbehind.max(max);
+
+    let consumed = reader.behind(max).to_string();
+
+    // Try the shortest suffixes first
+    for (start, _) in consumed.char_indices().rev() {
+        let input = &consumed[start..];
+        let mut reader = Reader::new(None, Box::new(io::Cursor::new(input.to_string())));
+
+        let mut thread = Thread::new(context.thread.program, vec![&mut reader]);
+        thread.debug = context.thread.debug;
+        thread.options = context.thread.options.clone();
+        thread.globals = std::mem::take(&mut context.thread.globals);
+
+        let result = p.call(
+            Some(&mut Context::new(
+                &mut thread,
+                context.parselet,
+                context.depth + 1,
+                Vec::new(),
+            )),
+            Vec::new(),
+            None,
+        );
+
+        context.thread.globals = std::mem::take(&mut thread.globals);
+
+        match result {
+            // The suffix must be matched completely
+            Ok(_) if thread.reader.tell().offset == input.len() => {
+                return Ok(Accept::Push(Capture::Empty))
+            }
+            Err(Reject::Error(error)) => return Err(Reject::Error(error)),
+            _ => {}
+        }
+    }
+
+    Err(Reject::Next)
+});
+
+tokay_function!("nocache : @", {
+    // Prevent memoization of the calling parselets, as they cause side effects
+    context.unwrap().thread.side_effects += 1;
+    Ok(Accept::Push(Capture::Empty))
+});
+
+tokay_function!("accept_if : @cond, value=void", {
+    // Accept the current parselet when cond is true, like the `accept` keyword does
+    if cond.is_true() {
+        Ok(Accept::Return(if value.is_void() {
+            Capture::Empty
+        } else {
+            value.into()
+        }))
+    } else {
+        Ok(Accept::Push(Capture::Empty))
+    }
+});
+
+tokay_function!("reject_if : @cond", {
+    // Reject the current sequence when cond is true, continuing with the next alternative
+    if cond.is_true() {
+        Err(Reject::Next)
+    } else {
+        Ok(Accept::Push(Capture::Empty))
+    }
+});
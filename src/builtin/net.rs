@@ -0,0 +1,511 @@
+//! Built-in tokens for IP addresses, MAC addresses, URLs and email addresses
+use crate::reader::Reader;
+use crate::value::{Dict, Object, RefValue};
+use crate::{Accept, Capture, Reject};
+use std::net::{Ipv4Addr, Ipv6Addr};
+use tokay_macros::tokay_token;
+extern crate self as tokay;
+
+/** Reads the longest prefix of characters accepted by `accept` which is parsed by `parse`.
+
+The reader is moved behind the parsed prefix, or reset when no prefix could be parsed. Only ASCII
+characters may be accepted, so that the length of a prefix in bytes equals its number of
+characters. */
+fn read_longest<T>(
+    reader: &mut Reader,
+    accept: fn(char) -> bool,
+    max: usize,
+    parse: impl Fn(&str) -> Option<T>,
+) -> Option<T> {
+    let start = reader.tell();
+    let mut input = String::new();
+
+    while input.len() < max {
+        match reader.once(accept) {
+            Some(ch) => input.push(ch),
+            None => break,
+        }
+    }
+
+    reader.reset(start);
+
+    for len in (1..=input.len()).rev() {
+        if let Some(value) = parse(&input[..len]) {
+            for _ in 0..len {
+                reader.next();
+            }
+
+            return Some(value);
+        }
+    }
+
+    None
+}
+
+// Dict describing an IPv4 address
+fn ipv4_dict(addr: Ipv4Addr) -> RefValue {
+    let mut dict = Dict::new();
+    dict.insert_str("address", RefValue::from(addr.to_string()));
+    dict.insert_str("version", RefValue::from(4));
+    dict.insert_str(
+        "octets",
+        RefValue::from(
+            addr.octets()
+                .iter()
+                .map(|octet| RefValue::from(*octet as i64))
+                .collect::<Vec<_>>(),
+        ),
+    );
+    RefValue::from(dict)
+}
+
+// Dict describing an IPv6 address
+fn ipv6_dict(addr: Ipv6Addr) -> RefValue {
+    let mut dict = Dict::new();
+    dict.insert_str("address", RefValue::from(addr.to_string()));
+    dict.insert_str("version", RefValue::from(6));
+    dict.insert_str(
+        "segments",
+        RefValue::from(
+            addr.segments()
+                .iter()
+                .map(|segment| RefValue::from(*segment as i64))
+                .collect::<Vec<_>>(),
+        ),
+    );
+    RefValue::from(dict)
+}
+
+// Reads an IPv4 address not surrounded by further digits or dotted digits
+fn read_ipv4(reader: &mut Reader) -> Option<Ipv4Addr> {
+    if preceded_by(reader, |ch| ch.is_ascii_digit(), '.') {
+        return None;
+    }
+
+    let start = reader.tell();
+    let addr = read_longest(
+        reader,
+        |ch| ch.is_ascii_digit() || ch == '.',
+        15,
+        |s| s.parse::<Ipv4Addr>().ok(),
+    )?;
+
+    if followed_by(reader, |ch| ch.is_ascii_digit(), '.') {
+        reader.reset(start);
+        return None;
+    }
+
+    Some(addr)
+}
+
+// Reads an IPv6 address not surrounded by further hex digits
+fn read_ipv6(reader: &mut Reader) -> Option<Ipv6Addr> {
+    if preceded_by(reader, |ch| ch.is_ascii_hexdigit(), ':') {
+        return None;
+    }
+
+    let start = reader.tell();
+    let addr = read_longest(
+        reader,
+        |ch| ch.is_ascii_hexdigit() || ch == ':' || ch == '.',
+        45,
+        |s| {
+            // At least one digit is required, a bare "::" is not taken as an address
+            if s.contains(|ch: char| ch.is_ascii_hexdigit()) {
+                s.parse::<Ipv6Addr>().ok()
+            } else {
+                None
+            }
+        },
+    )?;
+
+    if followed_by(reader, |ch| ch.is_ascii_hexdigit(), ':') {
+        reader.reset(start);
+        return None;
+    }
+
+    Some(addr)
+}
+
+/** Checks if the input before the reader's offset ends with a character accepted by `accept`, or
+with such a character followed by `sep`. */
+fn preceded_by(reader: &Reader, accept: fn(char) -> bool, sep: char) -> bool {
+    let mut before = reader.behind(2).chars().rev();
+
+    match before.next() {
+        Some(ch) if accept(ch) => true,
+        Some(ch) if ch == sep => before.next().is_some_and(accept),
+        _ => false,
+    }
+}
+
+/** Checks if the reader continues with a character accepted by `accept`, or with `sep` followed by
+such a character, without moving the reader. */
+fn followed_by(reader: &mut Reader, accept: fn(char) -> bool, sep: char) -> bool {
+    let start = reader.tell();
+
+    let followed = reader.once(accept).is_some()
+        || (reader.once(|ch| ch == sep).is_some() && reader.once(accept).is_some());
+
+    reader.reset(start);
+    followed
+}
+
+// Matching IPv4 addresses in dotted decimal notation
+tokay_token!("Ipv4", {
+    let reader = &mut context.thread.reader;
+
+    match read_ipv4(reader) {
+        Some(addr) => Ok(Accept::Push(Capture::Value(ipv4_dict(addr), None, 5))),
+        None => Err(Reject::Next),
+    }
+});
+
+// Matching IPv6 addresses, including IPv4-mapped notations
+tokay_token!("Ipv6", {
+    let reader = &mut context.thread.reader;
+
+    match read_ipv6(reader) {
+        Some(addr) => Ok(Accept::Push(Capture::Value(ipv6_dict(addr), None, 5))),
+        None => Err(Reject::Next),
+    }
+});
+
+// Matching IPv6 or IPv4 addresses
+tokay_token!("Ip", {
+    let reader = &mut context.thread.reader;
+
+    if let Some(addr) = read_ipv6(reader) {
+        Ok(Accept::Push(Capture::Value(ipv6_dict(addr), None, 5)))
+    } else if let Some(addr) = read_ipv4(reader) {
+        Ok(Accept::Push(Capture::Value(ipv4_dict(addr), None, 5)))
+    } else {
+        Err(Reject::Next)
+    }
+});
+
+/** Parses a MAC address.
+
+Accepted are six pairs of hex digits separated by either colons or dashes, and the dotted notation
+of three groups of four hex digits. */
+fn parse_mac(s: &str) -> Option<[u8; 6]> {
+    let digits: String = if s.len() == 17 {
+        let sep = s.as_bytes()[2];
+
+        if !matches!(sep, b':' | b'-')
+            || s.bytes()
+                .enumerate()
+                .any(|(i, b)| (i % 3 == 2) != (b == sep))
+        {
+            return None;
+        }
+
+        s.split(sep as char).collect()
+    } else if s.len() == 14 {
+        if s.bytes()
+            .enumerate()
+            .any(|(i, b)| (i % 5 == 4) != (b == b'.'))
+        {
+            return None;
+        }
+
+        s.split('.').collect()
+    } else {
+        return None;
+    };
+
+    if digits.len() != 12 || !digits.chars().all(|ch| ch.is_ascii_hexdigit()) {
+        return None;
+    }
+
+    let mut octets = [0; 6];
+
+    for (i, octet) in octets.iter_mut().enumerate() {
+        *octet = u8::from_str_radix(&digits[i * 2..i * 2 + 2], 16).ok()?;
+    }
+
+    Some(octets)
+}
+
+// Matching MAC addresses
+tokay_token!("Mac", {
+    let reader = &mut context.thread.reader;
+
+    if preceded_by(reader, |ch| ch.is_ascii_hexdigit(), ':') {
+        return Err(Reject::Next);
+    }
+
+    let start = reader.tell();
+
+    let Some(octets) = read_longest(
+        reader,
+        |ch| ch.is_ascii_hexdigit() || matches!(ch, ':' | '-' | '.'),
+        17,
+        parse_mac,
+    ) else {
+        return Err(Reject::Next);
+    };
+
+    if followed_by(reader, |ch| ch.is_ascii_hexdigit(), ':') {
+        reader.reset(start);
+        return Err(Reject::Next);
+    }
+
+    let mut dict = Dict::new();
+    dict.insert_str(
+        "address",
+        RefValue::from(
+            octets
+                .iter()
+                .map(|octet| format!("{:02x}", octet))
+                .collect::<Vec<_>>()
+                .join(":"),
+        ),
+    );
+    dict.insert_str(
+        "octets",
+        RefValue::from(
+            octets
+                .iter()
+                .map(|octet| RefValue::from(*octet as i64))
+                .collect::<Vec<_>>(),
+        ),
+    );
+
+    Ok(Accept::Push(Capture::Value(RefValue::from(dict), None, 5)))
+});
+
+/** Parses an absolute URL with an authority, like `https://user@example.com:8080/path?q=1#top`.
+
+Trailing punctuation, which usually belongs to the surrounding text, is not taken as part of the
+URL. Returns the URL's length and its components. */
+fn parse_url(s: &str) -> Option<(usize, Dict)> {
+    let (scheme, rest) = s.split_once("://")?;
+
+    if !scheme.starts_with(|ch: char| ch.is_ascii_alphabetic())
+        || !scheme
+            .chars()
+            .all(|ch| ch.is_ascii_alphanumeric() || matches!(ch, '+' | '-' | '.'))
+    {
+        return None;
+    }
+
+    // Strip trailing punctuation and unbalanced closing parentheses
+    let mut rest = rest;
+
+    loop {
+        let trimmed = rest.trim_end_matches(['.', ',', ';', ':', '!', '?', '\'', '"']);
+
+        match trimmed.strip_suffix(')') {
+            Some(stripped) if trimmed.matches('(').count() < trimmed.matches(')').count() => {
+                rest = stripped
+            }
+            _ => {
+                rest = trimmed;
+                break;
+            }
+        }
+    }
+
+    let (rest, fragment) = match rest.split_once('#') {
+        Some((rest, fragment)) => (rest, Some(fragment)),
+        None => (rest, None),
+    };
+
+    let (rest, query) = match rest.split_once('?') {
+        Some((rest, query)) => (rest, Some(query)),
+        None => (rest, None),
+    };
+
+    let (authority, path) = match rest.find('/') {
+        Some(idx) => rest.split_at(idx),
+        None => (rest, ""),
+    };
+
+    let (user, hostport) = match authority.rsplit_once('@') {
+        Some((user, hostport)) => (Some(user), hostport),
+        None => (None, authority),
+    };
+
+    let (host, port) = if let Some(host) = hostport.strip_prefix('[') {
+        // IPv6 literal
+        let (host, port) = host.split_once(']')?;
+        host.parse::<Ipv6Addr>().ok()?;
+
+        match port {
+            "" => (host, None),
+            port => (host, Some(port.strip_prefix(':')?)),
+        }
+    } else {
+        match hostport.rsplit_once(':') {
+            Some((host, port)) => (host, Some(port)),
+            None => (hostport, None),
+        }
+    };
+
+    if host.is_empty()
+        || !host
+            .chars()
+            .all(|ch| ch.is_ascii_alphanumeric() || matches!(ch, '-' | '.' | '_' | ':' | '%'))
+    {
+        return None;
+    }
+
+    let port = match port {
+        Some(port) => Some(port.parse::<u16>().ok()?),
+        None => None,
+    };
+
+    let len = scheme.len() + 3 + authority.len() + path.len();
+    let len = len + query.map_or(0, |query| query.len() + 1);
+    let len = len + fragment.map_or(0, |fragment| fragment.len() + 1);
+
+    let mut dict = Dict::new();
+    dict.insert_str("url", RefValue::from(&s[..len]));
+    dict.insert_str("scheme", RefValue::from(scheme.to_ascii_lowercase()));
+
+    if let Some(user) = user {
+        dict.insert_str("user", RefValue::from(user));
+    }
+
+    dict.insert_str("host", RefValue::from(host.to_ascii_lowercase()));
+
+    if let Some(port) = port {
+        dict.insert_str("port", RefValue::from(port as i64));
+    }
+
+    dict.insert_str("path", RefValue::from(path));
+
+    if let Some(query) = query {
+        dict.insert_str("query", RefValue::from(query));
+    }
+
+    if let Some(fragment) = fragment {
+        dict.insert_str("fragment", RefValue::from(fragment));
+    }
+
+    Some((len, dict))
+}
+
+// Matching absolute URLs
+tokay_token!("Url", {
+    let reader = &mut context.thread.reader;
+
+    // The scheme must not continue a word
+    if reader
+        .behind(1)
+        .ends_with(|ch: char| ch.is_ascii_alphanumeric())
+    {
+        return Err(Reject::Next);
+    }
+
+    let start = reader.tell();
+    let mut input = String::new();
+
+    // Read all characters allowed in URLs by RFC 3986
+    while let Some(ch) =
+        reader.once(|ch| ch.is_ascii_alphanumeric() || "-._~:/?#[]@!$&'()*+,;=%".contains(ch))
+    {
+        input.push(ch);
+    }
+
+    reader.reset(start);
+
+    let Some((len, dict)) = parse_url(&input) else {
+        return Err(Reject::Next);
+    };
+
+    for _ in 0..len {
+        reader.next();
+    }
+
+    Ok(Accept::Push(Capture::Value(RefValue::from(dict), None, 5)))
+});
+
+// Characters allowed in the local part of an email address, besides dots
+fn is_email_atext(ch: char) -> bool {
+    ch.is_ascii_alphanumeric() || "!#$%&'*+-/=?^_`{|}~".contains(ch)
+}
+
+/** Parses an email address like `john.doe+news@example.com`.
+
+The local part must not start or end with a dot, nor contain consecutive dots. The domain must
+consist of at least two labels made of letters, digits and inner dashes, with an alphabetic top-level
+domain. Returns the local part and the domain. */
+fn parse_email(s: &str) -> Option<(&str, &str)> {
+    let (local, domain) = s.split_once('@')?;
+
+    if local.is_empty()
+        || local.len() > 64
+        || local.split('.').any(|part| part.is_empty())
+        || !local.chars().all(|ch| ch == '.' || is_email_atext(ch))
+    {
+        return None;
+    }
+
+    let labels: Vec<&str> = domain.split('.').collect();
+
+    if labels.len() < 2
+        || labels.iter().any(|label| {
+            label.is_empty()
+                || label.len() > 63
+                || label.starts_with('-')
+                || label.ends_with('-')
+                || !label
+                    .chars()
+                    .all(|ch| ch.is_ascii_alphanumeric() || ch == '-')
+        })
+    {
+        return None;
+    }
+
+    let tld = labels.last().unwrap();
+
+    if tld.len() < 2 || !tld.chars().all(|ch| ch.is_ascii_alphabetic()) {
+        return None;
+    }
+
+    Some((local, domain))
+}
+
+// Matching email addresses
+tokay_token!("Email", {
+    let reader = &mut context.thread.reader;
+
+    if reader
+        .behind(1)
+        .ends_with(|ch: char| ch == '.' || is_email_atext(ch))
+    {
+        return Err(Reject::Next);
+    }
+
+    let start = reader.tell();
+
+    let Some(dict) = read_longest(
+        reader,
+        |ch| ch == '.' || ch == '@' || is_email_atext(ch),
+        254,
+        |s| {
+            let (local, domain) = parse_email(s)?;
+
+            let mut dict = Dict::new();
+            dict.insert_str("address", RefValue::from(s));
+            dict.insert_str("local", RefValue::from(local));
+            dict.insert_str("domain", RefValue::from(domain.to_ascii_lowercase()));
+            Some(dict)
+        },
+    ) else {
+        return Err(Reject::Next);
+    };
+
+    // The domain must not continue beyond the matched address
+    if reader
+        .once(|ch| ch == '@' || ch == '-' || ch.is_ascii_alphanumeric())
+        .is_some()
+    {
+        reader.reset(start);
+        return Err(Reject::Next);
+    }
+
+    Ok(Accept::Push(Capture::Value(RefValue::from(dict), None, 5)))
+});
@@ -0,0 +1,210 @@
+//! Loose number parsing and formatting with thousands separators and unit suffixes
+use crate::value;
+use crate::value::{Dict, Object, RefValue, Value};
+use crate::Error;
+use num::{ToPrimitive, Zero};
+use num_bigint::BigInt;
+use tokay_macros::tokay_function;
+extern crate self as tokay;
+
+/** Retrieve the decimal mark and the separator used to group thousands by a style.
+
+The style "en" uses a decimal point and commas to group thousands, "eu" uses a decimal comma and
+dots. A void style defaults to "en". */
+fn style_marks(style: &RefValue, function: &str) -> Result<(char, char), Error> {
+    if style.is_void() {
+        return Ok(('.', ','));
+    }
+
+    match style.to_string().as_str() {
+        "en" => Ok(('.', ',')),
+        "eu" => Ok((',', '.')),
+        style => Err(Error::from(format!(
+            "{} style '{}' is unknown, use 'en' or 'eu'",
+            function, style
+        ))),
+    }
+}
+
+/** Normalizes a number written by humans into a string parseable by Rust.
+
+Besides the grouping separator of the style, spaces, no-break spaces, apostrophes and underscores
+are accepted as grouping separators between digits. Returns None when the string is not a number. */
+fn normalize(s: &str, (decimal, group): (char, char)) -> Option<String> {
+    let s = s.trim();
+    let (sign, s) = match s.chars().next()? {
+        '-' | '\u{2212}' => ("-", &s[s.chars().next()?.len_utf8()..]),
+        '+' => ("", &s[1..]),
+        _ => ("", s),
+    };
+
+    let mut ret = String::from(sign);
+    let mut fraction = false;
+    let mut last_digit = false;
+    let mut chars = s.chars().peekable();
+
+    while let Some(ch) = chars.next() {
+        let next_digit = chars.peek().is_some_and(|ch| ch.is_ascii_digit());
+
+        match ch {
+            '0'..='9' => ret.push(ch),
+            ch if ch == decimal && !fraction && next_digit => {
+                fraction = true;
+                ret.push('.');
+            }
+            ch if (ch == group || matches!(ch, ' ' | '\u{a0}' | '\u{202f}' | '\'' | '_'))
+                && !fraction
+                && last_digit
+                && next_digit => {}
+            _ => return None,
+        }
+
+        last_digit = ch.is_ascii_digit();
+    }
+
+    if ret.contains(|ch: char| ch.is_ascii_digit()) {
+        Some(ret)
+    } else {
+        None
+    }
+}
+
+/// Default unit suffixes with their multipliers, as used by `parse_unit()`
+static UNITS: &[(&str, i64)] = &[
+    ("", 1),
+    ("B", 1),
+    ("k", 1_000),
+    ("K", 1_000),
+    ("kB", 1_000),
+    ("KB", 1_000),
+    ("M", 1_000_000),
+    ("MB", 1_000_000),
+    ("G", 1_000_000_000),
+    ("GB", 1_000_000_000),
+    ("T", 1_000_000_000_000),
+    ("TB", 1_000_000_000_000),
+    ("P", 1_000_000_000_000_000),
+    ("PB", 1_000_000_000_000_000),
+    ("Ki", 1 << 10),
+    ("KiB", 1 << 10),
+    ("Mi", 1 << 20),
+    ("MiB", 1 << 20),
+    ("Gi", 1 << 30),
+    ("GiB", 1 << 30),
+    ("Ti", 1 << 40),
+    ("TiB", 1 << 40),
+    ("Pi", 1 << 50),
+    ("PiB", 1 << 50),
+];
+
+tokay_function!("int_loose : @s, style=void", {
+    let marks = style_marks(&style, __function)?;
+
+    match normalize(&s.to_string(), marks) {
+        Some(number) => {
+            // Any fractional part is truncated, like int() does
+            let number = number.split('.').next().unwrap();
+            RefValue::from(number.parse::<BigInt>().unwrap_or_default())
+        }
+        None => value!(void),
+    }
+    .into()
+});
+
+tokay_function!("float_loose : @s, style=void", {
+    let marks = style_marks(&style, __function)?;
+
+    match normalize(&s.to_string(), marks) {
+        Some(number) => RefValue::from(number.parse::<f64>().unwrap()),
+        None => value!(void),
+    }
+    .into()
+});
+
+tokay_function!("format_thousands : @n, style=void", {
+    let (decimal, group) = style_marks(&style, __function)?;
+
+    let number = match &*n.borrow() {
+        Value::Int(int) => int.to_string(),
+        Value::Float(float) => float.to_string(),
+        _ => n.to_bigint()?.to_string(),
+    };
+
+    let (sign, number) = match number.strip_prefix('-') {
+        Some(number) => ("-", number),
+        None => ("", number.as_str()),
+    };
+
+    let (integer, fraction) = match number.split_once('.') {
+        Some((integer, fraction)) => (integer, Some(fraction)),
+        None => (number, None),
+    };
+
+    let mut ret = String::from(sign);
+
+    for (i, ch) in integer.chars().enumerate() {
+        if i > 0 && (integer.len() - i) % 3 == 0 {
+            ret.push(group);
+        }
+
+        ret.push(ch);
+    }
+
+    if let Some(fraction) = fraction {
+        ret.push(decimal);
+        ret.push_str(fraction);
+    }
+
+    RefValue::from(ret).into()
+});
+
+tokay_function!("parse_unit : @s, units=void, style=void", {
+    let marks = style_marks(&style, __function)?;
+    let s = s.to_string();
+    let s = s.trim();
+
+    // The unit is made of the trailing characters which are neither digits nor whitespace
+    let number = s.trim_end_matches(|ch: char| !ch.is_ascii_digit() && !ch.is_whitespace());
+    let unit = &s[number.len()..];
+
+    let Some(number) = normalize(number, marks) else {
+        return value!(void).into();
+    };
+
+    let multiplier = if units.is_void() {
+        match UNITS.iter().find(|(name, _)| *name == unit) {
+            Some((_, multiplier)) => RefValue::from(*multiplier),
+            None => return value!(void).into(),
+        }
+    } else if let Some(units) = units.borrow().object::<Dict>() {
+        match units.get_str(unit) {
+            Some(multiplier) => multiplier.clone(),
+            None => return value!(void).into(),
+        }
+    } else {
+        return Error::from(format!("{} argument 'units' must be a dict", __function)).into();
+    };
+
+    let multiplier = &*multiplier.borrow();
+
+    // Integer multipliers are applied exactly, resulting in an int when there's no remainder
+    if let Value::Int(multiplier) = multiplier {
+        let (integer, fraction) = number.split_once('.').unwrap_or((&number, ""));
+        let denominator = BigInt::from(10).pow(fraction.len() as u32);
+        let numerator: BigInt = format!("{}{}", integer, fraction)
+            .parse::<BigInt>()
+            .unwrap_or_default()
+            * multiplier;
+
+        if (&numerator % &denominator).is_zero() {
+            return RefValue::from(numerator / denominator).into();
+        }
+
+        return RefValue::from(
+            numerator.to_f64().unwrap_or(f64::NAN) / denominator.to_f64().unwrap_or(f64::NAN),
+        )
+        .into();
+    }
+
+    RefValue::from(number.parse::<f64>().unwrap() * multiplier.to_f64()?).into()
+});
@@ -0,0 +1,24 @@
+//! Access to the environment and the arguments of the process
+use crate::value;
+use crate::value::{List, Object, RefValue};
+use tokay_macros::tokay_function;
+extern crate self as tokay;
+
+tokay_function!("env : @name", {
+    match std::env::var(name.to_string()) {
+        Ok(value) => RefValue::from(value).into(),
+        Err(_) => value!(void).into(),
+    }
+});
+
+tokay_function!("args : @", {
+    let mut list = List::new();
+
+    if let Some(context) = context {
+        for arg in &context.thread.options.args {
+            list.push(RefValue::from(arg.as_str()));
+        }
+    }
+
+    RefValue::from(list).into()
+});
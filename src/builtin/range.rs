@@ -1,10 +1,171 @@
-use crate::value::{Iter, Object, RefValue, RefValueIter};
+use crate::value::{BoxedObject, Iter, MapIter, Object, RefValue, RefValueIter, Value};
 use crate::{Context, Error};
-use num::{One, Zero};
+use num::{One, Signed, Zero};
 use num_bigint::BigInt;
-use tokay_macros::tokay_function;
+use tokay_macros::{tokay_function, tokay_method};
 extern crate self as tokay;
 
+/** Range object type.
+
+A range describes the integers from `start` up to but not including `stop`, by `step`. It is
+lazy, so its items are only generated when the range is iterated, and it can be iterated any
+number of times. */
+#[derive(Debug, Clone, PartialEq, PartialOrd)]
+pub struct Range {
+    start: BigInt,
+    stop: BigInt,
+    step: BigInt, // Never zero
+}
+
+impl Range {
+    /// Number of items in the range
+    pub fn len(&self) -> BigInt {
+        let len = (&self.stop - &self.start + &self.step - self.step.signum()) / &self.step;
+        len.max(BigInt::zero())
+    }
+
+    /// Checks if the range contains no items
+    pub fn is_empty(&self) -> bool {
+        self.len().is_zero()
+    }
+
+    /// Returns the item at index, which counts from the end when negative
+    pub fn get(&self, index: &BigInt) -> Option<BigInt> {
+        let len = self.len();
+        let index = if index.is_negative() {
+            index + &len
+        } else {
+            index.clone()
+        };
+
+        if index.is_negative() || index >= len {
+            None
+        } else {
+            Some(&self.start + index * &self.step)
+        }
+    }
+
+    /// Creates an iterator on the range's items
+    pub fn iter(&self) -> Iter {
+        Iter::new(Box::new(RangeIter {
+            next: if self.is_empty() {
+                None
+            } else {
+                Some(self.start.clone())
+            },
+            stop: self.stop.clone(),
+            step: self.step.clone(),
+        }))
+    }
+
+    tokay_method!("range_iter : @range", {
+        let range = range.borrow();
+
+        if let Some(range) = range.object::<Range>() {
+            Ok(RefValue::from(range.iter()))
+        } else {
+            Err(Error::from(format!(
+                "{} only accepts '{}' as parameter, not '{}'",
+                __function,
+                "range",
+                range.name()
+            )))
+        }
+    });
+
+    tokay_method!("range_len : @range", {
+        let range = range.borrow();
+
+        Ok(RefValue::from(
+            if let Some(range) = range.object::<Range>() {
+                range.len()
+            } else {
+                BigInt::one()
+            },
+        ))
+    });
+
+    tokay_method!("range_get_item : @range, item, default=void", {
+        let range = range.borrow();
+
+        if let Some(range) = range.object::<Range>() {
+            if let Some(value) = range.get(&item.to_bigint()?) {
+                return Ok(RefValue::from(value));
+            }
+        }
+
+        Ok(default)
+    });
+
+    tokay_method!("range_rev : @range", {
+        let range = range.borrow();
+
+        if let Some(range) = range.object::<Range>() {
+            // The reversed range starts at the last item and stops before the first one
+            let start = range.get(&BigInt::from(-1)).unwrap_or(range.start.clone());
+
+            Ok(RefValue::from(Range {
+                stop: if range.is_empty() {
+                    start.clone()
+                } else {
+                    &range.start - &range.step
+                },
+                start,
+                step: -range.step.clone(),
+            }))
+        } else {
+            Err(Error::from(format!(
+                "{} only accepts '{}' as parameter, not '{}'",
+                __function,
+                "range",
+                range.name()
+            )))
+        }
+    });
+
+    tokay_method!("range_map : @range, map", {
+        let iter = if let Some(range) = range.borrow().object::<Range>() {
+            RefValue::from(range.iter())
+        } else {
+            return Err(Error::from("'range' must be of type range"));
+        };
+
+        if !map.is_callable(false) {
+            Err(Error::from("'map' must be a callable accepting arguments"))
+        } else {
+            Ok(RefValue::from(MapIter::new(iter, map)))
+        }
+    });
+}
+
+impl Object for Range {
+    fn severity(&self) -> u8 {
+        30
+    }
+
+    fn name(&self) -> &'static str {
+        "range"
+    }
+
+    fn repr(&self) -> String {
+        if self.step.is_one() {
+            format!("range({}, {})", self.start, self.stop)
+        } else {
+            format!("range({}, {}, {})", self.start, self.stop, self.step)
+        }
+    }
+
+    fn is_true(&self) -> bool {
+        !self.is_empty()
+    }
+}
+
+impl From<Range> for RefValue {
+    fn from(value: Range) -> Self {
+        Value::Object(Box::new(value) as BoxedObject).into()
+    }
+}
+
 #[derive(Clone)]
 struct RangeIter {
     next: Option<BigInt>,
@@ -15,7 +176,10 @@ struct RangeIter {
 impl RefValueIter for RangeIter {
     fn next(&mut self, _context: Option<&mut Context>) -> Option<RefValue> {
         if let Some(next) = self.next.as_mut() {
-            if *next != self.stop {
+            // Steps may pass by the stop value, so it is compared by the direction of the step
+            if (self.step.is_positive() && *next < self.stop)
+                || (self.step.is_negative() && *next > self.stop)
+            {
                 let ret = next.clone();
                 *next += &self.step;
                 return Some(RefValue::from(ret));
@@ -28,26 +192,25 @@ impl RefValueIter for RangeIter {
     }
 
     fn repr(&self) -> String {
-        if self.step.is_one() {
-            format!(
-                "range({}, {})",
-                self.next.as_ref().unwrap_or(&self.stop),
-                self.stop
-            )
-        } else {
-            format!(
-                "range({}, {}, {})",
-                self.next.as_ref().unwrap_or(&self.stop),
-                self.stop,
-                self.step
-            )
+        Range {
+            start: self.next.as_ref().unwrap_or(&self.stop).clone(),
+            stop: self.stop.clone(),
+            step: self.step.clone(),
         }
+        .repr()
     }
 
     fn rev(&mut self) -> Result<(), Error> {
-        self.step = -self.step.clone();
-        let next = self.next.as_ref().unwrap_or(&self.stop).clone();
-        (self.next, self.stop) = (Some(self.stop.clone() + &self.step), next + &self.step);
+        // Reverse the remaining items, which form a range on their own
+        let remaining = Range {
+            start: self.next.as_ref().unwrap_or(&self.stop).clone(),
+            stop: self.stop.clone(),
+            step: self.step.clone(),
+        };
+
+        self.next = remaining.get(&BigInt::from(-1));
+        self.stop = &remaining.start - &remaining.step;
+        self.step = -remaining.step;
         Ok(())
     }
 }
@@ -67,15 +230,5 @@ tokay_function!("range : @start, stop=void, step=1", {
         return Error::from(format!("{} argument 'step' may not be 0", __function)).into();
     }
 
-    RefValue::from(Iter::new(Box::new(RangeIter {
-        next: if (step > BigInt::zero() && start > stop) || (step < BigInt::zero() && stop > start)
-        {
-            None
-        } else {
-            Some(start)
-        },
-        stop,
-        step,
-    })))
-    .into()
+    RefValue::from(Range { start, stop, step }).into()
 });
@@ -0,0 +1,204 @@
+//! Built-in token for RFC 3339 and ISO 8601 timestamps
+use crate::value::{Dict, Object, RefValue};
+use crate::{Accept, Capture, Reject};
+use tokay_macros::tokay_token;
+extern crate self as tokay;
+
+// Maximum length of a timestamp, like "2024-02-29T23:59:60.123456789+01:00"
+const MAX_LEN: usize = 40;
+
+// Number of days in a month of a year
+pub(crate) fn days_in_month(year: i64, month: i64) -> i64 {
+    match month {
+        2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+// Simple cursor on the characters of a timestamp candidate
+struct Cursor<'s> {
+    s: &'s str,
+    pos: usize,
+}
+
+impl Cursor<'_> {
+    // Takes a character accepted by `accept`
+    fn char(&mut self, accept: impl Fn(char) -> bool) -> Option<char> {
+        let ch = self.s[self.pos..].chars().next().filter(|ch| accept(*ch))?;
+        self.pos += ch.len_utf8();
+        Some(ch)
+    }
+
+    // Takes exactly `count` digits as number within `min..=max`
+    fn number(&mut self, count: usize, min: i64, max: i64) -> Option<i64> {
+        let digits = self.s.get(self.pos..self.pos + count)?;
+
+        if !digits.chars().all(|ch| ch.is_ascii_digit()) {
+            return None;
+        }
+
+        let number = digits
+            .parse()
+            .ok()
+            .filter(|number| (min..=max).contains(number))?;
+        self.pos += count;
+        Some(number)
+    }
+
+    // Runs `parse`, and resets the cursor when it fails
+    fn attempt<T>(&mut self, parse: impl FnOnce(&mut Self) -> Option<T>) -> Option<T> {
+        let pos = self.pos;
+        let ret = parse(self);
+
+        if ret.is_none() {
+            self.pos = pos;
+        }
+
+        ret
+    }
+}
+
+/** Parses a timestamp at the start of `s`, like `2024-05-17T12:34:56.789+02:00`.
+
+The date is required in the extended format `YYYY-MM-DD`. It can be followed by a time `hh:mm`,
+`hh:mm:ss` or `hh:mm:ss.fraction`, separated by a `T` or a space. A time can be followed by `Z` or
+a UTC offset `+hh:mm`, `+hhmm` or `+hh`. Returns the length of the timestamp and its components. */
+pub(crate) fn parse_timestamp(s: &str) -> Option<(usize, Dict)> {
+    let mut cursor = Cursor { s, pos: 0 };
+    let mut components = Vec::new();
+
+    let year = cursor.number(4, 0, 9999)?;
+    cursor.char(|ch| ch == '-')?;
+    let month = cursor.number(2, 1, 12)?;
+    cursor.char(|ch| ch == '-')?;
+    let day = cursor.number(2, 1, days_in_month(year, month))?;
+
+    components.extend([("year", year), ("month", month), ("day", day)]);
+
+    let time = cursor.attempt(|cursor| {
+        cursor.char(|ch| matches!(ch, 'T' | 't' | ' '))?;
+        let hour = cursor.number(2, 0, 23)?;
+        cursor.char(|ch| ch == ':')?;
+        let minute = cursor.number(2, 0, 59)?;
+
+        let (second, nanosecond) = cursor
+            .attempt(|cursor| {
+                cursor.char(|ch| ch == ':')?;
+                // 60 is allowed for leap seconds
+                let second = cursor.number(2, 0, 60)?;
+
+                let nanosecond = cursor
+                    .attempt(|cursor| {
+                        cursor.char(|ch| ch == '.' || ch == ',')?;
+                        let start = cursor.pos;
+
+                        while cursor.char(|ch| ch.is_ascii_digit()).is_some() {}
+
+                        let digits = &cursor.s[start..cursor.pos];
+
+                        if digits.is_empty() || digits.len() > 9 {
+                            return None;
+                        }
+
+                        Some(format!("{:0<9}", digits).parse::<i64>().unwrap())
+                    })
+                    .unwrap_or(0);
+
+                Some((second, nanosecond))
+            })
+            .unwrap_or((0, 0));
+
+        Some((hour, minute, second, nanosecond))
+    });
+
+    if let Some((hour, minute, second, nanosecond)) = time {
+        components.extend([
+            ("hour", hour),
+            ("minute", minute),
+            ("second", second),
+            ("nanosecond", nanosecond),
+        ]);
+
+        // The UTC offset in minutes
+        let offset = cursor.attempt(|cursor| {
+            if cursor.char(|ch| ch == 'Z' || ch == 'z').is_some() {
+                return Some(0);
+            }
+
+            let sign = if cursor.char(|ch| ch == '+').is_some() {
+                1
+            } else {
+                cursor.char(|ch| ch == '-' || ch == '\u{2212}')?;
+                -1
+            };
+
+            let hours = cursor.number(2, 0, 23)?;
+            let minutes = cursor
+                .attempt(|cursor| {
+                    cursor.char(|ch| ch == ':');
+                    cursor.number(2, 0, 59)
+                })
+                .unwrap_or(0);
+
+            Some(sign * (hours * 60 + minutes))
+        });
+
+        if let Some(offset) = offset {
+            components.push(("offset", offset));
+        }
+    }
+
+    let mut dict = Dict::new();
+    dict.insert_str("timestamp", RefValue::from(&s[..cursor.pos]));
+
+    for (name, value) in components {
+        dict.insert_str(name, RefValue::from(value));
+    }
+
+    Some((cursor.pos, dict))
+}
+
+// Matching RFC 3339 and ISO 8601 timestamps
+tokay_token!("Timestamp", {
+    let reader = &mut context.thread.reader;
+
+    if reader.behind(1).ends_with(|ch: char| ch.is_ascii_digit()) {
+        return Err(Reject::Next);
+    }
+
+    let start = reader.tell();
+    let mut input = String::new();
+
+    while input.len() < MAX_LEN {
+        match reader.once(|ch| {
+            ch.is_ascii_digit()
+                || matches!(
+                    ch,
+                    '-' | ':' | '.' | ',' | '+' | 'T' | 't' | 'Z' | 'z' | ' ' | '\u{2212}'
+                )
+        }) {
+            Some(ch) => input.push(ch),
+            None => break,
+        }
+    }
+
+    reader.reset(start);
+
+    let Some((len, dict)) = parse_timestamp(&input) else {
+        return Err(Reject::Next);
+    };
+
+    // Skip the timestamp, and reject when it is directly followed by further digits
+    for _ in input[..len].chars() {
+        reader.next();
+    }
+
+    if reader.once(|ch| ch.is_ascii_digit()).is_some() {
+        reader.reset(start);
+        return Err(Reject::Next);
+    }
+
+    Ok(Accept::Push(Capture::Value(RefValue::from(dict), None, 5)))
+});
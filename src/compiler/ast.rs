@@ -7,7 +7,7 @@ use crate::builtin::Builtin;
 use crate::reader::Offset;
 use crate::utils;
 use crate::value;
-use crate::value::{Dict, List, Object, RefValue, Str, Token};
+use crate::value::{Dict, List, Object, Overflow, RefValue, Str, Token};
 use crate::vm::*;
 use charclass::CharClass;
 
@@ -17,8 +17,9 @@ pub static RESERVED_TOKENS: &[&'static str] = &[
 ];
 
 pub static RESERVED_KEYWORDS: &[&'static str] = &[
-    "accept", "begin", "break", "continue", "else", "end", "exit", "false", "for", "if", "in",
-    "loop", "next", "null", "push", "reject", "repeat", "reset", "return", "self", "true", "void",
+    "accept", "begin", "break", "cache", "continue", "else", "end", "exit", "false", "for", "if",
+    "import", "in", "loop", "next", "null", "push", "reject", "repeat", "reset", "return", "self",
+    "true", "void", "while",
 ];
 
 /// AST traversal entry
@@ -38,19 +39,42 @@ pub(in crate::compiler) fn traverse(scope: &Scope, ast: &RefValue) -> ImlOp {
     }
 }
 
+/// Traverse the AST of an imported file, which may only define constants and import other files
+pub(in crate::compiler) fn traverse_import(scope: &Scope, ast: &RefValue) {
+    let ast = ast.borrow();
+    let ast = ast.object::<Dict>().unwrap();
+
+    if let Some(children) = ast.get_str("children") {
+        for item in List::from(children).iter() {
+            let node = item.borrow();
+            let node = node.object::<Dict>().unwrap();
+
+            match node["emit"].to_string().as_str() {
+                "constant" | "import" => {
+                    traverse(scope, item);
+                }
+                _ => scope.error(
+                    traverse_node_offset(node),
+                    "Imported files may only define constants or import other files".to_string(),
+                ),
+            }
+        }
+    }
+}
+
 // Extract offset positions into an Offset structure
 fn traverse_node_offset(node: &Dict) -> Option<Offset> {
     //return None; // Temporarily discard any Offset information (shortens debug output)
 
     let offset = node
         .get_str("offset")
-        .and_then(|offset| Some(offset.to_usize().unwrap()));
+        .and_then(|offset| offset.to_usize().ok());
     let row = node
         .get_str("row")
-        .and_then(|row| Some(row.to_usize().unwrap() as u32));
+        .and_then(|row| row.to_usize().ok().map(|row| row as u32));
     let col = node
         .get_str("col")
-        .and_then(|col| Some(col.to_usize().unwrap() as u32));
+        .and_then(|col| col.to_usize().ok().map(|col| col as u32));
 
     if let (Some(offset), Some(row), Some(col)) = (offset, row, col) {
         Some(Offset { offset, row, col })
@@ -68,6 +92,36 @@ fn traverse_offset(node: &Dict) -> ImlOp {
     }
 }
 
+// Extract a capture index, reporting an error when it can't be used as index
+fn traverse_node_capture_index(scope: &Scope, node: &Dict) -> usize {
+    let index = traverse_node_value(scope, node, None).unwrap();
+
+    match index.to_usize() {
+        Ok(index) => index,
+        Err(err) => {
+            scope.error(
+                traverse_node_offset(node),
+                format!("Invalid capture index {}: {}", index.repr(), err),
+            );
+            0
+        }
+    }
+}
+
+// Traverse the alias of a capture into the variable of a named capture, if there is one
+fn traverse_node_capture_named(scope: &Scope, node: &Dict) -> Option<(usize, bool)> {
+    if node["emit"].to_string() != "value_string" {
+        return None;
+    }
+
+    match scope.resolve_capture(&node["value"].to_string()) {
+        Some(ImlValue::Variable {
+            addr, is_global, ..
+        }) => Some((addr, is_global)),
+        _ => None,
+    }
+}
+
 // Traverse a value node into an ImlValue instance
 fn traverse_node_value(scope: &Scope, node: &Dict, name: Option<String>) -> ImlValue {
     let emit = node["emit"].borrow();
@@ -92,7 +146,10 @@ fn traverse_node_value(scope: &Scope, node: &Dict, name: Option<String>) -> ImlV
         // Tokens
         "value_token_self" => ImlValue::SelfToken,
         "value_token_void" => ImlValue::VoidToken,
-        "value_token_match" | "value_token_touch" => {
+        "value_token_match"
+        | "value_token_touch"
+        | "value_token_match_caseless"
+        | "value_token_touch_caseless" => {
             let mut value = node["value"].to_string();
 
             if value.len() == 0 {
@@ -103,13 +160,12 @@ fn traverse_node_value(scope: &Scope, node: &Dict, name: Option<String>) -> ImlV
                 value = "#INVALID".to_string();
             }
 
-            scope
-                .compiler
-                .register_static(if emit == "value_token_match" {
-                    RefValue::from(Token::Match(value))
-                } else {
-                    RefValue::from(Token::Touch(value))
-                })
+            scope.compiler.register_static(RefValue::from(match emit {
+                "value_token_match" => Token::Match(value),
+                "value_token_touch" => Token::Touch(value),
+                "value_token_match_caseless" => Token::MatchCaseless(value),
+                _ => Token::TouchCaseless(value),
+            }))
         }
         "value_token_any" => scope
             .compiler
@@ -117,8 +173,30 @@ fn traverse_node_value(scope: &Scope, node: &Dict, name: Option<String>) -> ImlV
         "value_token_anys" => scope
             .compiler
             .register_static(RefValue::from(Token::Chars(CharClass::new().negate()))),
-        "value_token_ccl" | "value_token_ccls" => {
-            let many = emit.ends_with("s");
+        "value_token_ccl"
+        | "value_token_ccls"
+        | "value_token_ccl_caseless"
+        | "value_token_ccls_caseless" => {
+            let caseless = emit.ends_with("_caseless");
+            let many = emit.trim_end_matches("_caseless").ends_with("s");
+
+            // Adds a character to the class, in case-insensitive mode including its case variants
+            let add = |ccl: &mut CharClass, ch: char| {
+                ccl.add(ch..=ch);
+
+                if caseless {
+                    let cases = [ch.to_lowercase().to_string(), ch.to_uppercase().to_string()];
+
+                    // Only simple case mappings to a single character are considered
+                    for case in cases {
+                        let mut chars = case.chars();
+
+                        if let (Some(case), None) = (chars.next(), chars.next()) {
+                            ccl.add(case..=case);
+                        }
+                    }
+                }
+            };
 
             let node = node["children"].borrow();
             let node = node.object::<Dict>().unwrap();
@@ -141,15 +219,38 @@ fn traverse_node_value(scope: &Scope, node: &Dict, name: Option<String>) -> ImlV
                 let value = value.object::<Str>().unwrap().as_str();
 
                 match &emit[..] {
-                    "char" => {
-                        let ch = value.chars().next().unwrap();
-                        ccl.add(ch..=ch);
-                    }
+                    "char" => add(&mut ccl, value.chars().next().unwrap()),
                     "range" => {
                         let from = value.chars().nth(0).unwrap();
                         let to = value.chars().nth(1).unwrap();
 
                         ccl.add(from..=to);
+
+                        if caseless {
+                            for ch in from..=to {
+                                add(&mut ccl, ch);
+                            }
+                        }
+                    }
+                    "shorthand" => {
+                        let shorthand = value.chars().next().unwrap();
+                        ccl += Token::ccl_shorthand(shorthand).unwrap();
+                    }
+                    "category" => {
+                        let (negate, category) = value.split_at(1);
+
+                        if let Some(category) = Token::ccl_category(category) {
+                            ccl += if negate == "P" {
+                                category.negate()
+                            } else {
+                                category
+                            };
+                        } else {
+                            scope.error(
+                                traverse_node_offset(range),
+                                format!("Unknown Unicode general category '{}'", category),
+                            );
+                        }
                     }
                     _ => {
                         unreachable!();
@@ -171,9 +272,9 @@ fn traverse_node_value(scope: &Scope, node: &Dict, name: Option<String>) -> ImlV
         }
 
         // Parselets
-        "value_parselet" => {
+        "value_parselet" | "value_parselet_cached" => {
             let mut generics = IndexMap::new();
-            let mut signature = IndexMap::new();
+            let mut signature: IndexMap<String, Option<ImlValue>> = IndexMap::new();
 
             // Traverse the AST
             let mut sigs = List::from(node["children"].clone());
@@ -225,7 +326,7 @@ fn traverse_node_value(scope: &Scope, node: &Dict, name: Option<String>) -> ImlV
                             );
                         }
                     }
-                    "arg" => {
+                    "arg" | "arg_args" | "arg_nargs" => {
                         let first = name.chars().nth(0).unwrap();
 
                         // Check for correct identifier semantics
@@ -249,27 +350,65 @@ fn traverse_node_value(scope: &Scope, node: &Dict, name: Option<String>) -> ImlV
 
                         assert!(children.len() <= 2);
 
+                        // Variable arguments are marked by a prefix in the signature,
+                        // and must be specified last, in the order *args, **nargs
+                        let key = match emit {
+                            "arg_args" => format!("*{}", name),
+                            "arg_nargs" => format!("**{}", name),
+                            _ => name.clone(),
+                        };
+
+                        let prefix = &key[..key.len() - name.len()];
+
+                        // Only one of each kind of variable arguments is accepted
+                        if let Some(first) = signature.keys().find(|arg| {
+                            !prefix.is_empty()
+                                && arg.len() - arg.trim_start_matches('*').len() == prefix.len()
+                        }) {
+                            scope.error(
+                                traverse_node_offset(node),
+                                format!(
+                                    "Argument '{}' invalid; Only one '{}'-argument allowed, but '{}' already given",
+                                    key, prefix, first
+                                ),
+                            );
+                        } else if let Some(last) = signature.keys().last() {
+                            if last.starts_with("**")
+                                || (last.starts_with('*') && !key.starts_with("**"))
+                            {
+                                scope.error(
+                                    traverse_node_offset(node),
+                                    format!(
+                                        "Argument '{}' must be specified before '{}'",
+                                        key, last
+                                    ),
+                                );
+                            }
+                        }
+
                         if signature
-                            .insert(
-                                name.clone(),
-                                if children.len() == 2 {
-                                    let default = children[1].borrow();
-                                    Some(traverse_node_static(
-                                        scope,
-                                        Some(name.clone()),
-                                        default.object::<Dict>().unwrap(),
-                                    ))
-                                } else {
-                                    None
-                                },
-                            )
-                            .is_some()
+                            .keys()
+                            .any(|arg| arg.trim_start_matches('*') == name)
                         {
                             scope.error(
                                 traverse_node_offset(node),
                                 format!("Argument '{}' already given in signature before", name),
                             );
                         }
+
+                        signature.insert(
+                            key,
+                            if children.len() == 2 {
+                                let default = children[1].borrow();
+                                Some(traverse_node_static(
+                                    scope,
+                                    Some(name.clone()),
+                                    default.object::<Dict>().unwrap(),
+                                ))
+                            } else {
+                                None
+                            },
+                        );
                         //println!("{} {} {:?}", emit.to_string(), ident, default);
                     }
                     _ => unreachable!(),
@@ -278,7 +417,10 @@ fn traverse_node_value(scope: &Scope, node: &Dict, name: Option<String>) -> ImlV
 
             // Create new parselet to construct
             let parselet = ImlParselet::new(ImlParseletInstance::new(
-                Some(ImlParseletModel::new(Some(signature))),
+                Some(ImlParseletModel {
+                    is_cached: emit == "value_parselet_cached",
+                    ..ImlParseletModel::new(Some(signature))
+                }),
                 Some(generics),
                 traverse_node_offset(node),
                 name,
@@ -430,6 +572,20 @@ fn traverse_node_static(scope: &Scope, assign: Option<String>, node: &Dict) -> I
     */
     if emit.starts_with("value_") {
         traverse_node_value(scope, node, assign).try_resolve(scope)
+    }
+    // A flattened sequence becoming a parselet of its own, like in `(a b)<flat>+`, has no
+    // enclosing sequence to be flattened into, so it's kept a sequence.
+    else if emit == "inline_flat" {
+        let children = node["children"].borrow();
+        traverse_node_static(scope, assign, children.object::<Dict>().unwrap())
+    }
+    // Identifiers are loaded in the current scope, so that generics of the current parselet
+    // are preferred over constants with the same name from outer scopes.
+    else if emit == "identifier" {
+        match traverse_node_rvalue(scope, node, Rvalue::Load) {
+            ImlOp::Load { target: value, .. } => value,
+            _ => unreachable!(),
+        }
     } else {
         // Handle anything else as an implicit parselet in its own scope
         let implicit_parselet = ImlParselet::new(ImlParseletInstance::new(
@@ -486,37 +642,50 @@ fn traverse_node_lvalue(scope: &Scope, node: &Dict, store: bool, hold: bool) ->
 
                 match capture {
                     "capture_alias" | "capture_expr" => {
-                        ops.push(traverse_node_rvalue(
-                            scope,
-                            children.object::<Dict>().unwrap(),
-                            Rvalue::CallOrLoad,
-                        ));
+                        let children = children.object::<Dict>().unwrap();
 
-                        if store {
-                            if hold {
-                                ops.push(Op::StoreCaptureHold.into())
+                        // Named captures are held by variables
+                        if let Some((addr, is_global)) =
+                            traverse_node_capture_named(scope, children)
+                        {
+                            ops.push(
+                                match (store, hold, is_global) {
+                                    (true, true, true) => Op::StoreGlobalHold(addr),
+                                    (true, true, false) => Op::StoreFastHold(addr),
+                                    (true, false, true) => Op::StoreGlobal(addr),
+                                    (true, false, false) => Op::StoreFast(addr),
+                                    (false, _, true) => Op::LoadGlobal(addr),
+                                    (false, _, false) => Op::LoadFast(addr),
+                                }
+                                .into(),
+                            );
+                        } else {
+                            ops.push(traverse_node_rvalue(scope, children, Rvalue::CallOrLoad));
+
+                            if store {
+                                if hold {
+                                    ops.push(Op::StoreCaptureHold.into())
+                                } else {
+                                    ops.push(Op::StoreCapture.into())
+                                }
                             } else {
-                                ops.push(Op::StoreCapture.into())
+                                ops.push(Op::LoadCapture.into())
                             }
-                        } else {
-                            ops.push(Op::LoadCapture.into())
                         }
                     }
 
                     "capture_index" => {
                         let children = children.object::<Dict>().unwrap();
-                        let index = traverse_node_value(scope, children, None).unwrap();
+                        let index = traverse_node_capture_index(scope, children);
 
                         if store {
                             if hold {
-                                ops.push(
-                                    Op::StoreFastCaptureHold(index.to_usize().unwrap()).into(),
-                                );
+                                ops.push(Op::StoreFastCaptureHold(index).into());
                             } else {
-                                ops.push(Op::StoreFastCapture(index.to_usize().unwrap()).into());
+                                ops.push(Op::StoreFastCapture(index).into());
                             }
                         } else {
-                            ops.push(Op::LoadFastCapture(index.to_usize().unwrap()).into());
+                            ops.push(Op::LoadFastCapture(index).into());
                         }
                     }
 
@@ -577,6 +746,19 @@ fn traverse_node_lvalue(scope: &Scope, node: &Dict, store: bool, hold: bool) ->
                                 break 'load;
                             }
 
+                            // Check if identifier is not qualified by a namespace
+                            if name.contains("::") {
+                                scope.error(
+                                    traverse_node_offset(node),
+                                    format!(
+                                        "Cannot assign variable named '{}'; Qualified names only refer to imported constants",
+                                        name
+                                    ),
+                                );
+
+                                break 'load;
+                            }
+
                             // Check if identifier is not defining a consumable
                             if utils::identifier_is_consumable(name) {
                                 scope.error(
@@ -617,6 +799,27 @@ fn traverse_node_lvalue(scope: &Scope, node: &Dict, store: bool, hold: bool) ->
                 }
             }
 
+            // attribute ------------------------------------------------------
+            "attribute" => {
+                let children = item["children"].borrow();
+
+                ops.push(traverse_node_rvalue(
+                    scope,
+                    children.object::<Dict>().unwrap(),
+                    Rvalue::CallOrLoad,
+                ));
+
+                if store {
+                    if hold {
+                        ops.push(Op::StoreAttrHold.into());
+                    } else {
+                        ops.push(Op::StoreAttr.into());
+                    }
+                } else {
+                    ops.push(Op::LoadAttr.into())
+                }
+            }
+
             // item -----------------------------------------------------------
             "item" => {
                 ops.push(traverse(scope, &item["children"]));
@@ -774,8 +977,15 @@ fn traverse_node(scope: &Scope, node: &Dict) -> ImlOp {
             let expr =
                 traverse_node_rvalue(scope, expr.object::<Dict>().unwrap(), Rvalue::CallOrLoad);
 
-            // Push value first, then the alias
-            ImlOp::from(vec![expr, alias, ImlOp::from(Op::MakeAlias)])
+            match expr {
+                // Optionals skipped when unmatched only take the alias on a match
+                ImlOp::Alt { mut alts } if matches!(alts.last(), Some(ImlOp::Nop)) => {
+                    alts[0] = ImlOp::from(vec![alts[0].clone(), alias, ImlOp::from(Op::MakeAlias)]);
+                    ImlOp::Alt { alts }
+                }
+                // Push value first, then the alias
+                expr => ImlOp::from(vec![expr, alias, ImlOp::from(Op::MakeAlias)]),
+            }
         }
 
         // area -----------------------------------------------------------
@@ -784,6 +994,46 @@ fn traverse_node(scope: &Scope, node: &Dict) -> ImlOp {
             ImlOp::seq(vec![body, ImlOp::from(Op::Extend)], false)
         }
 
+        // inline sequence shapes -----------------------------------------
+        "inline_flat" | "inline_list" => {
+            // Applies the shape to the sequence, or to every sequence of alternatives
+            fn shape(op: ImlOp, list: bool) -> ImlOp {
+                match op {
+                    ImlOp::Alt { alts } => ImlOp::Alt {
+                        alts: alts.into_iter().map(|alt| shape(alt, list)).collect(),
+                    },
+                    // Sequence captures are taken into the enclosing sequence
+                    ImlOp::Seq { seq, collect: true } if !list => ImlOp::Seq {
+                        seq,
+                        collect: false,
+                    },
+                    // Sequence captures are collected into a list in any case
+                    ImlOp::Seq { seq, .. } if list => ImlOp::seq(
+                        vec![
+                            ImlOp::from(Op::Frame(0)),
+                            ImlOp::Seq {
+                                seq,
+                                collect: false,
+                            },
+                            ImlOp::from(Op::CollectList),
+                            ImlOp::from(Op::Close),
+                        ],
+                        false,
+                    ),
+                    op if list => shape(
+                        ImlOp::Seq {
+                            seq: vec![op],
+                            collect: false,
+                        },
+                        true,
+                    ),
+                    op => op,
+                }
+            }
+
+            shape(traverse(scope, &node["children"]), emit == "inline_list")
+        }
+
         // assign ---------------------------------------------------------
         assign if assign.starts_with("assign") => {
             let children = node["children"].borrow();
@@ -883,12 +1133,24 @@ fn traverse_node(scope: &Scope, node: &Dict) -> ImlOp {
             if let Some(ast) = node.get_str("children") {
                 fn traverse_alts(scope: &Scope, ast: &RefValue) -> ImlOp {
                     if let Some(list) = ast.borrow().object::<List>() {
-                        let mut alts = Vec::new();
+                        let mut alts: Vec<ImlOp> = Vec::new();
 
                         for item in list.iter() {
                             match traverse(scope, item) {
                                 ImlOp::Nop => {}
-                                item => alts.push(item),
+                                op => {
+                                    if alts.last().is_some_and(|alt| alt.is_leaving()) {
+                                        scope.lint(
+                                            traverse_node_offset(
+                                                item.borrow().object::<Dict>().unwrap(),
+                                            ),
+                                            "unreachable-alternative",
+                                            "Alternative is never reached, as the one before always leaves".to_string(),
+                                        );
+                                    }
+
+                                    alts.push(op)
+                                }
                             }
                         }
 
@@ -991,23 +1253,56 @@ fn traverse_node(scope: &Scope, node: &Dict) -> ImlOp {
         }
 
         // capture --------------------------------------------------------
-        "capture_alias" | "capture_expr" => ImlOp::from(vec![
-            {
-                let children = node["children"].borrow();
-                traverse_node_rvalue(
-                    scope,
-                    children.object::<Dict>().unwrap(),
-                    Rvalue::CallOrLoad,
-                )
-            },
-            ImlOp::from(Op::LoadCapture),
-        ]),
+        "capture_alias" | "capture_expr" => {
+            let children = node["children"].borrow();
+            let children = children.object::<Dict>().unwrap();
+
+            // Named captures are held by variables
+            match traverse_node_capture_named(scope, children) {
+                Some((addr, true)) => ImlOp::from(Op::LoadGlobal(addr)),
+                Some((addr, false)) => ImlOp::from(Op::LoadFast(addr)),
+                None => ImlOp::from(vec![
+                    traverse_node_rvalue(scope, children, Rvalue::CallOrLoad),
+                    ImlOp::from(Op::LoadCapture),
+                ]),
+            }
+        }
 
         "capture_index" => {
             let children = node["children"].borrow();
-            let index =
-                traverse_node_value(scope, children.object::<Dict>().unwrap(), None).unwrap();
-            ImlOp::from(Op::LoadFastCapture(index.to_usize().unwrap()))
+            let index = traverse_node_capture_index(scope, children.object::<Dict>().unwrap());
+            ImlOp::from(Op::LoadFastCapture(index))
+        }
+
+        "capture_named" => {
+            let children = node["children"].borrow();
+            let children = children.object::<List>().unwrap();
+
+            let (name, expr) = (&children[0].borrow(), &children[1].borrow());
+            let name = name.object::<Dict>().unwrap()["value"].to_string();
+
+            let expr =
+                traverse_node_rvalue(scope, expr.object::<Dict>().unwrap(), Rvalue::CallOrLoad);
+
+            // The value of a named capture is held by a variable which can't be named in code
+            scope.register_variable(&format!("${}", name));
+            scope.captures.borrow_mut().push(name.clone());
+
+            let (addr, is_global) = match scope.resolve_capture(&name) {
+                Some(ImlValue::Variable {
+                    addr, is_global, ..
+                }) => (addr, is_global),
+                _ => unreachable!(),
+            };
+
+            ImlOp::from(vec![
+                expr,
+                ImlOp::from(if is_global {
+                    Op::StoreGlobalHold(addr)
+                } else {
+                    Op::StoreFastHold(addr)
+                }),
+            ])
         }
 
         // comparison -----------------------------------------------------
@@ -1147,6 +1442,21 @@ fn traverse_node(scope: &Scope, node: &Dict) -> ImlOp {
                 return ImlOp::Nop;
             }
 
+            if !ident.starts_with('_') {
+                if let Some(shadowed) = scope.shadows(ident) {
+                    scope.lint(
+                        traverse_node_offset(node),
+                        "shadowed-name",
+                        format!("Constant '{}' hides {} of the same name", ident, shadowed),
+                    );
+                }
+
+                scope
+                    .unused
+                    .borrow_mut()
+                    .insert(ident.to_string(), traverse_node_offset(node));
+            }
+
             // println!("{} : {:#?}", ident, value);
             scope.define_constant(ident, value);
 
@@ -1156,6 +1466,54 @@ fn traverse_node(scope: &Scope, node: &Dict) -> ImlOp {
             ImlOp::Nop
         }
 
+        // import ---------------------------------------------------------
+        "import" => {
+            let children = List::from(&node["children"]);
+
+            let filename = children[0].borrow().object::<Dict>().unwrap()["value"].to_string();
+
+            // The namespace is either given, or derived from the filename
+            let namespace = if let Some(alias) = children.get(1) {
+                alias.borrow().object::<Dict>().unwrap()["value"].to_string()
+            } else {
+                std::path::Path::new(&filename)
+                    .file_stem()
+                    .map(|stem| stem.to_string_lossy().to_string())
+                    .unwrap_or_default()
+            };
+
+            if !namespace.starts_with(|ch: char| ch.is_ascii_alphabetic() || ch == '_')
+                || !namespace
+                    .chars()
+                    .all(|ch| ch.is_ascii_alphanumeric() || ch == '_')
+            {
+                scope.error(
+                    traverse_node_offset(node),
+                    format!(
+                        "Cannot use '{}' as namespace; Provide one, e.g. 'import \"{}\" as lib'",
+                        namespace, filename
+                    ),
+                );
+
+                return ImlOp::Nop;
+            }
+
+            if let Some(constants) =
+                scope
+                    .compiler
+                    .import(scope, traverse_node_offset(node), &filename)
+            {
+                for (name, value) in constants {
+                    scope.define_constant(&format!("{}::{}", namespace, name), value);
+                }
+
+                // Try to resolve usage of newly imported constants in current scope
+                scope.resolve_usages();
+            }
+
+            ImlOp::Nop
+        }
+
         // inplace --------------------------------------------------------
         inplace if inplace.starts_with("inplace_") => {
             let children = node["children"].borrow();
@@ -1253,6 +1611,35 @@ fn traverse_node(scope: &Scope, node: &Dict) -> ImlOp {
                     traverse_node_rvalue(scope, children, Rvalue::Load)
                 }
 
+                "guard" => {
+                    let children = node["children"].borrow();
+
+                    let condition = traverse_node_rvalue(
+                        scope,
+                        children.object::<Dict>().unwrap(),
+                        Rvalue::CallOrLoad,
+                    );
+
+                    // Compile time evaluation
+                    if let Ok(value) = condition.get_evaluable_value() {
+                        if value.is_true() {
+                            return ImlOp::Nop;
+                        }
+
+                        return Op::Next.into();
+                    }
+
+                    // Backtrack as if the sequence didn't match when the guard condition fails
+                    ops.push(condition);
+
+                    ImlOp::If {
+                        peek: false,
+                        test: false,
+                        then: Box::new(Op::Next.into()),
+                        else_: Box::new(ImlOp::Nop),
+                    }
+                }
+
                 "next" => Op::Next.into(),
 
                 "nop" => ImlOp::Nop,
@@ -1269,9 +1656,10 @@ fn traverse_node(scope: &Scope, node: &Dict) -> ImlOp {
 
                     let res = traverse_node_rvalue(scope, children, Rvalue::CallOrLoad);
 
-                    // Evaluate operation at compile-time if possible
+                    // Evaluate operation at compile-time if possible; overflowing integer results
+                    // are left to runtime, which decides on the overflow behavior.
                     if let Ok(value) = res.get_evaluable_value() {
-                        if let Ok(value) = value.unary_op(parts[2]) {
+                        if let Ok(value) = value.unary_op_overflow(parts[2], Overflow::Error) {
                             return ImlOp::load(
                                 scope,
                                 traverse_node_offset(node),
@@ -1335,11 +1723,14 @@ fn traverse_node(scope: &Scope, node: &Dict) -> ImlOp {
                             }
                         }
                         _ => {
-                            // When both operands are direct values, evaluate operation at compile-time
+                            // When both operands are direct values, evaluate operation at compile-time,
+                            // unless an integer result overflows and is left to runtime.
                             if let (Ok(left), Ok(right)) =
                                 (left.get_evaluable_value(), right.get_evaluable_value())
                             {
-                                if let Ok(value) = left.binary_op(right, parts[2]) {
+                                if let Ok(value) =
+                                    left.binary_op_overflow(right, parts[2], Overflow::Error)
+                                {
                                     return ImlOp::load(
                                         scope,
                                         traverse_node_offset(node),
@@ -1440,11 +1831,54 @@ fn traverse_node(scope: &Scope, node: &Dict) -> ImlOp {
                                 }
                             }
                             // fixme: This is an ugly hack to keep severity for modified versions
-                            Some(Token::Touch(_)) => assume_severity = Some(0),
+                            Some(Token::Touch(_) | Token::TouchCaseless(_)) => {
+                                assume_severity = Some(0)
+                            }
                             _ => {}
                         }
                     }
 
+                    if matches!(parts[2], "pos" | "kle") && res.matches_empty(true) {
+                        scope.lint(
+                            offset,
+                            "empty-repeat",
+                            "Repeated item matches empty input, so it can't make progress"
+                                .to_string(),
+                        );
+                    }
+
+                    if parts[2] == "opt" {
+                        // Optionals take the result for no match from the modifier, or the compiler
+                        let unmatched = match parts.get(3) {
+                            Some(&"void") => Unmatched::Void,
+                            Some(&"empty") => Unmatched::Empty,
+                            Some(&"skip") => Unmatched::Skip,
+                            _ => scope.compiler.unmatched,
+                        };
+
+                        match unmatched {
+                            Unmatched::Void => {}
+                            Unmatched::Empty => {
+                                let mut opt = res.into_generic("Opt", assume_severity, offset);
+
+                                if let ImlValue::Instance { nargs, .. } = &mut opt {
+                                    nargs.insert(
+                                        "unmatched".to_string(),
+                                        (offset, scope.compiler.register_static(value!(""))),
+                                    );
+                                }
+
+                                return ImlOp::call(scope, offset, opt, None);
+                            }
+                            // Without a match, the alternative is left without any capture
+                            Unmatched::Skip => {
+                                return ImlOp::Alt {
+                                    alts: vec![ImlOp::call(scope, offset, res, None), ImlOp::Nop],
+                                };
+                            }
+                        }
+                    }
+
                     ImlOp::call(
                         scope,
                         offset.clone(),
@@ -1640,6 +2074,9 @@ fn traverse_node(scope: &Scope, node: &Dict) -> ImlOp {
 
             let mut ops = Vec::new();
 
+            // Named captures are only visible within their sequence
+            let captures = scope.captures.borrow().len();
+
             for node in children.iter() {
                 ops.push(traverse_node_rvalue(
                     scope,
@@ -1648,6 +2085,12 @@ fn traverse_node(scope: &Scope, node: &Dict) -> ImlOp {
                 ));
             }
 
+            scope.captures.borrow_mut().truncate(captures);
+
+            if emit == "sequence" {
+                lint_capture_shifts(scope, &children, &ops);
+            }
+
             match emit {
                 "list" if ops.is_empty() => ImlOp::from(Op::MakeList(0)),
                 "list" => {
@@ -1664,6 +2107,46 @@ fn traverse_node(scope: &Scope, node: &Dict) -> ImlOp {
     }
 }
 
+/** Warns about numbered captures in a sequence, which are shifted by a previous item.
+
+When an item like `P?<skip>` takes no capture, `$n` refers to another item behind it, depending on
+the input. Aliases don't suffer from this. */
+fn lint_capture_shifts(scope: &Scope, children: &List, items: &[ImlOp]) {
+    for (pos, item) in items.iter().enumerate() {
+        if !item.is_capture_shifting() {
+            continue;
+        }
+
+        let mut indexes = Vec::new();
+
+        for item in &items[pos + 1..] {
+            item.capture_indexes(&mut indexes);
+        }
+
+        // Only captures from the shifting item on are affected
+        indexes.retain(|index| *index > pos);
+        indexes.sort();
+        indexes.dedup();
+
+        if indexes.is_empty() {
+            continue;
+        }
+
+        scope.lint(
+            traverse_node_offset(children[pos].borrow().object::<Dict>().unwrap()),
+            "capture-index",
+            format!(
+                "{} may refer to another item when this one takes no capture; use an alias instead",
+                indexes
+                    .iter()
+                    .map(|index| format!("'${}'", index))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+        );
+    }
+}
+
 /// Debug function to print an AST to stdout.
 pub fn print(ast: &RefValue) {
     fn print(value: &RefValue, indent: usize) {
@@ -1674,16 +2157,16 @@ pub fn print(ast: &RefValue) {
 
             let row = d
                 .get_str("row")
-                .and_then(|row| Some(row.borrow().to_usize().unwrap()));
+                .and_then(|row| row.borrow().to_usize().ok());
             let col = d
                 .get_str("col")
-                .and_then(|col| Some(col.borrow().to_usize().unwrap()));
+                .and_then(|col| col.borrow().to_usize().ok());
             let stop_row = d
                 .get_str("stop_row")
-                .and_then(|row| Some(row.borrow().to_usize().unwrap()));
+                .and_then(|row| row.borrow().to_usize().ok());
             let stop_col = d
                 .get_str("stop_col")
-                .and_then(|col| Some(col.borrow().to_usize().unwrap()));
+                .and_then(|col| col.borrow().to_usize().ok());
 
             let value = d.get_str("value");
             let children = d.get_str("children");
@@ -1774,18 +2257,19 @@ tokay_function!("ast : @emit, value=void, flatten=true, debug=false", {
         }
     }
 
-    let start = context.frame.reader_start;
-    let reader_start = context.thread.reader.start();
+    let reader = &context.thread.reader;
 
     // Store positions of reader start
-    ret.insert_str("offset", value!(start.offset + reader_start.offset));
+    let start = reader.origin(&context.frame.reader_start, false);
+
+    ret.insert_str("offset", value!(start.offset));
     ret.insert_str("row", value!(start.row as usize));
     ret.insert_str("col", value!(start.col as usize));
 
     // Store positions of reader stop
-    let current = context.thread.reader.tell();
+    let current = reader.origin(&reader.tell(), true);
 
-    ret.insert_str("stop_offset", value!(current.offset + reader_start.offset));
+    ret.insert_str("stop_offset", value!(current.offset));
     ret.insert_str("stop_row", value!(current.row as usize));
     ret.insert_str("stop_col", value!(current.col as usize));
 
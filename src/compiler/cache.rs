@@ -0,0 +1,135 @@
+//! Cache of compiled programs, keyed by their source and the compiler settings
+use super::{Compiler, LINTS};
+use crate::error::Error;
+use crate::reader::Reader;
+use crate::vm::Program;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::path::PathBuf;
+
+/** Directory caching compiled programs, so that they are loaded instead of compiled again.
+
+Programs are stored in their JSON representation of `Program::to_json()`, named by a hash of the
+Tokay version and build, the compiler settings, the source's filename and the source itself. Any change of
+these results in another entry, and entries which can't be loaded are compiled again.
+
+Programs importing other files aren't cached, as changes of the imported files wouldn't be
+noticed. Neither are programs with warnings, so that warnings are reported on every compilation. */
+pub struct Cache {
+    pub dir: PathBuf, // Directory the compiled programs are stored in
+}
+
+impl Cache {
+    /// Creates a cache in a directory, which is created when the first program is stored.
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    /// Returns the default cache directory, which is `tokay` in `$XDG_CACHE_HOME` or `~/.cache`.
+    pub fn default_dir() -> Option<PathBuf> {
+        std::env::var_os("XDG_CACHE_HOME")
+            .filter(|dir| !dir.is_empty())
+            .map(PathBuf::from)
+            .or_else(|| std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".cache")))
+            .map(|dir| dir.join("tokay"))
+    }
+
+    /// Returns the key of a source compiled by a compiler.
+    pub fn key(compiler: &Compiler, filename: Option<&str>, source: &str) -> String {
+        let mut hasher = DefaultHasher::new();
+
+        env!("CARGO_PKG_VERSION").hash(&mut hasher);
+
+        // The executable's build time tells apart builds of the same version, like during development
+        std::env::current_exe()
+            .and_then(|exe| exe.metadata())
+            .and_then(|metadata| metadata.modified())
+            .ok()
+            .hash(&mut hasher);
+
+        format!("{:?}", compiler.unmatched).hash(&mut hasher);
+        compiler.inline.hash(&mut hasher);
+        compiler.sandbox.hash(&mut hasher);
+
+        // Denied lints may fail a compilation which succeeded before
+        for (lint, ..) in LINTS {
+            format!("{:?}", compiler.lint_level(lint)).hash(&mut hasher);
+        }
+
+        filename.hash(&mut hasher);
+        source.hash(&mut hasher);
+
+        format!("{:016x}", hasher.finish())
+    }
+
+    /// Returns the path of the entry of a key
+    fn path(&self, key: &str) -> PathBuf {
+        self.dir.join(key).with_extension("tokc")
+    }
+
+    /// Loads the program stored under a key, if there is a valid one.
+    pub fn load(&self, key: &str) -> Option<Program> {
+        let json = std::fs::read_to_string(self.path(key)).ok()?;
+        Program::from_json(&json).ok()
+    }
+
+    /// Stores a program under a key.
+    pub fn store(&self, key: &str, program: &Program) -> Result<(), Error> {
+        let json = program.to_json()?;
+
+        // The entry is written under a temporary name first, so it is never read partially
+        let path = self.path(key);
+        let temp = path.with_extension(format!("tokc.{}", std::process::id()));
+
+        std::fs::create_dir_all(&self.dir)
+            .and_then(|_| std::fs::write(&temp, json))
+            .and_then(|_| std::fs::rename(&temp, &path))
+            .map_err(|err| {
+                Error::from(format!(
+                    "Can't store compiled program in '{}': {}",
+                    self.dir.display(),
+                    err
+                ))
+            })
+    }
+
+    /** Compiles a program from a reader with a compiler, or loads it from the cache.
+
+    Failing to store the compiled program doesn't fail the compilation. */
+    pub fn compile(
+        &self,
+        compiler: &mut Compiler,
+        mut reader: Reader,
+    ) -> Result<Option<Program>, Vec<Error>> {
+        // The compiler prints its intermediate steps in debug mode, so it always compiles
+        if compiler.debug > 0 {
+            return compiler.compile(reader);
+        }
+
+        let filename = reader.filename.clone();
+        let source = reader.span(|_| true).unwrap_or_default().to_string();
+        let key = Self::key(compiler, filename.as_deref(), &source);
+
+        if let Some(program) = self.load(&key) {
+            return Ok(Some(program));
+        }
+
+        let warnings = compiler.warnings.borrow().len();
+
+        let program = compiler.compile(Reader::new(
+            filename,
+            Box::new(std::io::Cursor::new(source)),
+        ))?;
+
+        if let Some(program) = &program {
+            if compiler.warnings.borrow().len() == warnings && compiler.modules.borrow().is_empty()
+            {
+                if let Err(error) = self.store(&key, program) {
+                    log::warn!("{}", error);
+                }
+            }
+        }
+
+        Ok(program)
+    }
+}
@@ -1,6 +1,7 @@
 //! Tokay compiler
 
 use super::*;
+use crate::builtin::{Builtin, BuiltinFn};
 use crate::error::Error;
 use crate::reader::*;
 use crate::value;
@@ -9,6 +10,59 @@ use crate::vm::*;
 use indexmap::{indexset, IndexMap, IndexSet};
 use log;
 use std::cell::RefCell;
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+/// Result of an optional `P?` in the captures of a sequence, when `P` doesn't match
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum Unmatched {
+    #[default]
+    Void, // Void keeps the capture index, is left out of lists and becomes null under an alias
+    Empty, // An empty string keeps the capture index and becomes part of lists and dicts
+    Skip,  // No capture is taken, so that the captures following move up
+}
+
+/// Level of a lint, deciding how its findings are reported
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum LintLevel {
+    Allow, // Findings are ignored
+    Warn,  // Findings are reported as warnings
+    Deny,  // Findings are reported as errors, which stop compilation
+}
+
+/// Lints checked during compilation, with their default level and a description
+pub const LINTS: &[(&str, LintLevel, &str)] = &[
+    (
+        "capture-index",
+        LintLevel::Warn,
+        "Numbered captures shifted by items which may take no capture",
+    ),
+    (
+        "empty-repeat",
+        LintLevel::Warn,
+        "Repetitions of items which match empty input",
+    ),
+    (
+        "infinite-recursion",
+        LintLevel::Warn,
+        "Parselets calling themselves in every alternative before consuming input",
+    ),
+    (
+        "shadowed-name",
+        LintLevel::Allow,
+        "Constants hiding a constant of an outer scope or a builtin",
+    ),
+    (
+        "unreachable-alternative",
+        LintLevel::Warn,
+        "Alternatives behind an alternative which always leaves or matches their beginning",
+    ),
+    (
+        "unused-constant",
+        LintLevel::Allow,
+        "Constants never used, unless named with a leading underscore",
+    ),
+];
 
 /** Tokay compiler instance
 
@@ -18,10 +72,18 @@ The compiler works in a mode so that statics, variables and constants once built
 won't be removed and can be accessed on later calls.
 */
 pub struct Compiler {
-    parser: Option<parser::Parser>, // Internal Tokay parser
-    pub debug: u8,                  // Compiler debug mode
-    pub(super) restrict: bool,      // Restrict assignment of reserved identifiers
+    parser: RefCell<Option<parser::Parser>>, // Internal Tokay parser
+    pub debug: u8,                           // Compiler debug mode
+    pub search_path: Vec<PathBuf>,           // Directories searched for imported files
+    pub unmatched: Unmatched,                // Result of unmatched optionals
+    pub inline: bool,                        // Inline tiny parselets at their call sites
+    pub sandbox: bool, // Disable builtins and imports touching the environment
+    lints: HashMap<String, LintLevel>, // Lint levels differing from their defaults
+    pub(super) restrict: bool, // Restrict assignment of reserved identifiers
     pub(super) statics: RefCell<IndexSet<RefValue>>, // Static values collected during compilation
+    pub(super) imports: RefCell<Vec<PathBuf>>, // Files currently compiled, to detect cyclic imports
+    pub(super) warnings: RefCell<Vec<Error>>, // Warnings raised during compilation
+    pub(super) modules: RefCell<HashMap<PathBuf, IndexMap<String, ImlValue>>>, // Constants of imported files
 
     // TODO: As workaround to emulate old behavior of the Compiler struct
     main: ImlParseletModel,                // keep global parselet
@@ -51,10 +113,18 @@ impl Compiler {
         ];
 
         let mut compiler = Self {
-            parser: None,
+            parser: RefCell::new(None),
             debug: 0,
+            search_path: Vec::new(),
+            unmatched: Unmatched::default(),
+            inline: true,
+            sandbox: false,
+            lints: HashMap::new(),
             restrict: true,
             statics: RefCell::new(statics),
+            imports: RefCell::new(Vec::new()),
+            warnings: RefCell::new(Vec::new()),
+            modules: RefCell::new(HashMap::new()),
             // TODO: workaround...
             main: ImlParseletModel::new(None),
             constants: IndexMap::new(),
@@ -62,6 +132,7 @@ impl Compiler {
 
         // Compile with the default prelude
         compiler.load_prelude();
+        compiler.warnings.borrow_mut().clear();
 
         // Set compiler debug level afterwards
         compiler.debug = if let Ok(level) = std::env::var("TOKAY_DEBUG") {
@@ -116,9 +187,11 @@ impl Compiler {
             // println!("usages = {:?}", global_scope.usages);
 
             for usage in global_scope.usages.borrow_mut().drain(..) {
-                global_scope.error(usage.offset(), format!("Use of undefined name '{}'", usage));
+                global_scope.error(usage.offset(), self.undefined(&usage.to_string()));
             }
 
+            global_scope.lint_unused();
+
             // Break on error
             if !global_scope.errors.borrow().is_empty() {
                 return Err(global_scope.errors.borrow_mut().drain(..).collect());
@@ -146,10 +219,22 @@ impl Compiler {
             println!("--- Intermediate main ---\n{:#?}", main_parselet);
         }
 
-        let program = ImlProgram::new(ImlValue::from(main_parselet));
+        let mut program = ImlProgram::new(ImlValue::from(main_parselet));
+        program.inline = self.inline;
+        let result = program.compile();
+
+        // Report lint findings, where denied findings stop compilation
+        let denied: Vec<Error> = program
+            .findings
+            .drain(..)
+            .filter_map(|finding| self.report(finding))
+            .collect();
+
+        match result {
+            Ok(_) if !denied.is_empty() => Err(denied),
+            Ok(mut program) => {
+                program.sandbox = self.sandbox;
 
-        match program.compile() {
-            Ok(program) => {
                 if self.debug > 1 {
                     println!("--- Finalized program ---");
                     program.dump();
@@ -161,22 +246,79 @@ impl Compiler {
         }
     }
 
+    /** Parse a Tokay program from a Reader source into its abstract syntax tree.
+
+    On a syntax error, parsing continues behind it, so that subsequent syntax errors are reported
+    as well. */
+    pub fn parse(&self, mut reader: Reader) -> Result<RefValue, Vec<Error>> {
+        // Create the Tokay parser when not already done
+        let mut parser = self.parser.borrow_mut();
+        let parser = parser.get_or_insert_with(Parser::new);
+
+        let filename = reader.filename.clone();
+
+        let error = match parser.parse(&mut reader) {
+            Ok(ast) => return Ok(ast),
+            Err(error) => error,
+        };
+
+        // Read the entire source to resume parsing behind the error
+        reader.reset(reader.start());
+        let source = reader.span(|_| true).unwrap_or_default().to_string();
+
+        let mut errors = vec![error];
+
+        while let Some(row) = errors.last().unwrap().offset.map(|offset| offset.row) {
+            // Resume at the next line starting a statement on top-level
+            let Some((skip, start)) = source
+                .split_inclusive('\n')
+                .scan(0, |start, line| {
+                    let ret = (*start, line);
+                    *start += line.len();
+                    Some(ret)
+                })
+                .enumerate()
+                .skip(row as usize)
+                .find(|(_, (_, line))| {
+                    line.starts_with(|ch: char| !ch.is_whitespace() && !"#)]}".contains(ch))
+                })
+                .map(|(skip, (start, _))| (skip as u32, start))
+            else {
+                break;
+            };
+
+            match parser.parse(&mut Reader::new(
+                None,
+                Box::new(std::io::Cursor::new(source[start..].to_string())),
+            )) {
+                Ok(_) => break,
+                Err(mut error) => {
+                    // Relocate the error's position into the entire source
+                    if let Some(offset) = &mut error.offset {
+                        offset.offset += start;
+                        offset.row += skip;
+                    }
+
+                    errors.push(error);
+                }
+            }
+        }
+
+        for error in errors.iter_mut() {
+            error.patch_filename(filename.as_deref());
+            error.code = Some("syntax");
+        }
+
+        Err(errors)
+    }
+
     /** Compile a Tokay program from a Reader source into the compiler. */
     pub fn compile(&mut self, reader: Reader) -> Result<Option<Program>, Vec<Error>> {
         log::trace!("compile");
 
-        // Create the Tokay parser when not already done
-        if self.parser.is_none() {
-            self.parser = Some(Parser::new());
-        }
+        let filename = reader.filename.clone();
 
-        let parser = self.parser.as_ref().unwrap();
-        let ast = match parser.parse(reader) {
-            Ok(ast) => ast,
-            Err(error) => {
-                return Err(vec![error]);
-            }
-        };
+        let ast = self.parse(reader)?;
 
         if self.debug > 0 {
             println!("--- Abstract Syntax Tree ---");
@@ -184,7 +326,72 @@ impl Compiler {
             //println!("###\n{:#?}\n###", ast);
         }
 
-        self.compile_from_ast(&ast, None)
+        // Remember the compiled file, so that imports are searched relative to it
+        let path = filename.as_ref().map(PathBuf::from);
+
+        if let Some(path) = &path {
+            self.imports.borrow_mut().push(path.clone());
+        }
+
+        let ret = self.compile_from_ast(&ast, None).map_err(|mut errors| {
+            for error in errors.iter_mut() {
+                error.patch_filename(filename.as_deref());
+                error.patch_code("compile");
+            }
+
+            errors
+        });
+
+        if path.is_some() {
+            self.imports.borrow_mut().pop();
+        }
+
+        ret
+    }
+
+    /// Sets the level of a lint by its name from `LINTS`, or of all lints by "all".
+    pub fn set_lint(&mut self, name: &str, level: LintLevel) -> Result<(), String> {
+        for (lint, ..) in LINTS {
+            if name == "all" || name == *lint {
+                self.lints.insert(lint.to_string(), level);
+            }
+        }
+
+        if name == "all" || self.lints.contains_key(name) {
+            Ok(())
+        } else {
+            Err(format!("Unknown lint '{}'", name))
+        }
+    }
+
+    /// Returns the level of a lint by its name.
+    pub fn lint_level(&self, name: &str) -> LintLevel {
+        self.lints.get(name).copied().unwrap_or_else(|| {
+            LINTS
+                .iter()
+                .find(|(lint, ..)| *lint == name)
+                .map_or(LintLevel::Allow, |(_, level, _)| *level)
+        })
+    }
+
+    /** Reports a lint finding according to the level of its lint, which is the finding's code.
+
+    Warnings are collected by the compiler, a denied finding is returned as an error. */
+    pub(super) fn report(&self, mut finding: Error) -> Option<Error> {
+        match self.lint_level(finding.code.unwrap_or_default()) {
+            LintLevel::Allow => None,
+            LintLevel::Warn => {
+                finding.patch_filename(self.imports.borrow().last().and_then(|path| path.to_str()));
+                self.warnings.borrow_mut().push(finding);
+                None
+            }
+            LintLevel::Deny => Some(finding),
+        }
+    }
+
+    /// Takes the warnings raised by previous compilations, which didn't stop them.
+    pub fn take_warnings(&self) -> Vec<Error> {
+        self.warnings.take()
     }
 
     /// Shortcut to compile a Tokay program from a &str into the compiler.
@@ -195,6 +402,174 @@ impl Compiler {
         ))
     }
 
+    /** Import the constants defined by another Tokay source file.
+
+    The file is searched relative to the directory of the file currently compiled, or the working
+    directory, and afterwards in the directories of the `search_path`. It is compiled in a global
+    scope of its own, which only knows the constants of the compiler, like the prelude. Constants
+    starting with an underscore are private to the file; all others are returned, so that they can
+    be defined under a namespace. Every file is compiled only once per compiler.
+    */
+    pub(super) fn import(
+        &self,
+        scope: &Scope,
+        offset: Option<Offset>,
+        filename: &str,
+    ) -> Option<IndexMap<String, ImlValue>> {
+        if self.sandbox {
+            scope.error(
+                offset,
+                format!("Can't import '{}' in sandbox mode", filename),
+            );
+            return None;
+        }
+
+        let base = self
+            .imports
+            .borrow()
+            .last()
+            .and_then(|path| path.parent())
+            .map(|path| path.to_path_buf())
+            .unwrap_or_default();
+
+        let found = std::iter::once(&base)
+            .chain(self.search_path.iter())
+            .map(|dir| dir.join(filename))
+            .find(|path| path.is_file());
+
+        let found = match found {
+            Some(found) => found,
+            None => {
+                scope.error(offset, format!("Can't find '{}' to import", filename));
+                return None;
+            }
+        };
+
+        let path = found.canonicalize().unwrap_or(found.clone());
+
+        if self
+            .imports
+            .borrow()
+            .iter()
+            .any(|import| import.canonicalize().is_ok_and(|import| import == path))
+        {
+            scope.error(offset, format!("Cyclic import of '{}'", filename));
+            return None;
+        }
+
+        if let Some(constants) = self.modules.borrow().get(&path) {
+            return Some(constants.clone());
+        }
+
+        let filename = found.to_string_lossy().to_string();
+
+        let ast = match Reader::from_file(&filename)
+            .map_err(|err| {
+                vec![Error::new(
+                    offset,
+                    format!("Can't import '{}': {}", filename, err),
+                )]
+            })
+            .and_then(|reader| self.parse(reader))
+        {
+            Ok(ast) => ast,
+            Err(errors) => {
+                scope.errors.borrow_mut().extend(errors);
+                return None;
+            }
+        };
+
+        let parselet = ImlParselet::new(ImlParseletInstance::new(
+            None,
+            None,
+            None,
+            Some(filename.clone()),
+            5,
+            false,
+        ));
+
+        let global_scope = Scope::new(self, ScopeLevel::Parselet(parselet), None);
+
+        global_scope
+            .constants
+            .borrow_mut()
+            .extend(self.constants.clone());
+
+        self.imports.borrow_mut().push(found.clone());
+
+        // Constants of the imported file are defined in a block scope, to tell them apart
+        let constants = {
+            let scope = global_scope.shadow(ScopeLevel::Block);
+            ast::traverse_import(&scope, &ast);
+            scope.resolve_usages();
+            scope.constants.take()
+        };
+
+        self.imports.borrow_mut().pop();
+
+        global_scope.resolve_usages();
+
+        for usage in global_scope.usages.borrow_mut().drain(..) {
+            global_scope.error(usage.offset(), self.undefined(&usage.to_string()));
+        }
+
+        let mut errors = global_scope.errors.borrow_mut();
+
+        if !errors.is_empty() {
+            for mut error in errors.drain(..) {
+                error.patch_filename(Some(&filename));
+                scope.errors.borrow_mut().push(error);
+            }
+
+            return None;
+        }
+
+        let constants: IndexMap<String, ImlValue> = constants
+            .into_iter()
+            .filter(|(name, _)| !name.starts_with('_'))
+            .collect();
+
+        self.modules.borrow_mut().insert(path, constants.clone());
+        Some(constants)
+    }
+
+    /// Describes the use of an undefined name, which might be a builtin removed in sandbox mode.
+    fn undefined(&self, name: &str) -> String {
+        if self.sandbox && Builtin::get(name).is_some_and(|builtin| builtin.touches_environment()) {
+            format!("Builtin '{}' isn't available in sandbox mode", name)
+        } else {
+            format!("Use of undefined name '{}'", name)
+        }
+    }
+
+    /** Register a native Rust function as builtin, which can be called from Tokay code by name.
+
+    Use the `tokay_function!` macro from the `tokay-macros` crate to have arguments unpacked
+    automatically from a signature.
+
+    ```
+    use tokay::{value, Compiler, Object};
+
+    tokay_macros::tokay_function!("double : @x", value!(x.to_i64()? * 2).into());
+
+    let mut compiler = Compiler::new();
+    compiler.register_builtin("double", tokay_function_double);
+
+    let program = compiler.compile_from_str("double(21)").unwrap().unwrap();
+    assert_eq!(program.run_from_str("").unwrap(), Some(value!(42)));
+    ```
+    */
+    pub fn register_builtin(&mut self, name: &str, func: BuiltinFn) {
+        // Builtins are referenced statically, so registered builtins live until the program ends.
+        let builtin: &'static Builtin = Box::leak(Box::new(Builtin {
+            name: Box::leak(name.to_string().into_boxed_str()),
+            func,
+        }));
+
+        self.constants
+            .insert(name.to_string(), RefValue::from(builtin).into());
+    }
+
     /** Register a static value within a compiler instance.
 
     This avoids that the compiler produces multiple results pointing to effectively the same values
@@ -0,0 +1,253 @@
+//! Grammar lints performed on the parselets of an ImlProgram.
+
+use super::*;
+use crate::builtin::BuiltinRef;
+use crate::reader::Offset;
+use crate::value::Token;
+use crate::Error;
+use std::collections::HashSet;
+
+/** Lints the parselets of a finalized program from a grammar's point of view.
+
+Every parselet model is checked once for
+
+- alternatives which are never reached, as an earlier alternative already matches their beginning,
+- parselets calling themselves in every alternative before consuming any input.
+
+Left-recursive parselets, as flagged along, are left out from the first check, as their
+alternatives are grown. */
+pub(super) fn lint<'a>(parselets: impl Iterator<Item = (&'a ImlParselet, bool)>) -> Vec<Error> {
+    let mut findings = Vec::new();
+    let mut models = HashSet::new();
+
+    for (parselet, is_leftrec) in parselets {
+        let model = parselet.borrow().model.clone();
+
+        if !models.insert(model.as_ptr() as usize) {
+            continue;
+        }
+
+        let model = model.borrow();
+
+        for op in [&model.begin, &model.body, &model.end] {
+            lint_op(op, parselet, is_leftrec, &mut findings);
+        }
+
+        let recurses = match &model.body {
+            ImlOp::Alt { alts } => {
+                !alts.is_empty() && alts.iter().all(|alt| recurses_first(alt, parselet))
+            }
+            body => recurses_first(body, parselet),
+        };
+
+        if recurses {
+            let parselet = parselet.borrow();
+
+            let mut finding = Error::new(
+                parselet.offset,
+                format!(
+                    "Parselet '{}' calls itself in every alternative before consuming any input, so it never ends",
+                    parselet.name.as_deref().unwrap_or("(unnamed)")
+                ),
+            );
+            finding.code = Some("infinite-recursion");
+            findings.push(finding);
+        }
+    }
+
+    findings
+}
+
+/// Resolves shared values and derives parselets by the current parselet
+fn resolve(value: &ImlValue, current: &ImlParselet) -> ImlValue {
+    match value {
+        ImlValue::Shared(value) => resolve(&value.borrow(), current),
+        ImlValue::Generic { name, .. } => match current.borrow().generics.get(name) {
+            Some(Some(value)) if !matches!(value, ImlValue::Generic { .. }) => {
+                resolve(value, current)
+            }
+            _ => value.clone(),
+        },
+        ImlValue::Parselet(parselet) => match parselet.derive(current) {
+            Ok(parselet) => ImlValue::Parselet(parselet),
+            Err(_) => value.clone(),
+        },
+        value => value.clone(),
+    }
+}
+
+/// Recursively checks the alternations inside of an ImlOp for unreachable alternatives
+fn lint_op(op: &ImlOp, current: &ImlParselet, is_leftrec: bool, findings: &mut Vec<Error>) {
+    match op {
+        ImlOp::Alt { alts } => {
+            if !is_leftrec {
+                for (idx, alt) in alts.iter().enumerate() {
+                    let (items, _) = prefix(alt, current);
+
+                    let Some((offset, _)) = items.first() else {
+                        continue;
+                    };
+
+                    if alts[..idx].iter().any(|earlier| {
+                        let (earlier, complete) = prefix(earlier, current);
+                        complete && covers(&earlier, &items)
+                    }) {
+                        let mut finding = Error::new(
+                            *offset,
+                            "Alternative is never reached, as an earlier one already matches its beginning"
+                                .to_string(),
+                        );
+                        finding.code = Some("unreachable-alternative");
+                        findings.push(finding);
+                    }
+                }
+            }
+
+            for alt in alts {
+                lint_op(alt, current, is_leftrec, findings);
+            }
+        }
+        ImlOp::Seq { seq, .. } => {
+            for item in seq {
+                lint_op(item, current, is_leftrec, findings);
+            }
+        }
+        ImlOp::If { then, else_, .. } => {
+            lint_op(then, current, is_leftrec, findings);
+            lint_op(else_, current, is_leftrec, findings);
+        }
+        ImlOp::Loop {
+            initial,
+            condition,
+            body,
+            ..
+        } => {
+            lint_op(initial, current, is_leftrec, findings);
+            lint_op(condition, current, is_leftrec, findings);
+            lint_op(body, current, is_leftrec, findings);
+        }
+        _ => {}
+    }
+}
+
+/** Collects the consuming items an alternative starts with.
+
+The collection stops at the first item which can't be compared, like blocks, conditionals or calls
+with arguments. The returned flag is true when the entire alternative only consists of the collected
+items and operations which don't let it fail. */
+fn prefix(alt: &ImlOp, current: &ImlParselet) -> (Vec<(Option<Offset>, ImlValue)>, bool) {
+    let ops = match alt {
+        ImlOp::Seq { seq, .. } => seq.as_slice(),
+        op => std::slice::from_ref(op),
+    };
+
+    let mut items = Vec::new();
+
+    for op in ops {
+        match op {
+            ImlOp::Call {
+                offset,
+                target,
+                args: None,
+            } if target.is_consuming() => items.push((*offset, resolve(target, current))),
+            ImlOp::Call { target, .. }
+                if !target.is_consuming() && !may_reject(target, current) => {}
+            ImlOp::Nop | ImlOp::Load { .. } => {}
+            ImlOp::Op(op) if !matches!(op, Op::Reject | Op::Next) => {}
+            _ => return (items, false),
+        }
+    }
+
+    (items, true)
+}
+
+/// Checks if a call to a non-consuming target may reject, which is assumed for anything but builtins
+fn may_reject(target: &ImlValue, current: &ImlParselet) -> bool {
+    match resolve(target, current) {
+        ImlValue::Value(value) => value
+            .borrow()
+            .object::<BuiltinRef>()
+            .is_some_and(|builtin| builtin.0.name == "reject_if"),
+        _ => true,
+    }
+}
+
+/// Checks if the items of an earlier alternative always match the beginning of the later items
+fn covers(earlier: &[(Option<Offset>, ImlValue)], later: &[(Option<Offset>, ImlValue)]) -> bool {
+    let Some(((_, last), init)) = earlier.split_last() else {
+        return false;
+    };
+
+    if earlier.len() > later.len() {
+        return false;
+    }
+
+    init.iter()
+        .zip(later)
+        .all(|((_, earlier), (_, later))| earlier == later)
+        && (*last == later[init.len()].1 || token_covers(last, &later[init.len()].1))
+}
+
+/// Checks if the token `earlier` always matches the beginning of token `later`
+fn token_covers(earlier: &ImlValue, later: &ImlValue) -> bool {
+    let (ImlValue::Value(earlier), ImlValue::Value(later)) = (earlier, later) else {
+        return false;
+    };
+
+    let (earlier, later) = (earlier.borrow(), later.borrow());
+
+    let (Some(earlier), Some(Token::Match(later) | Token::Touch(later))) =
+        (earlier.object::<Token>(), later.object::<Token>())
+    else {
+        return false;
+    };
+
+    let Some(ch) = later.chars().next() else {
+        return false;
+    };
+
+    match earlier {
+        Token::Match(earlier) | Token::Touch(earlier) => {
+            !earlier.is_empty() && later.starts_with(earlier.as_str())
+        }
+        Token::Char(ccl) | Token::Chars(ccl) => ccl.test(&(ch..=ch)),
+        Token::BuiltinChar(_, f) | Token::BuiltinChars(_, f) => f(ch),
+        _ => false,
+    }
+}
+
+/// Checks if an ImlOp calls the current parselet before consuming any input or leaving
+fn recurses_first(op: &ImlOp, current: &ImlParselet) -> bool {
+    let ops = match op {
+        ImlOp::Seq { seq, .. } => seq.as_slice(),
+        op => std::slice::from_ref(op),
+    };
+
+    for op in ops {
+        match op {
+            ImlOp::Call { target, .. } => {
+                let is_self = match resolve(target, current) {
+                    ImlValue::SelfToken | ImlValue::SelfValue => true,
+                    ImlValue::Parselet(parselet) => {
+                        parselet.borrow().model.as_ptr() == current.borrow().model.as_ptr()
+                    }
+                    _ => false,
+                };
+
+                if is_self {
+                    return true;
+                } else if target.is_consuming() {
+                    return false;
+                }
+            }
+            ImlOp::Nop | ImlOp::Load { .. } => {}
+            ImlOp::Op(inner) if !matches!(inner, Op::Next) && !op.is_leaving() => {}
+            ImlOp::Alt { alts } if !alts.is_empty() => {
+                return alts.iter().all(|alt| recurses_first(alt, current))
+            }
+            _ => return false,
+        }
+    }
+
+    false
+}
@@ -3,6 +3,7 @@
 use super::*;
 use crate::reader::Offset;
 use crate::utils;
+use crate::value::{Dict, List, Overflow};
 use crate::{Object, RefValue};
 
 #[derive(Debug, Clone)]
@@ -161,7 +162,19 @@ impl ImlOp {
                 target,
                 args,
             } => {
-                target.compile(program, current, &offset, Some(*args), ops);
+                // Only calls without arguments can be inlined
+                let inlined = if args.is_none() {
+                    program.inline(target)
+                } else {
+                    None
+                };
+
+                match inlined {
+                    Some(inlined) => {
+                        inlined.compile(program, current, ops);
+                    }
+                    None => target.compile(program, current, offset, Some(*args), ops),
+                }
             }
             ImlOp::Alt { alts } => {
                 let mut ret = Vec::new();
@@ -350,6 +363,85 @@ impl ImlOp {
         walk(self).unwrap_or(false)
     }
 
+    /// Returns true when the ImlOp matches the empty input in any case, like calls to optionals
+    pub fn matches_empty(&self) -> bool {
+        match self {
+            ImlOp::Nop => true,
+            ImlOp::Call { target, .. } => target.matches_empty(false),
+            ImlOp::Alt { alts } => alts.iter().any(|alt| alt.matches_empty()),
+            ImlOp::Seq { seq, .. } => seq.iter().all(|item| item.matches_empty()),
+            _ => false,
+        }
+    }
+
+    /// Returns true when the ImlOp always leaves its block without consuming input, like `accept`
+    pub fn is_leaving(&self) -> bool {
+        match self {
+            ImlOp::Op(
+                Op::Accept
+                | Op::LoadAccept
+                | Op::Reject
+                | Op::Repeat
+                | Op::Exit
+                | Op::LoadExit
+                | Op::Break
+                | Op::LoadBreak
+                | Op::Continue,
+            ) => true,
+            ImlOp::Seq { seq, .. } => !self.is_consuming() && seq.iter().any(|op| op.is_leaving()),
+            _ => false,
+        }
+    }
+
+    /** Returns true when the number of captures the ImlOp takes in a sequence depends on the input.
+
+    This is the case for alternatives with an empty branch, like optionals which take no capture
+    when unmatched. All captures behind such an item shift their index. */
+    pub fn is_capture_shifting(&self) -> bool {
+        match self {
+            ImlOp::Alt { alts } => {
+                alts.iter().any(|alt| matches!(alt, ImlOp::Nop))
+                    && alts.iter().any(|alt| !matches!(alt, ImlOp::Nop))
+            }
+            ImlOp::Seq {
+                seq,
+                collect: false,
+            } => seq.iter().any(|item| item.is_capture_shifting()),
+            _ => false,
+        }
+    }
+
+    /// Collects the indexes of numbered captures, like `$3`, used by the ImlOp
+    pub fn capture_indexes(&self, indexes: &mut Vec<usize>) {
+        match self {
+            ImlOp::Op(
+                Op::LoadFastCapture(index)
+                | Op::StoreFastCapture(index)
+                | Op::StoreFastCaptureHold(index),
+            ) => indexes.push(*index),
+            ImlOp::Alt { alts: items } | ImlOp::Seq { seq: items, .. } => {
+                for item in items {
+                    item.capture_indexes(indexes);
+                }
+            }
+            ImlOp::If { then, else_, .. } => {
+                then.capture_indexes(indexes);
+                else_.capture_indexes(indexes);
+            }
+            ImlOp::Loop {
+                initial,
+                condition,
+                body,
+                ..
+            } => {
+                initial.capture_indexes(indexes);
+                condition.capture_indexes(indexes);
+                body.capture_indexes(indexes);
+            }
+            _ => {}
+        }
+    }
+
     /** Returns a value to operate with or evaluate during compile-time.
 
     The function will only return Ok(Value) when the static_expression_evaluation-feature
@@ -369,6 +461,201 @@ impl ImlOp {
 
         Err(())
     }
+
+    /** Folds constant expressions and removes dead branches.
+
+    Unary and binary operations on constant operands are evaluated, conditionals on a constant
+    condition are replaced by the branch taken, and lists made of constant scalars are preloaded
+    as one value. Operations failing at compile-time, like integer overflows, are left to runtime.
+
+    The folding is only done when the static_expression_evaluation-feature is enabled. */
+    pub fn fold(self) -> ImlOp {
+        if !cfg!(feature = "static_expression_evaluation") {
+            return self;
+        }
+
+        match self {
+            ImlOp::Alt { alts } => ImlOp::Alt {
+                alts: alts.into_iter().map(|alt| alt.fold()).collect(),
+            },
+            ImlOp::Seq { seq, collect } => {
+                let mut folded = Vec::new();
+
+                for item in seq {
+                    Self::fold_into(&mut folded, item.fold());
+                }
+
+                ImlOp::seq(folded, collect)
+            }
+            ImlOp::If {
+                peek,
+                test,
+                then,
+                else_,
+            } => ImlOp::If {
+                peek,
+                test,
+                then: Box::new(then.fold()),
+                else_: Box::new(else_.fold()),
+            },
+            ImlOp::Loop {
+                use_iterator,
+                initial,
+                condition,
+                body,
+            } => ImlOp::Loop {
+                use_iterator,
+                initial: Box::new(initial.fold()),
+                condition: Box::new(condition.fold()),
+                body: Box::new(body.fold()),
+            },
+            op => op,
+        }
+    }
+
+    /// Appends a folded item to a sequence, evaluating it with the constants pushed before.
+    fn fold_into(seq: &mut Vec<ImlOp>, item: ImlOp) {
+        match item {
+            ImlOp::Seq {
+                seq: items,
+                collect: false,
+            } => {
+                for item in items {
+                    Self::fold_into(seq, item);
+                }
+            }
+
+            ImlOp::Op(Op::UnaryOp(op)) if !op.starts_with('i') => {
+                if let Some((start, offset, mut operands)) = Self::fold_operands(seq, 1) {
+                    if let Ok(value) = operands.remove(0).unary_op_overflow(op, Overflow::Error) {
+                        seq.truncate(start);
+                        seq.push(ImlOp::Load {
+                            offset,
+                            target: ImlValue::from(value),
+                        });
+                        return;
+                    }
+                }
+
+                seq.push(item)
+            }
+
+            ImlOp::Op(Op::BinaryOp(op)) if !op.starts_with('i') => {
+                if let Some((start, offset, mut operands)) = Self::fold_operands(seq, 2) {
+                    let right = operands.pop().unwrap();
+
+                    if let Ok(value) =
+                        operands
+                            .pop()
+                            .unwrap()
+                            .binary_op_overflow(right, op, Overflow::Error)
+                    {
+                        seq.truncate(start);
+                        seq.push(ImlOp::Load {
+                            offset,
+                            target: ImlValue::from(value),
+                        });
+                        return;
+                    }
+                }
+
+                seq.push(item)
+            }
+
+            ImlOp::Op(Op::MakeList(count)) => {
+                if let Some((start, offset, items)) = Self::fold_operands(seq, count) {
+                    // Lists and dicts are mutable, and can't be shared by a preloaded list
+                    if items.iter().all(|item| {
+                        let item = item.borrow();
+                        item.object::<List>().is_none() && item.object::<Dict>().is_none()
+                    }) {
+                        let mut list = List::new();
+
+                        for item in items {
+                            if !item.is_void() {
+                                list.push(item);
+                            }
+                        }
+
+                        seq.truncate(start);
+                        seq.push(ImlOp::Load {
+                            offset,
+                            target: ImlValue::from(RefValue::from(list)),
+                        });
+                        return;
+                    }
+                }
+
+                seq.push(item)
+            }
+
+            ImlOp::If {
+                peek,
+                test,
+                then,
+                else_,
+            } => {
+                let Some((start, offset, condition)) = Self::fold_operands(seq, 1) else {
+                    seq.push(ImlOp::If {
+                        peek,
+                        test,
+                        then,
+                        else_,
+                    });
+                    return;
+                };
+
+                seq.truncate(start);
+
+                if condition[0].is_true() == test {
+                    Self::fold_into(seq, *then);
+                } else if peek {
+                    // A peeked condition which isn't met remains as the result
+                    seq.push(ImlOp::Load {
+                        offset,
+                        target: ImlValue::from(condition[0].clone()),
+                    });
+                } else {
+                    Self::fold_into(seq, *else_);
+                }
+            }
+
+            ImlOp::Nop => {}
+
+            item => seq.push(item),
+        }
+    }
+
+    /** Retrieves the constants loaded by the last `count` operations of a sequence.
+
+    Source offsets in between are skipped. Returns the index where the operations start, the offset
+    of the first operation and the constants in their order of loading. */
+    fn fold_operands(
+        seq: &[ImlOp],
+        count: usize,
+    ) -> Option<(usize, Option<Offset>, Vec<RefValue>)> {
+        let mut operands = Vec::new();
+        let mut start = seq.len();
+        let mut offset = None;
+
+        while operands.len() < count {
+            start = start.checked_sub(1)?;
+
+            match &seq[start] {
+                ImlOp::Op(Op::Offset(_)) => {}
+                ImlOp::Load {
+                    offset: load_offset,
+                    target,
+                } => {
+                    operands.insert(0, target.constant()?);
+                    offset = *load_offset;
+                }
+                _ => return None,
+            }
+        }
+
+        Some((start, offset, operands))
+    }
 }
 
 impl From<Op> for ImlOp {
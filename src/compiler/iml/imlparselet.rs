@@ -22,6 +22,7 @@ during compilation.
 #[derive(Debug, Clone)]
 pub(in crate::compiler) struct ImlParseletModel {
     pub is_consuming: bool, // Flag if parselet is consuming
+    pub is_cached: bool,    // Flag if parselet is explicitly memoized (`cache` keyword)
     pub locals: usize,      // Total number of local variables present (including arguments)
     pub signature: IndexMap<String, Option<ImlValue>>, // Arguments signature with default values
     pub variables: IndexMap<String, usize>, // Named local variables
@@ -38,11 +39,12 @@ impl ImlParseletModel {
         let variables = signature
             .keys()
             .enumerate()
-            .map(|(index, key)| (key.to_string(), index))
+            .map(|(index, key)| (key.trim_start_matches('*').to_string(), index))
             .collect();
 
         Self {
             is_consuming: false,
+            is_cached: false,
             locals: signature.len(),
             signature,
             variables,
@@ -175,7 +177,25 @@ impl std::hash::Hash for ImlParseletInstance {
     fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
         let model = &*self.model.borrow();
         (model as *const ImlParseletModel as usize).hash(state);
-        self.generics.iter().collect::<Vec<_>>().hash(state);
+
+        // Parselets in generics are only hashed by their model, as they may refer to this instance
+        fn hash_generic<H: std::hash::Hasher>(value: &ImlValue, state: &mut H) {
+            match value {
+                ImlValue::Shared(value) => hash_generic(&value.borrow(), state),
+                ImlValue::Parselet(parselet) => {
+                    (parselet.borrow().model.as_ptr() as usize).hash(state)
+                }
+                value => value.hash(state),
+            }
+        }
+
+        for (name, value) in &self.generics {
+            name.hash(state);
+
+            if let Some(value) = value {
+                hash_generic(value, state);
+            }
+        }
     }
 }
 
@@ -296,11 +316,53 @@ impl ImlParselet {
                 })
                 .collect(),
             model.locals,
-            model.begin.compile_to_vec(program, (self, this)),
-            model.end.compile_to_vec(program, (self, this)),
-            model.body.compile_to_vec(program, (self, this)),
+            model
+                .begin
+                .clone()
+                .fold()
+                .compile_to_vec(program, (self, this)),
+            model
+                .end
+                .clone()
+                .fold()
+                .compile_to_vec(program, (self, this)),
+            Self::tail_call(
+                model
+                    .body
+                    .clone()
+                    .fold()
+                    .compile_to_vec(program, (self, this)),
+            ),
         )
     }
+
+    /** Turns static calls without arguments into tail calls, when they're only followed by
+    collecting captures, closing frames and forward jumps up to the end of the ops.
+
+    Whether such a call is really run as a tail call is decided by the VM. */
+    fn tail_call(mut ops: Vec<Op>) -> Vec<Op> {
+        for ip in 0..ops.len() {
+            let Op::CallStatic(addr) = ops[ip] else {
+                continue;
+            };
+
+            let mut next = ip + 1;
+
+            while let Some(op) = ops.get(next) {
+                match op {
+                    Op::Collect | Op::Close | Op::Offset(_) => next += 1,
+                    Op::Forward(goto) => next += goto,
+                    _ => break,
+                }
+            }
+
+            if next >= ops.len() {
+                ops[ip] = Op::CallStaticTail(addr);
+            }
+        }
+
+        ops
+    }
 }
 
 impl std::hash::Hash for ImlParselet {
@@ -9,11 +9,17 @@ use indexmap::{indexmap, IndexMap, IndexSet};
 use log;
 use std::collections::{HashMap, HashSet};
 
+/// Maximum number of tokens of a parselet which is inlined at its call sites
+pub(in crate::compiler) const INLINE_LIMIT: usize = 4;
+
 #[derive(Debug)]
 pub(in crate::compiler) struct ImlProgram {
     main: ImlValue,
     statics: IndexMap<ImlValue, Option<Parselet>>, // static values with optional final parselet replacement
     pub errors: Vec<Error>, // errors collected during finalization (at least these are unresolved symbols)
+    pub findings: Vec<Error>, // lint findings on the finalized parselets
+    pub inline: bool,       // inline tiny parselets at their call sites
+    inlined: HashSet<ImlParselet>, // parselets inlined at least once
 }
 
 impl ImlProgram {
@@ -22,6 +28,9 @@ impl ImlProgram {
             main: main.clone(),
             statics: indexmap!(main => None),
             errors: Vec::new(),
+            findings: Vec::new(),
+            inline: true,
+            inlined: HashSet::new(),
         }
     }
 
@@ -52,13 +61,80 @@ impl ImlProgram {
         0
     }
 
+    /** Returns the operations to be inlined for a call of `target`, when it is a tiny parselet.
+
+    Tiny parselets only consist of up to `INLINE_LIMIT` token calls, and have no arguments, generics,
+    variables, begin or end. Calling them only costs a frame, so their tokens are inlined as a
+    collecting sequence, which results in the same value. */
+    pub fn inline(&mut self, target: &ImlValue) -> Option<ImlOp> {
+        let parselet = match target {
+            ImlValue::Shared(value) => return self.inline(&value.borrow()),
+            ImlValue::Parselet(parselet) if self.inline => parselet,
+            _ => return None,
+        };
+
+        let instance = parselet.borrow();
+        let model = instance.model.borrow();
+
+        // Parselets lowering the severity of their result aren't inlined
+        if instance.severity < 5
+            || !instance.generics.is_empty()
+            || !model.is_consuming
+            || model.is_cached
+            || model.locals > 0
+            || !matches!(model.begin, ImlOp::Nop)
+            || !matches!(model.end, ImlOp::Nop)
+        {
+            return None;
+        }
+
+        fn is_token(value: &ImlValue) -> bool {
+            match value {
+                ImlValue::Shared(value) => is_token(&value.borrow()),
+                ImlValue::Value(value) => value.is_consuming(),
+                _ => false,
+            }
+        }
+
+        let items = match &model.body {
+            ImlOp::Seq { seq, .. } => seq.as_slice(),
+            op => std::slice::from_ref(op),
+        };
+
+        let mut tokens = Vec::new();
+
+        for item in items {
+            match item {
+                ImlOp::Op(Op::Offset(_)) => {}
+                ImlOp::Call {
+                    target, args: None, ..
+                } if is_token(target) && tokens.len() < INLINE_LIMIT => tokens.push(item.clone()),
+                _ => return None,
+            }
+        }
+
+        if tokens.is_empty() {
+            return None;
+        }
+
+        // Inlined parselets are still finalized, as the parselets calling them depend on it
+        drop(model);
+        drop(instance);
+        self.inlined.insert(parselet.clone());
+
+        Some(ImlOp::Seq {
+            seq: tokens,
+            collect: true,
+        })
+    }
+
     /** Turns the ImlProgram and its intermediate values into a final VM program ready for execution.
 
     The finalization is done according to a grammar's point of view, as this is one of Tokays core features.
     This closure algorithm runs until no more changes on any parselet configurations regarding left-recursive
-    and nullable parselet detection occurs.
+    and nullable parselet detection occurs. Afterwards, the parselets are linted into `findings`.
     */
-    pub fn compile(mut self) -> Result<Program, Vec<Error>> {
+    pub fn compile(&mut self) -> Result<Program, Vec<Error>> {
         log::info!("compiling {}", self.main);
 
         let mut finalize = HashSet::new(); // list of consuming parselets required to be finalized
@@ -94,17 +170,19 @@ impl ImlProgram {
 
             // Compile VM parselet from intermediate parselet
             // println!("...compiling {} {:?}", idx, parselet.name);
-            *self.statics.get_index_mut(idx).unwrap().1 = Some(parselet.compile(&mut self, idx));
+            *self.statics.get_index_mut(idx).unwrap().1 = Some(parselet.compile(self, idx));
 
             idx += 1;
         }
 
         // Stop on any raised error
         if !self.errors.is_empty() {
-            return Err(self.errors);
+            return Err(std::mem::take(&mut self.errors));
         }
 
         // Finalize parselets
+        finalize.extend(self.inlined.iter().cloned());
+
         log::debug!("{} has {} parselets to finalize", self.main, finalize.len());
 
         for (i, parselet) in finalize.iter().enumerate() {
@@ -113,9 +191,18 @@ impl ImlProgram {
 
         let leftrec = self.finalize(finalize);
 
+        self.findings = imllint::lint(self.statics.keys().filter_map(|iml| match iml {
+            ImlValue::Parselet(parselet) => Some((parselet, leftrec.get(parselet) == Some(&true))),
+            _ => None,
+        }));
+
+        // When any parselet is flagged by `cache`, only flagged parselets are memoized
+        let selective = self.statics.keys().any(|iml| {
+            matches!(iml, ImlValue::Parselet(parselet) if parselet.borrow().model.borrow().is_cached)
+        });
+
         // Assemble all statics to be transferred into a Program
-        let statics: Vec<RefValue> = self
-            .statics
+        let statics: Vec<RefValue> = std::mem::take(&mut self.statics)
             .into_iter()
             .map(|(iml, parselet)| {
                 if let Some(mut parselet) = parselet {
@@ -123,6 +210,9 @@ impl ImlProgram {
                         parselet.consuming = leftrec
                             .get(&imlparselet)
                             .map_or(None, |leftrec| Some(*leftrec));
+                        parselet.memo = !selective || imlparselet.borrow().model.borrow().is_cached;
+                        parselet.nullable = parselet.consuming.is_some()
+                            && imlparselet.borrow().model.borrow().body.matches_empty();
 
                         //println!("{:?} => {:?}", imlparselet.borrow().name, parselet.consuming);
                     }
@@ -140,7 +230,12 @@ impl ImlProgram {
             log::trace!(" {: >3} : {:#?}", i, value);
         }
 
-        Ok(Program::new(statics))
+        let mut program = Program::new(statics);
+        let removed = program.deduplicate();
+
+        log::debug!("{} has {} duplicate statics removed", self.main, removed);
+
+        Ok(program)
     }
 
     /** Internal function to finalize a program on a grammar's point of view.
@@ -172,16 +267,23 @@ impl ImlProgram {
                 ImlValue::Shared(value) => {
                     finalize_value(&*value.borrow(), current, visited, configs)
                 }
-                ImlValue::SelfToken => Some(Consumable {
-                    leftrec: true,
-                    nullable: false,
-                }),
+                ImlValue::SelfToken => {
+                    // Self in a leftmost position makes the current parselet left-recursive
+                    if !current.borrow().is_generated {
+                        configs.get_mut(current).unwrap().leftrec = true;
+                    }
+
+                    Some(Consumable {
+                        leftrec: true,
+                        nullable: configs[current].nullable,
+                    })
+                }
                 ImlValue::Parselet(parselet) => {
                     // Try to derive the parselet with current constants
                     let derived = parselet.derive(current).unwrap();
 
-                    // The derived parselet's original must be in the configs!
-                    let parselet = configs.get_key_value(&derived).unwrap().0.clone();
+                    // The derived parselet's original is in the configs, unless it doesn't consume
+                    let parselet = configs.get_key_value(&derived)?.0.clone();
 
                     finalize_parselet(&parselet, visited, configs)
                 }
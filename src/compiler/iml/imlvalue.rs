@@ -2,7 +2,7 @@
 use super::*;
 use crate::reader::Offset;
 use crate::utils;
-use crate::value::{Object, RefValue, Value};
+use crate::value::{List, Object, RefValue, Token, Value};
 use crate::Error;
 use indexmap::IndexMap;
 use log;
@@ -77,6 +77,26 @@ impl ImlValue {
         }
     }
 
+    /** Returns true when the value matches the empty input in any case, like optionals do.
+
+    With `deep`, the body of a parselet is looked into, but not the parselets it calls. */
+    pub fn matches_empty(&self, deep: bool) -> bool {
+        match self {
+            Self::Shared(value) => value.borrow().matches_empty(deep),
+            Self::Value(value) => matches!(value.borrow().object::<Token>(), Some(Token::Empty)),
+            Self::Instance { target, .. } => {
+                matches!(&**target, Self::Name { name, .. } if name == "Opt" || name == "Kle")
+            }
+            Self::Parselet(parselet) => {
+                let parselet = parselet.borrow();
+
+                matches!(parselet.name.as_deref(), Some("Opt" | "Kle"))
+                    || (deep && parselet.model.borrow().body.matches_empty())
+            }
+            _ => false,
+        }
+    }
+
     /// Returns the value's definition offset, if available
     pub fn offset(&self) -> Option<Offset> {
         match self {
@@ -86,6 +106,24 @@ impl ImlValue {
         }
     }
 
+    /// Checks if a shared value refers to another one, by following shared values.
+    fn refers_to(value: &Rc<RefCell<ImlValue>>, other: &Rc<RefCell<ImlValue>>) -> bool {
+        let mut value = value.clone();
+
+        loop {
+            if Rc::ptr_eq(&value, other) {
+                return true;
+            }
+
+            let next = match value.try_borrow().as_deref() {
+                Ok(Self::Shared(next)) => next.clone(),
+                _ => return false,
+            };
+
+            value = next;
+        }
+    }
+
     /// Try to resolve immediatelly, otherwise push shared reference to compiler's unresolved ImlValue.
     pub fn try_resolve(mut self, scope: &Scope) -> Self {
         log::trace!("try_resolve {:?}", self);
@@ -99,9 +137,19 @@ impl ImlValue {
                 scope.usages.borrow_mut().push(shared.clone());
                 shared
             }
-            Self::Shared(_) => {
+            Self::Shared(rc) => {
                 log::trace!("Reinserting already shared usage");
-                scope.usages.borrow_mut().push(self.clone());
+                let mut usages = scope.usages.borrow_mut();
+
+                // Aliases share the usage of their target, which is reported only once
+                if !usages
+                    .iter()
+                    .any(|usage| matches!(usage, Self::Shared(other) if Rc::ptr_eq(other, rc)))
+                {
+                    usages.push(self.clone());
+                }
+
+                drop(usages);
                 self
             }
             _ => self,
@@ -126,7 +174,30 @@ impl ImlValue {
                 }
                 Err(rc) => {
                     log::trace!("resolving still wrapped shared");
-                    let resolved = rc.borrow().clone().resolve(scope);
+
+                    // A value referring to itself during its resolution, like in `A : X<A>`,
+                    // is kept shared, and becomes the resolved value afterwards.
+                    if rc.try_borrow_mut().is_err() {
+                        return ImlValue::Shared(rc);
+                    }
+
+                    // Keep the value borrowed during its resolution to detect such references
+                    let value = rc.borrow();
+                    let resolved = value.clone().resolve(scope);
+                    drop(value);
+
+                    // Aliases referring to each other, like `A : B` and `B : A`, never resolve
+                    if let Self::Shared(other) = &resolved {
+                        if Self::refers_to(other, &rc) {
+                            let value = rc.replace(Self::VoidToken);
+                            scope.error(
+                                value.offset(),
+                                format!("Cyclic alias, '{}' refers to itself", value),
+                            );
+
+                            return Self::VoidToken;
+                        }
+                    }
 
                     if matches!(resolved, Self::Name { .. } | Self::Instance { .. }) {
                         ImlValue::Shared(rc)
@@ -280,6 +351,15 @@ impl ImlValue {
         }
     }
 
+    /// Returns the value of a resolved, non-callable constant
+    pub fn constant(&self) -> Option<RefValue> {
+        match self {
+            Self::Shared(value) => value.borrow().constant(),
+            Self::Value(value) if !value.is_callable(true) => Some(value.clone()),
+            _ => None,
+        }
+    }
+
     /// Check whether intermediate value represents callable,
     /// and when its callable if with or without arguments.
     pub fn is_callable(&self, without_arguments: bool) -> bool {
@@ -293,7 +373,10 @@ impl ImlValue {
 
                 if without_arguments {
                     parselet.signature.len() == 0
-                        || parselet.signature.iter().all(|arg| arg.1.is_some())
+                        || parselet
+                            .signature
+                            .iter()
+                            .all(|arg| arg.1.is_some() || arg.0.starts_with('*'))
                 } else {
                     true
                 }
@@ -346,8 +429,8 @@ impl ImlValue {
             ImlValue::Value(value) => match &*value.borrow() {
                 Value::Void => ops.push(Op::PushVoid),
                 Value::Null => ops.push(Op::PushNull),
-                Value::True => ops.push(Op::PushTrue),
-                Value::False => ops.push(Op::PushFalse),
+                Value::Bool(true) => ops.push(Op::PushTrue),
+                Value::Bool(false) => ops.push(Op::PushFalse),
                 Value::Int(i) => match i.to_i32() {
                     Some(0) => ops.push(Op::Push0),
                     Some(1) => ops.push(Op::Push1),
@@ -482,7 +565,13 @@ impl std::hash::Hash for ImlValue {
             Self::VoidToken => state.write_u8('V' as u8),
             Self::Value(value) => {
                 state.write_u8('v' as u8);
-                value.hash(state)
+
+                // Lists preloaded by constant folding are hashed by their representation
+                if value.borrow().object::<List>().is_some() {
+                    value.borrow().repr().hash(state)
+                } else {
+                    value.hash(state)
+                }
             }
             Self::Parselet(parselet) => {
                 state.write_u8('p' as u8);
@@ -2,6 +2,7 @@
 pub use super::*;
 pub use crate::vm::*;
 
+mod imllint;
 mod imlop;
 mod imlparselet;
 mod imlprogram;
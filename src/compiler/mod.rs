@@ -1,6 +1,7 @@
 //! Tokay compiler, parsing a program source into a VM program
 
 pub(crate) mod ast;
+mod cache;
 mod compiler;
 mod iml;
 mod parser;
@@ -12,4 +13,5 @@ use parser::*;
 use scope::*;
 
 pub(crate) use ast::{RESERVED_KEYWORDS, RESERVED_TOKENS};
-pub use compiler::Compiler;
+pub use cache::Cache;
+pub use compiler::{Compiler, LintLevel, Unmatched, LINTS};
@@ -11,6 +11,12 @@ pub struct Parser(Program);
 impl Parser {
     pub fn new() -> Self {
         // fixme: Make this lazy_static, so its created only once!
+
+        // With the `bootstrap` feature, the AST was generated from `tokay.tok` by build.rs.
+        #[cfg(tokay_bootstrap)]
+        let ast = include!(concat!(env!("OUT_DIR"), "/parser.rs"));
+
+        #[cfg(not(tokay_bootstrap))]
         let ast =
             // First of all: DON'T PANIC!
 
@@ -332,104 +338,6 @@ impl Parser {
                                                 "emit" => "body",
                                                 "children" =>
                                                     (value!([
-                                                        (value!([
-                                                            "emit" => "sequence",
-                                                            "children" =>
-                                                                (value!([
-                                                                    (value!([
-                                                                        "emit" => "value_token_touch",
-                                                                        "value" => "a"
-                                                                    ])),
-                                                                    (value!([
-                                                                        "emit" => "value_string",
-                                                                        "value" => ""
-                                                                    ]))
-                                                                ]))
-                                                        ])),
-                                                        (value!([
-                                                            "emit" => "sequence",
-                                                            "children" =>
-                                                                (value!([
-                                                                    (value!([
-                                                                        "emit" => "value_token_touch",
-                                                                        "value" => "b"
-                                                                    ])),
-                                                                    (value!([
-                                                                        "emit" => "value_string",
-                                                                        "value" => ""
-                                                                    ]))
-                                                                ]))
-                                                        ])),
-                                                        (value!([
-                                                            "emit" => "sequence",
-                                                            "children" =>
-                                                                (value!([
-                                                                    (value!([
-                                                                        "emit" => "value_token_touch",
-                                                                        "value" => "f"
-                                                                    ])),
-                                                                    (value!([
-                                                                        "emit" => "value_string",
-                                                                        "value" => ""
-                                                                    ]))
-                                                                ]))
-                                                        ])),
-                                                        (value!([
-                                                            "emit" => "sequence",
-                                                            "children" =>
-                                                                (value!([
-                                                                    (value!([
-                                                                        "emit" => "value_token_touch",
-                                                                        "value" => "n"
-                                                                    ])),
-                                                                    (value!([
-                                                                        "emit" => "value_string",
-                                                                        "value" => "\n"
-                                                                    ]))
-                                                                ]))
-                                                        ])),
-                                                        (value!([
-                                                            "emit" => "sequence",
-                                                            "children" =>
-                                                                (value!([
-                                                                    (value!([
-                                                                        "emit" => "value_token_touch",
-                                                                        "value" => "r"
-                                                                    ])),
-                                                                    (value!([
-                                                                        "emit" => "value_string",
-                                                                        "value" => "\r"
-                                                                    ]))
-                                                                ]))
-                                                        ])),
-                                                        (value!([
-                                                            "emit" => "sequence",
-                                                            "children" =>
-                                                                (value!([
-                                                                    (value!([
-                                                                        "emit" => "value_token_touch",
-                                                                        "value" => "t"
-                                                                    ])),
-                                                                    (value!([
-                                                                        "emit" => "value_string",
-                                                                        "value" => "\t"
-                                                                    ]))
-                                                                ]))
-                                                        ])),
-                                                        (value!([
-                                                            "emit" => "sequence",
-                                                            "children" =>
-                                                                (value!([
-                                                                    (value!([
-                                                                        "emit" => "value_token_touch",
-                                                                        "value" => "v"
-                                                                    ])),
-                                                                    (value!([
-                                                                        "emit" => "value_string",
-                                                                        "value" => ""
-                                                                    ]))
-                                                                ]))
-                                                        ])),
                                                         (value!([
                                                             "emit" => "sequence",
                                                             "children" =>
@@ -452,7 +360,7 @@ impl Parser {
                                                                             (value!([
                                                                                 (value!([
                                                                                     "emit" => "identifier",
-                                                                                    "value" => "chr"
+                                                                                    "value" => "unescape"
                                                                                 ])),
                                                                                 (value!([
                                                                                     "emit" => "callarg",
@@ -462,95 +370,15 @@ impl Parser {
                                                                                             "children" =>
                                                                                                 (value!([
                                                                                                     (value!([
-                                                                                                        "emit" => "op_binary_add",
-                                                                                                        "children" =>
-                                                                                                            (value!([
-                                                                                                                (value!([
-                                                                                                                    "emit" => "op_binary_mul",
-                                                                                                                    "children" =>
-                                                                                                                        (value!([
-                                                                                                                            (value!([
-                                                                                                                                "emit" => "call",
-                                                                                                                                "children" =>
-                                                                                                                                    (value!([
-                                                                                                                                        (value!([
-                                                                                                                                            "emit" => "identifier",
-                                                                                                                                            "value" => "int"
-                                                                                                                                        ])),
-                                                                                                                                        (value!([
-                                                                                                                                            "emit" => "callarg",
-                                                                                                                                            "children" =>
-                                                                                                                                                (value!([
-                                                                                                                                                    "emit" => "capture_index",
-                                                                                                                                                    "children" =>
-                                                                                                                                                        (value!([
-                                                                                                                                                            "emit" => "value_integer",
-                                                                                                                                                            "value" => 1
-                                                                                                                                                        ]))
-                                                                                                                                                ]))
-                                                                                                                                        ]))
-                                                                                                                                    ]))
-                                                                                                                            ])),
-                                                                                                                            (value!([
-                                                                                                                                "emit" => "value_integer",
-                                                                                                                                "value" => 64
-                                                                                                                            ]))
-                                                                                                                        ]))
-                                                                                                                ])),
-                                                                                                                (value!([
-                                                                                                                    "emit" => "op_binary_mul",
-                                                                                                                    "children" =>
-                                                                                                                        (value!([
-                                                                                                                            (value!([
-                                                                                                                                "emit" => "call",
-                                                                                                                                "children" =>
-                                                                                                                                    (value!([
-                                                                                                                                        (value!([
-                                                                                                                                            "emit" => "identifier",
-                                                                                                                                            "value" => "int"
-                                                                                                                                        ])),
-                                                                                                                                        (value!([
-                                                                                                                                            "emit" => "callarg",
-                                                                                                                                            "children" =>
-                                                                                                                                                (value!([
-                                                                                                                                                    "emit" => "capture_index",
-                                                                                                                                                    "children" =>
-                                                                                                                                                        (value!([
-                                                                                                                                                            "emit" => "value_integer",
-                                                                                                                                                            "value" => 2
-                                                                                                                                                        ]))
-                                                                                                                                                ]))
-                                                                                                                                        ]))
-                                                                                                                                    ]))
-                                                                                                                            ])),
-                                                                                                                            (value!([
-                                                                                                                                "emit" => "value_integer",
-                                                                                                                                "value" => 8
-                                                                                                                            ]))
-                                                                                                                        ]))
-                                                                                                                ]))
-                                                                                                            ]))
+                                                                                                        "emit" => "value_string",
+                                                                                                        "value" => "\\"
                                                                                                     ])),
                                                                                                     (value!([
-                                                                                                        "emit" => "call",
+                                                                                                        "emit" => "capture_index",
                                                                                                         "children" =>
                                                                                                             (value!([
-                                                                                                                (value!([
-                                                                                                                    "emit" => "identifier",
-                                                                                                                    "value" => "int"
-                                                                                                                ])),
-                                                                                                                (value!([
-                                                                                                                    "emit" => "callarg",
-                                                                                                                    "children" =>
-                                                                                                                        (value!([
-                                                                                                                            "emit" => "capture_index",
-                                                                                                                            "children" =>
-                                                                                                                                (value!([
-                                                                                                                                    "emit" => "value_integer",
-                                                                                                                                    "value" => 3
-                                                                                                                                ]))
-                                                                                                                        ]))
-                                                                                                                ]))
+                                                                                                                "emit" => "value_integer",
+                                                                                                                "value" => 0
                                                                                                             ]))
                                                                                                     ]))
                                                                                                 ]))
@@ -582,67 +410,25 @@ impl Parser {
                                                                             (value!([
                                                                                 (value!([
                                                                                     "emit" => "identifier",
-                                                                                    "value" => "chr"
+                                                                                    "value" => "unescape"
                                                                                 ])),
                                                                                 (value!([
                                                                                     "emit" => "callarg",
                                                                                     "children" =>
                                                                                         (value!([
-                                                                                            "emit" => "call",
+                                                                                            "emit" => "op_binary_add",
                                                                                             "children" =>
                                                                                                 (value!([
                                                                                                     (value!([
-                                                                                                        "emit" => "identifier",
-                                                                                                        "value" => "int"
+                                                                                                        "emit" => "value_string",
+                                                                                                        "value" => "\\"
                                                                                                     ])),
                                                                                                     (value!([
-                                                                                                        "emit" => "callarg",
+                                                                                                        "emit" => "capture_index",
                                                                                                         "children" =>
                                                                                                             (value!([
-                                                                                                                "emit" => "op_binary_add",
-                                                                                                                "children" =>
-                                                                                                                    (value!([
-                                                                                                                        (value!([
-                                                                                                                            "emit" => "value_string",
-                                                                                                                            "value" => "0x"
-                                                                                                                        ])),
-                                                                                                                        (value!([
-                                                                                                                            "emit" => "call",
-                                                                                                                            "children" =>
-                                                                                                                                (value!([
-                                                                                                                                    (value!([
-                                                                                                                                        "emit" => "rvalue",
-                                                                                                                                        "children" =>
-                                                                                                                                            (value!([
-                                                                                                                                                (value!([
-                                                                                                                                                    "emit" => "capture_index",
-                                                                                                                                                    "children" =>
-                                                                                                                                                        (value!([
-                                                                                                                                                            "emit" => "value_integer",
-                                                                                                                                                            "value" => 0
-                                                                                                                                                        ]))
-                                                                                                                                                ])),
-                                                                                                                                                (value!([
-                                                                                                                                                    "emit" => "attribute",
-                                                                                                                                                    "children" =>
-                                                                                                                                                        (value!([
-                                                                                                                                                            "emit" => "value_string",
-                                                                                                                                                            "value" => "substr"
-                                                                                                                                                        ]))
-                                                                                                                                                ]))
-                                                                                                                                            ]))
-                                                                                                                                    ])),
-                                                                                                                                    (value!([
-                                                                                                                                        "emit" => "callarg",
-                                                                                                                                        "children" =>
-                                                                                                                                            (value!([
-                                                                                                                                                "emit" => "value_integer",
-                                                                                                                                                "value" => 1
-                                                                                                                                            ]))
-                                                                                                                                    ]))
-                                                                                                                                ]))
-                                                                                                                        ]))
-                                                                                                                    ]))
+                                                                                                                "emit" => "value_integer",
+                                                                                                                "value" => 0
                                                                                                             ]))
                                                                                                     ]))
                                                                                                 ]))
@@ -682,67 +468,25 @@ impl Parser {
                                                                             (value!([
                                                                                 (value!([
                                                                                     "emit" => "identifier",
-                                                                                    "value" => "chr"
+                                                                                    "value" => "unescape"
                                                                                 ])),
                                                                                 (value!([
                                                                                     "emit" => "callarg",
                                                                                     "children" =>
                                                                                         (value!([
-                                                                                            "emit" => "call",
+                                                                                            "emit" => "op_binary_add",
                                                                                             "children" =>
                                                                                                 (value!([
                                                                                                     (value!([
-                                                                                                        "emit" => "identifier",
-                                                                                                        "value" => "int"
+                                                                                                        "emit" => "value_string",
+                                                                                                        "value" => "\\"
                                                                                                     ])),
                                                                                                     (value!([
-                                                                                                        "emit" => "callarg",
+                                                                                                        "emit" => "capture_index",
                                                                                                         "children" =>
                                                                                                             (value!([
-                                                                                                                "emit" => "op_binary_add",
-                                                                                                                "children" =>
-                                                                                                                    (value!([
-                                                                                                                        (value!([
-                                                                                                                            "emit" => "value_string",
-                                                                                                                            "value" => "0x"
-                                                                                                                        ])),
-                                                                                                                        (value!([
-                                                                                                                            "emit" => "call",
-                                                                                                                            "children" =>
-                                                                                                                                (value!([
-                                                                                                                                    (value!([
-                                                                                                                                        "emit" => "rvalue",
-                                                                                                                                        "children" =>
-                                                                                                                                            (value!([
-                                                                                                                                                (value!([
-                                                                                                                                                    "emit" => "capture_index",
-                                                                                                                                                    "children" =>
-                                                                                                                                                        (value!([
-                                                                                                                                                            "emit" => "value_integer",
-                                                                                                                                                            "value" => 0
-                                                                                                                                                        ]))
-                                                                                                                                                ])),
-                                                                                                                                                (value!([
-                                                                                                                                                    "emit" => "attribute",
-                                                                                                                                                    "children" =>
-                                                                                                                                                        (value!([
-                                                                                                                                                            "emit" => "value_string",
-                                                                                                                                                            "value" => "substr"
-                                                                                                                                                        ]))
-                                                                                                                                                ]))
-                                                                                                                                            ]))
-                                                                                                                                    ])),
-                                                                                                                                    (value!([
-                                                                                                                                        "emit" => "callarg",
-                                                                                                                                        "children" =>
-                                                                                                                                            (value!([
-                                                                                                                                                "emit" => "value_integer",
-                                                                                                                                                "value" => 1
-                                                                                                                                            ]))
-                                                                                                                                    ]))
-                                                                                                                                ]))
-                                                                                                                        ]))
-                                                                                                                    ]))
+                                                                                                                "emit" => "value_integer",
+                                                                                                                "value" => 0
                                                                                                             ]))
                                                                                                     ]))
                                                                                                 ]))
@@ -798,67 +542,25 @@ impl Parser {
                                                                             (value!([
                                                                                 (value!([
                                                                                     "emit" => "identifier",
-                                                                                    "value" => "chr"
+                                                                                    "value" => "unescape"
                                                                                 ])),
                                                                                 (value!([
                                                                                     "emit" => "callarg",
                                                                                     "children" =>
                                                                                         (value!([
-                                                                                            "emit" => "call",
+                                                                                            "emit" => "op_binary_add",
                                                                                             "children" =>
                                                                                                 (value!([
                                                                                                     (value!([
-                                                                                                        "emit" => "identifier",
-                                                                                                        "value" => "int"
+                                                                                                        "emit" => "value_string",
+                                                                                                        "value" => "\\"
                                                                                                     ])),
                                                                                                     (value!([
-                                                                                                        "emit" => "callarg",
+                                                                                                        "emit" => "capture_index",
                                                                                                         "children" =>
                                                                                                             (value!([
-                                                                                                                "emit" => "op_binary_add",
-                                                                                                                "children" =>
-                                                                                                                    (value!([
-                                                                                                                        (value!([
-                                                                                                                            "emit" => "value_string",
-                                                                                                                            "value" => "0x"
-                                                                                                                        ])),
-                                                                                                                        (value!([
-                                                                                                                            "emit" => "call",
-                                                                                                                            "children" =>
-                                                                                                                                (value!([
-                                                                                                                                    (value!([
-                                                                                                                                        "emit" => "rvalue",
-                                                                                                                                        "children" =>
-                                                                                                                                            (value!([
-                                                                                                                                                (value!([
-                                                                                                                                                    "emit" => "capture_index",
-                                                                                                                                                    "children" =>
-                                                                                                                                                        (value!([
-                                                                                                                                                            "emit" => "value_integer",
-                                                                                                                                                            "value" => 0
-                                                                                                                                                        ]))
-                                                                                                                                                ])),
-                                                                                                                                                (value!([
-                                                                                                                                                    "emit" => "attribute",
-                                                                                                                                                    "children" =>
-                                                                                                                                                        (value!([
-                                                                                                                                                            "emit" => "value_string",
-                                                                                                                                                            "value" => "substr"
-                                                                                                                                                        ]))
-                                                                                                                                                ]))
-                                                                                                                                            ]))
-                                                                                                                                    ])),
-                                                                                                                                    (value!([
-                                                                                                                                        "emit" => "callarg",
-                                                                                                                                        "children" =>
-                                                                                                                                            (value!([
-                                                                                                                                                "emit" => "value_integer",
-                                                                                                                                                "value" => 1
-                                                                                                                                            ]))
-                                                                                                                                    ]))
-                                                                                                                                ]))
-                                                                                                                        ]))
-                                                                                                                    ]))
+                                                                                                                "emit" => "value_integer",
+                                                                                                                "value" => 0
                                                                                                             ]))
                                                                                                     ]))
                                                                                                 ]))
@@ -869,28 +571,66 @@ impl Parser {
                                                                 ]))
                                                         ])),
                                                         (value!([
-                                                            "emit" => "value_token_any"
-                                                        ]))
-                                                    ]))
-                                            ]))
-                                    ]))
-                                ]))
-                        ])),
-                        (value!([
-                            "emit" => "constant",
-                            "children" =>
-                                (value!([
-                                    (value!([
-                                        "emit" => "identifier",
-                                        "value" => "T_Identifier"
-                                    ])),
-                                    (value!([
-                                        "emit" => "value_parselet",
-                                        "children" =>
-                                            (value!([
-                                                "emit" => "body",
-                                                "children" =>
-                                                    (value!([
+                                                            "emit" => "sequence",
+                                                            "children" =>
+                                                                (value!([
+                                                                    (value!([
+                                                                        "emit" => "value_token_any"
+                                                                    ])),
+                                                                    (value!([
+                                                                        "emit" => "call",
+                                                                        "children" =>
+                                                                            (value!([
+                                                                                (value!([
+                                                                                    "emit" => "identifier",
+                                                                                    "value" => "unescape"
+                                                                                ])),
+                                                                                (value!([
+                                                                                    "emit" => "callarg",
+                                                                                    "children" =>
+                                                                                        (value!([
+                                                                                            "emit" => "op_binary_add",
+                                                                                            "children" =>
+                                                                                                (value!([
+                                                                                                    (value!([
+                                                                                                        "emit" => "value_string",
+                                                                                                        "value" => "\\"
+                                                                                                    ])),
+                                                                                                    (value!([
+                                                                                                        "emit" => "capture_index",
+                                                                                                        "children" =>
+                                                                                                            (value!([
+                                                                                                                "emit" => "value_integer",
+                                                                                                                "value" => 0
+                                                                                                            ]))
+                                                                                                    ]))
+                                                                                                ]))
+                                                                                        ]))
+                                                                                ]))
+                                                                            ]))
+                                                                    ]))
+                                                                ]))
+                                                        ]))
+                                                    ]))
+                                            ]))
+                                    ]))
+                                ]))
+                        ])),
+                        (value!([
+                            "emit" => "constant",
+                            "children" =>
+                                (value!([
+                                    (value!([
+                                        "emit" => "identifier",
+                                        "value" => "T_Identifier"
+                                    ])),
+                                    (value!([
+                                        "emit" => "value_parselet",
+                                        "children" =>
+                                            (value!([
+                                                "emit" => "body",
+                                                "children" =>
+                                                    (value!([
                                                         "emit" => "call",
                                                         "children" =>
                                                             (value!([
@@ -938,6 +678,24 @@ impl Parser {
                                                         "emit" => "sequence",
                                                         "children" =>
                                                             (value!([
+                                                                (value!([
+                                                                    "emit" => "op_mod_kle",
+                                                                    "children" =>
+                                                                        (value!([
+                                                                            "emit" => "sequence",
+                                                                            "children" =>
+                                                                                (value!([
+                                                                                    (value!([
+                                                                                        "emit" => "identifier",
+                                                                                        "value" => "Ident"
+                                                                                    ])),
+                                                                                    (value!([
+                                                                                        "emit" => "value_token_touch",
+                                                                                        "value" => "::"
+                                                                                    ]))
+                                                                                ]))
+                                                                        ]))
+                                                                ])),
                                                                 (value!([
                                                                     "emit" => "value_token_ccl",
                                                                     "children" =>
@@ -1022,6 +780,82 @@ impl Parser {
                                     ]))
                                 ]))
                         ])),
+                        (value!([
+                            "emit" => "constant",
+                            "children" =>
+                                (value!([
+                                    (value!([
+                                        "emit" => "identifier",
+                                        "value" => "T_Name"
+                                    ])),
+                                    (value!([
+                                        "emit" => "value_parselet",
+                                        "children" =>
+                                            (value!([
+                                                "emit" => "body",
+                                                "children" =>
+                                                    (value!([
+                                                        "emit" => "sequence",
+                                                        "children" =>
+                                                            (value!([
+                                                                (value!([
+                                                                    "emit" => "identifier",
+                                                                    "value" => "Ident"
+                                                                ])),
+                                                                (value!([
+                                                                    "emit" => "op_mod_kle",
+                                                                    "children" =>
+                                                                        (value!([
+                                                                            "emit" => "sequence",
+                                                                            "children" =>
+                                                                                (value!([
+                                                                                    (value!([
+                                                                                        "emit" => "value_token_touch",
+                                                                                        "value" => "::"
+                                                                                    ])),
+                                                                                    (value!([
+                                                                                        "emit" => "identifier",
+                                                                                        "value" => "Ident"
+                                                                                    ]))
+                                                                                ]))
+                                                                        ]))
+                                                                ])),
+                                                                (value!([
+                                                                    "emit" => "call",
+                                                                    "children" =>
+                                                                        (value!([
+                                                                            (value!([
+                                                                                "emit" => "identifier",
+                                                                                "value" => "ast"
+                                                                            ])),
+                                                                            (value!([
+                                                                                "emit" => "callarg",
+                                                                                "children" =>
+                                                                                    (value!([
+                                                                                        "emit" => "value_string",
+                                                                                        "value" => "identifier"
+                                                                                    ]))
+                                                                            ])),
+                                                                            (value!([
+                                                                                "emit" => "callarg",
+                                                                                "children" =>
+                                                                                    (value!([
+                                                                                        "emit" => "capture_index",
+                                                                                        "children" =>
+                                                                                            (value!([
+                                                                                                "emit" => "value_integer",
+                                                                                                "value" => 0
+                                                                                            ]))
+                                                                                    ]))
+                                                                            ]))
+                                                                        ]))
+                                                                ]))
+                                                            ]))
+                                                    ]))
+                                            ]))
+                                    ]))
+                                ]))
+                        ])),
                         (value!([
                             "emit" => "constant",
                             "children" =>
@@ -1601,17 +1435,43 @@ impl Parser {
                                                             "emit" => "sequence",
                                                             "children" =>
                                                                 (value!([
-                                                                    (value!([
-                                                                        "emit" => "identifier",
-                                                                        "value" => "CclChar"
-                                                                    ])),
                                                                     (value!([
                                                                         "emit" => "value_token_touch",
-                                                                        "value" => "-"
+                                                                        "value" => "\\"
                                                                     ])),
                                                                     (value!([
-                                                                        "emit" => "identifier",
-                                                                        "value" => "CclChar"
+                                                                        "emit" => "value_token_ccl",
+                                                                        "children" =>
+                                                                            (value!([
+                                                                                "emit" => "ccl",
+                                                                                "children" =>
+                                                                                    (value!([
+                                                                                        (value!([
+                                                                                            "emit" => "char",
+                                                                                            "value" => "D"
+                                                                                        ])),
+                                                                                        (value!([
+                                                                                            "emit" => "char",
+                                                                                            "value" => "S"
+                                                                                        ])),
+                                                                                        (value!([
+                                                                                            "emit" => "char",
+                                                                                            "value" => "W"
+                                                                                        ])),
+                                                                                        (value!([
+                                                                                            "emit" => "char",
+                                                                                            "value" => "d"
+                                                                                        ])),
+                                                                                        (value!([
+                                                                                            "emit" => "char",
+                                                                                            "value" => "s"
+                                                                                        ])),
+                                                                                        (value!([
+                                                                                            "emit" => "char",
+                                                                                            "value" => "w"
+                                                                                        ]))
+                                                                                    ]))
+                                                                            ]))
                                                                     ])),
                                                                     (value!([
                                                                         "emit" => "call",
@@ -1626,32 +1486,18 @@ impl Parser {
                                                                                     "children" =>
                                                                                         (value!([
                                                                                             "emit" => "value_string",
-                                                                                            "value" => "range"
+                                                                                            "value" => "shorthand"
                                                                                         ]))
                                                                                 ])),
                                                                                 (value!([
                                                                                     "emit" => "callarg",
                                                                                     "children" =>
                                                                                         (value!([
-                                                                                            "emit" => "op_binary_add",
+                                                                                            "emit" => "capture_index",
                                                                                             "children" =>
                                                                                                 (value!([
-                                                                                                    (value!([
-                                                                                                        "emit" => "capture_index",
-                                                                                                        "children" =>
-                                                                                                            (value!([
-                                                                                                                "emit" => "value_integer",
-                                                                                                                "value" => 1
-                                                                                                            ]))
-                                                                                                    ])),
-                                                                                                    (value!([
-                                                                                                        "emit" => "capture_index",
-                                                                                                        "children" =>
-                                                                                                            (value!([
-                                                                                                                "emit" => "value_integer",
-                                                                                                                "value" => 3
-                                                                                                            ]))
-                                                                                                    ]))
+                                                                                                    "emit" => "value_integer",
+                                                                                                    "value" => 2
                                                                                                 ]))
                                                                                         ]))
                                                                                 ]))
@@ -1664,83 +1510,271 @@ impl Parser {
                                                             "children" =>
                                                                 (value!([
                                                                     (value!([
-                                                                        "emit" => "identifier",
-                                                                        "value" => "CclChar"
+                                                                        "emit" => "value_token_touch",
+                                                                        "value" => "\\"
                                                                     ])),
                                                                     (value!([
-                                                                        "emit" => "call",
+                                                                        "emit" => "value_token_ccl",
                                                                         "children" =>
                                                                             (value!([
-                                                                                (value!([
-                                                                                    "emit" => "identifier",
-                                                                                    "value" => "ast"
-                                                                                ])),
-                                                                                (value!([
-                                                                                    "emit" => "callarg",
-                                                                                    "children" =>
+                                                                                "emit" => "ccl",
+                                                                                "children" =>
+                                                                                    (value!([
                                                                                         (value!([
-                                                                                            "emit" => "value_string",
-                                                                                            "value" => "char"
+                                                                                            "emit" => "char",
+                                                                                            "value" => "P"
+                                                                                        ])),
+                                                                                        (value!([
+                                                                                            "emit" => "char",
+                                                                                            "value" => "p"
                                                                                         ]))
-                                                                                ]))
+                                                                                    ]))
                                                                             ]))
-                                                                    ]))
-                                                                ]))
-                                                        ]))
-                                                    ]))
-                                            ]))
-                                    ]))
-                                ]))
-                        ])),
-                        (value!([
-                            "emit" => "constant",
-                            "children" =>
-                                (value!([
-                                    (value!([
-                                        "emit" => "identifier",
-                                        "value" => "Ccl"
-                                    ])),
-                                    (value!([
-                                        "emit" => "value_parselet",
-                                        "children" =>
-                                            (value!([
-                                                "emit" => "body",
-                                                "children" =>
-                                                    (value!([
-                                                        (value!([
-                                                            "emit" => "sequence",
-                                                            "children" =>
-                                                                (value!([
-                                                                    (value!([
-                                                                        "emit" => "value_token_touch",
-                                                                        "value" => "^"
                                                                     ])),
                                                                     (value!([
-                                                                        "emit" => "op_mod_kle",
-                                                                        "children" =>
-                                                                            (value!([
-                                                                                "emit" => "identifier",
-                                                                                "value" => "CclRange"
-                                                                            ]))
+                                                                        "emit" => "value_token_touch",
+                                                                        "value" => "{"
                                                                     ])),
                                                                     (value!([
-                                                                        "emit" => "call",
+                                                                        "emit" => "value_generic",
                                                                         "children" =>
                                                                             (value!([
                                                                                 (value!([
                                                                                     "emit" => "identifier",
-                                                                                    "value" => "ast"
+                                                                                    "value" => "Expect"
                                                                                 ])),
                                                                                 (value!([
-                                                                                    "emit" => "callarg",
+                                                                                    "emit" => "genarg",
                                                                                     "children" =>
                                                                                         (value!([
-                                                                                            "emit" => "value_string",
-                                                                                            "value" => "ccl_neg"
-                                                                                        ]))
-                                                                                ]))
-                                                                            ]))
-                                                                    ]))
+                                                                                            "emit" => "value_token_ccls",
+                                                                                            "children" =>
+                                                                                                (value!([
+                                                                                                    "emit" => "ccl",
+                                                                                                    "children" =>
+                                                                                                        (value!([
+                                                                                                            (value!([
+                                                                                                                "emit" => "range",
+                                                                                                                "value" => "AZ"
+                                                                                                            ])),
+                                                                                                            (value!([
+                                                                                                                "emit" => "range",
+                                                                                                                "value" => "az"
+                                                                                                            ]))
+                                                                                                        ]))
+                                                                                                ]))
+                                                                                        ]))
+                                                                                ]))
+                                                                            ]))
+                                                                    ])),
+                                                                    (value!([
+                                                                        "emit" => "value_generic",
+                                                                        "children" =>
+                                                                            (value!([
+                                                                                (value!([
+                                                                                    "emit" => "identifier",
+                                                                                    "value" => "Expect"
+                                                                                ])),
+                                                                                (value!([
+                                                                                    "emit" => "genarg",
+                                                                                    "children" =>
+                                                                                        (value!([
+                                                                                            "emit" => "value_token_touch",
+                                                                                            "value" => "}"
+                                                                                        ]))
+                                                                                ]))
+                                                                            ]))
+                                                                    ])),
+                                                                    (value!([
+                                                                        "emit" => "call",
+                                                                        "children" =>
+                                                                            (value!([
+                                                                                (value!([
+                                                                                    "emit" => "identifier",
+                                                                                    "value" => "ast"
+                                                                                ])),
+                                                                                (value!([
+                                                                                    "emit" => "callarg",
+                                                                                    "children" =>
+                                                                                        (value!([
+                                                                                            "emit" => "value_string",
+                                                                                            "value" => "category"
+                                                                                        ]))
+                                                                                ])),
+                                                                                (value!([
+                                                                                    "emit" => "callarg",
+                                                                                    "children" =>
+                                                                                        (value!([
+                                                                                            "emit" => "op_binary_add",
+                                                                                            "children" =>
+                                                                                                (value!([
+                                                                                                    (value!([
+                                                                                                        "emit" => "capture_index",
+                                                                                                        "children" =>
+                                                                                                            (value!([
+                                                                                                                "emit" => "value_integer",
+                                                                                                                "value" => 2
+                                                                                                            ]))
+                                                                                                    ])),
+                                                                                                    (value!([
+                                                                                                        "emit" => "capture_index",
+                                                                                                        "children" =>
+                                                                                                            (value!([
+                                                                                                                "emit" => "value_integer",
+                                                                                                                "value" => 4
+                                                                                                            ]))
+                                                                                                    ]))
+                                                                                                ]))
+                                                                                        ]))
+                                                                                ]))
+                                                                            ]))
+                                                                    ]))
+                                                                ]))
+                                                        ])),
+                                                        (value!([
+                                                            "emit" => "sequence",
+                                                            "children" =>
+                                                                (value!([
+                                                                    (value!([
+                                                                        "emit" => "identifier",
+                                                                        "value" => "CclChar"
+                                                                    ])),
+                                                                    (value!([
+                                                                        "emit" => "value_token_touch",
+                                                                        "value" => "-"
+                                                                    ])),
+                                                                    (value!([
+                                                                        "emit" => "identifier",
+                                                                        "value" => "CclChar"
+                                                                    ])),
+                                                                    (value!([
+                                                                        "emit" => "call",
+                                                                        "children" =>
+                                                                            (value!([
+                                                                                (value!([
+                                                                                    "emit" => "identifier",
+                                                                                    "value" => "ast"
+                                                                                ])),
+                                                                                (value!([
+                                                                                    "emit" => "callarg",
+                                                                                    "children" =>
+                                                                                        (value!([
+                                                                                            "emit" => "value_string",
+                                                                                            "value" => "range"
+                                                                                        ]))
+                                                                                ])),
+                                                                                (value!([
+                                                                                    "emit" => "callarg",
+                                                                                    "children" =>
+                                                                                        (value!([
+                                                                                            "emit" => "op_binary_add",
+                                                                                            "children" =>
+                                                                                                (value!([
+                                                                                                    (value!([
+                                                                                                        "emit" => "capture_index",
+                                                                                                        "children" =>
+                                                                                                            (value!([
+                                                                                                                "emit" => "value_integer",
+                                                                                                                "value" => 1
+                                                                                                            ]))
+                                                                                                    ])),
+                                                                                                    (value!([
+                                                                                                        "emit" => "capture_index",
+                                                                                                        "children" =>
+                                                                                                            (value!([
+                                                                                                                "emit" => "value_integer",
+                                                                                                                "value" => 3
+                                                                                                            ]))
+                                                                                                    ]))
+                                                                                                ]))
+                                                                                        ]))
+                                                                                ]))
+                                                                            ]))
+                                                                    ]))
+                                                                ]))
+                                                        ])),
+                                                        (value!([
+                                                            "emit" => "sequence",
+                                                            "children" =>
+                                                                (value!([
+                                                                    (value!([
+                                                                        "emit" => "identifier",
+                                                                        "value" => "CclChar"
+                                                                    ])),
+                                                                    (value!([
+                                                                        "emit" => "call",
+                                                                        "children" =>
+                                                                            (value!([
+                                                                                (value!([
+                                                                                    "emit" => "identifier",
+                                                                                    "value" => "ast"
+                                                                                ])),
+                                                                                (value!([
+                                                                                    "emit" => "callarg",
+                                                                                    "children" =>
+                                                                                        (value!([
+                                                                                            "emit" => "value_string",
+                                                                                            "value" => "char"
+                                                                                        ]))
+                                                                                ]))
+                                                                            ]))
+                                                                    ]))
+                                                                ]))
+                                                        ]))
+                                                    ]))
+                                            ]))
+                                    ]))
+                                ]))
+                        ])),
+                        (value!([
+                            "emit" => "constant",
+                            "children" =>
+                                (value!([
+                                    (value!([
+                                        "emit" => "identifier",
+                                        "value" => "Ccl"
+                                    ])),
+                                    (value!([
+                                        "emit" => "value_parselet",
+                                        "children" =>
+                                            (value!([
+                                                "emit" => "body",
+                                                "children" =>
+                                                    (value!([
+                                                        (value!([
+                                                            "emit" => "sequence",
+                                                            "children" =>
+                                                                (value!([
+                                                                    (value!([
+                                                                        "emit" => "value_token_touch",
+                                                                        "value" => "^"
+                                                                    ])),
+                                                                    (value!([
+                                                                        "emit" => "op_mod_kle",
+                                                                        "children" =>
+                                                                            (value!([
+                                                                                "emit" => "identifier",
+                                                                                "value" => "CclRange"
+                                                                            ]))
+                                                                    ])),
+                                                                    (value!([
+                                                                        "emit" => "call",
+                                                                        "children" =>
+                                                                            (value!([
+                                                                                (value!([
+                                                                                    "emit" => "identifier",
+                                                                                    "value" => "ast"
+                                                                                ])),
+                                                                                (value!([
+                                                                                    "emit" => "callarg",
+                                                                                    "children" =>
+                                                                                        (value!([
+                                                                                            "emit" => "value_string",
+                                                                                            "value" => "ccl_neg"
+                                                                                        ]))
+                                                                                ]))
+                                                                            ]))
+                                                                    ]))
                                                                 ]))
                                                         ])),
                                                         (value!([
@@ -2085,7 +2119,7 @@ impl Parser {
                                                     (value!([
                                                         (value!([
                                                             "emit" => "identifier",
-                                                            "value" => "T_Identifier"
+                                                            "value" => "T_Name"
                                                         ])),
                                                         (value!([
                                                             "emit" => "identifier",
@@ -2126,8 +2160,18 @@ impl Parser {
                                                                     "emit" => "op_mod_kle",
                                                                     "children" =>
                                                                         (value!([
-                                                                            "emit" => "identifier",
-                                                                            "value" => "Subscript"
+                                                                            "emit" => "block",
+                                                                            "children" =>
+                                                                                (value!([
+                                                                                    (value!([
+                                                                                        "emit" => "identifier",
+                                                                                        "value" => "Attribute"
+                                                                                    ])),
+                                                                                    (value!([
+                                                                                        "emit" => "identifier",
+                                                                                        "value" => "Subscript"
+                                                                                    ]))
+                                                                                ]))
                                                                         ]))
                                                                 ])),
                                                                 (value!([
@@ -2349,88 +2393,196 @@ impl Parser {
                                                 "emit" => "body",
                                                 "children" =>
                                                     (value!([
-                                                        "emit" => "sequence",
-                                                        "children" =>
-                                                            (value!([
-                                                                (value!([
-                                                                    "emit" => "value_token_touch",
-                                                                    "value" => "@"
-                                                                ])),
-                                                                (value!([
-                                                                    "emit" => "identifier",
-                                                                    "value" => "_"
-                                                                ])),
-                                                                (value!([
-                                                                    "emit" => "op_mod_opt",
-                                                                    "children" =>
-                                                                        (value!([
-                                                                            "emit" => "identifier",
-                                                                            "value" => "ParseletGenerics"
-                                                                        ]))
-                                                                ])),
-                                                                (value!([
-                                                                    "emit" => "identifier",
-                                                                    "value" => "_"
-                                                                ])),
+                                                        (value!([
+                                                            "emit" => "sequence",
+                                                            "children" =>
                                                                 (value!([
-                                                                    "emit" => "op_mod_opt",
-                                                                    "children" =>
-                                                                        (value!([
-                                                                            "emit" => "identifier",
-                                                                            "value" => "ParseletArguments"
-                                                                        ]))
-                                                                ])),
-                                                                (value!([
-                                                                    "emit" => "value_generic",
-                                                                    "children" =>
-                                                                        (value!([
+                                                                    (value!([
+                                                                        "emit" => "value_generic",
+                                                                        "children" =>
+                                                                            (value!([
+                                                                                (value!([
+                                                                                    "emit" => "identifier",
+                                                                                    "value" => "Keyword"
+                                                                                ])),
+                                                                                (value!([
+                                                                                    "emit" => "genarg",
+                                                                                    "children" =>
+                                                                                        (value!([
+                                                                                            "emit" => "value_token_touch",
+                                                                                            "value" => "cache"
+                                                                                        ]))
+                                                                                ]))
+                                                                            ]))
+                                                                    ])),
+                                                                    (value!([
+                                                                        "emit" => "identifier",
+                                                                        "value" => "_"
+                                                                    ])),
+                                                                    (value!([
+                                                                        "emit" => "value_token_touch",
+                                                                        "value" => "@"
+                                                                    ])),
+                                                                    (value!([
+                                                                        "emit" => "identifier",
+                                                                        "value" => "_"
+                                                                    ])),
+                                                                    (value!([
+                                                                        "emit" => "op_mod_opt",
+                                                                        "children" =>
                                                                             (value!([
                                                                                 "emit" => "identifier",
-                                                                                "value" => "Expect"
-                                                                            ])),
+                                                                                "value" => "ParseletGenerics"
+                                                                            ]))
+                                                                    ])),
+                                                                    (value!([
+                                                                        "emit" => "identifier",
+                                                                        "value" => "_"
+                                                                    ])),
+                                                                    (value!([
+                                                                        "emit" => "op_mod_opt",
+                                                                        "children" =>
                                                                             (value!([
-                                                                                "emit" => "genarg",
-                                                                                "children" =>
-                                                                                    (value!([
-                                                                                        "emit" => "call",
-                                                                                        "children" =>
-                                                                                            (value!([
-                                                                                                (value!([
-                                                                                                    "emit" => "identifier",
-                                                                                                    "value" => "Block"
-                                                                                                ])),
+                                                                                "emit" => "identifier",
+                                                                                "value" => "ParseletArguments"
+                                                                            ]))
+                                                                    ])),
+                                                                    (value!([
+                                                                        "emit" => "value_generic",
+                                                                        "children" =>
+                                                                            (value!([
+                                                                                (value!([
+                                                                                    "emit" => "identifier",
+                                                                                    "value" => "Expect"
+                                                                                ])),
+                                                                                (value!([
+                                                                                    "emit" => "genarg",
+                                                                                    "children" =>
+                                                                                        (value!([
+                                                                                            "emit" => "call",
+                                                                                            "children" =>
                                                                                                 (value!([
-                                                                                                    "emit" => "callarg",
-                                                                                                    "children" =>
-                                                                                                        (value!([
-                                                                                                            "emit" => "value_string",
-                                                                                                            "value" => "body"
-                                                                                                        ]))
+                                                                                                    (value!([
+                                                                                                        "emit" => "identifier",
+                                                                                                        "value" => "Block"
+                                                                                                    ])),
+                                                                                                    (value!([
+                                                                                                        "emit" => "callarg",
+                                                                                                        "children" =>
+                                                                                                            (value!([
+                                                                                                                "emit" => "value_string",
+                                                                                                                "value" => "body"
+                                                                                                            ]))
+                                                                                                    ]))
                                                                                                 ]))
-                                                                                            ]))
-                                                                                    ]))
+                                                                                        ]))
+                                                                                ]))
                                                                             ]))
-                                                                        ]))
-                                                                ])),
+                                                                    ])),
+                                                                    (value!([
+                                                                        "emit" => "call",
+                                                                        "children" =>
+                                                                            (value!([
+                                                                                (value!([
+                                                                                    "emit" => "identifier",
+                                                                                    "value" => "ast"
+                                                                                ])),
+                                                                                (value!([
+                                                                                    "emit" => "callarg",
+                                                                                    "children" =>
+                                                                                        (value!([
+                                                                                            "emit" => "value_string",
+                                                                                            "value" => "value_parselet_cached"
+                                                                                        ]))
+                                                                                ]))
+                                                                            ]))
+                                                                    ]))
+                                                                ]))
+                                                        ])),
+                                                        (value!([
+                                                            "emit" => "sequence",
+                                                            "children" =>
                                                                 (value!([
-                                                                    "emit" => "call",
-                                                                    "children" =>
-                                                                        (value!([
+                                                                    (value!([
+                                                                        "emit" => "value_token_touch",
+                                                                        "value" => "@"
+                                                                    ])),
+                                                                    (value!([
+                                                                        "emit" => "identifier",
+                                                                        "value" => "_"
+                                                                    ])),
+                                                                    (value!([
+                                                                        "emit" => "op_mod_opt",
+                                                                        "children" =>
                                                                             (value!([
                                                                                 "emit" => "identifier",
-                                                                                "value" => "ast"
-                                                                            ])),
+                                                                                "value" => "ParseletGenerics"
+                                                                            ]))
+                                                                    ])),
+                                                                    (value!([
+                                                                        "emit" => "identifier",
+                                                                        "value" => "_"
+                                                                    ])),
+                                                                    (value!([
+                                                                        "emit" => "op_mod_opt",
+                                                                        "children" =>
                                                                             (value!([
-                                                                                "emit" => "callarg",
-                                                                                "children" =>
-                                                                                    (value!([
-                                                                                        "emit" => "value_string",
-                                                                                        "value" => "value_parselet"
-                                                                                    ]))
+                                                                                "emit" => "identifier",
+                                                                                "value" => "ParseletArguments"
                                                                             ]))
-                                                                        ]))
+                                                                    ])),
+                                                                    (value!([
+                                                                        "emit" => "value_generic",
+                                                                        "children" =>
+                                                                            (value!([
+                                                                                (value!([
+                                                                                    "emit" => "identifier",
+                                                                                    "value" => "Expect"
+                                                                                ])),
+                                                                                (value!([
+                                                                                    "emit" => "genarg",
+                                                                                    "children" =>
+                                                                                        (value!([
+                                                                                            "emit" => "call",
+                                                                                            "children" =>
+                                                                                                (value!([
+                                                                                                    (value!([
+                                                                                                        "emit" => "identifier",
+                                                                                                        "value" => "Block"
+                                                                                                    ])),
+                                                                                                    (value!([
+                                                                                                        "emit" => "callarg",
+                                                                                                        "children" =>
+                                                                                                            (value!([
+                                                                                                                "emit" => "value_string",
+                                                                                                                "value" => "body"
+                                                                                                            ]))
+                                                                                                    ]))
+                                                                                                ]))
+                                                                                        ]))
+                                                                                ]))
+                                                                            ]))
+                                                                    ])),
+                                                                    (value!([
+                                                                        "emit" => "call",
+                                                                        "children" =>
+                                                                            (value!([
+                                                                                (value!([
+                                                                                    "emit" => "identifier",
+                                                                                    "value" => "ast"
+                                                                                ])),
+                                                                                (value!([
+                                                                                    "emit" => "callarg",
+                                                                                    "children" =>
+                                                                                        (value!([
+                                                                                            "emit" => "value_string",
+                                                                                            "value" => "value_parselet"
+                                                                                        ]))
+                                                                                ]))
+                                                                            ]))
+                                                                    ]))
                                                                 ]))
-                                                            ]))
+                                                        ]))
                                                     ]))
                                             ]))
                                     ]))
@@ -2643,72 +2795,182 @@ impl Parser {
                                                 "emit" => "body",
                                                 "children" =>
                                                     (value!([
-                                                        "emit" => "sequence",
-                                                        "children" =>
-                                                            (value!([
-                                                                (value!([
-                                                                    "emit" => "identifier",
-                                                                    "value" => "T_Identifier"
-                                                                ])),
-                                                                (value!([
-                                                                    "emit" => "identifier",
-                                                                    "value" => "_"
-                                                                ])),
+                                                        (value!([
+                                                            "emit" => "sequence",
+                                                            "children" =>
                                                                 (value!([
-                                                                    "emit" => "op_mod_opt",
-                                                                    "children" =>
-                                                                        (value!([
-                                                                            "emit" => "sequence",
-                                                                            "children" =>
+                                                                    (value!([
+                                                                        "emit" => "value_token_touch",
+                                                                        "value" => "**"
+                                                                    ])),
+                                                                    (value!([
+                                                                        "emit" => "identifier",
+                                                                        "value" => "_"
+                                                                    ])),
+                                                                    (value!([
+                                                                        "emit" => "value_generic",
+                                                                        "children" =>
+                                                                            (value!([
                                                                                 (value!([
-                                                                                    (value!([
-                                                                                        "emit" => "value_token_touch",
-                                                                                        "value" => "="
-                                                                                    ])),
-                                                                                    (value!([
-                                                                                        "emit" => "identifier",
-                                                                                        "value" => "_"
-                                                                                    ])),
-                                                                                    (value!([
-                                                                                        "emit" => "value_generic",
-                                                                                        "children" =>
-                                                                                            (value!([
-                                                                                                (value!([
-                                                                                                    "emit" => "identifier",
-                                                                                                    "value" => "Expect"
-                                                                                                ])),
-                                                                                                (value!([
-                                                                                                    "emit" => "genarg",
-                                                                                                    "children" =>
-                                                                                                        (value!([
-                                                                                                            "emit" => "identifier",
-                                                                                                            "value" => "Expression"
-                                                                                                        ]))
-                                                                                                ]))
-                                                                                            ]))
-                                                                                    ]))
-                                                                                ]))
-                                                                        ]))
-                                                                ])),
+                                                                                    "emit" => "identifier",
+                                                                                    "value" => "Expect"
+                                                                                ])),
+                                                                                (value!([
+                                                                                    "emit" => "genarg",
+                                                                                    "children" =>
+                                                                                        (value!([
+                                                                                            "emit" => "identifier",
+                                                                                            "value" => "T_Identifier"
+                                                                                        ]))
+                                                                                ]))
+                                                                            ]))
+                                                                    ])),
+                                                                    (value!([
+                                                                        "emit" => "identifier",
+                                                                        "value" => "_"
+                                                                    ])),
+                                                                    (value!([
+                                                                        "emit" => "call",
+                                                                        "children" =>
+                                                                            (value!([
+                                                                                (value!([
+                                                                                    "emit" => "identifier",
+                                                                                    "value" => "ast"
+                                                                                ])),
+                                                                                (value!([
+                                                                                    "emit" => "callarg",
+                                                                                    "children" =>
+                                                                                        (value!([
+                                                                                            "emit" => "value_string",
+                                                                                            "value" => "arg_nargs"
+                                                                                        ]))
+                                                                                ]))
+                                                                            ]))
+                                                                    ]))
+                                                                ]))
+                                                        ])),
+                                                        (value!([
+                                                            "emit" => "sequence",
+                                                            "children" =>
                                                                 (value!([
-                                                                    "emit" => "call",
-                                                                    "children" =>
-                                                                        (value!([
+                                                                    (value!([
+                                                                        "emit" => "value_token_touch",
+                                                                        "value" => "*"
+                                                                    ])),
+                                                                    (value!([
+                                                                        "emit" => "identifier",
+                                                                        "value" => "_"
+                                                                    ])),
+                                                                    (value!([
+                                                                        "emit" => "value_generic",
+                                                                        "children" =>
                                                                             (value!([
-                                                                                "emit" => "identifier",
-                                                                                "value" => "ast"
-                                                                            ])),
+                                                                                (value!([
+                                                                                    "emit" => "identifier",
+                                                                                    "value" => "Expect"
+                                                                                ])),
+                                                                                (value!([
+                                                                                    "emit" => "genarg",
+                                                                                    "children" =>
+                                                                                        (value!([
+                                                                                            "emit" => "identifier",
+                                                                                            "value" => "T_Identifier"
+                                                                                        ]))
+                                                                                ]))
+                                                                            ]))
+                                                                    ])),
+                                                                    (value!([
+                                                                        "emit" => "identifier",
+                                                                        "value" => "_"
+                                                                    ])),
+                                                                    (value!([
+                                                                        "emit" => "call",
+                                                                        "children" =>
                                                                             (value!([
-                                                                                "emit" => "callarg",
+                                                                                (value!([
+                                                                                    "emit" => "identifier",
+                                                                                    "value" => "ast"
+                                                                                ])),
+                                                                                (value!([
+                                                                                    "emit" => "callarg",
+                                                                                    "children" =>
+                                                                                        (value!([
+                                                                                            "emit" => "value_string",
+                                                                                            "value" => "arg_args"
+                                                                                        ]))
+                                                                                ]))
+                                                                            ]))
+                                                                    ]))
+                                                                ]))
+                                                        ])),
+                                                        (value!([
+                                                            "emit" => "sequence",
+                                                            "children" =>
+                                                                (value!([
+                                                                    (value!([
+                                                                        "emit" => "identifier",
+                                                                        "value" => "T_Identifier"
+                                                                    ])),
+                                                                    (value!([
+                                                                        "emit" => "identifier",
+                                                                        "value" => "_"
+                                                                    ])),
+                                                                    (value!([
+                                                                        "emit" => "op_mod_opt",
+                                                                        "children" =>
+                                                                            (value!([
+                                                                                "emit" => "sequence",
                                                                                 "children" =>
                                                                                     (value!([
-                                                                                        "emit" => "value_string",
-                                                                                        "value" => "arg"
+                                                                                        (value!([
+                                                                                            "emit" => "value_token_touch",
+                                                                                            "value" => "="
+                                                                                        ])),
+                                                                                        (value!([
+                                                                                            "emit" => "identifier",
+                                                                                            "value" => "_"
+                                                                                        ])),
+                                                                                        (value!([
+                                                                                            "emit" => "value_generic",
+                                                                                            "children" =>
+                                                                                                (value!([
+                                                                                                    (value!([
+                                                                                                        "emit" => "identifier",
+                                                                                                        "value" => "Expect"
+                                                                                                    ])),
+                                                                                                    (value!([
+                                                                                                        "emit" => "genarg",
+                                                                                                        "children" =>
+                                                                                                            (value!([
+                                                                                                                "emit" => "identifier",
+                                                                                                                "value" => "Expression"
+                                                                                                            ]))
+                                                                                                    ]))
+                                                                                                ]))
+                                                                                        ]))
                                                                                     ]))
                                                                             ]))
-                                                                        ]))
+                                                                    ])),
+                                                                    (value!([
+                                                                        "emit" => "call",
+                                                                        "children" =>
+                                                                            (value!([
+                                                                                (value!([
+                                                                                    "emit" => "identifier",
+                                                                                    "value" => "ast"
+                                                                                ])),
+                                                                                (value!([
+                                                                                    "emit" => "callarg",
+                                                                                    "children" =>
+                                                                                        (value!([
+                                                                                            "emit" => "value_string",
+                                                                                            "value" => "arg"
+                                                                                        ]))
+                                                                                ]))
+                                                                            ]))
+                                                                    ]))
                                                                 ]))
-                                                            ]))
+                                                        ]))
                                                     ]))
                                             ]))
                                     ]))
@@ -3131,6 +3393,52 @@ impl Parser {
                                                                     ]))
                                                                 ]))
                                                         ])),
+                                                        (value!([
+                                                            "emit" => "sequence",
+                                                            "children" =>
+                                                                (value!([
+                                                                    (value!([
+                                                                        "emit" => "identifier",
+                                                                        "value" => "T_NamedCapture"
+                                                                    ])),
+                                                                    (value!([
+                                                                        "emit" => "value_generic",
+                                                                        "children" =>
+                                                                            (value!([
+                                                                                (value!([
+                                                                                    "emit" => "identifier",
+                                                                                    "value" => "Expect"
+                                                                                ])),
+                                                                                (value!([
+                                                                                    "emit" => "genarg",
+                                                                                    "children" =>
+                                                                                        (value!([
+                                                                                            "emit" => "identifier",
+                                                                                            "value" => "InlineAssignment"
+                                                                                        ]))
+                                                                                ]))
+                                                                            ]))
+                                                                    ])),
+                                                                    (value!([
+                                                                        "emit" => "call",
+                                                                        "children" =>
+                                                                            (value!([
+                                                                                (value!([
+                                                                                    "emit" => "identifier",
+                                                                                    "value" => "ast"
+                                                                                ])),
+                                                                                (value!([
+                                                                                    "emit" => "callarg",
+                                                                                    "children" =>
+                                                                                        (value!([
+                                                                                            "emit" => "value_string",
+                                                                                            "value" => "capture_named"
+                                                                                        ]))
+                                                                                ]))
+                                                                            ]))
+                                                                    ]))
+                                                                ]))
+                                                        ])),
                                                         (value!([
                                                             "emit" => "sequence",
                                                             "children" =>
@@ -3239,41 +3547,83 @@ impl Parser {
                                                                                 "children" =>
                                                                                     (value!([
                                                                                         (value!([
-                                                                                            "emit" => "op_logical_and",
+                                                                                            "emit" => "op_logical_or",
                                                                                             "children" =>
                                                                                                 (value!([
                                                                                                     (value!([
-                                                                                                        "emit" => "comparison",
+                                                                                                        "emit" => "op_logical_and",
                                                                                                         "children" =>
                                                                                                             (value!([
                                                                                                                 (value!([
-                                                                                                                    "emit" => "call",
+                                                                                                                    "emit" => "comparison",
                                                                                                                     "children" =>
                                                                                                                         (value!([
                                                                                                                             (value!([
-                                                                                                                                "emit" => "identifier",
-                                                                                                                                "value" => "type"
+                                                                                                                                "emit" => "call",
+                                                                                                                                "children" =>
+                                                                                                                                    (value!([
+                                                                                                                                        (value!([
+                                                                                                                                            "emit" => "identifier",
+                                                                                                                                            "value" => "type"
+                                                                                                                                        ])),
+                                                                                                                                        (value!([
+                                                                                                                                            "emit" => "callarg",
+                                                                                                                                            "children" =>
+                                                                                                                                                (value!([
+                                                                                                                                                    "emit" => "capture_index",
+                                                                                                                                                    "children" =>
+                                                                                                                                                        (value!([
+                                                                                                                                                            "emit" => "value_integer",
+                                                                                                                                                            "value" => 1
+                                                                                                                                                        ]))
+                                                                                                                                                ]))
+                                                                                                                                        ]))
+                                                                                                                                    ]))
                                                                                                                             ])),
                                                                                                                             (value!([
-                                                                                                                                "emit" => "callarg",
+                                                                                                                                "emit" => "cmp_eq",
                                                                                                                                 "children" =>
                                                                                                                                     (value!([
-                                                                                                                                        "emit" => "capture_index",
-                                                                                                                                        "children" =>
-                                                                                                                                            (value!([
-                                                                                                                                                "emit" => "value_integer",
-                                                                                                                                                "value" => 1
-                                                                                                                                            ]))
+                                                                                                                                        "emit" => "value_string",
+                                                                                                                                        "value" => "list"
                                                                                                                                     ]))
                                                                                                                             ]))
                                                                                                                         ]))
                                                                                                                 ])),
                                                                                                                 (value!([
-                                                                                                                    "emit" => "cmp_eq",
+                                                                                                                    "emit" => "comparison",
                                                                                                                     "children" =>
                                                                                                                         (value!([
-                                                                                                                            "emit" => "value_string",
-                                                                                                                            "value" => "list"
+                                                                                                                            (value!([
+                                                                                                                                "emit" => "rvalue",
+                                                                                                                                "children" =>
+                                                                                                                                    (value!([
+                                                                                                                                        (value!([
+                                                                                                                                            "emit" => "capture_index",
+                                                                                                                                            "children" =>
+                                                                                                                                                (value!([
+                                                                                                                                                    "emit" => "value_integer",
+                                                                                                                                                    "value" => 1
+                                                                                                                                                ]))
+                                                                                                                                        ])),
+                                                                                                                                        (value!([
+                                                                                                                                            "emit" => "attribute",
+                                                                                                                                            "children" =>
+                                                                                                                                                (value!([
+                                                                                                                                                    "emit" => "value_string",
+                                                                                                                                                    "value" => "len"
+                                                                                                                                                ]))
+                                                                                                                                        ]))
+                                                                                                                                    ]))
+                                                                                                                            ])),
+                                                                                                                            (value!([
+                                                                                                                                "emit" => "cmp_gt",
+                                                                                                                                "children" =>
+                                                                                                                                    (value!([
+                                                                                                                                        "emit" => "value_integer",
+                                                                                                                                        "value" => 1
+                                                                                                                                    ]))
+                                                                                                                            ]))
                                                                                                                         ]))
                                                                                                                 ]))
                                                                                                             ]))
@@ -3295,21 +3645,21 @@ impl Parser {
                                                                                                                                     ]))
                                                                                                                             ])),
                                                                                                                             (value!([
-                                                                                                                                "emit" => "attribute",
+                                                                                                                                "emit" => "item",
                                                                                                                                 "children" =>
                                                                                                                                     (value!([
                                                                                                                                         "emit" => "value_string",
-                                                                                                                                        "value" => "len"
+                                                                                                                                        "value" => "emit"
                                                                                                                                     ]))
                                                                                                                             ]))
                                                                                                                         ]))
                                                                                                                 ])),
                                                                                                                 (value!([
-                                                                                                                    "emit" => "cmp_gt",
+                                                                                                                    "emit" => "cmp_eq",
                                                                                                                     "children" =>
                                                                                                                         (value!([
-                                                                                                                            "emit" => "value_integer",
-                                                                                                                            "value" => 1
+                                                                                                                            "emit" => "value_string",
+                                                                                                                            "value" => "alias"
                                                                                                                         ]))
                                                                                                                 ]))
                                                                                                             ]))
@@ -3347,7 +3697,7 @@ impl Parser {
                                                                                                         "children" =>
                                                                                                             (value!([
                                                                                                                 "emit" => "value_string",
-                                                                                                                "value" => "alias"
+                                                                                                                "value" => "capture_named"
                                                                                                             ]))
                                                                                                     ]))
                                                                                                 ]))
@@ -3899,7 +4249,7 @@ impl Parser {
                                 (value!([
                                     (value!([
                                         "emit" => "identifier",
-                                        "value" => "TokenLiteral"
+                                        "value" => "T_Caseless"
                                     ])),
                                     (value!([
                                         "emit" => "value_parselet",
@@ -3908,9 +4258,117 @@ impl Parser {
                                                 "emit" => "body",
                                                 "children" =>
                                                     (value!([
-                                                        (value!([
-                                                            "emit" => "sequence",
-                                                            "children" =>
+                                                        "emit" => "sequence",
+                                                        "children" =>
+                                                            (value!([
+                                                                (value!([
+                                                                    "emit" => "value_token_touch",
+                                                                    "value" => "i"
+                                                                ])),
+                                                                (value!([
+                                                                    "emit" => "value_generic",
+                                                                    "children" =>
+                                                                        (value!([
+                                                                            (value!([
+                                                                                "emit" => "identifier",
+                                                                                "value" => "Not"
+                                                                            ])),
+                                                                            (value!([
+                                                                                "emit" => "genarg",
+                                                                                "children" =>
+                                                                                    (value!([
+                                                                                        "emit" => "value_token_ccl",
+                                                                                        "children" =>
+                                                                                            (value!([
+                                                                                                "emit" => "ccl",
+                                                                                                "children" =>
+                                                                                                    (value!([
+                                                                                                        (value!([
+                                                                                                            "emit" => "range",
+                                                                                                            "value" => "09"
+                                                                                                        ])),
+                                                                                                        (value!([
+                                                                                                            "emit" => "range",
+                                                                                                            "value" => "AZ"
+                                                                                                        ])),
+                                                                                                        (value!([
+                                                                                                            "emit" => "char",
+                                                                                                            "value" => "_"
+                                                                                                        ])),
+                                                                                                        (value!([
+                                                                                                            "emit" => "range",
+                                                                                                            "value" => "az"
+                                                                                                        ]))
+                                                                                                    ]))
+                                                                                            ]))
+                                                                                    ]))
+                                                                            ]))
+                                                                        ]))
+                                                                ]))
+                                                            ]))
+                                                    ]))
+                                            ]))
+                                    ]))
+                                ]))
+                        ])),
+                        (value!([
+                            "emit" => "constant",
+                            "children" =>
+                                (value!([
+                                    (value!([
+                                        "emit" => "identifier",
+                                        "value" => "TokenLiteral"
+                                    ])),
+                                    (value!([
+                                        "emit" => "value_parselet",
+                                        "children" =>
+                                            (value!([
+                                                "emit" => "body",
+                                                "children" =>
+                                                    (value!([
+                                                        (value!([
+                                                            "emit" => "sequence",
+                                                            "children" =>
+                                                                (value!([
+                                                                    (value!([
+                                                                        "emit" => "value_token_touch",
+                                                                        "value" => "'"
+                                                                    ])),
+                                                                    (value!([
+                                                                        "emit" => "identifier",
+                                                                        "value" => "T_Touch"
+                                                                    ])),
+                                                                    (value!([
+                                                                        "emit" => "value_token_touch",
+                                                                        "value" => "'"
+                                                                    ])),
+                                                                    (value!([
+                                                                        "emit" => "identifier",
+                                                                        "value" => "T_Caseless"
+                                                                    ])),
+                                                                    (value!([
+                                                                        "emit" => "call",
+                                                                        "children" =>
+                                                                            (value!([
+                                                                                (value!([
+                                                                                    "emit" => "identifier",
+                                                                                    "value" => "ast"
+                                                                                ])),
+                                                                                (value!([
+                                                                                    "emit" => "callarg",
+                                                                                    "children" =>
+                                                                                        (value!([
+                                                                                            "emit" => "value_string",
+                                                                                            "value" => "value_token_match_caseless"
+                                                                                        ]))
+                                                                                ]))
+                                                                            ]))
+                                                                    ]))
+                                                                ]))
+                                                        ])),
+                                                        (value!([
+                                                            "emit" => "sequence",
+                                                            "children" =>
                                                                 (value!([
                                                                     (value!([
                                                                         "emit" => "value_token_touch",
@@ -3949,8 +4407,492 @@ impl Parser {
                                                             "children" =>
                                                                 (value!([
                                                                     (value!([
-                                                                        "emit" => "identifier",
-                                                                        "value" => "T_Touch"
+                                                                        "emit" => "identifier",
+                                                                        "value" => "T_Touch"
+                                                                    ])),
+                                                                    (value!([
+                                                                        "emit" => "identifier",
+                                                                        "value" => "T_Caseless"
+                                                                    ])),
+                                                                    (value!([
+                                                                        "emit" => "call",
+                                                                        "children" =>
+                                                                            (value!([
+                                                                                (value!([
+                                                                                    "emit" => "identifier",
+                                                                                    "value" => "ast"
+                                                                                ])),
+                                                                                (value!([
+                                                                                    "emit" => "callarg",
+                                                                                    "children" =>
+                                                                                        (value!([
+                                                                                            "emit" => "value_string",
+                                                                                            "value" => "value_token_touch_caseless"
+                                                                                        ]))
+                                                                                ]))
+                                                                            ]))
+                                                                    ]))
+                                                                ]))
+                                                        ])),
+                                                        (value!([
+                                                            "emit" => "sequence",
+                                                            "children" =>
+                                                                (value!([
+                                                                    (value!([
+                                                                        "emit" => "identifier",
+                                                                        "value" => "T_Touch"
+                                                                    ])),
+                                                                    (value!([
+                                                                        "emit" => "call",
+                                                                        "children" =>
+                                                                            (value!([
+                                                                                (value!([
+                                                                                    "emit" => "identifier",
+                                                                                    "value" => "ast"
+                                                                                ])),
+                                                                                (value!([
+                                                                                    "emit" => "callarg",
+                                                                                    "children" =>
+                                                                                        (value!([
+                                                                                            "emit" => "value_string",
+                                                                                            "value" => "value_token_touch"
+                                                                                        ]))
+                                                                                ]))
+                                                                            ]))
+                                                                    ]))
+                                                                ]))
+                                                        ])),
+                                                        (value!([
+                                                            "emit" => "sequence",
+                                                            "children" =>
+                                                                (value!([
+                                                                    (value!([
+                                                                        "emit" => "value_generic",
+                                                                        "children" =>
+                                                                            (value!([
+                                                                                (value!([
+                                                                                    "emit" => "identifier",
+                                                                                    "value" => "Keyword"
+                                                                                ])),
+                                                                                (value!([
+                                                                                    "emit" => "genarg",
+                                                                                    "children" =>
+                                                                                        (value!([
+                                                                                            "emit" => "value_token_touch",
+                                                                                            "value" => "Chars"
+                                                                                        ]))
+                                                                                ]))
+                                                                            ]))
+                                                                    ])),
+                                                                    (value!([
+                                                                        "emit" => "value_token_touch",
+                                                                        "value" => "<"
+                                                                    ])),
+                                                                    (value!([
+                                                                        "emit" => "identifier",
+                                                                        "value" => "Ccl"
+                                                                    ])),
+                                                                    (value!([
+                                                                        "emit" => "value_token_touch",
+                                                                        "value" => ">"
+                                                                    ])),
+                                                                    (value!([
+                                                                        "emit" => "identifier",
+                                                                        "value" => "T_Caseless"
+                                                                    ])),
+                                                                    (value!([
+                                                                        "emit" => "call",
+                                                                        "children" =>
+                                                                            (value!([
+                                                                                (value!([
+                                                                                    "emit" => "identifier",
+                                                                                    "value" => "ast"
+                                                                                ])),
+                                                                                (value!([
+                                                                                    "emit" => "callarg",
+                                                                                    "children" =>
+                                                                                        (value!([
+                                                                                            "emit" => "value_string",
+                                                                                            "value" => "value_token_ccls_caseless"
+                                                                                        ]))
+                                                                                ]))
+                                                                            ]))
+                                                                    ]))
+                                                                ]))
+                                                        ])),
+                                                        (value!([
+                                                            "emit" => "sequence",
+                                                            "children" =>
+                                                                (value!([
+                                                                    (value!([
+                                                                        "emit" => "value_generic",
+                                                                        "children" =>
+                                                                            (value!([
+                                                                                (value!([
+                                                                                    "emit" => "identifier",
+                                                                                    "value" => "Keyword"
+                                                                                ])),
+                                                                                (value!([
+                                                                                    "emit" => "genarg",
+                                                                                    "children" =>
+                                                                                        (value!([
+                                                                                            "emit" => "value_token_touch",
+                                                                                            "value" => "Chars"
+                                                                                        ]))
+                                                                                ]))
+                                                                            ]))
+                                                                    ])),
+                                                                    (value!([
+                                                                        "emit" => "value_token_touch",
+                                                                        "value" => "<"
+                                                                    ])),
+                                                                    (value!([
+                                                                        "emit" => "identifier",
+                                                                        "value" => "Ccl"
+                                                                    ])),
+                                                                    (value!([
+                                                                        "emit" => "value_token_touch",
+                                                                        "value" => ">"
+                                                                    ])),
+                                                                    (value!([
+                                                                        "emit" => "call",
+                                                                        "children" =>
+                                                                            (value!([
+                                                                                (value!([
+                                                                                    "emit" => "identifier",
+                                                                                    "value" => "ast"
+                                                                                ])),
+                                                                                (value!([
+                                                                                    "emit" => "callarg",
+                                                                                    "children" =>
+                                                                                        (value!([
+                                                                                            "emit" => "value_string",
+                                                                                            "value" => "value_token_ccls"
+                                                                                        ]))
+                                                                                ]))
+                                                                            ]))
+                                                                    ]))
+                                                                ]))
+                                                        ])),
+                                                        (value!([
+                                                            "emit" => "sequence",
+                                                            "children" =>
+                                                                (value!([
+                                                                    (value!([
+                                                                        "emit" => "value_generic",
+                                                                        "children" =>
+                                                                            (value!([
+                                                                                (value!([
+                                                                                    "emit" => "identifier",
+                                                                                    "value" => "Keyword"
+                                                                                ])),
+                                                                                (value!([
+                                                                                    "emit" => "genarg",
+                                                                                    "children" =>
+                                                                                        (value!([
+                                                                                            "emit" => "value_token_touch",
+                                                                                            "value" => "Chars"
+                                                                                        ]))
+                                                                                ]))
+                                                                            ]))
+                                                                    ])),
+                                                                    (value!([
+                                                                        "emit" => "call",
+                                                                        "children" =>
+                                                                            (value!([
+                                                                                (value!([
+                                                                                    "emit" => "identifier",
+                                                                                    "value" => "ast"
+                                                                                ])),
+                                                                                (value!([
+                                                                                    "emit" => "callarg",
+                                                                                    "children" =>
+                                                                                        (value!([
+                                                                                            "emit" => "value_string",
+                                                                                            "value" => "value_token_anys"
+                                                                                        ]))
+                                                                                ]))
+                                                                            ]))
+                                                                    ]))
+                                                                ]))
+                                                        ])),
+                                                        (value!([
+                                                            "emit" => "sequence",
+                                                            "children" =>
+                                                                (value!([
+                                                                    (value!([
+                                                                        "emit" => "value_generic",
+                                                                        "children" =>
+                                                                            (value!([
+                                                                                (value!([
+                                                                                    "emit" => "identifier",
+                                                                                    "value" => "Keyword"
+                                                                                ])),
+                                                                                (value!([
+                                                                                    "emit" => "genarg",
+                                                                                    "children" =>
+                                                                                        (value!([
+                                                                                            "emit" => "value_token_touch",
+                                                                                            "value" => "Char"
+                                                                                        ]))
+                                                                                ]))
+                                                                            ]))
+                                                                    ])),
+                                                                    (value!([
+                                                                        "emit" => "value_token_touch",
+                                                                        "value" => "<"
+                                                                    ])),
+                                                                    (value!([
+                                                                        "emit" => "identifier",
+                                                                        "value" => "Ccl"
+                                                                    ])),
+                                                                    (value!([
+                                                                        "emit" => "value_token_touch",
+                                                                        "value" => ">"
+                                                                    ])),
+                                                                    (value!([
+                                                                        "emit" => "identifier",
+                                                                        "value" => "T_Caseless"
+                                                                    ])),
+                                                                    (value!([
+                                                                        "emit" => "call",
+                                                                        "children" =>
+                                                                            (value!([
+                                                                                (value!([
+                                                                                    "emit" => "identifier",
+                                                                                    "value" => "ast"
+                                                                                ])),
+                                                                                (value!([
+                                                                                    "emit" => "callarg",
+                                                                                    "children" =>
+                                                                                        (value!([
+                                                                                            "emit" => "value_string",
+                                                                                            "value" => "value_token_ccl_caseless"
+                                                                                        ]))
+                                                                                ]))
+                                                                            ]))
+                                                                    ]))
+                                                                ]))
+                                                        ])),
+                                                        (value!([
+                                                            "emit" => "sequence",
+                                                            "children" =>
+                                                                (value!([
+                                                                    (value!([
+                                                                        "emit" => "value_generic",
+                                                                        "children" =>
+                                                                            (value!([
+                                                                                (value!([
+                                                                                    "emit" => "identifier",
+                                                                                    "value" => "Keyword"
+                                                                                ])),
+                                                                                (value!([
+                                                                                    "emit" => "genarg",
+                                                                                    "children" =>
+                                                                                        (value!([
+                                                                                            "emit" => "value_token_touch",
+                                                                                            "value" => "Char"
+                                                                                        ]))
+                                                                                ]))
+                                                                            ]))
+                                                                    ])),
+                                                                    (value!([
+                                                                        "emit" => "value_token_touch",
+                                                                        "value" => "<"
+                                                                    ])),
+                                                                    (value!([
+                                                                        "emit" => "identifier",
+                                                                        "value" => "Ccl"
+                                                                    ])),
+                                                                    (value!([
+                                                                        "emit" => "value_token_touch",
+                                                                        "value" => ">"
+                                                                    ])),
+                                                                    (value!([
+                                                                        "emit" => "call",
+                                                                        "children" =>
+                                                                            (value!([
+                                                                                (value!([
+                                                                                    "emit" => "identifier",
+                                                                                    "value" => "ast"
+                                                                                ])),
+                                                                                (value!([
+                                                                                    "emit" => "callarg",
+                                                                                    "children" =>
+                                                                                        (value!([
+                                                                                            "emit" => "value_string",
+                                                                                            "value" => "value_token_ccl"
+                                                                                        ]))
+                                                                                ]))
+                                                                            ]))
+                                                                    ]))
+                                                                ]))
+                                                        ])),
+                                                        (value!([
+                                                            "emit" => "sequence",
+                                                            "children" =>
+                                                                (value!([
+                                                                    (value!([
+                                                                        "emit" => "value_generic",
+                                                                        "children" =>
+                                                                            (value!([
+                                                                                (value!([
+                                                                                    "emit" => "identifier",
+                                                                                    "value" => "Keyword"
+                                                                                ])),
+                                                                                (value!([
+                                                                                    "emit" => "genarg",
+                                                                                    "children" =>
+                                                                                        (value!([
+                                                                                            "emit" => "value_token_touch",
+                                                                                            "value" => "Char"
+                                                                                        ]))
+                                                                                ]))
+                                                                            ]))
+                                                                    ])),
+                                                                    (value!([
+                                                                        "emit" => "call",
+                                                                        "children" =>
+                                                                            (value!([
+                                                                                (value!([
+                                                                                    "emit" => "identifier",
+                                                                                    "value" => "ast"
+                                                                                ])),
+                                                                                (value!([
+                                                                                    "emit" => "callarg",
+                                                                                    "children" =>
+                                                                                        (value!([
+                                                                                            "emit" => "value_string",
+                                                                                            "value" => "value_token_any"
+                                                                                        ]))
+                                                                                ]))
+                                                                            ]))
+                                                                    ]))
+                                                                ]))
+                                                        ])),
+                                                        (value!([
+                                                            "emit" => "sequence",
+                                                            "children" =>
+                                                                (value!([
+                                                                    (value!([
+                                                                        "emit" => "value_generic",
+                                                                        "children" =>
+                                                                            (value!([
+                                                                                (value!([
+                                                                                    "emit" => "identifier",
+                                                                                    "value" => "Keyword"
+                                                                                ])),
+                                                                                (value!([
+                                                                                    "emit" => "genarg",
+                                                                                    "children" =>
+                                                                                        (value!([
+                                                                                            "emit" => "value_token_touch",
+                                                                                            "value" => "Self"
+                                                                                        ]))
+                                                                                ]))
+                                                                            ]))
+                                                                    ])),
+                                                                    (value!([
+                                                                        "emit" => "call",
+                                                                        "children" =>
+                                                                            (value!([
+                                                                                (value!([
+                                                                                    "emit" => "identifier",
+                                                                                    "value" => "ast"
+                                                                                ])),
+                                                                                (value!([
+                                                                                    "emit" => "callarg",
+                                                                                    "children" =>
+                                                                                        (value!([
+                                                                                            "emit" => "value_string",
+                                                                                            "value" => "value_token_self"
+                                                                                        ]))
+                                                                                ]))
+                                                                            ]))
+                                                                    ]))
+                                                                ]))
+                                                        ])),
+                                                        (value!([
+                                                            "emit" => "sequence",
+                                                            "children" =>
+                                                                (value!([
+                                                                    (value!([
+                                                                        "emit" => "value_generic",
+                                                                        "children" =>
+                                                                            (value!([
+                                                                                (value!([
+                                                                                    "emit" => "identifier",
+                                                                                    "value" => "Keyword"
+                                                                                ])),
+                                                                                (value!([
+                                                                                    "emit" => "genarg",
+                                                                                    "children" =>
+                                                                                        (value!([
+                                                                                            "emit" => "value_token_touch",
+                                                                                            "value" => "Void"
+                                                                                        ]))
+                                                                                ]))
+                                                                            ]))
+                                                                    ])),
+                                                                    (value!([
+                                                                        "emit" => "call",
+                                                                        "children" =>
+                                                                            (value!([
+                                                                                (value!([
+                                                                                    "emit" => "identifier",
+                                                                                    "value" => "ast"
+                                                                                ])),
+                                                                                (value!([
+                                                                                    "emit" => "callarg",
+                                                                                    "children" =>
+                                                                                        (value!([
+                                                                                            "emit" => "value_string",
+                                                                                            "value" => "value_token_void"
+                                                                                        ]))
+                                                                                ]))
+                                                                            ]))
+                                                                    ]))
+                                                                ]))
+                                                        ]))
+                                                    ]))
+                                            ]))
+                                    ]))
+                                ]))
+                        ])),
+                        (value!([
+                            "emit" => "constant",
+                            "children" =>
+                                (value!([
+                                    (value!([
+                                        "emit" => "identifier",
+                                        "value" => "Token"
+                                    ])),
+                                    (value!([
+                                        "emit" => "value_parselet",
+                                        "children" =>
+                                            (value!([
+                                                "emit" => "body",
+                                                "children" =>
+                                                    (value!([
+                                                        (value!([
+                                                            "emit" => "sequence",
+                                                            "children" =>
+                                                                (value!([
+                                                                    (value!([
+                                                                        "emit" => "value_token_touch",
+                                                                        "value" => "("
+                                                                    ])),
+                                                                    (value!([
+                                                                        "emit" => "identifier",
+                                                                        "value" => "_"
+                                                                    ])),
+                                                                    (value!([
+                                                                        "emit" => "identifier",
+                                                                        "value" => "___"
+                                                                    ])),
+                                                                    (value!([
+                                                                        "emit" => "value_token_touch",
+                                                                        "value" => ")"
                                                                     ])),
                                                                     (value!([
                                                                         "emit" => "call",
@@ -3965,7 +4907,7 @@ impl Parser {
                                                                                     "children" =>
                                                                                         (value!([
                                                                                             "emit" => "value_string",
-                                                                                            "value" => "value_token_touch"
+                                                                                            "value" => "dict"
                                                                                         ]))
                                                                                 ]))
                                                                             ]))
@@ -3976,6 +4918,38 @@ impl Parser {
                                                             "emit" => "sequence",
                                                             "children" =>
                                                                 (value!([
+                                                                    (value!([
+                                                                        "emit" => "value_token_touch",
+                                                                        "value" => "("
+                                                                    ])),
+                                                                    (value!([
+                                                                        "emit" => "identifier",
+                                                                        "value" => "_"
+                                                                    ])),
+                                                                    (value!([
+                                                                        "emit" => "identifier",
+                                                                        "value" => "___"
+                                                                    ])),
+                                                                    (value!([
+                                                                        "emit" => "identifier",
+                                                                        "value" => "InlineSequences"
+                                                                    ])),
+                                                                    (value!([
+                                                                        "emit" => "identifier",
+                                                                        "value" => "___"
+                                                                    ])),
+                                                                    (value!([
+                                                                        "emit" => "value_token_touch",
+                                                                        "value" => ")"
+                                                                    ])),
+                                                                    (value!([
+                                                                        "emit" => "value_token_touch",
+                                                                        "value" => "<"
+                                                                    ])),
+                                                                    (value!([
+                                                                        "emit" => "identifier",
+                                                                        "value" => "_"
+                                                                    ])),
                                                                     (value!([
                                                                         "emit" => "value_generic",
                                                                         "children" =>
@@ -3989,18 +4963,14 @@ impl Parser {
                                                                                     "children" =>
                                                                                         (value!([
                                                                                             "emit" => "value_token_touch",
-                                                                                            "value" => "Chars"
+                                                                                            "value" => "flat"
                                                                                         ]))
                                                                                 ]))
                                                                             ]))
                                                                     ])),
-                                                                    (value!([
-                                                                        "emit" => "value_token_touch",
-                                                                        "value" => "<"
-                                                                    ])),
                                                                     (value!([
                                                                         "emit" => "identifier",
-                                                                        "value" => "Ccl"
+                                                                        "value" => "_"
                                                                     ])),
                                                                     (value!([
                                                                         "emit" => "value_token_touch",
@@ -4019,7 +4989,7 @@ impl Parser {
                                                                                     "children" =>
                                                                                         (value!([
                                                                                             "emit" => "value_string",
-                                                                                            "value" => "value_token_ccls"
+                                                                                            "value" => "inline_flat"
                                                                                         ]))
                                                                                 ]))
                                                                             ]))
@@ -4030,6 +5000,38 @@ impl Parser {
                                                             "emit" => "sequence",
                                                             "children" =>
                                                                 (value!([
+                                                                    (value!([
+                                                                        "emit" => "value_token_touch",
+                                                                        "value" => "("
+                                                                    ])),
+                                                                    (value!([
+                                                                        "emit" => "identifier",
+                                                                        "value" => "_"
+                                                                    ])),
+                                                                    (value!([
+                                                                        "emit" => "identifier",
+                                                                        "value" => "___"
+                                                                    ])),
+                                                                    (value!([
+                                                                        "emit" => "identifier",
+                                                                        "value" => "InlineSequences"
+                                                                    ])),
+                                                                    (value!([
+                                                                        "emit" => "identifier",
+                                                                        "value" => "___"
+                                                                    ])),
+                                                                    (value!([
+                                                                        "emit" => "value_token_touch",
+                                                                        "value" => ")"
+                                                                    ])),
+                                                                    (value!([
+                                                                        "emit" => "value_token_touch",
+                                                                        "value" => "<"
+                                                                    ])),
+                                                                    (value!([
+                                                                        "emit" => "identifier",
+                                                                        "value" => "_"
+                                                                    ])),
                                                                     (value!([
                                                                         "emit" => "value_generic",
                                                                         "children" =>
@@ -4043,11 +5045,19 @@ impl Parser {
                                                                                     "children" =>
                                                                                         (value!([
                                                                                             "emit" => "value_token_touch",
-                                                                                            "value" => "Chars"
+                                                                                            "value" => "list"
                                                                                         ]))
                                                                                 ]))
                                                                             ]))
                                                                     ])),
+                                                                    (value!([
+                                                                        "emit" => "identifier",
+                                                                        "value" => "_"
+                                                                    ])),
+                                                                    (value!([
+                                                                        "emit" => "value_token_touch",
+                                                                        "value" => ">"
+                                                                    ])),
                                                                     (value!([
                                                                         "emit" => "call",
                                                                         "children" =>
@@ -4061,7 +5071,7 @@ impl Parser {
                                                                                     "children" =>
                                                                                         (value!([
                                                                                             "emit" => "value_string",
-                                                                                            "value" => "value_token_anys"
+                                                                                            "value" => "inline_list"
                                                                                         ]))
                                                                                 ]))
                                                                             ]))
@@ -4073,49 +5083,49 @@ impl Parser {
                                                             "children" =>
                                                                 (value!([
                                                                     (value!([
-                                                                        "emit" => "value_generic",
+                                                                        "emit" => "value_token_touch",
+                                                                        "value" => "("
+                                                                    ])),
+                                                                    (value!([
+                                                                        "emit" => "identifier",
+                                                                        "value" => "_"
+                                                                    ])),
+                                                                    (value!([
+                                                                        "emit" => "identifier",
+                                                                        "value" => "___"
+                                                                    ])),
+                                                                    (value!([
+                                                                        "emit" => "block",
                                                                         "children" =>
                                                                             (value!([
                                                                                 (value!([
                                                                                     "emit" => "identifier",
-                                                                                    "value" => "Keyword"
+                                                                                    "value" => "InlineList"
                                                                                 ])),
                                                                                 (value!([
-                                                                                    "emit" => "genarg",
-                                                                                    "children" =>
-                                                                                        (value!([
-                                                                                            "emit" => "value_token_touch",
-                                                                                            "value" => "Char"
-                                                                                        ]))
+                                                                                    "emit" => "identifier",
+                                                                                    "value" => "InlineSequences"
                                                                                 ]))
                                                                             ]))
                                                                     ])),
-                                                                    (value!([
-                                                                        "emit" => "value_token_touch",
-                                                                        "value" => "<"
-                                                                    ])),
                                                                     (value!([
                                                                         "emit" => "identifier",
-                                                                        "value" => "Ccl"
-                                                                    ])),
-                                                                    (value!([
-                                                                        "emit" => "value_token_touch",
-                                                                        "value" => ">"
+                                                                        "value" => "___"
                                                                     ])),
                                                                     (value!([
-                                                                        "emit" => "call",
+                                                                        "emit" => "value_generic",
                                                                         "children" =>
                                                                             (value!([
                                                                                 (value!([
                                                                                     "emit" => "identifier",
-                                                                                    "value" => "ast"
+                                                                                    "value" => "Expect"
                                                                                 ])),
                                                                                 (value!([
-                                                                                    "emit" => "callarg",
+                                                                                    "emit" => "genarg",
                                                                                     "children" =>
                                                                                         (value!([
-                                                                                            "emit" => "value_string",
-                                                                                            "value" => "value_token_ccl"
+                                                                                            "emit" => "value_token_touch",
+                                                                                            "value" => ")"
                                                                                         ]))
                                                                                 ]))
                                                                             ]))
@@ -4127,61 +5137,57 @@ impl Parser {
                                                             "children" =>
                                                                 (value!([
                                                                     (value!([
-                                                                        "emit" => "value_generic",
-                                                                        "children" =>
-                                                                            (value!([
-                                                                                (value!([
-                                                                                    "emit" => "identifier",
-                                                                                    "value" => "Keyword"
-                                                                                ])),
-                                                                                (value!([
-                                                                                    "emit" => "genarg",
-                                                                                    "children" =>
-                                                                                        (value!([
-                                                                                            "emit" => "value_token_touch",
-                                                                                            "value" => "Char"
-                                                                                        ]))
-                                                                                ]))
-                                                                            ]))
+                                                                        "emit" => "value_token_touch",
+                                                                        "value" => "@"
                                                                     ])),
                                                                     (value!([
-                                                                        "emit" => "call",
+                                                                        "emit" => "identifier",
+                                                                        "value" => "_"
+                                                                    ])),
+                                                                    (value!([
+                                                                        "emit" => "value_token_touch",
+                                                                        "value" => "("
+                                                                    ])),
+                                                                    (value!([
+                                                                        "emit" => "identifier",
+                                                                        "value" => "_"
+                                                                    ])),
+                                                                    (value!([
+                                                                        "emit" => "identifier",
+                                                                        "value" => "___"
+                                                                    ])),
+                                                                    (value!([
+                                                                        "emit" => "block",
                                                                         "children" =>
                                                                             (value!([
                                                                                 (value!([
                                                                                     "emit" => "identifier",
-                                                                                    "value" => "ast"
+                                                                                    "value" => "InlineList"
                                                                                 ])),
                                                                                 (value!([
-                                                                                    "emit" => "callarg",
-                                                                                    "children" =>
-                                                                                        (value!([
-                                                                                            "emit" => "value_string",
-                                                                                            "value" => "value_token_any"
-                                                                                        ]))
+                                                                                    "emit" => "identifier",
+                                                                                    "value" => "InlineSequences"
                                                                                 ]))
                                                                             ]))
-                                                                    ]))
-                                                                ]))
-                                                        ])),
-                                                        (value!([
-                                                            "emit" => "sequence",
-                                                            "children" =>
-                                                                (value!([
+                                                                    ])),
+                                                                    (value!([
+                                                                        "emit" => "identifier",
+                                                                        "value" => "___"
+                                                                    ])),
                                                                     (value!([
                                                                         "emit" => "value_generic",
                                                                         "children" =>
                                                                             (value!([
                                                                                 (value!([
                                                                                     "emit" => "identifier",
-                                                                                    "value" => "Keyword"
+                                                                                    "value" => "Expect"
                                                                                 ])),
                                                                                 (value!([
                                                                                     "emit" => "genarg",
                                                                                     "children" =>
                                                                                         (value!([
                                                                                             "emit" => "value_token_touch",
-                                                                                            "value" => "Self"
+                                                                                            "value" => ")"
                                                                                         ]))
                                                                                 ]))
                                                                             ]))
@@ -4199,31 +5205,67 @@ impl Parser {
                                                                                     "children" =>
                                                                                         (value!([
                                                                                             "emit" => "value_string",
-                                                                                            "value" => "value_token_self"
+                                                                                            "value" => "area"
                                                                                         ]))
                                                                                 ]))
                                                                             ]))
                                                                     ]))
                                                                 ]))
                                                         ])),
+                                                        (value!([
+                                                            "emit" => "identifier",
+                                                            "value" => "Block"
+                                                        ])),
+                                                        (value!([
+                                                            "emit" => "identifier",
+                                                            "value" => "TokenLiteral"
+                                                        ])),
                                                         (value!([
                                                             "emit" => "sequence",
                                                             "children" =>
                                                                 (value!([
+                                                                    (value!([
+                                                                        "emit" => "identifier",
+                                                                        "value" => "ParseletInstance"
+                                                                    ])),
+                                                                    (value!([
+                                                                        "emit" => "value_token_touch",
+                                                                        "value" => "("
+                                                                    ])),
+                                                                    (value!([
+                                                                        "emit" => "identifier",
+                                                                        "value" => "_"
+                                                                    ])),
+                                                                    (value!([
+                                                                        "emit" => "identifier",
+                                                                        "value" => "___"
+                                                                    ])),
+                                                                    (value!([
+                                                                        "emit" => "op_mod_opt",
+                                                                        "children" =>
+                                                                            (value!([
+                                                                                "emit" => "identifier",
+                                                                                "value" => "CallArguments"
+                                                                            ]))
+                                                                    ])),
+                                                                    (value!([
+                                                                        "emit" => "identifier",
+                                                                        "value" => "___"
+                                                                    ])),
                                                                     (value!([
                                                                         "emit" => "value_generic",
                                                                         "children" =>
                                                                             (value!([
                                                                                 (value!([
                                                                                     "emit" => "identifier",
-                                                                                    "value" => "Keyword"
+                                                                                    "value" => "Expect"
                                                                                 ])),
                                                                                 (value!([
                                                                                     "emit" => "genarg",
                                                                                     "children" =>
                                                                                         (value!([
                                                                                             "emit" => "value_token_touch",
-                                                                                            "value" => "Void"
+                                                                                            "value" => ")"
                                                                                         ]))
                                                                                 ]))
                                                                             ]))
@@ -4241,12 +5283,16 @@ impl Parser {
                                                                                     "children" =>
                                                                                         (value!([
                                                                                             "emit" => "value_string",
-                                                                                            "value" => "value_token_void"
+                                                                                            "value" => "call"
                                                                                         ]))
                                                                                 ]))
                                                                             ]))
                                                                     ]))
                                                                 ]))
+                                                        ])),
+                                                        (value!([
+                                                            "emit" => "identifier",
+                                                            "value" => "ParseletInstance"
                                                         ]))
                                                     ]))
                                             ]))
@@ -4259,7 +5305,7 @@ impl Parser {
                                 (value!([
                                     (value!([
                                         "emit" => "identifier",
-                                        "value" => "Token"
+                                        "value" => "TokenModifier"
                                     ])),
                                     (value!([
                                         "emit" => "value_parselet",
@@ -4272,21 +5318,13 @@ impl Parser {
                                                             "emit" => "sequence",
                                                             "children" =>
                                                                 (value!([
-                                                                    (value!([
-                                                                        "emit" => "value_token_touch",
-                                                                        "value" => "("
-                                                                    ])),
-                                                                    (value!([
-                                                                        "emit" => "identifier",
-                                                                        "value" => "_"
-                                                                    ])),
                                                                     (value!([
                                                                         "emit" => "identifier",
-                                                                        "value" => "___"
+                                                                        "value" => "Token"
                                                                     ])),
                                                                     (value!([
                                                                         "emit" => "value_token_touch",
-                                                                        "value" => ")"
+                                                                        "value" => "+"
                                                                     ])),
                                                                     (value!([
                                                                         "emit" => "call",
@@ -4301,7 +5339,7 @@ impl Parser {
                                                                                     "children" =>
                                                                                         (value!([
                                                                                             "emit" => "value_string",
-                                                                                            "value" => "dict"
+                                                                                            "value" => "op_mod_pos"
                                                                                         ]))
                                                                                 ]))
                                                                             ]))
@@ -4312,50 +5350,28 @@ impl Parser {
                                                             "emit" => "sequence",
                                                             "children" =>
                                                                 (value!([
-                                                                    (value!([
-                                                                        "emit" => "value_token_touch",
-                                                                        "value" => "("
-                                                                    ])),
-                                                                    (value!([
-                                                                        "emit" => "identifier",
-                                                                        "value" => "_"
-                                                                    ])),
                                                                     (value!([
                                                                         "emit" => "identifier",
-                                                                        "value" => "___"
-                                                                    ])),
-                                                                    (value!([
-                                                                        "emit" => "block",
-                                                                        "children" =>
-                                                                            (value!([
-                                                                                (value!([
-                                                                                    "emit" => "identifier",
-                                                                                    "value" => "InlineList"
-                                                                                ])),
-                                                                                (value!([
-                                                                                    "emit" => "identifier",
-                                                                                    "value" => "InlineSequences"
-                                                                                ]))
-                                                                            ]))
+                                                                        "value" => "Token"
                                                                     ])),
                                                                     (value!([
-                                                                        "emit" => "identifier",
-                                                                        "value" => "___"
+                                                                        "emit" => "value_token_touch",
+                                                                        "value" => "*"
                                                                     ])),
                                                                     (value!([
-                                                                        "emit" => "value_generic",
+                                                                        "emit" => "call",
                                                                         "children" =>
                                                                             (value!([
                                                                                 (value!([
                                                                                     "emit" => "identifier",
-                                                                                    "value" => "Expect"
+                                                                                    "value" => "ast"
                                                                                 ])),
                                                                                 (value!([
-                                                                                    "emit" => "genarg",
+                                                                                    "emit" => "callarg",
                                                                                     "children" =>
                                                                                         (value!([
-                                                                                            "emit" => "value_token_touch",
-                                                                                            "value" => ")"
+                                                                                            "emit" => "value_string",
+                                                                                            "value" => "op_mod_kle"
                                                                                         ]))
                                                                                 ]))
                                                                             ]))
@@ -4366,43 +5382,39 @@ impl Parser {
                                                             "emit" => "sequence",
                                                             "children" =>
                                                                 (value!([
-                                                                    (value!([
-                                                                        "emit" => "value_token_touch",
-                                                                        "value" => "@"
-                                                                    ])),
                                                                     (value!([
                                                                         "emit" => "identifier",
-                                                                        "value" => "_"
+                                                                        "value" => "Token"
                                                                     ])),
                                                                     (value!([
                                                                         "emit" => "value_token_touch",
-                                                                        "value" => "("
+                                                                        "value" => "?<"
                                                                     ])),
                                                                     (value!([
                                                                         "emit" => "identifier",
                                                                         "value" => "_"
                                                                     ])),
                                                                     (value!([
-                                                                        "emit" => "identifier",
-                                                                        "value" => "___"
-                                                                    ])),
-                                                                    (value!([
-                                                                        "emit" => "block",
+                                                                        "emit" => "value_generic",
                                                                         "children" =>
                                                                             (value!([
                                                                                 (value!([
                                                                                     "emit" => "identifier",
-                                                                                    "value" => "InlineList"
+                                                                                    "value" => "Keyword"
                                                                                 ])),
                                                                                 (value!([
-                                                                                    "emit" => "identifier",
-                                                                                    "value" => "InlineSequences"
+                                                                                    "emit" => "genarg",
+                                                                                    "children" =>
+                                                                                        (value!([
+                                                                                            "emit" => "value_token_touch",
+                                                                                            "value" => "void"
+                                                                                        ]))
                                                                                 ]))
                                                                             ]))
                                                                     ])),
                                                                     (value!([
                                                                         "emit" => "identifier",
-                                                                        "value" => "___"
+                                                                        "value" => "_"
                                                                     ])),
                                                                     (value!([
                                                                         "emit" => "value_generic",
@@ -4417,7 +5429,7 @@ impl Parser {
                                                                                     "children" =>
                                                                                         (value!([
                                                                                             "emit" => "value_token_touch",
-                                                                                            "value" => ")"
+                                                                                            "value" => ">"
                                                                                         ]))
                                                                                 ]))
                                                                             ]))
@@ -4435,52 +5447,50 @@ impl Parser {
                                                                                     "children" =>
                                                                                         (value!([
                                                                                             "emit" => "value_string",
-                                                                                            "value" => "area"
+                                                                                            "value" => "op_mod_opt_void"
                                                                                         ]))
                                                                                 ]))
                                                                             ]))
                                                                     ]))
                                                                 ]))
                                                         ])),
-                                                        (value!([
-                                                            "emit" => "identifier",
-                                                            "value" => "Block"
-                                                        ])),
-                                                        (value!([
-                                                            "emit" => "identifier",
-                                                            "value" => "TokenLiteral"
-                                                        ])),
                                                         (value!([
                                                             "emit" => "sequence",
                                                             "children" =>
                                                                 (value!([
                                                                     (value!([
                                                                         "emit" => "identifier",
-                                                                        "value" => "ParseletInstance"
+                                                                        "value" => "Token"
                                                                     ])),
                                                                     (value!([
                                                                         "emit" => "value_token_touch",
-                                                                        "value" => "("
+                                                                        "value" => "?<"
                                                                     ])),
                                                                     (value!([
                                                                         "emit" => "identifier",
                                                                         "value" => "_"
                                                                     ])),
                                                                     (value!([
-                                                                        "emit" => "identifier",
-                                                                        "value" => "___"
-                                                                    ])),
-                                                                    (value!([
-                                                                        "emit" => "op_mod_opt",
+                                                                        "emit" => "value_generic",
                                                                         "children" =>
                                                                             (value!([
-                                                                                "emit" => "identifier",
-                                                                                "value" => "CallArguments"
+                                                                                (value!([
+                                                                                    "emit" => "identifier",
+                                                                                    "value" => "Keyword"
+                                                                                ])),
+                                                                                (value!([
+                                                                                    "emit" => "genarg",
+                                                                                    "children" =>
+                                                                                        (value!([
+                                                                                            "emit" => "value_token_touch",
+                                                                                            "value" => "empty"
+                                                                                        ]))
+                                                                                ]))
                                                                             ]))
                                                                     ])),
                                                                     (value!([
                                                                         "emit" => "identifier",
-                                                                        "value" => "___"
+                                                                        "value" => "_"
                                                                     ])),
                                                                     (value!([
                                                                         "emit" => "value_generic",
@@ -4495,7 +5505,7 @@ impl Parser {
                                                                                     "children" =>
                                                                                         (value!([
                                                                                             "emit" => "value_token_touch",
-                                                                                            "value" => ")"
+                                                                                            "value" => ">"
                                                                                         ]))
                                                                                 ]))
                                                                             ]))
@@ -4513,37 +5523,13 @@ impl Parser {
                                                                                     "children" =>
                                                                                         (value!([
                                                                                             "emit" => "value_string",
-                                                                                            "value" => "call"
+                                                                                            "value" => "op_mod_opt_empty"
                                                                                         ]))
                                                                                 ]))
                                                                             ]))
                                                                     ]))
                                                                 ]))
                                                         ])),
-                                                        (value!([
-                                                            "emit" => "identifier",
-                                                            "value" => "ParseletInstance"
-                                                        ]))
-                                                    ]))
-                                            ]))
-                                    ]))
-                                ]))
-                        ])),
-                        (value!([
-                            "emit" => "constant",
-                            "children" =>
-                                (value!([
-                                    (value!([
-                                        "emit" => "identifier",
-                                        "value" => "TokenModifier"
-                                    ])),
-                                    (value!([
-                                        "emit" => "value_parselet",
-                                        "children" =>
-                                            (value!([
-                                                "emit" => "body",
-                                                "children" =>
-                                                    (value!([
                                                         (value!([
                                                             "emit" => "sequence",
                                                             "children" =>
@@ -4554,39 +5540,51 @@ impl Parser {
                                                                     ])),
                                                                     (value!([
                                                                         "emit" => "value_token_touch",
-                                                                        "value" => "+"
+                                                                        "value" => "?<"
                                                                     ])),
                                                                     (value!([
-                                                                        "emit" => "call",
+                                                                        "emit" => "identifier",
+                                                                        "value" => "_"
+                                                                    ])),
+                                                                    (value!([
+                                                                        "emit" => "value_generic",
                                                                         "children" =>
                                                                             (value!([
                                                                                 (value!([
                                                                                     "emit" => "identifier",
-                                                                                    "value" => "ast"
+                                                                                    "value" => "Keyword"
                                                                                 ])),
                                                                                 (value!([
-                                                                                    "emit" => "callarg",
+                                                                                    "emit" => "genarg",
                                                                                     "children" =>
                                                                                         (value!([
-                                                                                            "emit" => "value_string",
-                                                                                            "value" => "op_mod_pos"
+                                                                                            "emit" => "value_token_touch",
+                                                                                            "value" => "skip"
                                                                                         ]))
                                                                                 ]))
                                                                             ]))
-                                                                    ]))
-                                                                ]))
-                                                        ])),
-                                                        (value!([
-                                                            "emit" => "sequence",
-                                                            "children" =>
-                                                                (value!([
+                                                                    ])),
                                                                     (value!([
                                                                         "emit" => "identifier",
-                                                                        "value" => "Token"
+                                                                        "value" => "_"
                                                                     ])),
                                                                     (value!([
-                                                                        "emit" => "value_token_touch",
-                                                                        "value" => "*"
+                                                                        "emit" => "value_generic",
+                                                                        "children" =>
+                                                                            (value!([
+                                                                                (value!([
+                                                                                    "emit" => "identifier",
+                                                                                    "value" => "Expect"
+                                                                                ])),
+                                                                                (value!([
+                                                                                    "emit" => "genarg",
+                                                                                    "children" =>
+                                                                                        (value!([
+                                                                                            "emit" => "value_token_touch",
+                                                                                            "value" => ">"
+                                                                                        ]))
+                                                                                ]))
+                                                                            ]))
                                                                     ])),
                                                                     (value!([
                                                                         "emit" => "call",
@@ -4601,7 +5599,7 @@ impl Parser {
                                                                                     "children" =>
                                                                                         (value!([
                                                                                             "emit" => "value_string",
-                                                                                            "value" => "op_mod_kle"
+                                                                                            "value" => "op_mod_opt_skip"
                                                                                         ]))
                                                                                 ]))
                                                                             ]))
@@ -5310,21 +6308,107 @@ impl Parser {
                                                                             (value!([
                                                                                 (value!([
                                                                                     "emit" => "identifier",
-                                                                                    "value" => "Keyword"
+                                                                                    "value" => "Keyword"
+                                                                                ])),
+                                                                                (value!([
+                                                                                    "emit" => "genarg",
+                                                                                    "children" =>
+                                                                                        (value!([
+                                                                                            "emit" => "value_token_touch",
+                                                                                            "value" => "loop"
+                                                                                        ]))
+                                                                                ]))
+                                                                            ]))
+                                                                    ])),
+                                                                    (value!([
+                                                                        "emit" => "identifier",
+                                                                        "value" => "_"
+                                                                    ])),
+                                                                    (value!([
+                                                                        "emit" => "value_generic",
+                                                                        "children" =>
+                                                                            (value!([
+                                                                                (value!([
+                                                                                    "emit" => "identifier",
+                                                                                    "value" => "Expect"
+                                                                                ])),
+                                                                                (value!([
+                                                                                    "emit" => "genarg",
+                                                                                    "children" =>
+                                                                                        (value!([
+                                                                                            "emit" => "identifier",
+                                                                                            "value" => "Block"
+                                                                                        ]))
+                                                                                ]))
+                                                                            ]))
+                                                                    ])),
+                                                                    (value!([
+                                                                        "emit" => "call",
+                                                                        "children" =>
+                                                                            (value!([
+                                                                                (value!([
+                                                                                    "emit" => "identifier",
+                                                                                    "value" => "ast"
+                                                                                ])),
+                                                                                (value!([
+                                                                                    "emit" => "callarg",
+                                                                                    "children" =>
+                                                                                        (value!([
+                                                                                            "emit" => "value_string",
+                                                                                            "value" => "op_loop"
+                                                                                        ]))
+                                                                                ]))
+                                                                            ]))
+                                                                    ]))
+                                                                ]))
+                                                        ])),
+                                                        (value!([
+                                                            "emit" => "sequence",
+                                                            "children" =>
+                                                                (value!([
+                                                                    (value!([
+                                                                        "emit" => "value_generic",
+                                                                        "children" =>
+                                                                            (value!([
+                                                                                (value!([
+                                                                                    "emit" => "identifier",
+                                                                                    "value" => "Keyword"
+                                                                                ])),
+                                                                                (value!([
+                                                                                    "emit" => "genarg",
+                                                                                    "children" =>
+                                                                                        (value!([
+                                                                                            "emit" => "value_token_touch",
+                                                                                            "value" => "while"
+                                                                                        ]))
+                                                                                ]))
+                                                                            ]))
+                                                                    ])),
+                                                                    (value!([
+                                                                        "emit" => "identifier",
+                                                                        "value" => "_"
+                                                                    ])),
+                                                                    (value!([
+                                                                        "emit" => "value_generic",
+                                                                        "children" =>
+                                                                            (value!([
+                                                                                (value!([
+                                                                                    "emit" => "identifier",
+                                                                                    "value" => "Expect"
                                                                                 ])),
                                                                                 (value!([
                                                                                     "emit" => "genarg",
                                                                                     "children" =>
                                                                                         (value!([
-                                                                                            "emit" => "value_token_touch",
-                                                                                            "value" => "loop"
+                                                                                            "emit" => "identifier",
+                                                                                            "value" => "Expression"
                                                                                         ]))
                                                                                 ]))
                                                                             ]))
                                                                     ])),
                                                                     (value!([
                                                                         "emit" => "identifier",
-                                                                        "value" => "_"
+                                                                        "value" => "___"
                                                                     ])),
                                                                     (value!([
                                                                         "emit" => "value_generic",
@@ -8126,6 +9210,114 @@ impl Parser {
                                     ]))
                                 ]))
                         ])),
+                        (value!([
+                            "emit" => "constant",
+                            "children" =>
+                                (value!([
+                                    (value!([
+                                        "emit" => "identifier",
+                                        "value" => "T_NamedCapture"
+                                    ])),
+                                    (value!([
+                                        "emit" => "value_parselet",
+                                        "children" =>
+                                            (value!([
+                                                "emit" => "body",
+                                                "children" =>
+                                                    (value!([
+                                                        "emit" => "sequence",
+                                                        "children" =>
+                                                            (value!([
+                                                                (value!([
+                                                                    "emit" => "value_generic",
+                                                                    "children" =>
+                                                                        (value!([
+                                                                            (value!([
+                                                                                "emit" => "identifier",
+                                                                                "value" => "Not"
+                                                                            ])),
+                                                                            (value!([
+                                                                                "emit" => "genarg",
+                                                                                "children" =>
+                                                                                    (value!([
+                                                                                        "emit" => "value_token_ccl",
+                                                                                        "children" =>
+                                                                                            (value!([
+                                                                                                "emit" => "ccl",
+                                                                                                "children" =>
+                                                                                                    (value!([
+                                                                                                        (value!([
+                                                                                                            "emit" => "range",
+                                                                                                            "value" => "AZ"
+                                                                                                        ])),
+                                                                                                        (value!([
+                                                                                                            "emit" => "char",
+                                                                                                            "value" => "_"
+                                                                                                        ]))
+                                                                                                    ]))
+                                                                                            ]))
+                                                                                    ]))
+                                                                            ]))
+                                                                        ]))
+                                                                ])),
+                                                                (value!([
+                                                                    "emit" => "identifier",
+                                                                    "value" => "T_Alias"
+                                                                ])),
+                                                                (value!([
+                                                                    "emit" => "value_token_touch",
+                                                                    "value" => ":"
+                                                                ])),
+                                                                (value!([
+                                                                    "emit" => "value_generic",
+                                                                    "children" =>
+                                                                        (value!([
+                                                                            (value!([
+                                                                                "emit" => "identifier",
+                                                                                "value" => "Not"
+                                                                            ])),
+                                                                            (value!([
+                                                                                "emit" => "genarg",
+                                                                                "children" =>
+                                                                                    (value!([
+                                                                                        "emit" => "value_token_ccl",
+                                                                                        "children" =>
+                                                                                            (value!([
+                                                                                                "emit" => "ccl",
+                                                                                                "children" =>
+                                                                                                    (value!([
+                                                                                                        (value!([
+                                                                                                            "emit" => "char",
+                                                                                                            "value" => "\t"
+                                                                                                        ])),
+                                                                                                        (value!([
+                                                                                                            "emit" => "char",
+                                                                                                            "value" => "\n"
+                                                                                                        ])),
+                                                                                                        (value!([
+                                                                                                            "emit" => "char",
+                                                                                                            "value" => "\r"
+                                                                                                        ])),
+                                                                                                        (value!([
+                                                                                                            "emit" => "char",
+                                                                                                            "value" => " "
+                                                                                                        ])),
+                                                                                                        (value!([
+                                                                                                            "emit" => "char",
+                                                                                                            "value" => ":"
+                                                                                                        ]))
+                                                                                                    ]))
+                                                                                            ]))
+                                                                                    ]))
+                                                                            ]))
+                                                                        ]))
+                                                                ]))
+                                                            ]))
+                                                    ]))
+                                            ]))
+                                    ]))
+                                ]))
+                        ])),
                         (value!([
                             "emit" => "constant",
                             "children" =>
@@ -8199,6 +9391,52 @@ impl Parser {
                                                                     ]))
                                                                 ]))
                                                         ])),
+                                                        (value!([
+                                                            "emit" => "sequence",
+                                                            "children" =>
+                                                                (value!([
+                                                                    (value!([
+                                                                        "emit" => "identifier",
+                                                                        "value" => "T_NamedCapture"
+                                                                    ])),
+                                                                    (value!([
+                                                                        "emit" => "value_generic",
+                                                                        "children" =>
+                                                                            (value!([
+                                                                                (value!([
+                                                                                    "emit" => "identifier",
+                                                                                    "value" => "Expect"
+                                                                                ])),
+                                                                                (value!([
+                                                                                    "emit" => "genarg",
+                                                                                    "children" =>
+                                                                                        (value!([
+                                                                                            "emit" => "identifier",
+                                                                                            "value" => "Expression"
+                                                                                        ]))
+                                                                                ]))
+                                                                            ]))
+                                                                    ])),
+                                                                    (value!([
+                                                                        "emit" => "call",
+                                                                        "children" =>
+                                                                            (value!([
+                                                                                (value!([
+                                                                                    "emit" => "identifier",
+                                                                                    "value" => "ast"
+                                                                                ])),
+                                                                                (value!([
+                                                                                    "emit" => "callarg",
+                                                                                    "children" =>
+                                                                                        (value!([
+                                                                                            "emit" => "value_string",
+                                                                                            "value" => "capture_named"
+                                                                                        ]))
+                                                                                ]))
+                                                                            ]))
+                                                                    ]))
+                                                                ]))
+                                                        ])),
                                                         (value!([
                                                             "emit" => "sequence",
                                                             "children" =>
@@ -8237,26 +9475,142 @@ impl Parser {
                                                                         "emit" => "call",
                                                                         "children" =>
                                                                             (value!([
-                                                                                (value!([
-                                                                                    "emit" => "identifier",
-                                                                                    "value" => "ast"
-                                                                                ])),
-                                                                                (value!([
-                                                                                    "emit" => "callarg",
-                                                                                    "children" =>
-                                                                                        (value!([
-                                                                                            "emit" => "value_string",
-                                                                                            "value" => "alias"
-                                                                                        ]))
-                                                                                ]))
+                                                                                (value!([
+                                                                                    "emit" => "identifier",
+                                                                                    "value" => "ast"
+                                                                                ])),
+                                                                                (value!([
+                                                                                    "emit" => "callarg",
+                                                                                    "children" =>
+                                                                                        (value!([
+                                                                                            "emit" => "value_string",
+                                                                                            "value" => "alias"
+                                                                                        ]))
+                                                                                ]))
+                                                                            ]))
+                                                                    ]))
+                                                                ]))
+                                                        ])),
+                                                        (value!([
+                                                            "emit" => "identifier",
+                                                            "value" => "Statement"
+                                                        ]))
+                                                    ]))
+                                            ]))
+                                    ]))
+                                ]))
+                        ])),
+                        (value!([
+                            "emit" => "constant",
+                            "children" =>
+                                (value!([
+                                    (value!([
+                                        "emit" => "identifier",
+                                        "value" => "SequenceGuard"
+                                    ])),
+                                    (value!([
+                                        "emit" => "value_parselet",
+                                        "children" =>
+                                            (value!([
+                                                "emit" => "body",
+                                                "children" =>
+                                                    (value!([
+                                                        "emit" => "sequence",
+                                                        "children" =>
+                                                            (value!([
+                                                                (value!([
+                                                                    "emit" => "value_generic",
+                                                                    "children" =>
+                                                                        (value!([
+                                                                            (value!([
+                                                                                "emit" => "identifier",
+                                                                                "value" => "Keyword"
+                                                                            ])),
+                                                                            (value!([
+                                                                                "emit" => "genarg",
+                                                                                "children" =>
+                                                                                    (value!([
+                                                                                        "emit" => "value_token_touch",
+                                                                                        "value" => "if"
+                                                                                    ]))
+                                                                            ]))
+                                                                        ]))
+                                                                ])),
+                                                                (value!([
+                                                                    "emit" => "identifier",
+                                                                    "value" => "_"
+                                                                ])),
+                                                                (value!([
+                                                                    "emit" => "identifier",
+                                                                    "value" => "Expression"
+                                                                ])),
+                                                                (value!([
+                                                                    "emit" => "value_generic",
+                                                                    "children" =>
+                                                                        (value!([
+                                                                            (value!([
+                                                                                "emit" => "identifier",
+                                                                                "value" => "Peek"
+                                                                            ])),
+                                                                            (value!([
+                                                                                "emit" => "genarg",
+                                                                                "children" =>
+                                                                                    (value!([
+                                                                                        "emit" => "block",
+                                                                                        "children" =>
+                                                                                            (value!([
+                                                                                                (value!([
+                                                                                                    "emit" => "value_token_touch",
+                                                                                                    "value" => "|"
+                                                                                                ])),
+                                                                                                (value!([
+                                                                                                    "emit" => "sequence",
+                                                                                                    "children" =>
+                                                                                                        (value!([
+                                                                                                            (value!([
+                                                                                                                "emit" => "identifier",
+                                                                                                                "value" => "___"
+                                                                                                            ])),
+                                                                                                            (value!([
+                                                                                                                "emit" => "block",
+                                                                                                                "children" =>
+                                                                                                                    (value!([
+                                                                                                                        (value!([
+                                                                                                                            "emit" => "value_token_touch",
+                                                                                                                            "value" => "}"
+                                                                                                                        ])),
+                                                                                                                        (value!([
+                                                                                                                            "emit" => "identifier",
+                                                                                                                            "value" => "EOF"
+                                                                                                                        ]))
+                                                                                                                    ]))
+                                                                                                            ]))
+                                                                                                        ]))
+                                                                                                ]))
+                                                                                            ]))
+                                                                                    ]))
+                                                                            ]))
+                                                                        ]))
+                                                                ])),
+                                                                (value!([
+                                                                    "emit" => "call",
+                                                                    "children" =>
+                                                                        (value!([
+                                                                            (value!([
+                                                                                "emit" => "identifier",
+                                                                                "value" => "ast"
+                                                                            ])),
+                                                                            (value!([
+                                                                                "emit" => "callarg",
+                                                                                "children" =>
+                                                                                    (value!([
+                                                                                        "emit" => "value_string",
+                                                                                        "value" => "op_guard"
+                                                                                    ]))
                                                                             ]))
-                                                                    ]))
+                                                                        ]))
                                                                 ]))
-                                                        ])),
-                                                        (value!([
-                                                            "emit" => "identifier",
-                                                            "value" => "Statement"
-                                                        ]))
+                                                            ]))
                                                     ]))
                                             ]))
                                     ]))
@@ -8281,11 +9635,25 @@ impl Parser {
                                                         "children" =>
                                                             (value!([
                                                                 (value!([
-                                                                    "emit" => "op_mod_pos",
+                                                                    "emit" => "identifier",
+                                                                    "value" => "SequenceItem"
+                                                                ])),
+                                                                (value!([
+                                                                    "emit" => "op_mod_kle",
                                                                     "children" =>
                                                                         (value!([
-                                                                            "emit" => "identifier",
-                                                                            "value" => "SequenceItem"
+                                                                            "emit" => "block",
+                                                                            "children" =>
+                                                                                (value!([
+                                                                                    (value!([
+                                                                                        "emit" => "identifier",
+                                                                                        "value" => "SequenceGuard"
+                                                                                    ])),
+                                                                                    (value!([
+                                                                                        "emit" => "identifier",
+                                                                                        "value" => "SequenceItem"
+                                                                                    ]))
+                                                                                ]))
                                                                         ]))
                                                                 ])),
                                                                 (value!([
@@ -8297,43 +9665,15 @@ impl Parser {
                                                                                 "children" =>
                                                                                     (value!([
                                                                                         (value!([
-                                                                                            "emit" => "op_logical_and",
+                                                                                            "emit" => "op_logical_or",
                                                                                             "children" =>
                                                                                                 (value!([
                                                                                                     (value!([
-                                                                                                        "emit" => "comparison",
+                                                                                                        "emit" => "capture_index",
                                                                                                         "children" =>
                                                                                                             (value!([
-                                                                                                                (value!([
-                                                                                                                    "emit" => "call",
-                                                                                                                    "children" =>
-                                                                                                                        (value!([
-                                                                                                                            (value!([
-                                                                                                                                "emit" => "identifier",
-                                                                                                                                "value" => "type"
-                                                                                                                            ])),
-                                                                                                                            (value!([
-                                                                                                                                "emit" => "callarg",
-                                                                                                                                "children" =>
-                                                                                                                                    (value!([
-                                                                                                                                        "emit" => "capture_index",
-                                                                                                                                        "children" =>
-                                                                                                                                            (value!([
-                                                                                                                                                "emit" => "value_integer",
-                                                                                                                                                "value" => 1
-                                                                                                                                            ]))
-                                                                                                                                    ]))
-                                                                                                                            ]))
-                                                                                                                        ]))
-                                                                                                                ])),
-                                                                                                                (value!([
-                                                                                                                    "emit" => "cmp_eq",
-                                                                                                                    "children" =>
-                                                                                                                        (value!([
-                                                                                                                            "emit" => "value_string",
-                                                                                                                            "value" => "list"
-                                                                                                                        ]))
-                                                                                                                ]))
+                                                                                                                "emit" => "value_integer",
+                                                                                                                "value" => 2
                                                                                                             ]))
                                                                                                     ])),
                                                                                                     (value!([
@@ -8353,21 +9693,21 @@ impl Parser {
                                                                                                                                     ]))
                                                                                                                             ])),
                                                                                                                             (value!([
-                                                                                                                                "emit" => "attribute",
+                                                                                                                                "emit" => "item",
                                                                                                                                 "children" =>
                                                                                                                                     (value!([
                                                                                                                                         "emit" => "value_string",
-                                                                                                                                        "value" => "len"
+                                                                                                                                        "value" => "emit"
                                                                                                                                     ]))
                                                                                                                             ]))
                                                                                                                         ]))
                                                                                                                 ])),
                                                                                                                 (value!([
-                                                                                                                    "emit" => "cmp_gt",
+                                                                                                                    "emit" => "cmp_eq",
                                                                                                                     "children" =>
                                                                                                                         (value!([
-                                                                                                                            "emit" => "value_integer",
-                                                                                                                            "value" => 1
+                                                                                                                            "emit" => "value_string",
+                                                                                                                            "value" => "alias"
                                                                                                                         ]))
                                                                                                                 ]))
                                                                                                             ]))
@@ -8405,7 +9745,7 @@ impl Parser {
                                                                                                         "children" =>
                                                                                                             (value!([
                                                                                                                 "emit" => "value_string",
-                                                                                                                "value" => "alias"
+                                                                                                                "value" => "capture_named"
                                                                                                             ]))
                                                                                                     ]))
                                                                                                 ]))
@@ -8526,6 +9866,52 @@ impl Parser {
                                     ]))
                                 ]))
                         ])),
+                        (value!([
+                            "emit" => "constant",
+                            "children" =>
+                                (value!([
+                                    (value!([
+                                        "emit" => "identifier",
+                                        "value" => "ImportPath"
+                                    ])),
+                                    (value!([
+                                        "emit" => "value_parselet",
+                                        "children" =>
+                                            (value!([
+                                                "emit" => "body",
+                                                "children" =>
+                                                    (value!([
+                                                        "emit" => "sequence",
+                                                        "children" =>
+                                                            (value!([
+                                                                (value!([
+                                                                    "emit" => "identifier",
+                                                                    "value" => "T_String"
+                                                                ])),
+                                                                (value!([
+                                                                    "emit" => "call",
+                                                                    "children" =>
+                                                                        (value!([
+                                                                            (value!([
+                                                                                "emit" => "identifier",
+                                                                                "value" => "ast"
+                                                                            ])),
+                                                                            (value!([
+                                                                                "emit" => "callarg",
+                                                                                "children" =>
+                                                                                    (value!([
+                                                                                        "emit" => "value_string",
+                                                                                        "value" => "value_string"
+                                                                                    ]))
+                                                                            ]))
+                                                                        ]))
+                                                                ]))
+                                                            ]))
+                                                    ]))
+                                            ]))
+                                    ]))
+                                ]))
+                        ])),
                         (value!([
                             "emit" => "constant",
                             "children" =>
@@ -8545,6 +9931,146 @@ impl Parser {
                                                             "emit" => "identifier",
                                                             "value" => "T_EOL"
                                                         ])),
+                                                        (value!([
+                                                            "emit" => "sequence",
+                                                            "children" =>
+                                                                (value!([
+                                                                    (value!([
+                                                                        "emit" => "value_generic",
+                                                                        "children" =>
+                                                                            (value!([
+                                                                                (value!([
+                                                                                    "emit" => "identifier",
+                                                                                    "value" => "Keyword"
+                                                                                ])),
+                                                                                (value!([
+                                                                                    "emit" => "genarg",
+                                                                                    "children" =>
+                                                                                        (value!([
+                                                                                            "emit" => "value_token_touch",
+                                                                                            "value" => "import"
+                                                                                        ]))
+                                                                                ]))
+                                                                            ]))
+                                                                    ])),
+                                                                    (value!([
+                                                                        "emit" => "identifier",
+                                                                        "value" => "_"
+                                                                    ])),
+                                                                    (value!([
+                                                                        "emit" => "value_generic",
+                                                                        "children" =>
+                                                                            (value!([
+                                                                                (value!([
+                                                                                    "emit" => "identifier",
+                                                                                    "value" => "Expect"
+                                                                                ])),
+                                                                                (value!([
+                                                                                    "emit" => "genarg",
+                                                                                    "children" =>
+                                                                                        (value!([
+                                                                                            "emit" => "identifier",
+                                                                                            "value" => "ImportPath"
+                                                                                        ]))
+                                                                                ]))
+                                                                            ]))
+                                                                    ])),
+                                                                    (value!([
+                                                                        "emit" => "identifier",
+                                                                        "value" => "_"
+                                                                    ])),
+                                                                    (value!([
+                                                                        "emit" => "op_mod_opt",
+                                                                        "children" =>
+                                                                            (value!([
+                                                                                "emit" => "sequence",
+                                                                                "children" =>
+                                                                                    (value!([
+                                                                                        (value!([
+                                                                                            "emit" => "value_generic",
+                                                                                            "children" =>
+                                                                                                (value!([
+                                                                                                    (value!([
+                                                                                                        "emit" => "identifier",
+                                                                                                        "value" => "Keyword"
+                                                                                                    ])),
+                                                                                                    (value!([
+                                                                                                        "emit" => "genarg",
+                                                                                                        "children" =>
+                                                                                                            (value!([
+                                                                                                                "emit" => "value_token_touch",
+                                                                                                                "value" => "as"
+                                                                                                            ]))
+                                                                                                    ]))
+                                                                                                ]))
+                                                                                        ])),
+                                                                                        (value!([
+                                                                                            "emit" => "identifier",
+                                                                                            "value" => "_"
+                                                                                        ])),
+                                                                                        (value!([
+                                                                                            "emit" => "value_generic",
+                                                                                            "children" =>
+                                                                                                (value!([
+                                                                                                    (value!([
+                                                                                                        "emit" => "identifier",
+                                                                                                        "value" => "Expect"
+                                                                                                    ])),
+                                                                                                    (value!([
+                                                                                                        "emit" => "genarg",
+                                                                                                        "children" =>
+                                                                                                            (value!([
+                                                                                                                "emit" => "identifier",
+                                                                                                                "value" => "T_Identifier"
+                                                                                                            ]))
+                                                                                                    ]))
+                                                                                                ]))
+                                                                                        ])),
+                                                                                        (value!([
+                                                                                            "emit" => "identifier",
+                                                                                            "value" => "_"
+                                                                                        ]))
+                                                                                    ]))
+                                                                            ]))
+                                                                    ])),
+                                                                    (value!([
+                                                                        "emit" => "value_generic",
+                                                                        "children" =>
+                                                                            (value!([
+                                                                                (value!([
+                                                                                    "emit" => "identifier",
+                                                                                    "value" => "Expect"
+                                                                                ])),
+                                                                                (value!([
+                                                                                    "emit" => "genarg",
+                                                                                    "children" =>
+                                                                                        (value!([
+                                                                                            "emit" => "identifier",
+                                                                                            "value" => "T_EOL"
+                                                                                        ]))
+                                                                                ]))
+                                                                            ]))
+                                                                    ])),
+                                                                    (value!([
+                                                                        "emit" => "call",
+                                                                        "children" =>
+                                                                            (value!([
+                                                                                (value!([
+                                                                                    "emit" => "identifier",
+                                                                                    "value" => "ast"
+                                                                                ])),
+                                                                                (value!([
+                                                                                    "emit" => "callarg",
+                                                                                    "children" =>
+                                                                                        (value!([
+                                                                                            "emit" => "value_string",
+                                                                                            "value" => "import"
+                                                                                        ]))
+                                                                                ]))
+                                                                            ]))
+                                                                    ]))
+                                                                ]))
+                                                        ])),
                                                         (value!([
                                                             "emit" => "sequence",
                                                             "children" =>
@@ -8934,9 +10460,9 @@ impl Parser {
         )
     }
 
-    pub fn parse(&self, mut reader: Reader) -> Result<RefValue, Error> {
+    pub fn parse(&self, reader: &mut Reader) -> Result<RefValue, Error> {
         //self.0.dump();
-        let mut thread = Thread::new(&self.0, vec![&mut reader]);
+        let mut thread = Thread::new(&self.0, vec![reader]);
 
         if let Ok(level) = std::env::var("TOKAY_PARSER_DEBUG") {
             thread.debug = level.parse::<u8>().unwrap_or_default();
@@ -8953,7 +10479,11 @@ impl Parser {
                 }
             }
             Ok(None) => Ok(crate::value!(void)),
-            Err(error) => Err(error),
+            Err(mut error) => {
+                // Syntax errors don't refer to the parselets of the Tokay grammar
+                error.trace.clear();
+                Err(error)
+            }
         }
     }
 }
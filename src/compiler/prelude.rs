@@ -105,6 +105,175 @@ impl Compiler {
                                     ]))
                                 ]))
                         ])),
+                        (value!([
+                            "emit" => "constant",
+                            "children" =>
+                                (value!([
+                                    (value!([
+                                        "emit" => "identifier",
+                                        "value" => "Behind"
+                                    ])),
+                                    (value!([
+                                        "emit" => "value_parselet",
+                                        "children" =>
+                                            (value!([
+                                                (value!([
+                                                    "emit" => "gen",
+                                                    "children" =>
+                                                        (value!([
+                                                            "emit" => "identifier",
+                                                            "value" => "P"
+                                                        ]))
+                                                ])),
+                                                (value!([
+                                                    "emit" => "gen",
+                                                    "children" =>
+                                                        (value!([
+                                                            (value!([
+                                                                "emit" => "identifier",
+                                                                "value" => "max"
+                                                            ])),
+                                                            (value!([
+                                                                "emit" => "value_integer",
+                                                                "value" => 1
+                                                            ]))
+                                                        ]))
+                                                ])),
+                                                (value!([
+                                                    "emit" => "body",
+                                                    "children" =>
+                                                        (value!([
+                                                            "emit" => "sequence",
+                                                            "children" =>
+                                                                (value!([
+                                                                    (value!([
+                                                                        "emit" => "call",
+                                                                        "children" =>
+                                                                            (value!([
+                                                                                (value!([
+                                                                                    "emit" => "identifier",
+                                                                                    "value" => "behind"
+                                                                                ])),
+                                                                                (value!([
+                                                                                    "emit" => "callarg",
+                                                                                    "children" =>
+                                                                                        (value!([
+                                                                                            "emit" => "op_deref",
+                                                                                            "children" =>
+                                                                                                (value!([
+                                                                                                    "emit" => "identifier",
+                                                                                                    "value" => "P"
+                                                                                                ]))
+                                                                                        ]))
+                                                                                ])),
+                                                                                (value!([
+                                                                                    "emit" => "callarg",
+                                                                                    "children" =>
+                                                                                        (value!([
+                                                                                            "emit" => "identifier",
+                                                                                            "value" => "max"
+                                                                                        ]))
+                                                                                ]))
+                                                                            ]))
+                                                                    ])),
+                                                                    (value!([
+                                                                        "emit" => "identifier",
+                                                                        "value" => "Empty"
+                                                                    ]))
+                                                                ]))
+                                                        ]))
+                                                ]))
+                                            ]))
+                                    ]))
+                                ]))
+                        ])),
+                        (value!([
+                            "emit" => "constant",
+                            "children" =>
+                                (value!([
+                                    (value!([
+                                        "emit" => "identifier",
+                                        "value" => "NotBehind"
+                                    ])),
+                                    (value!([
+                                        "emit" => "value_parselet",
+                                        "children" =>
+                                            (value!([
+                                                (value!([
+                                                    "emit" => "gen",
+                                                    "children" =>
+                                                        (value!([
+                                                            "emit" => "identifier",
+                                                            "value" => "P"
+                                                        ]))
+                                                ])),
+                                                (value!([
+                                                    "emit" => "gen",
+                                                    "children" =>
+                                                        (value!([
+                                                            (value!([
+                                                                "emit" => "identifier",
+                                                                "value" => "max"
+                                                            ])),
+                                                            (value!([
+                                                                "emit" => "value_integer",
+                                                                "value" => 1
+                                                            ]))
+                                                        ]))
+                                                ])),
+                                                (value!([
+                                                    "emit" => "body",
+                                                    "children" =>
+                                                        (value!([
+                                                            (value!([
+                                                                "emit" => "sequence",
+                                                                "children" =>
+                                                                    (value!([
+                                                                        (value!([
+                                                                            "emit" => "call",
+                                                                            "children" =>
+                                                                                (value!([
+                                                                                    (value!([
+                                                                                        "emit" => "identifier",
+                                                                                        "value" => "behind"
+                                                                                    ])),
+                                                                                    (value!([
+                                                                                        "emit" => "callarg",
+                                                                                        "children" =>
+                                                                                            (value!([
+                                                                                                "emit" => "op_deref",
+                                                                                                "children" =>
+                                                                                                    (value!([
+                                                                                                        "emit" => "identifier",
+                                                                                                        "value" => "P"
+                                                                                                    ]))
+                                                                                            ]))
+                                                                                    ])),
+                                                                                    (value!([
+                                                                                        "emit" => "callarg",
+                                                                                        "children" =>
+                                                                                            (value!([
+                                                                                                "emit" => "identifier",
+                                                                                                "value" => "max"
+                                                                                            ]))
+                                                                                    ]))
+                                                                                ]))
+                                                                        ])),
+                                                                        (value!([
+                                                                            "emit" => "op_reject"
+                                                                        ]))
+                                                                    ]))
+                                                            ])),
+                                                            (value!([
+                                                                "emit" => "identifier",
+                                                                "value" => "Empty"
+                                                            ]))
+                                                        ]))
+                                                ]))
+                                            ]))
+                                    ]))
+                                ]))
+                        ])),
                         (value!([
                             "emit" => "constant",
                             "children" =>
@@ -852,6 +1021,19 @@ impl Compiler {
                                                             "value" => "P"
                                                         ]))
                                                 ])),
+                                                (value!([
+                                                    "emit" => "gen",
+                                                    "children" =>
+                                                        (value!([
+                                                            (value!([
+                                                                "emit" => "identifier",
+                                                                "value" => "unmatched"
+                                                            ])),
+                                                            (value!([
+                                                                "emit" => "value_void"
+                                                            ]))
+                                                        ]))
+                                                ])),
                                                 (value!([
                                                     "emit" => "body",
                                                     "children" =>
@@ -864,8 +1046,12 @@ impl Compiler {
                                                                         "value" => "P"
                                                                     ])),
                                                                     (value!([
-                                                                        "emit" => "identifier",
-                                                                        "value" => "Empty"
+                                                                        "emit" => "op_accept",
+                                                                        "children" =>
+                                                                            (value!([
+                                                                                "emit" => "identifier",
+                                                                                "value" => "unmatched"
+                                                                            ]))
                                                                     ]))
                                                                 ]))
                                                         ]))
@@ -1068,8 +1254,18 @@ impl Compiler {
                                                                                     "emit" => "genarg",
                                                                                     "children" =>
                                                                                         (value!([
-                                                                                            "emit" => "identifier",
-                                                                                            "value" => "Alphanumeric"
+                                                                                            "emit" => "block",
+                                                                                            "children" =>
+                                                                                                (value!([
+                                                                                                    (value!([
+                                                                                                        "emit" => "identifier",
+                                                                                                        "value" => "Alphanumeric"
+                                                                                                    ])),
+                                                                                                    (value!([
+                                                                                                        "emit" => "value_token_touch",
+                                                                                                        "value" => "_"
+                                                                                                    ]))
+                                                                                                ]))
                                                                                         ]))
                                                                                 ]))
                                                                             ]))
@@ -1087,48 +1283,3948 @@ impl Compiler {
                                 (value!([
                                     (value!([
                                         "emit" => "identifier",
-                                        "value" => "Number"
-                                    ])),
-                                    (value!([
-                                        "emit" => "block",
-                                        "children" =>
-                                            (value!([
-                                                (value!([
-                                                    "emit" => "identifier",
-                                                    "value" => "Float"
-                                                ])),
-                                                (value!([
-                                                    "emit" => "identifier",
-                                                    "value" => "Int"
-                                                ]))
-                                            ]))
-                                    ]))
-                                ]))
-                        ])),
-                        (value!([
-                            "emit" => "constant",
-                            "children" =>
-                                (value!([
-                                    (value!([
-                                        "emit" => "identifier",
-                                        "value" => "Token"
+                                        "value" => "Until"
                                     ])),
                                     (value!([
-                                        "emit" => "block",
+                                        "emit" => "value_parselet",
                                         "children" =>
                                             (value!([
                                                 (value!([
-                                                    "emit" => "identifier",
-                                                    "value" => "Word"
+                                                    "emit" => "gen",
+                                                    "children" =>
+                                                        (value!([
+                                                            "emit" => "identifier",
+                                                            "value" => "P"
+                                                        ]))
                                                 ])),
                                                 (value!([
-                                                    "emit" => "identifier",
-                                                    "value" => "Number"
+                                                    "emit" => "gen",
+                                                    "children" =>
+                                                        (value!([
+                                                            (value!([
+                                                                "emit" => "identifier",
+                                                                "value" => "empty"
+                                                            ])),
+                                                            (value!([
+                                                                "emit" => "value_false"
+                                                            ]))
+                                                        ]))
                                                 ])),
                                                 (value!([
-                                                    "emit" => "identifier",
-                                                    "value" => "AsciiPunctuation"
-                                                ]))
+                                                    "emit" => "body",
+                                                    "children" =>
+                                                        (value!([
+                                                            (value!([
+                                                                "emit" => "op_loop",
+                                                                "children" =>
+                                                                    (value!([
+                                                                        "emit" => "block",
+                                                                        "children" =>
+                                                                            (value!([
+                                                                                (value!([
+                                                                                    "emit" => "sequence",
+                                                                                    "children" =>
+                                                                                        (value!([
+                                                                                            (value!([
+                                                                                                "emit" => "value_generic",
+                                                                                                "children" =>
+                                                                                                    (value!([
+                                                                                                        (value!([
+                                                                                                            "emit" => "identifier",
+                                                                                                            "value" => "Peek"
+                                                                                                        ])),
+                                                                                                        (value!([
+                                                                                                            "emit" => "genarg",
+                                                                                                            "children" =>
+                                                                                                                (value!([
+                                                                                                                    "emit" => "identifier",
+                                                                                                                    "value" => "P"
+                                                                                                                ]))
+                                                                                                        ]))
+                                                                                                    ]))
+                                                                                            ])),
+                                                                                            (value!([
+                                                                                                "emit" => "op_break"
+                                                                                            ]))
+                                                                                        ]))
+                                                                                ])),
+                                                                                (value!([
+                                                                                    "emit" => "sequence",
+                                                                                    "children" =>
+                                                                                        (value!([
+                                                                                            (value!([
+                                                                                                "emit" => "identifier",
+                                                                                                "value" => "EOF"
+                                                                                            ])),
+                                                                                            (value!([
+                                                                                                "emit" => "op_reject"
+                                                                                            ]))
+                                                                                        ]))
+                                                                                ])),
+                                                                                (value!([
+                                                                                    "emit" => "value_token_any"
+                                                                                ]))
+                                                                            ]))
+                                                                    ]))
+                                                            ])),
+                                                            (value!([
+                                                                "emit" => "op_if",
+                                                                "children" =>
+                                                                    (value!([
+                                                                        (value!([
+                                                                            "emit" => "op_logical_and",
+                                                                            "children" =>
+                                                                                (value!([
+                                                                                    (value!([
+                                                                                        "emit" => "op_unary_not",
+                                                                                        "children" =>
+                                                                                            (value!([
+                                                                                                "emit" => "identifier",
+                                                                                                "value" => "empty"
+                                                                                            ]))
+                                                                                    ])),
+                                                                                    (value!([
+                                                                                        "emit" => "op_unary_not",
+                                                                                        "children" =>
+                                                                                            (value!([
+                                                                                                "emit" => "capture_index",
+                                                                                                "children" =>
+                                                                                                    (value!([
+                                                                                                        "emit" => "value_integer",
+                                                                                                        "value" => 0
+                                                                                                    ]))
+                                                                                            ]))
+                                                                                    ]))
+                                                                                ]))
+                                                                        ])),
+                                                                        (value!([
+                                                                            "emit" => "op_reject"
+                                                                        ]))
+                                                                    ]))
+                                                            ])),
+                                                            (value!([
+                                                                "emit" => "capture_index",
+                                                                "children" =>
+                                                                    (value!([
+                                                                        "emit" => "value_integer",
+                                                                        "value" => 0
+                                                                    ]))
+                                                            ]))
+                                                        ]))
+                                                ]))
+                                            ]))
+                                    ]))
+                                ]))
+                        ])),
+                        (value!([
+                            "emit" => "constant",
+                            "children" =>
+                                (value!([
+                                    (value!([
+                                        "emit" => "identifier",
+                                        "value" => "Token"
+                                    ])),
+                                    (value!([
+                                        "emit" => "block",
+                                        "children" =>
+                                            (value!([
+                                                (value!([
+                                                    "emit" => "identifier",
+                                                    "value" => "Word"
+                                                ])),
+                                                (value!([
+                                                    "emit" => "identifier",
+                                                    "value" => "Number"
+                                                ])),
+                                                (value!([
+                                                    "emit" => "identifier",
+                                                    "value" => "AsciiPunctuation"
+                                                ]))
+                                            ]))
+                                    ]))
+                                ]))
+                        ])),
+                        (value!([
+                            "emit" => "constant",
+                            "children" =>
+                                (value!([
+                                    (value!([
+                                        "emit" => "identifier",
+                                        "value" => "AccessLog"
+                                    ])),
+                                    (value!([
+                                        "emit" => "value_parselet",
+                                        "children" =>
+                                            (value!([
+                                                "emit" => "body",
+                                                "children" =>
+                                                    (value!([
+                                                        (value!([
+                                                            "emit" => "constant",
+                                                            "children" =>
+                                                                (value!([
+                                                                    (value!([
+                                                                        "emit" => "identifier",
+                                                                        "value" => "Field"
+                                                                    ])),
+                                                                    (value!([
+                                                                        "emit" => "value_parselet",
+                                                                        "children" =>
+                                                                            (value!([
+                                                                                "emit" => "body",
+                                                                                "children" =>
+                                                                                    (value!([
+                                                                                        (value!([
+                                                                                            "emit" => "sequence",
+                                                                                            "children" =>
+                                                                                                (value!([
+                                                                                                    (value!([
+                                                                                                        "emit" => "value_token_touch",
+                                                                                                        "value" => "-"
+                                                                                                    ])),
+                                                                                                    (value!([
+                                                                                                        "emit" => "value_generic",
+                                                                                                        "children" =>
+                                                                                                            (value!([
+                                                                                                                (value!([
+                                                                                                                    "emit" => "identifier",
+                                                                                                                    "value" => "Peek"
+                                                                                                                ])),
+                                                                                                                (value!([
+                                                                                                                    "emit" => "genarg",
+                                                                                                                    "children" =>
+                                                                                                                        (value!([
+                                                                                                                            "emit" => "value_token_ccl",
+                                                                                                                            "children" =>
+                                                                                                                                (value!([
+                                                                                                                                    "emit" => "ccl",
+                                                                                                                                    "children" =>
+                                                                                                                                        (value!([
+                                                                                                                                            (value!([
+                                                                                                                                                "emit" => "char",
+                                                                                                                                                "value" => " "
+                                                                                                                                            ])),
+                                                                                                                                            (value!([
+                                                                                                                                                "emit" => "char",
+                                                                                                                                                "value" => "\t"
+                                                                                                                                            ])),
+                                                                                                                                            (value!([
+                                                                                                                                                "emit" => "char",
+                                                                                                                                                "value" => "\r"
+                                                                                                                                            ])),
+                                                                                                                                            (value!([
+                                                                                                                                                "emit" => "char",
+                                                                                                                                                "value" => "\n"
+                                                                                                                                            ]))
+                                                                                                                                        ]))
+                                                                                                                                ]))
+                                                                                                                        ]))
+                                                                                                                ]))
+                                                                                                            ]))
+                                                                                                    ])),
+                                                                                                    (value!([
+                                                                                                        "emit" => "value_null"
+                                                                                                    ]))
+                                                                                                ]))
+                                                                                        ])),
+                                                                                        (value!([
+                                                                                            "emit" => "value_token_ccls",
+                                                                                            "children" =>
+                                                                                                (value!([
+                                                                                                    "emit" => "ccl_neg",
+                                                                                                    "children" =>
+                                                                                                        (value!([
+                                                                                                            (value!([
+                                                                                                                "emit" => "char",
+                                                                                                                "value" => " "
+                                                                                                            ])),
+                                                                                                            (value!([
+                                                                                                                "emit" => "char",
+                                                                                                                "value" => "\t"
+                                                                                                            ])),
+                                                                                                            (value!([
+                                                                                                                "emit" => "char",
+                                                                                                                "value" => "\r"
+                                                                                                            ])),
+                                                                                                            (value!([
+                                                                                                                "emit" => "char",
+                                                                                                                "value" => "\n"
+                                                                                                            ]))
+                                                                                                        ]))
+                                                                                                ]))
+                                                                                        ]))
+                                                                                    ]))
+                                                                            ]))
+                                                                    ]))
+                                                                ]))
+                                                        ])),
+                                                        (value!([
+                                                            "emit" => "constant",
+                                                            "children" =>
+                                                                (value!([
+                                                                    (value!([
+                                                                        "emit" => "identifier",
+                                                                        "value" => "Quoted"
+                                                                    ])),
+                                                                    (value!([
+                                                                        "emit" => "value_parselet",
+                                                                        "children" =>
+                                                                            (value!([
+                                                                                "emit" => "body",
+                                                                                "children" =>
+                                                                                    (value!([
+                                                                                        (value!([
+                                                                                            "emit" => "sequence",
+                                                                                            "children" =>
+                                                                                                (value!([
+                                                                                                    (value!([
+                                                                                                        "emit" => "value_token_touch",
+                                                                                                        "value" => "\""
+                                                                                                    ])),
+                                                                                                    (value!([
+                                                                                                        "emit" => "value_token_touch",
+                                                                                                        "value" => "-"
+                                                                                                    ])),
+                                                                                                    (value!([
+                                                                                                        "emit" => "value_token_touch",
+                                                                                                        "value" => "\""
+                                                                                                    ])),
+                                                                                                    (value!([
+                                                                                                        "emit" => "value_null"
+                                                                                                    ]))
+                                                                                                ]))
+                                                                                        ])),
+                                                                                        (value!([
+                                                                                            "emit" => "sequence",
+                                                                                            "children" =>
+                                                                                                (value!([
+                                                                                                    (value!([
+                                                                                                        "emit" => "value_token_touch",
+                                                                                                        "value" => "\""
+                                                                                                    ])),
+                                                                                                    (value!([
+                                                                                                        "emit" => "value_token_touch",
+                                                                                                        "value" => "\""
+                                                                                                    ])),
+                                                                                                    (value!([
+                                                                                                        "emit" => "value_string",
+                                                                                                        "value" => ""
+                                                                                                    ]))
+                                                                                                ]))
+                                                                                        ])),
+                                                                                        (value!([
+                                                                                            "emit" => "sequence",
+                                                                                            "children" =>
+                                                                                                (value!([
+                                                                                                    (value!([
+                                                                                                        "emit" => "value_token_touch",
+                                                                                                        "value" => "\""
+                                                                                                    ])),
+                                                                                                    (value!([
+                                                                                                        "emit" => "value_generic",
+                                                                                                        "children" =>
+                                                                                                            (value!([
+                                                                                                                (value!([
+                                                                                                                    "emit" => "identifier",
+                                                                                                                    "value" => "Pos"
+                                                                                                                ])),
+                                                                                                                (value!([
+                                                                                                                    "emit" => "genarg",
+                                                                                                                    "children" =>
+                                                                                                                        (value!([
+                                                                                                                            "emit" => "block",
+                                                                                                                            "children" =>
+                                                                                                                                (value!([
+                                                                                                                                    (value!([
+                                                                                                                                        "emit" => "value_token_ccls",
+                                                                                                                                        "children" =>
+                                                                                                                                            (value!([
+                                                                                                                                                "emit" => "ccl_neg",
+                                                                                                                                                "children" =>
+                                                                                                                                                    (value!([
+                                                                                                                                                        (value!([
+                                                                                                                                                            "emit" => "char",
+                                                                                                                                                            "value" => "\\"
+                                                                                                                                                        ])),
+                                                                                                                                                        (value!([
+                                                                                                                                                            "emit" => "char",
+                                                                                                                                                            "value" => "\""
+                                                                                                                                                        ]))
+                                                                                                                                                    ]))
+                                                                                                                                            ]))
+                                                                                                                                    ])),
+                                                                                                                                    (value!([
+                                                                                                                                        "emit" => "sequence",
+                                                                                                                                        "children" =>
+                                                                                                                                            (value!([
+                                                                                                                                                (value!([
+                                                                                                                                                    "emit" => "value_token_touch",
+                                                                                                                                                    "value" => "\\"
+                                                                                                                                                ])),
+                                                                                                                                                (value!([
+                                                                                                                                                    "emit" => "value_token_ccl",
+                                                                                                                                                    "children" =>
+                                                                                                                                                        (value!([
+                                                                                                                                                            "emit" => "ccl",
+                                                                                                                                                            "children" =>
+                                                                                                                                                                (value!([
+                                                                                                                                                                    (value!([
+                                                                                                                                                                        "emit" => "char",
+                                                                                                                                                                        "value" => "\\"
+                                                                                                                                                                    ])),
+                                                                                                                                                                    (value!([
+                                                                                                                                                                        "emit" => "char",
+                                                                                                                                                                        "value" => "\""
+                                                                                                                                                                    ]))
+                                                                                                                                                                ]))
+                                                                                                                                                        ]))
+                                                                                                                                                ]))
+                                                                                                                                            ]))
+                                                                                                                                    ])),
+                                                                                                                                    (value!([
+                                                                                                                                        "emit" => "value_token_ccl",
+                                                                                                                                        "children" =>
+                                                                                                                                            (value!([
+                                                                                                                                                "emit" => "ccl",
+                                                                                                                                                "children" =>
+                                                                                                                                                    (value!([
+                                                                                                                                                        "emit" => "char",
+                                                                                                                                                        "value" => "\\"
+                                                                                                                                                    ]))
+                                                                                                                                            ]))
+                                                                                                                                    ]))
+                                                                                                                                ]))
+                                                                                                                        ]))
+                                                                                                                ])),
+                                                                                                                (value!([
+                                                                                                                    "emit" => "genarg_named",
+                                                                                                                    "children" =>
+                                                                                                                        (value!([
+                                                                                                                            (value!([
+                                                                                                                                "emit" => "identifier",
+                                                                                                                                "value" => "blur"
+                                                                                                                            ])),
+                                                                                                                            (value!([
+                                                                                                                                "emit" => "value_false"
+                                                                                                                            ]))
+                                                                                                                        ]))
+                                                                                                                ]))
+                                                                                                            ]))
+                                                                                                    ])),
+                                                                                                    (value!([
+                                                                                                        "emit" => "value_token_touch",
+                                                                                                        "value" => "\""
+                                                                                                    ])),
+                                                                                                    (value!([
+                                                                                                        "emit" => "call",
+                                                                                                        "children" =>
+                                                                                                            (value!([
+                                                                                                                (value!([
+                                                                                                                    "emit" => "rvalue",
+                                                                                                                    "children" =>
+                                                                                                                        (value!([
+                                                                                                                            (value!([
+                                                                                                                                "emit" => "value_string",
+                                                                                                                                "value" => ""
+                                                                                                                            ])),
+                                                                                                                            (value!([
+                                                                                                                                "emit" => "attribute",
+                                                                                                                                "children" =>
+                                                                                                                                    (value!([
+                                                                                                                                        "emit" => "value_string",
+                                                                                                                                        "value" => "join"
+                                                                                                                                    ]))
+                                                                                                                            ]))
+                                                                                                                        ]))
+                                                                                                                ])),
+                                                                                                                (value!([
+                                                                                                                    "emit" => "callarg",
+                                                                                                                    "children" =>
+                                                                                                                        (value!([
+                                                                                                                            "emit" => "capture_index",
+                                                                                                                            "children" =>
+                                                                                                                                (value!([
+                                                                                                                                    "emit" => "value_integer",
+                                                                                                                                    "value" => 2
+                                                                                                                                ]))
+                                                                                                                        ]))
+                                                                                                                ]))
+                                                                                                            ]))
+                                                                                                    ]))
+                                                                                                ]))
+                                                                                        ]))
+                                                                                    ]))
+                                                                            ]))
+                                                                    ]))
+                                                                ]))
+                                                        ])),
+                                                        (value!([
+                                                            "emit" => "constant",
+                                                            "children" =>
+                                                                (value!([
+                                                                    (value!([
+                                                                        "emit" => "identifier",
+                                                                        "value" => "Request"
+                                                                    ])),
+                                                                    (value!([
+                                                                        "emit" => "value_parselet",
+                                                                        "children" =>
+                                                                            (value!([
+                                                                                "emit" => "body",
+                                                                                "children" =>
+                                                                                    (value!([
+                                                                                        "emit" => "sequence",
+                                                                                        "children" =>
+                                                                                            (value!([
+                                                                                                (value!([
+                                                                                                    "emit" => "identifier",
+                                                                                                    "value" => "Quoted"
+                                                                                                ])),
+                                                                                                (value!([
+                                                                                                    "emit" => "block",
+                                                                                                    "children" =>
+                                                                                                        (value!([
+                                                                                                            (value!([
+                                                                                                                "emit" => "assign_drop",
+                                                                                                                "children" =>
+                                                                                                                    (value!([
+                                                                                                                        (value!([
+                                                                                                                            "emit" => "lvalue",
+                                                                                                                            "children" =>
+                                                                                                                                (value!([
+                                                                                                                                    "emit" => "identifier",
+                                                                                                                                    "value" => "request"
+                                                                                                                                ]))
+                                                                                                                        ])),
+                                                                                                                        (value!([
+                                                                                                                            "emit" => "op_logical_and",
+                                                                                                                            "children" =>
+                                                                                                                                (value!([
+                                                                                                                                    (value!([
+                                                                                                                                        "emit" => "capture_index",
+                                                                                                                                        "children" =>
+                                                                                                                                            (value!([
+                                                                                                                                                "emit" => "value_integer",
+                                                                                                                                                "value" => 1
+                                                                                                                                            ]))
+                                                                                                                                    ])),
+                                                                                                                                    (value!([
+                                                                                                                                        "emit" => "call",
+                                                                                                                                        "children" =>
+                                                                                                                                            (value!([
+                                                                                                                                                (value!([
+                                                                                                                                                    "emit" => "rvalue",
+                                                                                                                                                    "children" =>
+                                                                                                                                                        (value!([
+                                                                                                                                                            (value!([
+                                                                                                                                                                "emit" => "capture_index",
+                                                                                                                                                                "children" =>
+                                                                                                                                                                    (value!([
+                                                                                                                                                                        "emit" => "value_integer",
+                                                                                                                                                                        "value" => 1
+                                                                                                                                                                    ]))
+                                                                                                                                                            ])),
+                                                                                                                                                            (value!([
+                                                                                                                                                                "emit" => "attribute",
+                                                                                                                                                                "children" =>
+                                                                                                                                                                    (value!([
+                                                                                                                                                                        "emit" => "value_string",
+                                                                                                                                                                        "value" => "split"
+                                                                                                                                                                    ]))
+                                                                                                                                                            ]))
+                                                                                                                                                        ]))
+                                                                                                                                                ])),
+                                                                                                                                                (value!([
+                                                                                                                                                    "emit" => "callarg",
+                                                                                                                                                    "children" =>
+                                                                                                                                                        (value!([
+                                                                                                                                                            "emit" => "value_string",
+                                                                                                                                                            "value" => " "
+                                                                                                                                                        ]))
+                                                                                                                                                ]))
+                                                                                                                                            ]))
+                                                                                                                                    ]))
+                                                                                                                                ]))
+                                                                                                                        ]))
+                                                                                                                    ]))
+                                                                                                            ])),
+                                                                                                            (value!([
+                                                                                                                "emit" => "op_if",
+                                                                                                                "children" =>
+                                                                                                                    (value!([
+                                                                                                                        (value!([
+                                                                                                                            "emit" => "op_logical_and",
+                                                                                                                            "children" =>
+                                                                                                                                (value!([
+                                                                                                                                    (value!([
+                                                                                                                                        "emit" => "identifier",
+                                                                                                                                        "value" => "request"
+                                                                                                                                    ])),
+                                                                                                                                    (value!([
+                                                                                                                                        "emit" => "comparison",
+                                                                                                                                        "children" =>
+                                                                                                                                            (value!([
+                                                                                                                                                (value!([
+                                                                                                                                                    "emit" => "rvalue",
+                                                                                                                                                    "children" =>
+                                                                                                                                                        (value!([
+                                                                                                                                                            (value!([
+                                                                                                                                                                "emit" => "identifier",
+                                                                                                                                                                "value" => "request"
+                                                                                                                                                            ])),
+                                                                                                                                                            (value!([
+                                                                                                                                                                "emit" => "attribute",
+                                                                                                                                                                "children" =>
+                                                                                                                                                                    (value!([
+                                                                                                                                                                        "emit" => "value_string",
+                                                                                                                                                                        "value" => "len"
+                                                                                                                                                                    ]))
+                                                                                                                                                            ]))
+                                                                                                                                                        ]))
+                                                                                                                                                ])),
+                                                                                                                                                (value!([
+                                                                                                                                                    "emit" => "cmp_eq",
+                                                                                                                                                    "children" =>
+                                                                                                                                                        (value!([
+                                                                                                                                                            "emit" => "value_integer",
+                                                                                                                                                            "value" => 3
+                                                                                                                                                        ]))
+                                                                                                                                                ]))
+                                                                                                                                            ]))
+                                                                                                                                    ]))
+                                                                                                                                ]))
+                                                                                                                        ])),
+                                                                                                                        (value!([
+                                                                                                                            "emit" => "sequence",
+                                                                                                                            "children" =>
+                                                                                                                                (value!([
+                                                                                                                                    (value!([
+                                                                                                                                        "emit" => "alias",
+                                                                                                                                        "children" =>
+                                                                                                                                            (value!([
+                                                                                                                                                (value!([
+                                                                                                                                                    "emit" => "value_string",
+                                                                                                                                                    "value" => "method"
+                                                                                                                                                ])),
+                                                                                                                                                (value!([
+                                                                                                                                                    "emit" => "rvalue",
+                                                                                                                                                    "children" =>
+                                                                                                                                                        (value!([
+                                                                                                                                                            (value!([
+                                                                                                                                                                "emit" => "identifier",
+                                                                                                                                                                "value" => "request"
+                                                                                                                                                            ])),
+                                                                                                                                                            (value!([
+                                                                                                                                                                "emit" => "item",
+                                                                                                                                                                "children" =>
+                                                                                                                                                                    (value!([
+                                                                                                                                                                        "emit" => "value_integer",
+                                                                                                                                                                        "value" => 0
+                                                                                                                                                                    ]))
+                                                                                                                                                            ]))
+                                                                                                                                                        ]))
+                                                                                                                                                ]))
+                                                                                                                                            ]))
+                                                                                                                                    ])),
+                                                                                                                                    (value!([
+                                                                                                                                        "emit" => "alias",
+                                                                                                                                        "children" =>
+                                                                                                                                            (value!([
+                                                                                                                                                (value!([
+                                                                                                                                                    "emit" => "value_string",
+                                                                                                                                                    "value" => "path"
+                                                                                                                                                ])),
+                                                                                                                                                (value!([
+                                                                                                                                                    "emit" => "rvalue",
+                                                                                                                                                    "children" =>
+                                                                                                                                                        (value!([
+                                                                                                                                                            (value!([
+                                                                                                                                                                "emit" => "identifier",
+                                                                                                                                                                "value" => "request"
+                                                                                                                                                            ])),
+                                                                                                                                                            (value!([
+                                                                                                                                                                "emit" => "item",
+                                                                                                                                                                "children" =>
+                                                                                                                                                                    (value!([
+                                                                                                                                                                        "emit" => "value_integer",
+                                                                                                                                                                        "value" => 1
+                                                                                                                                                                    ]))
+                                                                                                                                                            ]))
+                                                                                                                                                        ]))
+                                                                                                                                                ]))
+                                                                                                                                            ]))
+                                                                                                                                    ])),
+                                                                                                                                    (value!([
+                                                                                                                                        "emit" => "alias",
+                                                                                                                                        "children" =>
+                                                                                                                                            (value!([
+                                                                                                                                                (value!([
+                                                                                                                                                    "emit" => "value_string",
+                                                                                                                                                    "value" => "protocol"
+                                                                                                                                                ])),
+                                                                                                                                                (value!([
+                                                                                                                                                    "emit" => "rvalue",
+                                                                                                                                                    "children" =>
+                                                                                                                                                        (value!([
+                                                                                                                                                            (value!([
+                                                                                                                                                                "emit" => "identifier",
+                                                                                                                                                                "value" => "request"
+                                                                                                                                                            ])),
+                                                                                                                                                            (value!([
+                                                                                                                                                                "emit" => "item",
+                                                                                                                                                                "children" =>
+                                                                                                                                                                    (value!([
+                                                                                                                                                                        "emit" => "value_integer",
+                                                                                                                                                                        "value" => 2
+                                                                                                                                                                    ]))
+                                                                                                                                                            ]))
+                                                                                                                                                        ]))
+                                                                                                                                                ]))
+                                                                                                                                            ]))
+                                                                                                                                    ]))
+                                                                                                                                ]))
+                                                                                                                        ])),
+                                                                                                                        (value!([
+                                                                                                                            "emit" => "sequence",
+                                                                                                                            "children" =>
+                                                                                                                                (value!([
+                                                                                                                                    (value!([
+                                                                                                                                        "emit" => "alias",
+                                                                                                                                        "children" =>
+                                                                                                                                            (value!([
+                                                                                                                                                (value!([
+                                                                                                                                                    "emit" => "value_string",
+                                                                                                                                                    "value" => "method"
+                                                                                                                                                ])),
+                                                                                                                                                (value!([
+                                                                                                                                                    "emit" => "value_null"
+                                                                                                                                                ]))
+                                                                                                                                            ]))
+                                                                                                                                    ])),
+                                                                                                                                    (value!([
+                                                                                                                                        "emit" => "alias",
+                                                                                                                                        "children" =>
+                                                                                                                                            (value!([
+                                                                                                                                                (value!([
+                                                                                                                                                    "emit" => "value_string",
+                                                                                                                                                    "value" => "path"
+                                                                                                                                                ])),
+                                                                                                                                                (value!([
+                                                                                                                                                    "emit" => "capture_index",
+                                                                                                                                                    "children" =>
+                                                                                                                                                        (value!([
+                                                                                                                                                            "emit" => "value_integer",
+                                                                                                                                                            "value" => 1
+                                                                                                                                                        ]))
+                                                                                                                                                ]))
+                                                                                                                                            ]))
+                                                                                                                                    ])),
+                                                                                                                                    (value!([
+                                                                                                                                        "emit" => "alias",
+                                                                                                                                        "children" =>
+                                                                                                                                            (value!([
+                                                                                                                                                (value!([
+                                                                                                                                                    "emit" => "value_string",
+                                                                                                                                                    "value" => "protocol"
+                                                                                                                                                ])),
+                                                                                                                                                (value!([
+                                                                                                                                                    "emit" => "value_null"
+                                                                                                                                                ]))
+                                                                                                                                            ]))
+                                                                                                                                    ]))
+                                                                                                                                ]))
+                                                                                                                        ]))
+                                                                                                                    ]))
+                                                                                                            ]))
+                                                                                                        ]))
+                                                                                                ]))
+                                                                                            ]))
+                                                                                    ]))
+                                                                            ]))
+                                                                    ]))
+                                                                ]))
+                                                        ])),
+                                                        (value!([
+                                                            "emit" => "constant",
+                                                            "children" =>
+                                                                (value!([
+                                                                    (value!([
+                                                                        "emit" => "identifier",
+                                                                        "value" => "Size"
+                                                                    ])),
+                                                                    (value!([
+                                                                        "emit" => "value_parselet",
+                                                                        "children" =>
+                                                                            (value!([
+                                                                                "emit" => "body",
+                                                                                "children" =>
+                                                                                    (value!([
+                                                                                        (value!([
+                                                                                            "emit" => "sequence",
+                                                                                            "children" =>
+                                                                                                (value!([
+                                                                                                    (value!([
+                                                                                                        "emit" => "value_token_touch",
+                                                                                                        "value" => "-"
+                                                                                                    ])),
+                                                                                                    (value!([
+                                                                                                        "emit" => "value_null"
+                                                                                                    ]))
+                                                                                                ]))
+                                                                                        ])),
+                                                                                        (value!([
+                                                                                            "emit" => "identifier",
+                                                                                            "value" => "Int"
+                                                                                        ]))
+                                                                                    ]))
+                                                                            ]))
+                                                                    ]))
+                                                                ]))
+                                                        ])),
+                                                        (value!([
+                                                            "emit" => "constant",
+                                                            "children" =>
+                                                                (value!([
+                                                                    (value!([
+                                                                        "emit" => "identifier",
+                                                                        "value" => "Combined"
+                                                                    ])),
+                                                                    (value!([
+                                                                        "emit" => "value_parselet",
+                                                                        "children" =>
+                                                                            (value!([
+                                                                                "emit" => "body",
+                                                                                "children" =>
+                                                                                    (value!([
+                                                                                        (value!([
+                                                                                            "emit" => "sequence",
+                                                                                            "children" =>
+                                                                                                (value!([
+                                                                                                    (value!([
+                                                                                                        "emit" => "value_token_touch",
+                                                                                                        "value" => " "
+                                                                                                    ])),
+                                                                                                    (value!([
+                                                                                                        "emit" => "identifier",
+                                                                                                        "value" => "Quoted"
+                                                                                                    ])),
+                                                                                                    (value!([
+                                                                                                        "emit" => "value_token_touch",
+                                                                                                        "value" => " "
+                                                                                                    ])),
+                                                                                                    (value!([
+                                                                                                        "emit" => "identifier",
+                                                                                                        "value" => "Quoted"
+                                                                                                    ])),
+                                                                                                    (value!([
+                                                                                                        "emit" => "sequence",
+                                                                                                        "children" =>
+                                                                                                            (value!([
+                                                                                                                (value!([
+                                                                                                                    "emit" => "alias",
+                                                                                                                    "children" =>
+                                                                                                                        (value!([
+                                                                                                                            (value!([
+                                                                                                                                "emit" => "value_string",
+                                                                                                                                "value" => "referer"
+                                                                                                                            ])),
+                                                                                                                            (value!([
+                                                                                                                                "emit" => "capture_index",
+                                                                                                                                "children" =>
+                                                                                                                                    (value!([
+                                                                                                                                        "emit" => "value_integer",
+                                                                                                                                        "value" => 2
+                                                                                                                                    ]))
+                                                                                                                            ]))
+                                                                                                                        ]))
+                                                                                                                ])),
+                                                                                                                (value!([
+                                                                                                                    "emit" => "alias",
+                                                                                                                    "children" =>
+                                                                                                                        (value!([
+                                                                                                                            (value!([
+                                                                                                                                "emit" => "value_string",
+                                                                                                                                "value" => "agent"
+                                                                                                                            ])),
+                                                                                                                            (value!([
+                                                                                                                                "emit" => "capture_index",
+                                                                                                                                "children" =>
+                                                                                                                                    (value!([
+                                                                                                                                        "emit" => "value_integer",
+                                                                                                                                        "value" => 4
+                                                                                                                                    ]))
+                                                                                                                            ]))
+                                                                                                                        ]))
+                                                                                                                ]))
+                                                                                                            ]))
+                                                                                                    ]))
+                                                                                                ]))
+                                                                                        ])),
+                                                                                        (value!([
+                                                                                            "emit" => "sequence",
+                                                                                            "children" =>
+                                                                                                (value!([
+                                                                                                    (value!([
+                                                                                                        "emit" => "identifier",
+                                                                                                        "value" => "Empty"
+                                                                                                    ])),
+                                                                                                    (value!([
+                                                                                                        "emit" => "sequence",
+                                                                                                        "children" =>
+                                                                                                            (value!([
+                                                                                                                (value!([
+                                                                                                                    "emit" => "alias",
+                                                                                                                    "children" =>
+                                                                                                                        (value!([
+                                                                                                                            (value!([
+                                                                                                                                "emit" => "value_string",
+                                                                                                                                "value" => "referer"
+                                                                                                                            ])),
+                                                                                                                            (value!([
+                                                                                                                                "emit" => "value_null"
+                                                                                                                            ]))
+                                                                                                                        ]))
+                                                                                                                ])),
+                                                                                                                (value!([
+                                                                                                                    "emit" => "alias",
+                                                                                                                    "children" =>
+                                                                                                                        (value!([
+                                                                                                                            (value!([
+                                                                                                                                "emit" => "value_string",
+                                                                                                                                "value" => "agent"
+                                                                                                                            ])),
+                                                                                                                            (value!([
+                                                                                                                                "emit" => "value_null"
+                                                                                                                            ]))
+                                                                                                                        ]))
+                                                                                                                ]))
+                                                                                                            ]))
+                                                                                                    ]))
+                                                                                                ]))
+                                                                                        ]))
+                                                                                    ]))
+                                                                            ]))
+                                                                    ]))
+                                                                ]))
+                                                        ])),
+                                                        (value!([
+                                                            "emit" => "sequence",
+                                                            "children" =>
+                                                                (value!([
+                                                                    (value!([
+                                                                        "emit" => "identifier",
+                                                                        "value" => "Field"
+                                                                    ])),
+                                                                    (value!([
+                                                                        "emit" => "value_token_touch",
+                                                                        "value" => " "
+                                                                    ])),
+                                                                    (value!([
+                                                                        "emit" => "identifier",
+                                                                        "value" => "Field"
+                                                                    ])),
+                                                                    (value!([
+                                                                        "emit" => "value_token_touch",
+                                                                        "value" => " "
+                                                                    ])),
+                                                                    (value!([
+                                                                        "emit" => "identifier",
+                                                                        "value" => "Field"
+                                                                    ])),
+                                                                    (value!([
+                                                                        "emit" => "value_token_touch",
+                                                                        "value" => " ["
+                                                                    ])),
+                                                                    (value!([
+                                                                        "emit" => "value_token_ccls",
+                                                                        "children" =>
+                                                                            (value!([
+                                                                                "emit" => "ccl_neg",
+                                                                                "children" =>
+                                                                                    (value!([
+                                                                                        "emit" => "char",
+                                                                                        "value" => "]"
+                                                                                    ]))
+                                                                            ]))
+                                                                    ])),
+                                                                    (value!([
+                                                                        "emit" => "value_token_touch",
+                                                                        "value" => "] "
+                                                                    ])),
+                                                                    (value!([
+                                                                        "emit" => "identifier",
+                                                                        "value" => "Request"
+                                                                    ])),
+                                                                    (value!([
+                                                                        "emit" => "value_token_touch",
+                                                                        "value" => " "
+                                                                    ])),
+                                                                    (value!([
+                                                                        "emit" => "identifier",
+                                                                        "value" => "Int"
+                                                                    ])),
+                                                                    (value!([
+                                                                        "emit" => "value_token_touch",
+                                                                        "value" => " "
+                                                                    ])),
+                                                                    (value!([
+                                                                        "emit" => "identifier",
+                                                                        "value" => "Size"
+                                                                    ])),
+                                                                    (value!([
+                                                                        "emit" => "identifier",
+                                                                        "value" => "Combined"
+                                                                    ])),
+                                                                    (value!([
+                                                                        "emit" => "block",
+                                                                        "children" =>
+                                                                            (value!([
+                                                                                (value!([
+                                                                                    "emit" => "assign_drop",
+                                                                                    "children" =>
+                                                                                        (value!([
+                                                                                            (value!([
+                                                                                                "emit" => "lvalue",
+                                                                                                "children" =>
+                                                                                                    (value!([
+                                                                                                        "emit" => "identifier",
+                                                                                                        "value" => "res"
+                                                                                                    ]))
+                                                                                            ])),
+                                                                                            (value!([
+                                                                                                "emit" => "sequence",
+                                                                                                "children" =>
+                                                                                                    (value!([
+                                                                                                        (value!([
+                                                                                                            "emit" => "alias",
+                                                                                                            "children" =>
+                                                                                                                (value!([
+                                                                                                                    (value!([
+                                                                                                                        "emit" => "value_string",
+                                                                                                                        "value" => "host"
+                                                                                                                    ])),
+                                                                                                                    (value!([
+                                                                                                                        "emit" => "capture_index",
+                                                                                                                        "children" =>
+                                                                                                                            (value!([
+                                                                                                                                "emit" => "value_integer",
+                                                                                                                                "value" => 1
+                                                                                                                            ]))
+                                                                                                                    ]))
+                                                                                                                ]))
+                                                                                                        ])),
+                                                                                                        (value!([
+                                                                                                            "emit" => "alias",
+                                                                                                            "children" =>
+                                                                                                                (value!([
+                                                                                                                    (value!([
+                                                                                                                        "emit" => "value_string",
+                                                                                                                        "value" => "ident"
+                                                                                                                    ])),
+                                                                                                                    (value!([
+                                                                                                                        "emit" => "capture_index",
+                                                                                                                        "children" =>
+                                                                                                                            (value!([
+                                                                                                                                "emit" => "value_integer",
+                                                                                                                                "value" => 3
+                                                                                                                            ]))
+                                                                                                                    ]))
+                                                                                                                ]))
+                                                                                                        ])),
+                                                                                                        (value!([
+                                                                                                            "emit" => "alias",
+                                                                                                            "children" =>
+                                                                                                                (value!([
+                                                                                                                    (value!([
+                                                                                                                        "emit" => "value_string",
+                                                                                                                        "value" => "user"
+                                                                                                                    ])),
+                                                                                                                    (value!([
+                                                                                                                        "emit" => "capture_index",
+                                                                                                                        "children" =>
+                                                                                                                            (value!([
+                                                                                                                                "emit" => "value_integer",
+                                                                                                                                "value" => 5
+                                                                                                                            ]))
+                                                                                                                    ]))
+                                                                                                                ]))
+                                                                                                        ])),
+                                                                                                        (value!([
+                                                                                                            "emit" => "alias",
+                                                                                                            "children" =>
+                                                                                                                (value!([
+                                                                                                                    (value!([
+                                                                                                                        "emit" => "value_string",
+                                                                                                                        "value" => "time"
+                                                                                                                    ])),
+                                                                                                                    (value!([
+                                                                                                                        "emit" => "capture_index",
+                                                                                                                        "children" =>
+                                                                                                                            (value!([
+                                                                                                                                "emit" => "value_integer",
+                                                                                                                                "value" => 7
+                                                                                                                            ]))
+                                                                                                                    ]))
+                                                                                                                ]))
+                                                                                                        ]))
+                                                                                                    ]))
+                                                                                            ]))
+                                                                                        ]))
+                                                                                ])),
+                                                                                (value!([
+                                                                                    "emit" => "call",
+                                                                                    "children" =>
+                                                                                        (value!([
+                                                                                            (value!([
+                                                                                                "emit" => "rvalue",
+                                                                                                "children" =>
+                                                                                                    (value!([
+                                                                                                        (value!([
+                                                                                                            "emit" => "identifier",
+                                                                                                            "value" => "res"
+                                                                                                        ])),
+                                                                                                        (value!([
+                                                                                                            "emit" => "attribute",
+                                                                                                            "children" =>
+                                                                                                                (value!([
+                                                                                                                    "emit" => "value_string",
+                                                                                                                    "value" => "merge"
+                                                                                                                ]))
+                                                                                                        ]))
+                                                                                                    ]))
+                                                                                            ])),
+                                                                                            (value!([
+                                                                                                "emit" => "callarg",
+                                                                                                "children" =>
+                                                                                                    (value!([
+                                                                                                        "emit" => "capture_index",
+                                                                                                        "children" =>
+                                                                                                            (value!([
+                                                                                                                "emit" => "value_integer",
+                                                                                                                "value" => 9
+                                                                                                            ]))
+                                                                                                    ]))
+                                                                                            ]))
+                                                                                        ]))
+                                                                                ])),
+                                                                                (value!([
+                                                                                    "emit" => "assign_drop",
+                                                                                    "children" =>
+                                                                                        (value!([
+                                                                                            (value!([
+                                                                                                "emit" => "lvalue",
+                                                                                                "children" =>
+                                                                                                    (value!([
+                                                                                                        (value!([
+                                                                                                            "emit" => "identifier",
+                                                                                                            "value" => "res"
+                                                                                                        ])),
+                                                                                                        (value!([
+                                                                                                            "emit" => "item",
+                                                                                                            "children" =>
+                                                                                                                (value!([
+                                                                                                                    "emit" => "value_string",
+                                                                                                                    "value" => "status"
+                                                                                                                ]))
+                                                                                                        ]))
+                                                                                                    ]))
+                                                                                            ])),
+                                                                                            (value!([
+                                                                                                "emit" => "capture_index",
+                                                                                                "children" =>
+                                                                                                    (value!([
+                                                                                                        "emit" => "value_integer",
+                                                                                                        "value" => 11
+                                                                                                    ]))
+                                                                                            ]))
+                                                                                        ]))
+                                                                                ])),
+                                                                                (value!([
+                                                                                    "emit" => "assign_drop",
+                                                                                    "children" =>
+                                                                                        (value!([
+                                                                                            (value!([
+                                                                                                "emit" => "lvalue",
+                                                                                                "children" =>
+                                                                                                    (value!([
+                                                                                                        (value!([
+                                                                                                            "emit" => "identifier",
+                                                                                                            "value" => "res"
+                                                                                                        ])),
+                                                                                                        (value!([
+                                                                                                            "emit" => "item",
+                                                                                                            "children" =>
+                                                                                                                (value!([
+                                                                                                                    "emit" => "value_string",
+                                                                                                                    "value" => "size"
+                                                                                                                ]))
+                                                                                                        ]))
+                                                                                                    ]))
+                                                                                            ])),
+                                                                                            (value!([
+                                                                                                "emit" => "capture_index",
+                                                                                                "children" =>
+                                                                                                    (value!([
+                                                                                                        "emit" => "value_integer",
+                                                                                                        "value" => 13
+                                                                                                    ]))
+                                                                                            ]))
+                                                                                        ]))
+                                                                                ])),
+                                                                                (value!([
+                                                                                    "emit" => "call",
+                                                                                    "children" =>
+                                                                                        (value!([
+                                                                                            (value!([
+                                                                                                "emit" => "rvalue",
+                                                                                                "children" =>
+                                                                                                    (value!([
+                                                                                                        (value!([
+                                                                                                            "emit" => "identifier",
+                                                                                                            "value" => "res"
+                                                                                                        ])),
+                                                                                                        (value!([
+                                                                                                            "emit" => "attribute",
+                                                                                                            "children" =>
+                                                                                                                (value!([
+                                                                                                                    "emit" => "value_string",
+                                                                                                                    "value" => "merge"
+                                                                                                                ]))
+                                                                                                        ]))
+                                                                                                    ]))
+                                                                                            ])),
+                                                                                            (value!([
+                                                                                                "emit" => "callarg",
+                                                                                                "children" =>
+                                                                                                    (value!([
+                                                                                                        "emit" => "capture_index",
+                                                                                                        "children" =>
+                                                                                                            (value!([
+                                                                                                                "emit" => "value_integer",
+                                                                                                                "value" => 14
+                                                                                                            ]))
+                                                                                                    ]))
+                                                                                            ]))
+                                                                                        ]))
+                                                                                ]))
+                                                                            ]))
+                                                                    ]))
+                                                                ]))
+                                                        ]))
+                                                    ]))
+                                            ]))
+                                    ]))
+                                ]))
+                        ])),
+                        (value!([
+                            "emit" => "constant",
+                            "children" =>
+                                (value!([
+                                    (value!([
+                                        "emit" => "identifier",
+                                        "value" => "Syslog"
+                                    ])),
+                                    (value!([
+                                        "emit" => "value_parselet",
+                                        "children" =>
+                                            (value!([
+                                                "emit" => "body",
+                                                "children" =>
+                                                    (value!([
+                                                        (value!([
+                                                            "emit" => "constant",
+                                                            "children" =>
+                                                                (value!([
+                                                                    (value!([
+                                                                        "emit" => "identifier",
+                                                                        "value" => "Field"
+                                                                    ])),
+                                                                    (value!([
+                                                                        "emit" => "value_parselet",
+                                                                        "children" =>
+                                                                            (value!([
+                                                                                "emit" => "body",
+                                                                                "children" =>
+                                                                                    (value!([
+                                                                                        (value!([
+                                                                                            "emit" => "sequence",
+                                                                                            "children" =>
+                                                                                                (value!([
+                                                                                                    (value!([
+                                                                                                        "emit" => "value_token_touch",
+                                                                                                        "value" => "-"
+                                                                                                    ])),
+                                                                                                    (value!([
+                                                                                                        "emit" => "value_generic",
+                                                                                                        "children" =>
+                                                                                                            (value!([
+                                                                                                                (value!([
+                                                                                                                    "emit" => "identifier",
+                                                                                                                    "value" => "Peek"
+                                                                                                                ])),
+                                                                                                                (value!([
+                                                                                                                    "emit" => "genarg",
+                                                                                                                    "children" =>
+                                                                                                                        (value!([
+                                                                                                                            "emit" => "value_token_ccl",
+                                                                                                                            "children" =>
+                                                                                                                                (value!([
+                                                                                                                                    "emit" => "ccl",
+                                                                                                                                    "children" =>
+                                                                                                                                        (value!([
+                                                                                                                                            (value!([
+                                                                                                                                                "emit" => "char",
+                                                                                                                                                "value" => " "
+                                                                                                                                            ])),
+                                                                                                                                            (value!([
+                                                                                                                                                "emit" => "char",
+                                                                                                                                                "value" => "\t"
+                                                                                                                                            ])),
+                                                                                                                                            (value!([
+                                                                                                                                                "emit" => "char",
+                                                                                                                                                "value" => "\r"
+                                                                                                                                            ])),
+                                                                                                                                            (value!([
+                                                                                                                                                "emit" => "char",
+                                                                                                                                                "value" => "\n"
+                                                                                                                                            ]))
+                                                                                                                                        ]))
+                                                                                                                                ]))
+                                                                                                                        ]))
+                                                                                                                ]))
+                                                                                                            ]))
+                                                                                                    ])),
+                                                                                                    (value!([
+                                                                                                        "emit" => "value_null"
+                                                                                                    ]))
+                                                                                                ]))
+                                                                                        ])),
+                                                                                        (value!([
+                                                                                            "emit" => "value_token_ccls",
+                                                                                            "children" =>
+                                                                                                (value!([
+                                                                                                    "emit" => "ccl_neg",
+                                                                                                    "children" =>
+                                                                                                        (value!([
+                                                                                                            (value!([
+                                                                                                                "emit" => "char",
+                                                                                                                "value" => " "
+                                                                                                            ])),
+                                                                                                            (value!([
+                                                                                                                "emit" => "char",
+                                                                                                                "value" => "\t"
+                                                                                                            ])),
+                                                                                                            (value!([
+                                                                                                                "emit" => "char",
+                                                                                                                "value" => "\r"
+                                                                                                            ])),
+                                                                                                            (value!([
+                                                                                                                "emit" => "char",
+                                                                                                                "value" => "\n"
+                                                                                                            ]))
+                                                                                                        ]))
+                                                                                                ]))
+                                                                                        ]))
+                                                                                    ]))
+                                                                            ]))
+                                                                    ]))
+                                                                ]))
+                                                        ])),
+                                                        (value!([
+                                                            "emit" => "constant",
+                                                            "children" =>
+                                                                (value!([
+                                                                    (value!([
+                                                                        "emit" => "identifier",
+                                                                        "value" => "Time"
+                                                                    ])),
+                                                                    (value!([
+                                                                        "emit" => "value_parselet",
+                                                                        "children" =>
+                                                                            (value!([
+                                                                                "emit" => "body",
+                                                                                "children" =>
+                                                                                    (value!([
+                                                                                        (value!([
+                                                                                            "emit" => "sequence",
+                                                                                            "children" =>
+                                                                                                (value!([
+                                                                                                    (value!([
+                                                                                                        "emit" => "value_token_touch",
+                                                                                                        "value" => "-"
+                                                                                                    ])),
+                                                                                                    (value!([
+                                                                                                        "emit" => "value_null"
+                                                                                                    ]))
+                                                                                                ]))
+                                                                                        ])),
+                                                                                        (value!([
+                                                                                            "emit" => "identifier",
+                                                                                            "value" => "Timestamp"
+                                                                                        ]))
+                                                                                    ]))
+                                                                            ]))
+                                                                    ]))
+                                                                ]))
+                                                        ])),
+                                                        (value!([
+                                                            "emit" => "constant",
+                                                            "children" =>
+                                                                (value!([
+                                                                    (value!([
+                                                                        "emit" => "identifier",
+                                                                        "value" => "Param"
+                                                                    ])),
+                                                                    (value!([
+                                                                        "emit" => "value_parselet",
+                                                                        "children" =>
+                                                                            (value!([
+                                                                                "emit" => "body",
+                                                                                "children" =>
+                                                                                    (value!([
+                                                                                        (value!([
+                                                                                            "emit" => "sequence",
+                                                                                            "children" =>
+                                                                                                (value!([
+                                                                                                    (value!([
+                                                                                                        "emit" => "value_token_touch",
+                                                                                                        "value" => " "
+                                                                                                    ])),
+                                                                                                    (value!([
+                                                                                                        "emit" => "value_token_ccls",
+                                                                                                        "children" =>
+                                                                                                            (value!([
+                                                                                                                "emit" => "ccl_neg",
+                                                                                                                "children" =>
+                                                                                                                    (value!([
+                                                                                                                        (value!([
+                                                                                                                            "emit" => "char",
+                                                                                                                            "value" => " "
+                                                                                                                        ])),
+                                                                                                                        (value!([
+                                                                                                                            "emit" => "char",
+                                                                                                                            "value" => "="
+                                                                                                                        ])),
+                                                                                                                        (value!([
+                                                                                                                            "emit" => "char",
+                                                                                                                            "value" => "]"
+                                                                                                                        ])),
+                                                                                                                        (value!([
+                                                                                                                            "emit" => "char",
+                                                                                                                            "value" => "\""
+                                                                                                                        ]))
+                                                                                                                    ]))
+                                                                                                            ]))
+                                                                                                    ])),
+                                                                                                    (value!([
+                                                                                                        "emit" => "value_token_touch",
+                                                                                                        "value" => "=\""
+                                                                                                    ])),
+                                                                                                    (value!([
+                                                                                                        "emit" => "value_token_touch",
+                                                                                                        "value" => "\""
+                                                                                                    ])),
+                                                                                                    (value!([
+                                                                                                        "emit" => "list",
+                                                                                                        "children" =>
+                                                                                                            (value!([
+                                                                                                                (value!([
+                                                                                                                    "emit" => "capture_index",
+                                                                                                                    "children" =>
+                                                                                                                        (value!([
+                                                                                                                            "emit" => "value_integer",
+                                                                                                                            "value" => 2
+                                                                                                                        ]))
+                                                                                                                ])),
+                                                                                                                (value!([
+                                                                                                                    "emit" => "value_string",
+                                                                                                                    "value" => ""
+                                                                                                                ]))
+                                                                                                            ]))
+                                                                                                    ]))
+                                                                                                ]))
+                                                                                        ])),
+                                                                                        (value!([
+                                                                                            "emit" => "sequence",
+                                                                                            "children" =>
+                                                                                                (value!([
+                                                                                                    (value!([
+                                                                                                        "emit" => "value_token_touch",
+                                                                                                        "value" => " "
+                                                                                                    ])),
+                                                                                                    (value!([
+                                                                                                        "emit" => "value_token_ccls",
+                                                                                                        "children" =>
+                                                                                                            (value!([
+                                                                                                                "emit" => "ccl_neg",
+                                                                                                                "children" =>
+                                                                                                                    (value!([
+                                                                                                                        (value!([
+                                                                                                                            "emit" => "char",
+                                                                                                                            "value" => " "
+                                                                                                                        ])),
+                                                                                                                        (value!([
+                                                                                                                            "emit" => "char",
+                                                                                                                            "value" => "="
+                                                                                                                        ])),
+                                                                                                                        (value!([
+                                                                                                                            "emit" => "char",
+                                                                                                                            "value" => "]"
+                                                                                                                        ])),
+                                                                                                                        (value!([
+                                                                                                                            "emit" => "char",
+                                                                                                                            "value" => "\""
+                                                                                                                        ]))
+                                                                                                                    ]))
+                                                                                                            ]))
+                                                                                                    ])),
+                                                                                                    (value!([
+                                                                                                        "emit" => "value_token_touch",
+                                                                                                        "value" => "=\""
+                                                                                                    ])),
+                                                                                                    (value!([
+                                                                                                        "emit" => "value_generic",
+                                                                                                        "children" =>
+                                                                                                            (value!([
+                                                                                                                (value!([
+                                                                                                                    "emit" => "identifier",
+                                                                                                                    "value" => "Pos"
+                                                                                                                ])),
+                                                                                                                (value!([
+                                                                                                                    "emit" => "genarg",
+                                                                                                                    "children" =>
+                                                                                                                        (value!([
+                                                                                                                            "emit" => "block",
+                                                                                                                            "children" =>
+                                                                                                                                (value!([
+                                                                                                                                    (value!([
+                                                                                                                                        "emit" => "value_token_ccls",
+                                                                                                                                        "children" =>
+                                                                                                                                            (value!([
+                                                                                                                                                "emit" => "ccl_neg",
+                                                                                                                                                "children" =>
+                                                                                                                                                    (value!([
+                                                                                                                                                        (value!([
+                                                                                                                                                            "emit" => "char",
+                                                                                                                                                            "value" => "\\"
+                                                                                                                                                        ])),
+                                                                                                                                                        (value!([
+                                                                                                                                                            "emit" => "char",
+                                                                                                                                                            "value" => "\""
+                                                                                                                                                        ])),
+                                                                                                                                                        (value!([
+                                                                                                                                                            "emit" => "char",
+                                                                                                                                                            "value" => "]"
+                                                                                                                                                        ]))
+                                                                                                                                                    ]))
+                                                                                                                                            ]))
+                                                                                                                                    ])),
+                                                                                                                                    (value!([
+                                                                                                                                        "emit" => "sequence",
+                                                                                                                                        "children" =>
+                                                                                                                                            (value!([
+                                                                                                                                                (value!([
+                                                                                                                                                    "emit" => "value_token_touch",
+                                                                                                                                                    "value" => "\\"
+                                                                                                                                                ])),
+                                                                                                                                                (value!([
+                                                                                                                                                    "emit" => "value_token_ccl",
+                                                                                                                                                    "children" =>
+                                                                                                                                                        (value!([
+                                                                                                                                                            "emit" => "ccl",
+                                                                                                                                                            "children" =>
+                                                                                                                                                                (value!([
+                                                                                                                                                                    (value!([
+                                                                                                                                                                        "emit" => "char",
+                                                                                                                                                                        "value" => "\\"
+                                                                                                                                                                    ])),
+                                                                                                                                                                    (value!([
+                                                                                                                                                                        "emit" => "char",
+                                                                                                                                                                        "value" => "\""
+                                                                                                                                                                    ])),
+                                                                                                                                                                    (value!([
+                                                                                                                                                                        "emit" => "char",
+                                                                                                                                                                        "value" => "]"
+                                                                                                                                                                    ]))
+                                                                                                                                                                ]))
+                                                                                                                                                        ]))
+                                                                                                                                                ]))
+                                                                                                                                            ]))
+                                                                                                                                    ])),
+                                                                                                                                    (value!([
+                                                                                                                                        "emit" => "value_token_ccl",
+                                                                                                                                        "children" =>
+                                                                                                                                            (value!([
+                                                                                                                                                "emit" => "ccl",
+                                                                                                                                                "children" =>
+                                                                                                                                                    (value!([
+                                                                                                                                                        "emit" => "char",
+                                                                                                                                                        "value" => "\\"
+                                                                                                                                                    ]))
+                                                                                                                                            ]))
+                                                                                                                                    ]))
+                                                                                                                                ]))
+                                                                                                                        ]))
+                                                                                                                ])),
+                                                                                                                (value!([
+                                                                                                                    "emit" => "genarg_named",
+                                                                                                                    "children" =>
+                                                                                                                        (value!([
+                                                                                                                            (value!([
+                                                                                                                                "emit" => "identifier",
+                                                                                                                                "value" => "blur"
+                                                                                                                            ])),
+                                                                                                                            (value!([
+                                                                                                                                "emit" => "value_false"
+                                                                                                                            ]))
+                                                                                                                        ]))
+                                                                                                                ]))
+                                                                                                            ]))
+                                                                                                    ])),
+                                                                                                    (value!([
+                                                                                                        "emit" => "value_token_touch",
+                                                                                                        "value" => "\""
+                                                                                                    ])),
+                                                                                                    (value!([
+                                                                                                        "emit" => "block",
+                                                                                                        "children" =>
+                                                                                                            (value!([
+                                                                                                                "emit" => "list",
+                                                                                                                "children" =>
+                                                                                                                    (value!([
+                                                                                                                        (value!([
+                                                                                                                            "emit" => "capture_index",
+                                                                                                                            "children" =>
+                                                                                                                                (value!([
+                                                                                                                                    "emit" => "value_integer",
+                                                                                                                                    "value" => 2
+                                                                                                                                ]))
+                                                                                                                        ])),
+                                                                                                                        (value!([
+                                                                                                                            "emit" => "call",
+                                                                                                                            "children" =>
+                                                                                                                                (value!([
+                                                                                                                                    (value!([
+                                                                                                                                        "emit" => "rvalue",
+                                                                                                                                        "children" =>
+                                                                                                                                            (value!([
+                                                                                                                                                (value!([
+                                                                                                                                                    "emit" => "value_string",
+                                                                                                                                                    "value" => ""
+                                                                                                                                                ])),
+                                                                                                                                                (value!([
+                                                                                                                                                    "emit" => "attribute",
+                                                                                                                                                    "children" =>
+                                                                                                                                                        (value!([
+                                                                                                                                                            "emit" => "value_string",
+                                                                                                                                                            "value" => "join"
+                                                                                                                                                        ]))
+                                                                                                                                                ]))
+                                                                                                                                            ]))
+                                                                                                                                    ])),
+                                                                                                                                    (value!([
+                                                                                                                                        "emit" => "callarg",
+                                                                                                                                        "children" =>
+                                                                                                                                            (value!([
+                                                                                                                                                "emit" => "capture_index",
+                                                                                                                                                "children" =>
+                                                                                                                                                    (value!([
+                                                                                                                                                        "emit" => "value_integer",
+                                                                                                                                                        "value" => 4
+                                                                                                                                                    ]))
+                                                                                                                                            ]))
+                                                                                                                                    ]))
+                                                                                                                                ]))
+                                                                                                                        ]))
+                                                                                                                    ]))
+                                                                                                            ]))
+                                                                                                    ]))
+                                                                                                ]))
+                                                                                        ]))
+                                                                                    ]))
+                                                                            ]))
+                                                                    ]))
+                                                                ]))
+                                                        ])),
+                                                        (value!([
+                                                            "emit" => "constant",
+                                                            "children" =>
+                                                                (value!([
+                                                                    (value!([
+                                                                        "emit" => "identifier",
+                                                                        "value" => "Element"
+                                                                    ])),
+                                                                    (value!([
+                                                                        "emit" => "value_parselet",
+                                                                        "children" =>
+                                                                            (value!([
+                                                                                "emit" => "body",
+                                                                                "children" =>
+                                                                                    (value!([
+                                                                                        "emit" => "sequence",
+                                                                                        "children" =>
+                                                                                            (value!([
+                                                                                                (value!([
+                                                                                                    "emit" => "value_token_touch",
+                                                                                                    "value" => "["
+                                                                                                ])),
+                                                                                                (value!([
+                                                                                                    "emit" => "value_token_ccls",
+                                                                                                    "children" =>
+                                                                                                        (value!([
+                                                                                                            "emit" => "ccl_neg",
+                                                                                                            "children" =>
+                                                                                                                (value!([
+                                                                                                                    (value!([
+                                                                                                                        "emit" => "char",
+                                                                                                                        "value" => " "
+                                                                                                                    ])),
+                                                                                                                    (value!([
+                                                                                                                        "emit" => "char",
+                                                                                                                        "value" => "]"
+                                                                                                                    ])),
+                                                                                                                    (value!([
+                                                                                                                        "emit" => "char",
+                                                                                                                        "value" => "\""
+                                                                                                                    ])),
+                                                                                                                    (value!([
+                                                                                                                        "emit" => "char",
+                                                                                                                        "value" => "="
+                                                                                                                    ]))
+                                                                                                                ]))
+                                                                                                        ]))
+                                                                                                ])),
+                                                                                                (value!([
+                                                                                                    "emit" => "value_generic",
+                                                                                                    "children" =>
+                                                                                                        (value!([
+                                                                                                            (value!([
+                                                                                                                "emit" => "identifier",
+                                                                                                                "value" => "Kle"
+                                                                                                            ])),
+                                                                                                            (value!([
+                                                                                                                "emit" => "genarg",
+                                                                                                                "children" =>
+                                                                                                                    (value!([
+                                                                                                                        "emit" => "identifier",
+                                                                                                                        "value" => "Param"
+                                                                                                                    ]))
+                                                                                                            ])),
+                                                                                                            (value!([
+                                                                                                                "emit" => "genarg_named",
+                                                                                                                "children" =>
+                                                                                                                    (value!([
+                                                                                                                        (value!([
+                                                                                                                            "emit" => "identifier",
+                                                                                                                            "value" => "blur"
+                                                                                                                        ])),
+                                                                                                                        (value!([
+                                                                                                                            "emit" => "value_false"
+                                                                                                                        ]))
+                                                                                                                    ]))
+                                                                                                            ]))
+                                                                                                        ]))
+                                                                                                ])),
+                                                                                                (value!([
+                                                                                                    "emit" => "value_token_touch",
+                                                                                                    "value" => "]"
+                                                                                                ])),
+                                                                                                (value!([
+                                                                                                    "emit" => "block",
+                                                                                                    "children" =>
+                                                                                                        (value!([
+                                                                                                            (value!([
+                                                                                                                "emit" => "assign_drop",
+                                                                                                                "children" =>
+                                                                                                                    (value!([
+                                                                                                                        (value!([
+                                                                                                                            "emit" => "lvalue",
+                                                                                                                            "children" =>
+                                                                                                                                (value!([
+                                                                                                                                    "emit" => "identifier",
+                                                                                                                                    "value" => "params"
+                                                                                                                                ]))
+                                                                                                                        ])),
+                                                                                                                        (value!([
+                                                                                                                            "emit" => "call",
+                                                                                                                            "children" =>
+                                                                                                                                (value!([
+                                                                                                                                    "emit" => "identifier",
+                                                                                                                                    "value" => "dict"
+                                                                                                                                ]))
+                                                                                                                        ]))
+                                                                                                                    ]))
+                                                                                                            ])),
+                                                                                                            (value!([
+                                                                                                                "emit" => "op_for",
+                                                                                                                "children" =>
+                                                                                                                    (value!([
+                                                                                                                        (value!([
+                                                                                                                            "emit" => "lvalue",
+                                                                                                                            "children" =>
+                                                                                                                                (value!([
+                                                                                                                                    "emit" => "identifier",
+                                                                                                                                    "value" => "param"
+                                                                                                                                ]))
+                                                                                                                        ])),
+                                                                                                                        (value!([
+                                                                                                                            "emit" => "capture_index",
+                                                                                                                            "children" =>
+                                                                                                                                (value!([
+                                                                                                                                    "emit" => "value_integer",
+                                                                                                                                    "value" => 3
+                                                                                                                                ]))
+                                                                                                                        ])),
+                                                                                                                        (value!([
+                                                                                                                            "emit" => "block",
+                                                                                                                            "children" =>
+                                                                                                                                (value!([
+                                                                                                                                    "emit" => "assign_drop",
+                                                                                                                                    "children" =>
+                                                                                                                                        (value!([
+                                                                                                                                            (value!([
+                                                                                                                                                "emit" => "lvalue",
+                                                                                                                                                "children" =>
+                                                                                                                                                    (value!([
+                                                                                                                                                        (value!([
+                                                                                                                                                            "emit" => "identifier",
+                                                                                                                                                            "value" => "params"
+                                                                                                                                                        ])),
+                                                                                                                                                        (value!([
+                                                                                                                                                            "emit" => "item",
+                                                                                                                                                            "children" =>
+                                                                                                                                                                (value!([
+                                                                                                                                                                    "emit" => "rvalue",
+                                                                                                                                                                    "children" =>
+                                                                                                                                                                        (value!([
+                                                                                                                                                                            (value!([
+                                                                                                                                                                                "emit" => "identifier",
+                                                                                                                                                                                "value" => "param"
+                                                                                                                                                                            ])),
+                                                                                                                                                                            (value!([
+                                                                                                                                                                                "emit" => "item",
+                                                                                                                                                                                "children" =>
+                                                                                                                                                                                    (value!([
+                                                                                                                                                                                        "emit" => "value_integer",
+                                                                                                                                                                                        "value" => 0
+                                                                                                                                                                                    ]))
+                                                                                                                                                                            ]))
+                                                                                                                                                                        ]))
+                                                                                                                                                                ]))
+                                                                                                                                                        ]))
+                                                                                                                                                    ]))
+                                                                                                                                            ])),
+                                                                                                                                            (value!([
+                                                                                                                                                "emit" => "rvalue",
+                                                                                                                                                "children" =>
+                                                                                                                                                    (value!([
+                                                                                                                                                        (value!([
+                                                                                                                                                            "emit" => "identifier",
+                                                                                                                                                            "value" => "param"
+                                                                                                                                                        ])),
+                                                                                                                                                        (value!([
+                                                                                                                                                            "emit" => "item",
+                                                                                                                                                            "children" =>
+                                                                                                                                                                (value!([
+                                                                                                                                                                    "emit" => "value_integer",
+                                                                                                                                                                    "value" => 1
+                                                                                                                                                                ]))
+                                                                                                                                                        ]))
+                                                                                                                                                    ]))
+                                                                                                                                            ]))
+                                                                                                                                        ]))
+                                                                                                                                ]))
+                                                                                                                        ]))
+                                                                                                                    ]))
+                                                                                                            ])),
+                                                                                                            (value!([
+                                                                                                                "emit" => "list",
+                                                                                                                "children" =>
+                                                                                                                    (value!([
+                                                                                                                        (value!([
+                                                                                                                            "emit" => "capture_index",
+                                                                                                                            "children" =>
+                                                                                                                                (value!([
+                                                                                                                                    "emit" => "value_integer",
+                                                                                                                                    "value" => 2
+                                                                                                                                ]))
+                                                                                                                        ])),
+                                                                                                                        (value!([
+                                                                                                                            "emit" => "identifier",
+                                                                                                                            "value" => "params"
+                                                                                                                        ]))
+                                                                                                                    ]))
+                                                                                                            ]))
+                                                                                                        ]))
+                                                                                                ]))
+                                                                                            ]))
+                                                                                    ]))
+                                                                            ]))
+                                                                    ]))
+                                                                ]))
+                                                        ])),
+                                                        (value!([
+                                                            "emit" => "constant",
+                                                            "children" =>
+                                                                (value!([
+                                                                    (value!([
+                                                                        "emit" => "identifier",
+                                                                        "value" => "Data"
+                                                                    ])),
+                                                                    (value!([
+                                                                        "emit" => "value_parselet",
+                                                                        "children" =>
+                                                                            (value!([
+                                                                                "emit" => "body",
+                                                                                "children" =>
+                                                                                    (value!([
+                                                                                        (value!([
+                                                                                            "emit" => "sequence",
+                                                                                            "children" =>
+                                                                                                (value!([
+                                                                                                    (value!([
+                                                                                                        "emit" => "value_token_touch",
+                                                                                                        "value" => "-"
+                                                                                                    ])),
+                                                                                                    (value!([
+                                                                                                        "emit" => "value_null"
+                                                                                                    ]))
+                                                                                                ]))
+                                                                                        ])),
+                                                                                        (value!([
+                                                                                            "emit" => "sequence",
+                                                                                            "children" =>
+                                                                                                (value!([
+                                                                                                    (value!([
+                                                                                                        "emit" => "value_generic",
+                                                                                                        "children" =>
+                                                                                                            (value!([
+                                                                                                                (value!([
+                                                                                                                    "emit" => "identifier",
+                                                                                                                    "value" => "Pos"
+                                                                                                                ])),
+                                                                                                                (value!([
+                                                                                                                    "emit" => "genarg",
+                                                                                                                    "children" =>
+                                                                                                                        (value!([
+                                                                                                                            "emit" => "identifier",
+                                                                                                                            "value" => "Element"
+                                                                                                                        ]))
+                                                                                                                ])),
+                                                                                                                (value!([
+                                                                                                                    "emit" => "genarg_named",
+                                                                                                                    "children" =>
+                                                                                                                        (value!([
+                                                                                                                            (value!([
+                                                                                                                                "emit" => "identifier",
+                                                                                                                                "value" => "blur"
+                                                                                                                            ])),
+                                                                                                                            (value!([
+                                                                                                                                "emit" => "value_false"
+                                                                                                                            ]))
+                                                                                                                        ]))
+                                                                                                                ]))
+                                                                                                            ]))
+                                                                                                    ])),
+                                                                                                    (value!([
+                                                                                                        "emit" => "block",
+                                                                                                        "children" =>
+                                                                                                            (value!([
+                                                                                                                (value!([
+                                                                                                                    "emit" => "assign_drop",
+                                                                                                                    "children" =>
+                                                                                                                        (value!([
+                                                                                                                            (value!([
+                                                                                                                                "emit" => "lvalue",
+                                                                                                                                "children" =>
+                                                                                                                                    (value!([
+                                                                                                                                        "emit" => "identifier",
+                                                                                                                                        "value" => "data"
+                                                                                                                                    ]))
+                                                                                                                            ])),
+                                                                                                                            (value!([
+                                                                                                                                "emit" => "call",
+                                                                                                                                "children" =>
+                                                                                                                                    (value!([
+                                                                                                                                        "emit" => "identifier",
+                                                                                                                                        "value" => "dict"
+                                                                                                                                    ]))
+                                                                                                                            ]))
+                                                                                                                        ]))
+                                                                                                                ])),
+                                                                                                                (value!([
+                                                                                                                    "emit" => "op_for",
+                                                                                                                    "children" =>
+                                                                                                                        (value!([
+                                                                                                                            (value!([
+                                                                                                                                "emit" => "lvalue",
+                                                                                                                                "children" =>
+                                                                                                                                    (value!([
+                                                                                                                                        "emit" => "identifier",
+                                                                                                                                        "value" => "element"
+                                                                                                                                    ]))
+                                                                                                                            ])),
+                                                                                                                            (value!([
+                                                                                                                                "emit" => "capture_index",
+                                                                                                                                "children" =>
+                                                                                                                                    (value!([
+                                                                                                                                        "emit" => "value_integer",
+                                                                                                                                        "value" => 1
+                                                                                                                                    ]))
+                                                                                                                            ])),
+                                                                                                                            (value!([
+                                                                                                                                "emit" => "block",
+                                                                                                                                "children" =>
+                                                                                                                                    (value!([
+                                                                                                                                        "emit" => "assign_drop",
+                                                                                                                                        "children" =>
+                                                                                                                                            (value!([
+                                                                                                                                                (value!([
+                                                                                                                                                    "emit" => "lvalue",
+                                                                                                                                                    "children" =>
+                                                                                                                                                        (value!([
+                                                                                                                                                            (value!([
+                                                                                                                                                                "emit" => "identifier",
+                                                                                                                                                                "value" => "data"
+                                                                                                                                                            ])),
+                                                                                                                                                            (value!([
+                                                                                                                                                                "emit" => "item",
+                                                                                                                                                                "children" =>
+                                                                                                                                                                    (value!([
+                                                                                                                                                                        "emit" => "rvalue",
+                                                                                                                                                                        "children" =>
+                                                                                                                                                                            (value!([
+                                                                                                                                                                                (value!([
+                                                                                                                                                                                    "emit" => "identifier",
+                                                                                                                                                                                    "value" => "element"
+                                                                                                                                                                                ])),
+                                                                                                                                                                                (value!([
+                                                                                                                                                                                    "emit" => "item",
+                                                                                                                                                                                    "children" =>
+                                                                                                                                                                                        (value!([
+                                                                                                                                                                                            "emit" => "value_integer",
+                                                                                                                                                                                            "value" => 0
+                                                                                                                                                                                        ]))
+                                                                                                                                                                                ]))
+                                                                                                                                                                            ]))
+                                                                                                                                                                    ]))
+                                                                                                                                                            ]))
+                                                                                                                                                        ]))
+                                                                                                                                                ])),
+                                                                                                                                                (value!([
+                                                                                                                                                    "emit" => "rvalue",
+                                                                                                                                                    "children" =>
+                                                                                                                                                        (value!([
+                                                                                                                                                            (value!([
+                                                                                                                                                                "emit" => "identifier",
+                                                                                                                                                                "value" => "element"
+                                                                                                                                                            ])),
+                                                                                                                                                            (value!([
+                                                                                                                                                                "emit" => "item",
+                                                                                                                                                                "children" =>
+                                                                                                                                                                    (value!([
+                                                                                                                                                                        "emit" => "value_integer",
+                                                                                                                                                                        "value" => 1
+                                                                                                                                                                    ]))
+                                                                                                                                                            ]))
+                                                                                                                                                        ]))
+                                                                                                                                                ]))
+                                                                                                                                            ]))
+                                                                                                                                    ]))
+                                                                                                                            ]))
+                                                                                                                        ]))
+                                                                                                                ])),
+                                                                                                                (value!([
+                                                                                                                    "emit" => "identifier",
+                                                                                                                    "value" => "data"
+                                                                                                                ]))
+                                                                                                            ]))
+                                                                                                    ]))
+                                                                                                ]))
+                                                                                        ]))
+                                                                                    ]))
+                                                                            ]))
+                                                                    ]))
+                                                                ]))
+                                                        ])),
+                                                        (value!([
+                                                            "emit" => "constant",
+                                                            "children" =>
+                                                                (value!([
+                                                                    (value!([
+                                                                        "emit" => "identifier",
+                                                                        "value" => "Message"
+                                                                    ])),
+                                                                    (value!([
+                                                                        "emit" => "value_parselet",
+                                                                        "children" =>
+                                                                            (value!([
+                                                                                "emit" => "body",
+                                                                                "children" =>
+                                                                                    (value!([
+                                                                                        (value!([
+                                                                                            "emit" => "sequence",
+                                                                                            "children" =>
+                                                                                                (value!([
+                                                                                                    (value!([
+                                                                                                        "emit" => "value_token_touch",
+                                                                                                        "value" => " "
+                                                                                                    ])),
+                                                                                                    (value!([
+                                                                                                        "emit" => "op_mod_opt",
+                                                                                                        "children" =>
+                                                                                                            (value!([
+                                                                                                                "emit" => "value_token_ccl",
+                                                                                                                "children" =>
+                                                                                                                    (value!([
+                                                                                                                        "emit" => "ccl",
+                                                                                                                        "children" =>
+                                                                                                                            (value!([
+                                                                                                                                "emit" => "char",
+                                                                                                                                "value" => "﻿"
+                                                                                                                            ]))
+                                                                                                                    ]))
+                                                                                                            ]))
+                                                                                                    ])),
+                                                                                                    (value!([
+                                                                                                        "emit" => "value_token_ccls",
+                                                                                                        "children" =>
+                                                                                                            (value!([
+                                                                                                                "emit" => "ccl_neg",
+                                                                                                                "children" =>
+                                                                                                                    (value!([
+                                                                                                                        (value!([
+                                                                                                                            "emit" => "char",
+                                                                                                                            "value" => "\r"
+                                                                                                                        ])),
+                                                                                                                        (value!([
+                                                                                                                            "emit" => "char",
+                                                                                                                            "value" => "\n"
+                                                                                                                        ]))
+                                                                                                                    ]))
+                                                                                                            ]))
+                                                                                                    ])),
+                                                                                                    (value!([
+                                                                                                        "emit" => "capture_index",
+                                                                                                        "children" =>
+                                                                                                            (value!([
+                                                                                                                "emit" => "value_integer",
+                                                                                                                "value" => 3
+                                                                                                            ]))
+                                                                                                    ]))
+                                                                                                ]))
+                                                                                        ])),
+                                                                                        (value!([
+                                                                                            "emit" => "sequence",
+                                                                                            "children" =>
+                                                                                                (value!([
+                                                                                                    (value!([
+                                                                                                        "emit" => "op_mod_opt",
+                                                                                                        "children" =>
+                                                                                                            (value!([
+                                                                                                                "emit" => "value_token_touch",
+                                                                                                                "value" => " "
+                                                                                                            ]))
+                                                                                                    ])),
+                                                                                                    (value!([
+                                                                                                        "emit" => "value_string",
+                                                                                                        "value" => ""
+                                                                                                    ]))
+                                                                                                ]))
+                                                                                        ]))
+                                                                                    ]))
+                                                                            ]))
+                                                                    ]))
+                                                                ]))
+                                                        ])),
+                                                        (value!([
+                                                            "emit" => "sequence",
+                                                            "children" =>
+                                                                (value!([
+                                                                    (value!([
+                                                                        "emit" => "value_token_touch",
+                                                                        "value" => "<"
+                                                                    ])),
+                                                                    (value!([
+                                                                        "emit" => "identifier",
+                                                                        "value" => "Int"
+                                                                    ])),
+                                                                    (value!([
+                                                                        "emit" => "value_token_touch",
+                                                                        "value" => ">"
+                                                                    ])),
+                                                                    (value!([
+                                                                        "emit" => "identifier",
+                                                                        "value" => "Int"
+                                                                    ])),
+                                                                    (value!([
+                                                                        "emit" => "value_token_touch",
+                                                                        "value" => " "
+                                                                    ])),
+                                                                    (value!([
+                                                                        "emit" => "identifier",
+                                                                        "value" => "Time"
+                                                                    ])),
+                                                                    (value!([
+                                                                        "emit" => "value_token_touch",
+                                                                        "value" => " "
+                                                                    ])),
+                                                                    (value!([
+                                                                        "emit" => "identifier",
+                                                                        "value" => "Field"
+                                                                    ])),
+                                                                    (value!([
+                                                                        "emit" => "value_token_touch",
+                                                                        "value" => " "
+                                                                    ])),
+                                                                    (value!([
+                                                                        "emit" => "identifier",
+                                                                        "value" => "Field"
+                                                                    ])),
+                                                                    (value!([
+                                                                        "emit" => "value_token_touch",
+                                                                        "value" => " "
+                                                                    ])),
+                                                                    (value!([
+                                                                        "emit" => "identifier",
+                                                                        "value" => "Field"
+                                                                    ])),
+                                                                    (value!([
+                                                                        "emit" => "value_token_touch",
+                                                                        "value" => " "
+                                                                    ])),
+                                                                    (value!([
+                                                                        "emit" => "identifier",
+                                                                        "value" => "Field"
+                                                                    ])),
+                                                                    (value!([
+                                                                        "emit" => "value_token_touch",
+                                                                        "value" => " "
+                                                                    ])),
+                                                                    (value!([
+                                                                        "emit" => "identifier",
+                                                                        "value" => "Data"
+                                                                    ])),
+                                                                    (value!([
+                                                                        "emit" => "identifier",
+                                                                        "value" => "Message"
+                                                                    ])),
+                                                                    (value!([
+                                                                        "emit" => "block",
+                                                                        "children" =>
+                                                                            (value!([
+                                                                                "emit" => "sequence",
+                                                                                "children" =>
+                                                                                    (value!([
+                                                                                        (value!([
+                                                                                            "emit" => "alias",
+                                                                                            "children" =>
+                                                                                                (value!([
+                                                                                                    (value!([
+                                                                                                        "emit" => "value_string",
+                                                                                                        "value" => "priority"
+                                                                                                    ])),
+                                                                                                    (value!([
+                                                                                                        "emit" => "capture_index",
+                                                                                                        "children" =>
+                                                                                                            (value!([
+                                                                                                                "emit" => "value_integer",
+                                                                                                                "value" => 2
+                                                                                                            ]))
+                                                                                                    ]))
+                                                                                                ]))
+                                                                                        ])),
+                                                                                        (value!([
+                                                                                            "emit" => "alias",
+                                                                                            "children" =>
+                                                                                                (value!([
+                                                                                                    (value!([
+                                                                                                        "emit" => "value_string",
+                                                                                                        "value" => "facility"
+                                                                                                    ])),
+                                                                                                    (value!([
+                                                                                                        "emit" => "op_binary_divi",
+                                                                                                        "children" =>
+                                                                                                            (value!([
+                                                                                                                (value!([
+                                                                                                                    "emit" => "capture_index",
+                                                                                                                    "children" =>
+                                                                                                                        (value!([
+                                                                                                                            "emit" => "value_integer",
+                                                                                                                            "value" => 2
+                                                                                                                        ]))
+                                                                                                                ])),
+                                                                                                                (value!([
+                                                                                                                    "emit" => "value_integer",
+                                                                                                                    "value" => 8
+                                                                                                                ]))
+                                                                                                            ]))
+                                                                                                    ]))
+                                                                                                ]))
+                                                                                        ])),
+                                                                                        (value!([
+                                                                                            "emit" => "alias",
+                                                                                            "children" =>
+                                                                                                (value!([
+                                                                                                    (value!([
+                                                                                                        "emit" => "value_string",
+                                                                                                        "value" => "severity"
+                                                                                                    ])),
+                                                                                                    (value!([
+                                                                                                        "emit" => "op_binary_mod",
+                                                                                                        "children" =>
+                                                                                                            (value!([
+                                                                                                                (value!([
+                                                                                                                    "emit" => "capture_index",
+                                                                                                                    "children" =>
+                                                                                                                        (value!([
+                                                                                                                            "emit" => "value_integer",
+                                                                                                                            "value" => 2
+                                                                                                                        ]))
+                                                                                                                ])),
+                                                                                                                (value!([
+                                                                                                                    "emit" => "value_integer",
+                                                                                                                    "value" => 8
+                                                                                                                ]))
+                                                                                                            ]))
+                                                                                                    ]))
+                                                                                                ]))
+                                                                                        ])),
+                                                                                        (value!([
+                                                                                            "emit" => "alias",
+                                                                                            "children" =>
+                                                                                                (value!([
+                                                                                                    (value!([
+                                                                                                        "emit" => "value_string",
+                                                                                                        "value" => "version"
+                                                                                                    ])),
+                                                                                                    (value!([
+                                                                                                        "emit" => "capture_index",
+                                                                                                        "children" =>
+                                                                                                            (value!([
+                                                                                                                "emit" => "value_integer",
+                                                                                                                "value" => 4
+                                                                                                            ]))
+                                                                                                    ]))
+                                                                                                ]))
+                                                                                        ])),
+                                                                                        (value!([
+                                                                                            "emit" => "alias",
+                                                                                            "children" =>
+                                                                                                (value!([
+                                                                                                    (value!([
+                                                                                                        "emit" => "value_string",
+                                                                                                        "value" => "timestamp"
+                                                                                                    ])),
+                                                                                                    (value!([
+                                                                                                        "emit" => "capture_index",
+                                                                                                        "children" =>
+                                                                                                            (value!([
+                                                                                                                "emit" => "value_integer",
+                                                                                                                "value" => 6
+                                                                                                            ]))
+                                                                                                    ]))
+                                                                                                ]))
+                                                                                        ])),
+                                                                                        (value!([
+                                                                                            "emit" => "alias",
+                                                                                            "children" =>
+                                                                                                (value!([
+                                                                                                    (value!([
+                                                                                                        "emit" => "value_string",
+                                                                                                        "value" => "hostname"
+                                                                                                    ])),
+                                                                                                    (value!([
+                                                                                                        "emit" => "capture_index",
+                                                                                                        "children" =>
+                                                                                                            (value!([
+                                                                                                                "emit" => "value_integer",
+                                                                                                                "value" => 8
+                                                                                                            ]))
+                                                                                                    ]))
+                                                                                                ]))
+                                                                                        ])),
+                                                                                        (value!([
+                                                                                            "emit" => "alias",
+                                                                                            "children" =>
+                                                                                                (value!([
+                                                                                                    (value!([
+                                                                                                        "emit" => "value_string",
+                                                                                                        "value" => "appname"
+                                                                                                    ])),
+                                                                                                    (value!([
+                                                                                                        "emit" => "capture_index",
+                                                                                                        "children" =>
+                                                                                                            (value!([
+                                                                                                                "emit" => "value_integer",
+                                                                                                                "value" => 10
+                                                                                                            ]))
+                                                                                                    ]))
+                                                                                                ]))
+                                                                                        ])),
+                                                                                        (value!([
+                                                                                            "emit" => "alias",
+                                                                                            "children" =>
+                                                                                                (value!([
+                                                                                                    (value!([
+                                                                                                        "emit" => "value_string",
+                                                                                                        "value" => "procid"
+                                                                                                    ])),
+                                                                                                    (value!([
+                                                                                                        "emit" => "capture_index",
+                                                                                                        "children" =>
+                                                                                                            (value!([
+                                                                                                                "emit" => "value_integer",
+                                                                                                                "value" => 12
+                                                                                                            ]))
+                                                                                                    ]))
+                                                                                                ]))
+                                                                                        ])),
+                                                                                        (value!([
+                                                                                            "emit" => "alias",
+                                                                                            "children" =>
+                                                                                                (value!([
+                                                                                                    (value!([
+                                                                                                        "emit" => "value_string",
+                                                                                                        "value" => "msgid"
+                                                                                                    ])),
+                                                                                                    (value!([
+                                                                                                        "emit" => "capture_index",
+                                                                                                        "children" =>
+                                                                                                            (value!([
+                                                                                                                "emit" => "value_integer",
+                                                                                                                "value" => 14
+                                                                                                            ]))
+                                                                                                    ]))
+                                                                                                ]))
+                                                                                        ])),
+                                                                                        (value!([
+                                                                                            "emit" => "alias",
+                                                                                            "children" =>
+                                                                                                (value!([
+                                                                                                    (value!([
+                                                                                                        "emit" => "value_string",
+                                                                                                        "value" => "data"
+                                                                                                    ])),
+                                                                                                    (value!([
+                                                                                                        "emit" => "capture_index",
+                                                                                                        "children" =>
+                                                                                                            (value!([
+                                                                                                                "emit" => "value_integer",
+                                                                                                                "value" => 16
+                                                                                                            ]))
+                                                                                                    ]))
+                                                                                                ]))
+                                                                                        ])),
+                                                                                        (value!([
+                                                                                            "emit" => "alias",
+                                                                                            "children" =>
+                                                                                                (value!([
+                                                                                                    (value!([
+                                                                                                        "emit" => "value_string",
+                                                                                                        "value" => "message"
+                                                                                                    ])),
+                                                                                                    (value!([
+                                                                                                        "emit" => "capture_index",
+                                                                                                        "children" =>
+                                                                                                            (value!([
+                                                                                                                "emit" => "value_integer",
+                                                                                                                "value" => 17
+                                                                                                            ]))
+                                                                                                    ]))
+                                                                                                ]))
+                                                                                        ]))
+                                                                                    ]))
+                                                                            ]))
+                                                                    ]))
+                                                                ]))
+                                                        ]))
+                                                    ]))
+                                            ]))
+                                    ]))
+                                ]))
+                        ])),
+                        (value!([
+                            "emit" => "constant",
+                            "children" =>
+                                (value!([
+                                    (value!([
+                                        "emit" => "identifier",
+                                        "value" => "Diff"
+                                    ])),
+                                    (value!([
+                                        "emit" => "value_parselet",
+                                        "children" =>
+                                            (value!([
+                                                "emit" => "body",
+                                                "children" =>
+                                                    (value!([
+                                                        (value!([
+                                                            "emit" => "constant",
+                                                            "children" =>
+                                                                (value!([
+                                                                    (value!([
+                                                                        "emit" => "identifier",
+                                                                        "value" => "Line"
+                                                                    ])),
+                                                                    (value!([
+                                                                        "emit" => "value_parselet",
+                                                                        "children" =>
+                                                                            (value!([
+                                                                                "emit" => "body",
+                                                                                "children" =>
+                                                                                    (value!([
+                                                                                        "emit" => "sequence",
+                                                                                        "children" =>
+                                                                                            (value!([
+                                                                                                (value!([
+                                                                                                    "emit" => "value_generic",
+                                                                                                    "children" =>
+                                                                                                        (value!([
+                                                                                                            (value!([
+                                                                                                                "emit" => "identifier",
+                                                                                                                "value" => "Until"
+                                                                                                            ])),
+                                                                                                            (value!([
+                                                                                                                "emit" => "genarg",
+                                                                                                                "children" =>
+                                                                                                                    (value!([
+                                                                                                                        "emit" => "block",
+                                                                                                                        "children" =>
+                                                                                                                            (value!([
+                                                                                                                                (value!([
+                                                                                                                                    "emit" => "value_token_ccl",
+                                                                                                                                    "children" =>
+                                                                                                                                        (value!([
+                                                                                                                                            "emit" => "ccl",
+                                                                                                                                            "children" =>
+                                                                                                                                                (value!([
+                                                                                                                                                    "emit" => "char",
+                                                                                                                                                    "value" => "\n"
+                                                                                                                                                ]))
+                                                                                                                                        ]))
+                                                                                                                                ])),
+                                                                                                                                (value!([
+                                                                                                                                    "emit" => "identifier",
+                                                                                                                                    "value" => "EOF"
+                                                                                                                                ]))
+                                                                                                                            ]))
+                                                                                                                    ]))
+                                                                                                            ])),
+                                                                                                            (value!([
+                                                                                                                "emit" => "genarg_named",
+                                                                                                                "children" =>
+                                                                                                                    (value!([
+                                                                                                                        (value!([
+                                                                                                                            "emit" => "identifier",
+                                                                                                                            "value" => "empty"
+                                                                                                                        ])),
+                                                                                                                        (value!([
+                                                                                                                            "emit" => "value_true"
+                                                                                                                        ]))
+                                                                                                                    ]))
+                                                                                                            ]))
+                                                                                                        ]))
+                                                                                                ])),
+                                                                                                (value!([
+                                                                                                    "emit" => "op_mod_opt",
+                                                                                                    "children" =>
+                                                                                                        (value!([
+                                                                                                            "emit" => "value_token_ccl",
+                                                                                                            "children" =>
+                                                                                                                (value!([
+                                                                                                                    "emit" => "ccl",
+                                                                                                                    "children" =>
+                                                                                                                        (value!([
+                                                                                                                            "emit" => "char",
+                                                                                                                            "value" => "\n"
+                                                                                                                        ]))
+                                                                                                                ]))
+                                                                                                        ]))
+                                                                                                ])),
+                                                                                                (value!([
+                                                                                                    "emit" => "capture_index",
+                                                                                                    "children" =>
+                                                                                                        (value!([
+                                                                                                            "emit" => "value_integer",
+                                                                                                            "value" => 1
+                                                                                                        ]))
+                                                                                                ]))
+                                                                                            ]))
+                                                                                    ]))
+                                                                            ]))
+                                                                    ]))
+                                                                ]))
+                                                        ])),
+                                                        (value!([
+                                                            "emit" => "constant",
+                                                            "children" =>
+                                                                (value!([
+                                                                    (value!([
+                                                                        "emit" => "identifier",
+                                                                        "value" => "Header"
+                                                                    ])),
+                                                                    (value!([
+                                                                        "emit" => "value_parselet",
+                                                                        "children" =>
+                                                                            (value!([
+                                                                                "emit" => "body",
+                                                                                "children" =>
+                                                                                    (value!([
+                                                                                        "emit" => "sequence",
+                                                                                        "children" =>
+                                                                                            (value!([
+                                                                                                (value!([
+                                                                                                    "emit" => "value_generic",
+                                                                                                    "children" =>
+                                                                                                        (value!([
+                                                                                                            (value!([
+                                                                                                                "emit" => "identifier",
+                                                                                                                "value" => "Not"
+                                                                                                            ])),
+                                                                                                            (value!([
+                                                                                                                "emit" => "genarg",
+                                                                                                                "children" =>
+                                                                                                                    (value!([
+                                                                                                                        "emit" => "block",
+                                                                                                                        "children" =>
+                                                                                                                            (value!([
+                                                                                                                                (value!([
+                                                                                                                                    "emit" => "value_token_match",
+                                                                                                                                    "value" => "--- "
+                                                                                                                                ])),
+                                                                                                                                (value!([
+                                                                                                                                    "emit" => "value_token_match",
+                                                                                                                                    "value" => "+++ "
+                                                                                                                                ])),
+                                                                                                                                (value!([
+                                                                                                                                    "emit" => "value_token_match",
+                                                                                                                                    "value" => "@@ "
+                                                                                                                                ]))
+                                                                                                                            ]))
+                                                                                                                    ]))
+                                                                                                            ]))
+                                                                                                        ]))
+                                                                                                ])),
+                                                                                                (value!([
+                                                                                                    "emit" => "identifier",
+                                                                                                    "value" => "Line"
+                                                                                                ]))
+                                                                                            ]))
+                                                                                    ]))
+                                                                            ]))
+                                                                    ]))
+                                                                ]))
+                                                        ])),
+                                                        (value!([
+                                                            "emit" => "constant",
+                                                            "children" =>
+                                                                (value!([
+                                                                    (value!([
+                                                                        "emit" => "identifier",
+                                                                        "value" => "Path"
+                                                                    ])),
+                                                                    (value!([
+                                                                        "emit" => "value_parselet",
+                                                                        "children" =>
+                                                                            (value!([
+                                                                                "emit" => "body",
+                                                                                "children" =>
+                                                                                    (value!([
+                                                                                        "emit" => "sequence",
+                                                                                        "children" =>
+                                                                                            (value!([
+                                                                                                (value!([
+                                                                                                    "emit" => "value_generic",
+                                                                                                    "children" =>
+                                                                                                        (value!([
+                                                                                                            (value!([
+                                                                                                                "emit" => "identifier",
+                                                                                                                "value" => "Until"
+                                                                                                            ])),
+                                                                                                            (value!([
+                                                                                                                "emit" => "genarg",
+                                                                                                                "children" =>
+                                                                                                                    (value!([
+                                                                                                                        "emit" => "value_token_ccl",
+                                                                                                                        "children" =>
+                                                                                                                            (value!([
+                                                                                                                                "emit" => "ccl",
+                                                                                                                                "children" =>
+                                                                                                                                    (value!([
+                                                                                                                                        (value!([
+                                                                                                                                            "emit" => "char",
+                                                                                                                                            "value" => "\t"
+                                                                                                                                        ])),
+                                                                                                                                        (value!([
+                                                                                                                                            "emit" => "char",
+                                                                                                                                            "value" => "\n"
+                                                                                                                                        ]))
+                                                                                                                                    ]))
+                                                                                                                            ]))
+                                                                                                                    ]))
+                                                                                                            ]))
+                                                                                                        ]))
+                                                                                                ])),
+                                                                                                (value!([
+                                                                                                    "emit" => "identifier",
+                                                                                                    "value" => "Line"
+                                                                                                ])),
+                                                                                                (value!([
+                                                                                                    "emit" => "capture_index",
+                                                                                                    "children" =>
+                                                                                                        (value!([
+                                                                                                            "emit" => "value_integer",
+                                                                                                            "value" => 1
+                                                                                                        ]))
+                                                                                                ]))
+                                                                                            ]))
+                                                                                    ]))
+                                                                            ]))
+                                                                    ]))
+                                                                ]))
+                                                        ])),
+                                                        (value!([
+                                                            "emit" => "constant",
+                                                            "children" =>
+                                                                (value!([
+                                                                    (value!([
+                                                                        "emit" => "identifier",
+                                                                        "value" => "Range"
+                                                                    ])),
+                                                                    (value!([
+                                                                        "emit" => "value_parselet",
+                                                                        "children" =>
+                                                                            (value!([
+                                                                                "emit" => "body",
+                                                                                "children" =>
+                                                                                    (value!([
+                                                                                        (value!([
+                                                                                            "emit" => "sequence",
+                                                                                            "children" =>
+                                                                                                (value!([
+                                                                                                    (value!([
+                                                                                                        "emit" => "identifier",
+                                                                                                        "value" => "Int"
+                                                                                                    ])),
+                                                                                                    (value!([
+                                                                                                        "emit" => "value_token_touch",
+                                                                                                        "value" => ","
+                                                                                                    ])),
+                                                                                                    (value!([
+                                                                                                        "emit" => "identifier",
+                                                                                                        "value" => "Int"
+                                                                                                    ])),
+                                                                                                    (value!([
+                                                                                                        "emit" => "list",
+                                                                                                        "children" =>
+                                                                                                            (value!([
+                                                                                                                (value!([
+                                                                                                                    "emit" => "capture_index",
+                                                                                                                    "children" =>
+                                                                                                                        (value!([
+                                                                                                                            "emit" => "value_integer",
+                                                                                                                            "value" => 1
+                                                                                                                        ]))
+                                                                                                                ])),
+                                                                                                                (value!([
+                                                                                                                    "emit" => "capture_index",
+                                                                                                                    "children" =>
+                                                                                                                        (value!([
+                                                                                                                            "emit" => "value_integer",
+                                                                                                                            "value" => 3
+                                                                                                                        ]))
+                                                                                                                ]))
+                                                                                                            ]))
+                                                                                                    ]))
+                                                                                                ]))
+                                                                                        ])),
+                                                                                        (value!([
+                                                                                            "emit" => "sequence",
+                                                                                            "children" =>
+                                                                                                (value!([
+                                                                                                    (value!([
+                                                                                                        "emit" => "identifier",
+                                                                                                        "value" => "Int"
+                                                                                                    ])),
+                                                                                                    (value!([
+                                                                                                        "emit" => "list",
+                                                                                                        "children" =>
+                                                                                                            (value!([
+                                                                                                                (value!([
+                                                                                                                    "emit" => "capture_index",
+                                                                                                                    "children" =>
+                                                                                                                        (value!([
+                                                                                                                            "emit" => "value_integer",
+                                                                                                                            "value" => 1
+                                                                                                                        ]))
+                                                                                                                ])),
+                                                                                                                (value!([
+                                                                                                                    "emit" => "value_integer",
+                                                                                                                    "value" => 1
+                                                                                                                ]))
+                                                                                                            ]))
+                                                                                                    ]))
+                                                                                                ]))
+                                                                                        ]))
+                                                                                    ]))
+                                                                            ]))
+                                                                    ]))
+                                                                ]))
+                                                        ])),
+                                                        (value!([
+                                                            "emit" => "constant",
+                                                            "children" =>
+                                                                (value!([
+                                                                    (value!([
+                                                                        "emit" => "identifier",
+                                                                        "value" => "NoNewline"
+                                                                    ])),
+                                                                    (value!([
+                                                                        "emit" => "value_parselet",
+                                                                        "children" =>
+                                                                            (value!([
+                                                                                "emit" => "body",
+                                                                                "children" =>
+                                                                                    (value!([
+                                                                                        "emit" => "sequence",
+                                                                                        "children" =>
+                                                                                            (value!([
+                                                                                                (value!([
+                                                                                                    "emit" => "value_token_touch",
+                                                                                                    "value" => "\\"
+                                                                                                ])),
+                                                                                                (value!([
+                                                                                                    "emit" => "identifier",
+                                                                                                    "value" => "Line"
+                                                                                                ])),
+                                                                                                (value!([
+                                                                                                    "emit" => "value_void"
+                                                                                                ]))
+                                                                                            ]))
+                                                                                    ]))
+                                                                            ]))
+                                                                    ]))
+                                                                ]))
+                                                        ])),
+                                                        (value!([
+                                                            "emit" => "constant",
+                                                            "children" =>
+                                                                (value!([
+                                                                    (value!([
+                                                                        "emit" => "identifier",
+                                                                        "value" => "HunkLine"
+                                                                    ])),
+                                                                    (value!([
+                                                                        "emit" => "value_parselet",
+                                                                        "children" =>
+                                                                            (value!([
+                                                                                "emit" => "body",
+                                                                                "children" =>
+                                                                                    (value!([
+                                                                                        "emit" => "sequence",
+                                                                                        "children" =>
+                                                                                            (value!([
+                                                                                                (value!([
+                                                                                                    "emit" => "value_token_ccl",
+                                                                                                    "children" =>
+                                                                                                        (value!([
+                                                                                                            "emit" => "ccl",
+                                                                                                            "children" =>
+                                                                                                                (value!([
+                                                                                                                    (value!([
+                                                                                                                        "emit" => "char",
+                                                                                                                        "value" => "-"
+                                                                                                                    ])),
+                                                                                                                    (value!([
+                                                                                                                        "emit" => "char",
+                                                                                                                        "value" => " "
+                                                                                                                    ])),
+                                                                                                                    (value!([
+                                                                                                                        "emit" => "char",
+                                                                                                                        "value" => "+"
+                                                                                                                    ]))
+                                                                                                                ]))
+                                                                                                        ]))
+                                                                                                ])),
+                                                                                                (value!([
+                                                                                                    "emit" => "identifier",
+                                                                                                    "value" => "Line"
+                                                                                                ])),
+                                                                                                (value!([
+                                                                                                    "emit" => "op_mod_opt",
+                                                                                                    "children" =>
+                                                                                                        (value!([
+                                                                                                            "emit" => "identifier",
+                                                                                                            "value" => "NoNewline"
+                                                                                                        ]))
+                                                                                                ])),
+                                                                                                (value!([
+                                                                                                    "emit" => "sequence",
+                                                                                                    "children" =>
+                                                                                                        (value!([
+                                                                                                            (value!([
+                                                                                                                "emit" => "alias",
+                                                                                                                "children" =>
+                                                                                                                    (value!([
+                                                                                                                        (value!([
+                                                                                                                            "emit" => "value_string",
+                                                                                                                            "value" => "op"
+                                                                                                                        ])),
+                                                                                                                        (value!([
+                                                                                                                            "emit" => "capture_index",
+                                                                                                                            "children" =>
+                                                                                                                                (value!([
+                                                                                                                                    "emit" => "value_integer",
+                                                                                                                                    "value" => 1
+                                                                                                                                ]))
+                                                                                                                        ]))
+                                                                                                                    ]))
+                                                                                                            ])),
+                                                                                                            (value!([
+                                                                                                                "emit" => "alias",
+                                                                                                                "children" =>
+                                                                                                                    (value!([
+                                                                                                                        (value!([
+                                                                                                                            "emit" => "value_string",
+                                                                                                                            "value" => "text"
+                                                                                                                        ])),
+                                                                                                                        (value!([
+                                                                                                                            "emit" => "capture_index",
+                                                                                                                            "children" =>
+                                                                                                                                (value!([
+                                                                                                                                    "emit" => "value_integer",
+                                                                                                                                    "value" => 2
+                                                                                                                                ]))
+                                                                                                                        ]))
+                                                                                                                    ]))
+                                                                                                            ]))
+                                                                                                        ]))
+                                                                                                ]))
+                                                                                            ]))
+                                                                                    ]))
+                                                                            ]))
+                                                                    ]))
+                                                                ]))
+                                                        ])),
+                                                        (value!([
+                                                            "emit" => "constant",
+                                                            "children" =>
+                                                                (value!([
+                                                                    (value!([
+                                                                        "emit" => "identifier",
+                                                                        "value" => "Lines"
+                                                                    ])),
+                                                                    (value!([
+                                                                        "emit" => "value_parselet",
+                                                                        "children" =>
+                                                                            (value!([
+                                                                                (value!([
+                                                                                    "emit" => "arg",
+                                                                                    "children" =>
+                                                                                        (value!([
+                                                                                            "emit" => "identifier",
+                                                                                            "value" => "old"
+                                                                                        ]))
+                                                                                ])),
+                                                                                (value!([
+                                                                                    "emit" => "arg",
+                                                                                    "children" =>
+                                                                                        (value!([
+                                                                                            "emit" => "identifier",
+                                                                                            "value" => "new"
+                                                                                        ]))
+                                                                                ])),
+                                                                                (value!([
+                                                                                    "emit" => "body",
+                                                                                    "children" =>
+                                                                                        (value!([
+                                                                                            (value!([
+                                                                                                "emit" => "assign_drop",
+                                                                                                "children" =>
+                                                                                                    (value!([
+                                                                                                        (value!([
+                                                                                                            "emit" => "lvalue",
+                                                                                                            "children" =>
+                                                                                                                (value!([
+                                                                                                                    "emit" => "identifier",
+                                                                                                                    "value" => "lines"
+                                                                                                                ]))
+                                                                                                        ])),
+                                                                                                        (value!([
+                                                                                                            "emit" => "call",
+                                                                                                            "children" =>
+                                                                                                                (value!([
+                                                                                                                    "emit" => "identifier",
+                                                                                                                    "value" => "list"
+                                                                                                                ]))
+                                                                                                        ]))
+                                                                                                    ]))
+                                                                                            ])),
+                                                                                            (value!([
+                                                                                                "emit" => "op_loop",
+                                                                                                "children" =>
+                                                                                                    (value!([
+                                                                                                        (value!([
+                                                                                                            "emit" => "op_logical_or",
+                                                                                                            "children" =>
+                                                                                                                (value!([
+                                                                                                                    (value!([
+                                                                                                                        "emit" => "comparison",
+                                                                                                                        "children" =>
+                                                                                                                            (value!([
+                                                                                                                                (value!([
+                                                                                                                                    "emit" => "identifier",
+                                                                                                                                    "value" => "old"
+                                                                                                                                ])),
+                                                                                                                                (value!([
+                                                                                                                                    "emit" => "cmp_gt",
+                                                                                                                                    "children" =>
+                                                                                                                                        (value!([
+                                                                                                                                            "emit" => "value_integer",
+                                                                                                                                            "value" => 0
+                                                                                                                                        ]))
+                                                                                                                                ]))
+                                                                                                                            ]))
+                                                                                                                    ])),
+                                                                                                                    (value!([
+                                                                                                                        "emit" => "comparison",
+                                                                                                                        "children" =>
+                                                                                                                            (value!([
+                                                                                                                                (value!([
+                                                                                                                                    "emit" => "identifier",
+                                                                                                                                    "value" => "new"
+                                                                                                                                ])),
+                                                                                                                                (value!([
+                                                                                                                                    "emit" => "cmp_gt",
+                                                                                                                                    "children" =>
+                                                                                                                                        (value!([
+                                                                                                                                            "emit" => "value_integer",
+                                                                                                                                            "value" => 0
+                                                                                                                                        ]))
+                                                                                                                                ]))
+                                                                                                                            ]))
+                                                                                                                    ]))
+                                                                                                                ]))
+                                                                                                        ])),
+                                                                                                        (value!([
+                                                                                                            "emit" => "block",
+                                                                                                            "children" =>
+                                                                                                                (value!([
+                                                                                                                    (value!([
+                                                                                                                        "emit" => "sequence",
+                                                                                                                        "children" =>
+                                                                                                                            (value!([
+                                                                                                                                (value!([
+                                                                                                                                    "emit" => "identifier",
+                                                                                                                                    "value" => "HunkLine"
+                                                                                                                                ])),
+                                                                                                                                (value!([
+                                                                                                                                    "emit" => "block",
+                                                                                                                                    "children" =>
+                                                                                                                                        (value!([
+                                                                                                                                            (value!([
+                                                                                                                                                "emit" => "call",
+                                                                                                                                                "children" =>
+                                                                                                                                                    (value!([
+                                                                                                                                                        (value!([
+                                                                                                                                                            "emit" => "rvalue",
+                                                                                                                                                            "children" =>
+                                                                                                                                                                (value!([
+                                                                                                                                                                    (value!([
+                                                                                                                                                                        "emit" => "identifier",
+                                                                                                                                                                        "value" => "lines"
+                                                                                                                                                                    ])),
+                                                                                                                                                                    (value!([
+                                                                                                                                                                        "emit" => "attribute",
+                                                                                                                                                                        "children" =>
+                                                                                                                                                                            (value!([
+                                                                                                                                                                                "emit" => "value_string",
+                                                                                                                                                                                "value" => "push"
+                                                                                                                                                                            ]))
+                                                                                                                                                                    ]))
+                                                                                                                                                                ]))
+                                                                                                                                                        ])),
+                                                                                                                                                        (value!([
+                                                                                                                                                            "emit" => "callarg",
+                                                                                                                                                            "children" =>
+                                                                                                                                                                (value!([
+                                                                                                                                                                    "emit" => "capture_index",
+                                                                                                                                                                    "children" =>
+                                                                                                                                                                        (value!([
+                                                                                                                                                                            "emit" => "value_integer",
+                                                                                                                                                                            "value" => 1
+                                                                                                                                                                        ]))
+                                                                                                                                                                ]))
+                                                                                                                                                        ]))
+                                                                                                                                                    ]))
+                                                                                                                                            ])),
+                                                                                                                                            (value!([
+                                                                                                                                                "emit" => "op_if",
+                                                                                                                                                "children" =>
+                                                                                                                                                    (value!([
+                                                                                                                                                        (value!([
+                                                                                                                                                            "emit" => "comparison",
+                                                                                                                                                            "children" =>
+                                                                                                                                                                (value!([
+                                                                                                                                                                    (value!([
+                                                                                                                                                                        "emit" => "rvalue",
+                                                                                                                                                                        "children" =>
+                                                                                                                                                                            (value!([
+                                                                                                                                                                                (value!([
+                                                                                                                                                                                    "emit" => "capture_index",
+                                                                                                                                                                                    "children" =>
+                                                                                                                                                                                        (value!([
+                                                                                                                                                                                            "emit" => "value_integer",
+                                                                                                                                                                                            "value" => 1
+                                                                                                                                                                                        ]))
+                                                                                                                                                                                ])),
+                                                                                                                                                                                (value!([
+                                                                                                                                                                                    "emit" => "item",
+                                                                                                                                                                                    "children" =>
+                                                                                                                                                                                        (value!([
+                                                                                                                                                                                            "emit" => "value_string",
+                                                                                                                                                                                            "value" => "op"
+                                                                                                                                                                                        ]))
+                                                                                                                                                                                ]))
+                                                                                                                                                                            ]))
+                                                                                                                                                                    ])),
+                                                                                                                                                                    (value!([
+                                                                                                                                                                        "emit" => "cmp_neq",
+                                                                                                                                                                        "children" =>
+                                                                                                                                                                            (value!([
+                                                                                                                                                                                "emit" => "value_string",
+                                                                                                                                                                                "value" => "+"
+                                                                                                                                                                            ]))
+                                                                                                                                                                    ]))
+                                                                                                                                                                ]))
+                                                                                                                                                        ])),
+                                                                                                                                                        (value!([
+                                                                                                                                                            "emit" => "assign_sub_drop",
+                                                                                                                                                            "children" =>
+                                                                                                                                                                (value!([
+                                                                                                                                                                    (value!([
+                                                                                                                                                                        "emit" => "lvalue",
+                                                                                                                                                                        "children" =>
+                                                                                                                                                                            (value!([
+                                                                                                                                                                                "emit" => "identifier",
+                                                                                                                                                                                "value" => "old"
+                                                                                                                                                                            ]))
+                                                                                                                                                                    ])),
+                                                                                                                                                                    (value!([
+                                                                                                                                                                        "emit" => "value_integer",
+                                                                                                                                                                        "value" => 1
+                                                                                                                                                                    ]))
+                                                                                                                                                                ]))
+                                                                                                                                                        ]))
+                                                                                                                                                    ]))
+                                                                                                                                            ])),
+                                                                                                                                            (value!([
+                                                                                                                                                "emit" => "op_if",
+                                                                                                                                                "children" =>
+                                                                                                                                                    (value!([
+                                                                                                                                                        (value!([
+                                                                                                                                                            "emit" => "comparison",
+                                                                                                                                                            "children" =>
+                                                                                                                                                                (value!([
+                                                                                                                                                                    (value!([
+                                                                                                                                                                        "emit" => "rvalue",
+                                                                                                                                                                        "children" =>
+                                                                                                                                                                            (value!([
+                                                                                                                                                                                (value!([
+                                                                                                                                                                                    "emit" => "capture_index",
+                                                                                                                                                                                    "children" =>
+                                                                                                                                                                                        (value!([
+                                                                                                                                                                                            "emit" => "value_integer",
+                                                                                                                                                                                            "value" => 1
+                                                                                                                                                                                        ]))
+                                                                                                                                                                                ])),
+                                                                                                                                                                                (value!([
+                                                                                                                                                                                    "emit" => "item",
+                                                                                                                                                                                    "children" =>
+                                                                                                                                                                                        (value!([
+                                                                                                                                                                                            "emit" => "value_string",
+                                                                                                                                                                                            "value" => "op"
+                                                                                                                                                                                        ]))
+                                                                                                                                                                                ]))
+                                                                                                                                                                            ]))
+                                                                                                                                                                    ])),
+                                                                                                                                                                    (value!([
+                                                                                                                                                                        "emit" => "cmp_neq",
+                                                                                                                                                                        "children" =>
+                                                                                                                                                                            (value!([
+                                                                                                                                                                                "emit" => "value_string",
+                                                                                                                                                                                "value" => "-"
+                                                                                                                                                                            ]))
+                                                                                                                                                                    ]))
+                                                                                                                                                                ]))
+                                                                                                                                                        ])),
+                                                                                                                                                        (value!([
+                                                                                                                                                            "emit" => "assign_sub_drop",
+                                                                                                                                                            "children" =>
+                                                                                                                                                                (value!([
+                                                                                                                                                                    (value!([
+                                                                                                                                                                        "emit" => "lvalue",
+                                                                                                                                                                        "children" =>
+                                                                                                                                                                            (value!([
+                                                                                                                                                                                "emit" => "identifier",
+                                                                                                                                                                                "value" => "new"
+                                                                                                                                                                            ]))
+                                                                                                                                                                    ])),
+                                                                                                                                                                    (value!([
+                                                                                                                                                                        "emit" => "value_integer",
+                                                                                                                                                                        "value" => 1
+                                                                                                                                                                    ]))
+                                                                                                                                                                ]))
+                                                                                                                                                        ]))
+                                                                                                                                                    ]))
+                                                                                                                                            ]))
+                                                                                                                                        ]))
+                                                                                                                                ]))
+                                                                                                                            ]))
+                                                                                                                    ])),
+                                                                                                                    (value!([
+                                                                                                                        "emit" => "op_reject"
+                                                                                                                    ]))
+                                                                                                                ]))
+                                                                                                        ]))
+                                                                                                    ]))
+                                                                                            ])),
+                                                                                            (value!([
+                                                                                                "emit" => "identifier",
+                                                                                                "value" => "lines"
+                                                                                            ]))
+                                                                                        ]))
+                                                                                ]))
+                                                                            ]))
+                                                                    ]))
+                                                                ]))
+                                                        ])),
+                                                        (value!([
+                                                            "emit" => "constant",
+                                                            "children" =>
+                                                                (value!([
+                                                                    (value!([
+                                                                        "emit" => "identifier",
+                                                                        "value" => "Hunk"
+                                                                    ])),
+                                                                    (value!([
+                                                                        "emit" => "value_parselet",
+                                                                        "children" =>
+                                                                            (value!([
+                                                                                "emit" => "body",
+                                                                                "children" =>
+                                                                                    (value!([
+                                                                                        "emit" => "sequence",
+                                                                                        "children" =>
+                                                                                            (value!([
+                                                                                                (value!([
+                                                                                                    "emit" => "value_token_match",
+                                                                                                    "value" => "@@ -"
+                                                                                                ])),
+                                                                                                (value!([
+                                                                                                    "emit" => "identifier",
+                                                                                                    "value" => "Range"
+                                                                                                ])),
+                                                                                                (value!([
+                                                                                                    "emit" => "value_token_match",
+                                                                                                    "value" => " +"
+                                                                                                ])),
+                                                                                                (value!([
+                                                                                                    "emit" => "identifier",
+                                                                                                    "value" => "Range"
+                                                                                                ])),
+                                                                                                (value!([
+                                                                                                    "emit" => "value_token_match",
+                                                                                                    "value" => " @@"
+                                                                                                ])),
+                                                                                                (value!([
+                                                                                                    "emit" => "identifier",
+                                                                                                    "value" => "Line"
+                                                                                                ])),
+                                                                                                (value!([
+                                                                                                    "emit" => "call",
+                                                                                                    "children" =>
+                                                                                                        (value!([
+                                                                                                            (value!([
+                                                                                                                "emit" => "identifier",
+                                                                                                                "value" => "Lines"
+                                                                                                            ])),
+                                                                                                            (value!([
+                                                                                                                "emit" => "callarg",
+                                                                                                                "children" =>
+                                                                                                                    (value!([
+                                                                                                                        "emit" => "rvalue",
+                                                                                                                        "children" =>
+                                                                                                                            (value!([
+                                                                                                                                (value!([
+                                                                                                                                    "emit" => "capture_index",
+                                                                                                                                    "children" =>
+                                                                                                                                        (value!([
+                                                                                                                                            "emit" => "value_integer",
+                                                                                                                                            "value" => 2
+                                                                                                                                        ]))
+                                                                                                                                ])),
+                                                                                                                                (value!([
+                                                                                                                                    "emit" => "item",
+                                                                                                                                    "children" =>
+                                                                                                                                        (value!([
+                                                                                                                                            "emit" => "value_integer",
+                                                                                                                                            "value" => 1
+                                                                                                                                        ]))
+                                                                                                                                ]))
+                                                                                                                            ]))
+                                                                                                                    ]))
+                                                                                                            ])),
+                                                                                                            (value!([
+                                                                                                                "emit" => "callarg",
+                                                                                                                "children" =>
+                                                                                                                    (value!([
+                                                                                                                        "emit" => "rvalue",
+                                                                                                                        "children" =>
+                                                                                                                            (value!([
+                                                                                                                                (value!([
+                                                                                                                                    "emit" => "capture_index",
+                                                                                                                                    "children" =>
+                                                                                                                                        (value!([
+                                                                                                                                            "emit" => "value_integer",
+                                                                                                                                            "value" => 4
+                                                                                                                                        ]))
+                                                                                                                                ])),
+                                                                                                                                (value!([
+                                                                                                                                    "emit" => "item",
+                                                                                                                                    "children" =>
+                                                                                                                                        (value!([
+                                                                                                                                            "emit" => "value_integer",
+                                                                                                                                            "value" => 1
+                                                                                                                                        ]))
+                                                                                                                                ]))
+                                                                                                                            ]))
+                                                                                                                    ]))
+                                                                                                            ]))
+                                                                                                        ]))
+                                                                                                ])),
+                                                                                                (value!([
+                                                                                                    "emit" => "block",
+                                                                                                    "children" =>
+                                                                                                        (value!([
+                                                                                                            "emit" => "sequence",
+                                                                                                            "children" =>
+                                                                                                                (value!([
+                                                                                                                    (value!([
+                                                                                                                        "emit" => "alias",
+                                                                                                                        "children" =>
+                                                                                                                            (value!([
+                                                                                                                                (value!([
+                                                                                                                                    "emit" => "value_string",
+                                                                                                                                    "value" => "old_start"
+                                                                                                                                ])),
+                                                                                                                                (value!([
+                                                                                                                                    "emit" => "rvalue",
+                                                                                                                                    "children" =>
+                                                                                                                                        (value!([
+                                                                                                                                            (value!([
+                                                                                                                                                "emit" => "capture_index",
+                                                                                                                                                "children" =>
+                                                                                                                                                    (value!([
+                                                                                                                                                        "emit" => "value_integer",
+                                                                                                                                                        "value" => 2
+                                                                                                                                                    ]))
+                                                                                                                                            ])),
+                                                                                                                                            (value!([
+                                                                                                                                                "emit" => "item",
+                                                                                                                                                "children" =>
+                                                                                                                                                    (value!([
+                                                                                                                                                        "emit" => "value_integer",
+                                                                                                                                                        "value" => 0
+                                                                                                                                                    ]))
+                                                                                                                                            ]))
+                                                                                                                                        ]))
+                                                                                                                                ]))
+                                                                                                                            ]))
+                                                                                                                    ])),
+                                                                                                                    (value!([
+                                                                                                                        "emit" => "alias",
+                                                                                                                        "children" =>
+                                                                                                                            (value!([
+                                                                                                                                (value!([
+                                                                                                                                    "emit" => "value_string",
+                                                                                                                                    "value" => "old_count"
+                                                                                                                                ])),
+                                                                                                                                (value!([
+                                                                                                                                    "emit" => "rvalue",
+                                                                                                                                    "children" =>
+                                                                                                                                        (value!([
+                                                                                                                                            (value!([
+                                                                                                                                                "emit" => "capture_index",
+                                                                                                                                                "children" =>
+                                                                                                                                                    (value!([
+                                                                                                                                                        "emit" => "value_integer",
+                                                                                                                                                        "value" => 2
+                                                                                                                                                    ]))
+                                                                                                                                            ])),
+                                                                                                                                            (value!([
+                                                                                                                                                "emit" => "item",
+                                                                                                                                                "children" =>
+                                                                                                                                                    (value!([
+                                                                                                                                                        "emit" => "value_integer",
+                                                                                                                                                        "value" => 1
+                                                                                                                                                    ]))
+                                                                                                                                            ]))
+                                                                                                                                        ]))
+                                                                                                                                ]))
+                                                                                                                            ]))
+                                                                                                                    ])),
+                                                                                                                    (value!([
+                                                                                                                        "emit" => "alias",
+                                                                                                                        "children" =>
+                                                                                                                            (value!([
+                                                                                                                                (value!([
+                                                                                                                                    "emit" => "value_string",
+                                                                                                                                    "value" => "new_start"
+                                                                                                                                ])),
+                                                                                                                                (value!([
+                                                                                                                                    "emit" => "rvalue",
+                                                                                                                                    "children" =>
+                                                                                                                                        (value!([
+                                                                                                                                            (value!([
+                                                                                                                                                "emit" => "capture_index",
+                                                                                                                                                "children" =>
+                                                                                                                                                    (value!([
+                                                                                                                                                        "emit" => "value_integer",
+                                                                                                                                                        "value" => 4
+                                                                                                                                                    ]))
+                                                                                                                                            ])),
+                                                                                                                                            (value!([
+                                                                                                                                                "emit" => "item",
+                                                                                                                                                "children" =>
+                                                                                                                                                    (value!([
+                                                                                                                                                        "emit" => "value_integer",
+                                                                                                                                                        "value" => 0
+                                                                                                                                                    ]))
+                                                                                                                                            ]))
+                                                                                                                                        ]))
+                                                                                                                                ]))
+                                                                                                                            ]))
+                                                                                                                    ])),
+                                                                                                                    (value!([
+                                                                                                                        "emit" => "alias",
+                                                                                                                        "children" =>
+                                                                                                                            (value!([
+                                                                                                                                (value!([
+                                                                                                                                    "emit" => "value_string",
+                                                                                                                                    "value" => "new_count"
+                                                                                                                                ])),
+                                                                                                                                (value!([
+                                                                                                                                    "emit" => "rvalue",
+                                                                                                                                    "children" =>
+                                                                                                                                        (value!([
+                                                                                                                                            (value!([
+                                                                                                                                                "emit" => "capture_index",
+                                                                                                                                                "children" =>
+                                                                                                                                                    (value!([
+                                                                                                                                                        "emit" => "value_integer",
+                                                                                                                                                        "value" => 4
+                                                                                                                                                    ]))
+                                                                                                                                            ])),
+                                                                                                                                            (value!([
+                                                                                                                                                "emit" => "item",
+                                                                                                                                                "children" =>
+                                                                                                                                                    (value!([
+                                                                                                                                                        "emit" => "value_integer",
+                                                                                                                                                        "value" => 1
+                                                                                                                                                    ]))
+                                                                                                                                            ]))
+                                                                                                                                        ]))
+                                                                                                                                ]))
+                                                                                                                            ]))
+                                                                                                                    ])),
+                                                                                                                    (value!([
+                                                                                                                        "emit" => "alias",
+                                                                                                                        "children" =>
+                                                                                                                            (value!([
+                                                                                                                                (value!([
+                                                                                                                                    "emit" => "value_string",
+                                                                                                                                    "value" => "section"
+                                                                                                                                ])),
+                                                                                                                                (value!([
+                                                                                                                                    "emit" => "call",
+                                                                                                                                    "children" =>
+                                                                                                                                        (value!([
+                                                                                                                                            "emit" => "rvalue",
+                                                                                                                                            "children" =>
+                                                                                                                                                (value!([
+                                                                                                                                                    (value!([
+                                                                                                                                                        "emit" => "capture_index",
+                                                                                                                                                        "children" =>
+                                                                                                                                                            (value!([
+                                                                                                                                                                "emit" => "value_integer",
+                                                                                                                                                                "value" => 6
+                                                                                                                                                            ]))
+                                                                                                                                                    ])),
+                                                                                                                                                    (value!([
+                                                                                                                                                        "emit" => "attribute",
+                                                                                                                                                        "children" =>
+                                                                                                                                                            (value!([
+                                                                                                                                                                "emit" => "value_string",
+                                                                                                                                                                "value" => "trim"
+                                                                                                                                                            ]))
+                                                                                                                                                    ]))
+                                                                                                                                                ]))
+                                                                                                                                        ]))
+                                                                                                                                ]))
+                                                                                                                            ]))
+                                                                                                                    ])),
+                                                                                                                    (value!([
+                                                                                                                        "emit" => "alias",
+                                                                                                                        "children" =>
+                                                                                                                            (value!([
+                                                                                                                                (value!([
+                                                                                                                                    "emit" => "value_string",
+                                                                                                                                    "value" => "lines"
+                                                                                                                                ])),
+                                                                                                                                (value!([
+                                                                                                                                    "emit" => "capture_index",
+                                                                                                                                    "children" =>
+                                                                                                                                        (value!([
+                                                                                                                                            "emit" => "value_integer",
+                                                                                                                                            "value" => 7
+                                                                                                                                        ]))
+                                                                                                                                ]))
+                                                                                                                            ]))
+                                                                                                                    ]))
+                                                                                                                ]))
+                                                                                                        ]))
+                                                                                                ]))
+                                                                                            ]))
+                                                                                    ]))
+                                                                            ]))
+                                                                    ]))
+                                                                ]))
+                                                        ])),
+                                                        (value!([
+                                                            "emit" => "sequence",
+                                                            "children" =>
+                                                                (value!([
+                                                                    (value!([
+                                                                        "emit" => "value_generic",
+                                                                        "children" =>
+                                                                            (value!([
+                                                                                (value!([
+                                                                                    "emit" => "identifier",
+                                                                                    "value" => "Kle"
+                                                                                ])),
+                                                                                (value!([
+                                                                                    "emit" => "genarg",
+                                                                                    "children" =>
+                                                                                        (value!([
+                                                                                            "emit" => "identifier",
+                                                                                            "value" => "Header"
+                                                                                        ]))
+                                                                                ])),
+                                                                                (value!([
+                                                                                    "emit" => "genarg_named",
+                                                                                    "children" =>
+                                                                                        (value!([
+                                                                                            (value!([
+                                                                                                "emit" => "identifier",
+                                                                                                "value" => "blur"
+                                                                                            ])),
+                                                                                            (value!([
+                                                                                                "emit" => "value_false"
+                                                                                            ]))
+                                                                                        ]))
+                                                                                ]))
+                                                                            ]))
+                                                                    ])),
+                                                                    (value!([
+                                                                        "emit" => "value_token_match",
+                                                                        "value" => "--- "
+                                                                    ])),
+                                                                    (value!([
+                                                                        "emit" => "identifier",
+                                                                        "value" => "Path"
+                                                                    ])),
+                                                                    (value!([
+                                                                        "emit" => "value_token_match",
+                                                                        "value" => "+++ "
+                                                                    ])),
+                                                                    (value!([
+                                                                        "emit" => "identifier",
+                                                                        "value" => "Path"
+                                                                    ])),
+                                                                    (value!([
+                                                                        "emit" => "value_generic",
+                                                                        "children" =>
+                                                                            (value!([
+                                                                                (value!([
+                                                                                    "emit" => "identifier",
+                                                                                    "value" => "Pos"
+                                                                                ])),
+                                                                                (value!([
+                                                                                    "emit" => "genarg",
+                                                                                    "children" =>
+                                                                                        (value!([
+                                                                                            "emit" => "identifier",
+                                                                                            "value" => "Hunk"
+                                                                                        ]))
+                                                                                ])),
+                                                                                (value!([
+                                                                                    "emit" => "genarg_named",
+                                                                                    "children" =>
+                                                                                        (value!([
+                                                                                            (value!([
+                                                                                                "emit" => "identifier",
+                                                                                                "value" => "blur"
+                                                                                            ])),
+                                                                                            (value!([
+                                                                                                "emit" => "value_false"
+                                                                                            ]))
+                                                                                        ]))
+                                                                                ]))
+                                                                            ]))
+                                                                    ])),
+                                                                    (value!([
+                                                                        "emit" => "block",
+                                                                        "children" =>
+                                                                            (value!([
+                                                                                (value!([
+                                                                                    "emit" => "assign_drop",
+                                                                                    "children" =>
+                                                                                        (value!([
+                                                                                            (value!([
+                                                                                                "emit" => "lvalue",
+                                                                                                "children" =>
+                                                                                                    (value!([
+                                                                                                        "emit" => "identifier",
+                                                                                                        "value" => "added"
+                                                                                                    ]))
+                                                                                            ])),
+                                                                                            (value!([
+                                                                                                "emit" => "value_integer",
+                                                                                                "value" => 0
+                                                                                            ]))
+                                                                                        ]))
+                                                                                ])),
+                                                                                (value!([
+                                                                                    "emit" => "assign_drop",
+                                                                                    "children" =>
+                                                                                        (value!([
+                                                                                            (value!([
+                                                                                                "emit" => "lvalue",
+                                                                                                "children" =>
+                                                                                                    (value!([
+                                                                                                        "emit" => "identifier",
+                                                                                                        "value" => "removed"
+                                                                                                    ]))
+                                                                                            ])),
+                                                                                            (value!([
+                                                                                                "emit" => "value_integer",
+                                                                                                "value" => 0
+                                                                                            ]))
+                                                                                        ]))
+                                                                                ])),
+                                                                                (value!([
+                                                                                    "emit" => "op_for",
+                                                                                    "children" =>
+                                                                                        (value!([
+                                                                                            (value!([
+                                                                                                "emit" => "lvalue",
+                                                                                                "children" =>
+                                                                                                    (value!([
+                                                                                                        "emit" => "identifier",
+                                                                                                        "value" => "hunk"
+                                                                                                    ]))
+                                                                                            ])),
+                                                                                            (value!([
+                                                                                                "emit" => "capture_index",
+                                                                                                "children" =>
+                                                                                                    (value!([
+                                                                                                        "emit" => "value_integer",
+                                                                                                        "value" => 6
+                                                                                                    ]))
+                                                                                            ])),
+                                                                                            (value!([
+                                                                                                "emit" => "block",
+                                                                                                "children" =>
+                                                                                                    (value!([
+                                                                                                        "emit" => "op_for",
+                                                                                                        "children" =>
+                                                                                                            (value!([
+                                                                                                                (value!([
+                                                                                                                    "emit" => "lvalue",
+                                                                                                                    "children" =>
+                                                                                                                        (value!([
+                                                                                                                            "emit" => "identifier",
+                                                                                                                            "value" => "line"
+                                                                                                                        ]))
+                                                                                                                ])),
+                                                                                                                (value!([
+                                                                                                                    "emit" => "rvalue",
+                                                                                                                    "children" =>
+                                                                                                                        (value!([
+                                                                                                                            (value!([
+                                                                                                                                "emit" => "identifier",
+                                                                                                                                "value" => "hunk"
+                                                                                                                            ])),
+                                                                                                                            (value!([
+                                                                                                                                "emit" => "item",
+                                                                                                                                "children" =>
+                                                                                                                                    (value!([
+                                                                                                                                        "emit" => "value_string",
+                                                                                                                                        "value" => "lines"
+                                                                                                                                    ]))
+                                                                                                                            ]))
+                                                                                                                        ]))
+                                                                                                                ])),
+                                                                                                                (value!([
+                                                                                                                    "emit" => "block",
+                                                                                                                    "children" =>
+                                                                                                                        (value!([
+                                                                                                                            (value!([
+                                                                                                                                "emit" => "op_if",
+                                                                                                                                "children" =>
+                                                                                                                                    (value!([
+                                                                                                                                        (value!([
+                                                                                                                                            "emit" => "comparison",
+                                                                                                                                            "children" =>
+                                                                                                                                                (value!([
+                                                                                                                                                    (value!([
+                                                                                                                                                        "emit" => "rvalue",
+                                                                                                                                                        "children" =>
+                                                                                                                                                            (value!([
+                                                                                                                                                                (value!([
+                                                                                                                                                                    "emit" => "identifier",
+                                                                                                                                                                    "value" => "line"
+                                                                                                                                                                ])),
+                                                                                                                                                                (value!([
+                                                                                                                                                                    "emit" => "item",
+                                                                                                                                                                    "children" =>
+                                                                                                                                                                        (value!([
+                                                                                                                                                                            "emit" => "value_string",
+                                                                                                                                                                            "value" => "op"
+                                                                                                                                                                        ]))
+                                                                                                                                                                ]))
+                                                                                                                                                            ]))
+                                                                                                                                                    ])),
+                                                                                                                                                    (value!([
+                                                                                                                                                        "emit" => "cmp_eq",
+                                                                                                                                                        "children" =>
+                                                                                                                                                            (value!([
+                                                                                                                                                                "emit" => "value_string",
+                                                                                                                                                                "value" => "+"
+                                                                                                                                                            ]))
+                                                                                                                                                    ]))
+                                                                                                                                                ]))
+                                                                                                                                        ])),
+                                                                                                                                        (value!([
+                                                                                                                                            "emit" => "assign_add_drop",
+                                                                                                                                            "children" =>
+                                                                                                                                                (value!([
+                                                                                                                                                    (value!([
+                                                                                                                                                        "emit" => "lvalue",
+                                                                                                                                                        "children" =>
+                                                                                                                                                            (value!([
+                                                                                                                                                                "emit" => "identifier",
+                                                                                                                                                                "value" => "added"
+                                                                                                                                                            ]))
+                                                                                                                                                    ])),
+                                                                                                                                                    (value!([
+                                                                                                                                                        "emit" => "value_integer",
+                                                                                                                                                        "value" => 1
+                                                                                                                                                    ]))
+                                                                                                                                                ]))
+                                                                                                                                        ]))
+                                                                                                                                    ]))
+                                                                                                                            ])),
+                                                                                                                            (value!([
+                                                                                                                                "emit" => "op_if",
+                                                                                                                                "children" =>
+                                                                                                                                    (value!([
+                                                                                                                                        (value!([
+                                                                                                                                            "emit" => "comparison",
+                                                                                                                                            "children" =>
+                                                                                                                                                (value!([
+                                                                                                                                                    (value!([
+                                                                                                                                                        "emit" => "rvalue",
+                                                                                                                                                        "children" =>
+                                                                                                                                                            (value!([
+                                                                                                                                                                (value!([
+                                                                                                                                                                    "emit" => "identifier",
+                                                                                                                                                                    "value" => "line"
+                                                                                                                                                                ])),
+                                                                                                                                                                (value!([
+                                                                                                                                                                    "emit" => "item",
+                                                                                                                                                                    "children" =>
+                                                                                                                                                                        (value!([
+                                                                                                                                                                            "emit" => "value_string",
+                                                                                                                                                                            "value" => "op"
+                                                                                                                                                                        ]))
+                                                                                                                                                                ]))
+                                                                                                                                                            ]))
+                                                                                                                                                    ])),
+                                                                                                                                                    (value!([
+                                                                                                                                                        "emit" => "cmp_eq",
+                                                                                                                                                        "children" =>
+                                                                                                                                                            (value!([
+                                                                                                                                                                "emit" => "value_string",
+                                                                                                                                                                "value" => "-"
+                                                                                                                                                            ]))
+                                                                                                                                                    ]))
+                                                                                                                                                ]))
+                                                                                                                                        ])),
+                                                                                                                                        (value!([
+                                                                                                                                            "emit" => "assign_add_drop",
+                                                                                                                                            "children" =>
+                                                                                                                                                (value!([
+                                                                                                                                                    (value!([
+                                                                                                                                                        "emit" => "lvalue",
+                                                                                                                                                        "children" =>
+                                                                                                                                                            (value!([
+                                                                                                                                                                "emit" => "identifier",
+                                                                                                                                                                "value" => "removed"
+                                                                                                                                                            ]))
+                                                                                                                                                    ])),
+                                                                                                                                                    (value!([
+                                                                                                                                                        "emit" => "value_integer",
+                                                                                                                                                        "value" => 1
+                                                                                                                                                    ]))
+                                                                                                                                                ]))
+                                                                                                                                        ]))
+                                                                                                                                    ]))
+                                                                                                                            ]))
+                                                                                                                        ]))
+                                                                                                                ]))
+                                                                                                            ]))
+                                                                                                    ]))
+                                                                                            ]))
+                                                                                        ]))
+                                                                                ])),
+                                                                                (value!([
+                                                                                    "emit" => "sequence",
+                                                                                    "children" =>
+                                                                                        (value!([
+                                                                                            (value!([
+                                                                                                "emit" => "alias",
+                                                                                                "children" =>
+                                                                                                    (value!([
+                                                                                                        (value!([
+                                                                                                            "emit" => "value_string",
+                                                                                                            "value" => "headers"
+                                                                                                        ])),
+                                                                                                        (value!([
+                                                                                                            "emit" => "op_logical_or",
+                                                                                                            "children" =>
+                                                                                                                (value!([
+                                                                                                                    (value!([
+                                                                                                                        "emit" => "capture_index",
+                                                                                                                        "children" =>
+                                                                                                                            (value!([
+                                                                                                                                "emit" => "value_integer",
+                                                                                                                                "value" => 1
+                                                                                                                            ]))
+                                                                                                                    ])),
+                                                                                                                    (value!([
+                                                                                                                        "emit" => "call",
+                                                                                                                        "children" =>
+                                                                                                                            (value!([
+                                                                                                                                "emit" => "identifier",
+                                                                                                                                "value" => "list"
+                                                                                                                            ]))
+                                                                                                                    ]))
+                                                                                                                ]))
+                                                                                                        ]))
+                                                                                                    ]))
+                                                                                            ])),
+                                                                                            (value!([
+                                                                                                "emit" => "alias",
+                                                                                                "children" =>
+                                                                                                    (value!([
+                                                                                                        (value!([
+                                                                                                            "emit" => "value_string",
+                                                                                                            "value" => "old"
+                                                                                                        ])),
+                                                                                                        (value!([
+                                                                                                            "emit" => "capture_index",
+                                                                                                            "children" =>
+                                                                                                                (value!([
+                                                                                                                    "emit" => "value_integer",
+                                                                                                                    "value" => 3
+                                                                                                                ]))
+                                                                                                        ]))
+                                                                                                    ]))
+                                                                                            ])),
+                                                                                            (value!([
+                                                                                                "emit" => "alias",
+                                                                                                "children" =>
+                                                                                                    (value!([
+                                                                                                        (value!([
+                                                                                                            "emit" => "value_string",
+                                                                                                            "value" => "new"
+                                                                                                        ])),
+                                                                                                        (value!([
+                                                                                                            "emit" => "capture_index",
+                                                                                                            "children" =>
+                                                                                                                (value!([
+                                                                                                                    "emit" => "value_integer",
+                                                                                                                    "value" => 5
+                                                                                                                ]))
+                                                                                                        ]))
+                                                                                                    ]))
+                                                                                            ])),
+                                                                                            (value!([
+                                                                                                "emit" => "alias",
+                                                                                                "children" =>
+                                                                                                    (value!([
+                                                                                                        (value!([
+                                                                                                            "emit" => "value_string",
+                                                                                                            "value" => "hunks"
+                                                                                                        ])),
+                                                                                                        (value!([
+                                                                                                            "emit" => "capture_index",
+                                                                                                            "children" =>
+                                                                                                                (value!([
+                                                                                                                    "emit" => "value_integer",
+                                                                                                                    "value" => 6
+                                                                                                                ]))
+                                                                                                        ]))
+                                                                                                    ]))
+                                                                                            ])),
+                                                                                            (value!([
+                                                                                                "emit" => "alias",
+                                                                                                "children" =>
+                                                                                                    (value!([
+                                                                                                        (value!([
+                                                                                                            "emit" => "value_string",
+                                                                                                            "value" => "added"
+                                                                                                        ])),
+                                                                                                        (value!([
+                                                                                                            "emit" => "identifier",
+                                                                                                            "value" => "added"
+                                                                                                        ]))
+                                                                                                    ]))
+                                                                                            ])),
+                                                                                            (value!([
+                                                                                                "emit" => "alias",
+                                                                                                "children" =>
+                                                                                                    (value!([
+                                                                                                        (value!([
+                                                                                                            "emit" => "value_string",
+                                                                                                            "value" => "removed"
+                                                                                                        ])),
+                                                                                                        (value!([
+                                                                                                            "emit" => "identifier",
+                                                                                                            "value" => "removed"
+                                                                                                        ]))
+                                                                                                    ]))
+                                                                                            ]))
+                                                                                        ]))
+                                                                                ]))
+                                                                            ]))
+                                                                    ]))
+                                                                ]))
+                                                        ]))
+                                                    ]))
                                             ]))
                                     ]))
                                 ]))
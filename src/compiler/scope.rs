@@ -24,6 +24,8 @@ pub(super) struct Scope<'compiler, 'parent> {
     parent: Option<&'parent Scope<'compiler, 'parent>>, // Previous scope
     pub constants: RefCell<IndexMap<String, ImlValue>>, // Symbol table of named constants
     pub usages: RefCell<Vec<ImlValue>>, // Unresolved usages within scope
+    pub captures: RefCell<Vec<String>>, // Named captures of the sequences currently traversed
+    pub unused: RefCell<IndexMap<String, Option<Offset>>>, // Constants defined but not used yet
     pub errors: RefCell<Vec<Error>>,   // Errors raised
 }
 
@@ -39,6 +41,8 @@ impl<'compiler, 'parent> Scope<'compiler, 'parent> {
             parent,
             constants: RefCell::new(IndexMap::new()),
             usages: RefCell::new(Vec::new()),
+            captures: RefCell::new(Vec::new()),
+            unused: RefCell::new(IndexMap::new()),
             errors: RefCell::new(Vec::new()),
         };
 
@@ -125,6 +129,7 @@ impl<'compiler, 'parent> Scope<'compiler, 'parent> {
         while let Some(scope) = top {
             // Check constants of scope
             if let Some(value) = scope.constants.borrow().get(name) {
+                scope.unused.borrow_mut().shift_remove(name);
                 return Some(value.clone());
             }
 
@@ -158,7 +163,10 @@ impl<'compiler, 'parent> Scope<'compiler, 'parent> {
 
         // Check for a builtin function
         if let Some(builtin) = Builtin::get(name) {
-            return Some(RefValue::from(builtin).into());
+            // Builtins touching the environment are removed in sandbox mode
+            if !(self.compiler.sandbox && builtin.touches_environment()) {
+                return Some(RefValue::from(builtin).into());
+            }
         }
 
         // Check for built-in token
@@ -169,6 +177,52 @@ impl<'compiler, 'parent> Scope<'compiler, 'parent> {
         None
     }
 
+    /// Describes what a constant defined by name in this scope would hide, if anything.
+    pub fn shadows(&self, name: &str) -> Option<&'static str> {
+        let mut top = self.parent;
+
+        while let Some(scope) = top {
+            if scope.constants.borrow().contains_key(name) {
+                return Some("a constant of an outer scope");
+            }
+
+            top = scope.parent;
+        }
+
+        if Builtin::get(name).is_some() {
+            return Some("a builtin");
+        }
+
+        None
+    }
+
+    /** Resolve a named capture to the variable holding its value.
+
+    Named captures are visible after their definition within their sequence, including any
+    nested blocks, but not beyond the parselet. */
+    pub fn resolve_capture(&self, name: &str) -> Option<ImlValue> {
+        let mut top = Some(self);
+
+        while let Some(scope) = top {
+            if scope
+                .captures
+                .borrow()
+                .iter()
+                .any(|capture| capture == name)
+            {
+                return self.resolve_name(None, &format!("${}", name));
+            }
+
+            if let ScopeLevel::Parselet(_) = scope.level {
+                break;
+            }
+
+            top = scope.parent;
+        }
+
+        None
+    }
+
     pub fn resolve_usages(&self) {
         let resolve: Vec<ImlValue> = self.usages.borrow_mut().drain(..).collect();
 
@@ -181,12 +235,42 @@ impl<'compiler, 'parent> Scope<'compiler, 'parent> {
     pub fn error(&self, offset: Option<Offset>, msg: String) {
         self.errors.borrow_mut().push(Error::new(offset, msg))
     }
+
+    /// Reports a finding of a lint according to its level, either as warning or as error
+    pub fn lint(&self, offset: Option<Offset>, lint: &'static str, msg: String) {
+        let mut finding = Error::new(offset, msg);
+        finding.code = Some(lint);
+
+        if let Some(finding) = self.compiler.report(finding) {
+            self.errors.borrow_mut().push(finding);
+        }
+    }
+
+    /// Reports constants of the scope which were defined but never used
+    pub fn lint_unused(&self) {
+        let unused: Vec<_> = self.unused.borrow_mut().drain(..).collect();
+
+        for (name, offset) in unused {
+            // Constants taken from the scope, like exports of an import, are left out
+            if self.constants.borrow().contains_key(&name) {
+                self.lint(
+                    offset,
+                    "unused-constant",
+                    format!("Constant '{}' is never used", name),
+                );
+            }
+        }
+    }
 }
 
 impl<'compiler, 'parent> Drop for Scope<'compiler, 'parent> {
     fn drop(&mut self) {
         self.resolve_usages();
 
+        if self.parent.is_some() {
+            self.lint_unused();
+        }
+
         match &mut self.parent {
             Some(parent) => {
                 parent
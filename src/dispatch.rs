@@ -0,0 +1,95 @@
+//! Dispatch of inputs to the programs of grammars, selected by patterns
+use crate::Program;
+use std::path::Path;
+
+/** Grammars, each selected for the inputs matching its pattern.
+
+A pattern is a wildcard pattern, where `*` matches any sequence of characters and `?` matches one
+character. It is matched against
+
+- the file name of an input, like `*.csv`, or against its entire path when it contains a `/`,
+- the first line of an input's content when it starts with `^`, like `^<?xml*`, which sniffs
+  inputs regardless of their name.
+
+```
+use tokay::dispatch::Grammars;
+use tokay::Program;
+
+let mut grammars = Grammars::new();
+grammars.add("*.csv", Program::from_source("Int (',' Int)*").unwrap());
+grammars.add("^{*", Program::from_source("'{' Word '}'").unwrap());
+
+assert!(grammars.select("data/values.csv", "1,2,3").is_some());
+assert!(grammars.select("config", "{a}").is_some());
+assert!(grammars.select("notes.txt", "Hello").is_none());
+```
+*/
+#[derive(Default)]
+pub struct Grammars {
+    grammars: Vec<(String, Program)>, // Patterns and their programs, in order of precedence
+}
+
+impl Grammars {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a program used for inputs matching a pattern, after all grammars added before.
+    pub fn add(&mut self, pattern: &str, program: Program) {
+        self.grammars.push((pattern.to_string(), program));
+    }
+
+    /// Selects the program of the first grammar matching an input's filename or its first line.
+    pub fn select(&self, filename: &str, head: &str) -> Option<&Program> {
+        let name = Path::new(filename)
+            .file_name()
+            .and_then(|name| name.to_str())
+            .unwrap_or(filename);
+
+        self.grammars
+            .iter()
+            .find(|(pattern, _)| {
+                if let Some(pattern) = pattern.strip_prefix('^') {
+                    glob(pattern, head)
+                } else if pattern.contains('/') {
+                    glob(pattern, filename)
+                } else {
+                    glob(pattern, name)
+                }
+            })
+            .map(|(_, program)| program)
+    }
+}
+
+/// Matches a text against a wildcard pattern, where `*` matches any sequence of characters and `?` matches one character.
+pub fn glob(pattern: &str, text: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let text: Vec<char> = text.chars().collect();
+
+    // Position behind the last `*` in the pattern, and the text position it continues from
+    let mut backtrack: Option<(usize, usize)> = None;
+    let (mut p, mut t) = (0, 0);
+
+    while t < text.len() {
+        match pattern.get(p) {
+            Some('*') => {
+                p += 1;
+                backtrack = Some((p, t));
+            }
+            Some(ch) if *ch == '?' || *ch == text[t] => {
+                p += 1;
+                t += 1;
+            }
+            _ => match backtrack {
+                Some((star_p, star_t)) => {
+                    p = star_p;
+                    t = star_t + 1;
+                    backtrack = Some((star_p, star_t + 1));
+                }
+                None => return false,
+            },
+        }
+    }
+
+    pattern[p..].iter().all(|ch| *ch == '*')
+}
@@ -4,16 +4,50 @@ use crate::value::{Object, Str};
 extern crate self as tokay;
 use tokay_macros::tokay_function;
 
+/// Parselet call within the stack trace of a runtime error
+#[derive(Debug, Clone, PartialEq)]
+pub struct TraceFrame {
+    pub parselet: String,       // Name of the called parselet
+    pub source: Option<Offset>, // Position in the program source where the error passed by
+    pub reader: Offset,         // Reader offset where the parselet started
+}
+
+impl std::fmt::Display for TraceFrame {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "in {}", self.parselet)?;
+
+        if let Some(source) = self.source {
+            write!(f, ", line {}, column {}", source.row, source.col)?;
+        }
+
+        write!(f, " (input {}:{})", self.reader.row, self.reader.col)
+    }
+}
+
+/** Error object for compile-time and runtime errors.
+
+Runtime errors additionally carry a stack trace of the parselets they passed by, innermost first.
+The alternate format `{:#}` prints the stack trace below the error message, when the error
+passed by more than one parselet. */
 #[derive(Debug, Clone, PartialEq)]
 pub struct Error {
     pub offset: Option<Offset>,
+    pub filename: Option<String>,
     pub message: String,
+    pub code: Option<&'static str>, // Category, like "syntax", "compile" or "runtime"
+    pub trace: Vec<TraceFrame>,
 }
 
 impl Error {
     /// Creates a new Error object with a message.
     pub fn new(offset: Option<Offset>, message: String) -> Error {
-        Error { offset, message }
+        Error {
+            offset,
+            filename: None,
+            message,
+            code: None,
+            trace: Vec::new(),
+        }
     }
 
     /// Attaches position information to an error message when not already present
@@ -22,11 +56,67 @@ impl Error {
             self.offset = Some(offset);
         }
     }
+
+    /// Attaches a category code to an error, when not already present
+    pub fn patch_code(&mut self, code: &'static str) {
+        if self.code.is_none() {
+            self.code = Some(code);
+        }
+    }
+
+    /// Attaches a source filename to an error message with position information, when not already present
+    pub fn patch_filename(&mut self, filename: Option<&str>) {
+        if self.offset.is_some() && self.filename.is_none() {
+            self.filename = filename.map(|filename| filename.to_string());
+        }
+    }
+
+    /** Renders the source line an error refers to, annotated by a caret at the error's column.
+
+    The caret is extended by a squiggle up to the end of a word starting at the column. Returns
+    None when the error has no position, or the source doesn't contain its line. */
+    pub fn excerpt(&self, source: &str) -> Option<String> {
+        let offset = self.offset?;
+        let line = source.lines().nth((offset.row as usize).checked_sub(1)?)?;
+        let col = offset.col as usize - 1;
+
+        // Indent by the same whitespace as the line, to keep tab stops aligned
+        let indent: String = line
+            .chars()
+            .take(col)
+            .map(|ch| if ch == '\t' { '\t' } else { ' ' })
+            .collect();
+
+        let word = line
+            .chars()
+            .skip(col)
+            .take_while(|ch| ch.is_alphanumeric() || *ch == '_')
+            .count();
+
+        let width = offset.row.to_string().len();
+
+        Some(format!(
+            "{:width$} |\n{} | {}\n{:width$} | {}^{}",
+            "",
+            offset.row,
+            line,
+            "",
+            indent,
+            "~".repeat(word.saturating_sub(1)),
+            width = width
+        ))
+    }
 }
 
 impl std::fmt::Display for Error {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        if let Some(offset) = self.offset {
+        if let (Some(offset), Some(filename)) = (self.offset, &self.filename) {
+            write!(
+                f,
+                "{}:{}:{}: {}",
+                filename, offset.row, offset.col, self.message
+            )
+        } else if let Some(offset) = self.offset {
             write!(
                 f,
                 "Line {}, column {}: {}",
@@ -34,7 +124,143 @@ impl std::fmt::Display for Error {
             )
         } else {
             write!(f, "{}", self.message)
+        }?;
+
+        // A trace of just one frame doesn't tell more than the error's position
+        if f.alternate() && self.trace.len() > 1 {
+            for frame in &self.trace {
+                write!(f, "\n  {}", frame)?;
+            }
         }
+
+        Ok(())
+    }
+}
+
+/// Severity of a diagnostic
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Severity {
+    Error,
+    Warning,
+}
+
+impl Severity {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Error => "error",
+            Self::Warning => "warning",
+        }
+    }
+}
+
+/// Output format of diagnostics
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum DiagnosticFormat {
+    #[default]
+    Plain, // One line per diagnostic, followed by the stack trace
+    Rich, // Severity, code and location, followed by a source excerpt and the stack trace
+    Json, // One JSON object per line and diagnostic, for editor integrations
+}
+
+/** Renderer for errors and warnings as diagnostics.
+
+The plain format is the one of `{:#}`. The rich format is meant for terminals, and optionally
+highlighted by colors. The JSON format provides all details of a diagnostic in one line. */
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Diagnostics {
+    pub format: DiagnosticFormat, // Output format
+    pub color: bool,              // Highlight rich diagnostics by colors
+}
+
+impl Diagnostics {
+    /// Renders an error as diagnostic; `source` is used for source excerpts of errors without filename.
+    pub fn render(&self, severity: Severity, error: &Error, source: Option<&str>) -> String {
+        match self.format {
+            DiagnosticFormat::Plain => match severity {
+                Severity::Error => format!("{:#}", error),
+                Severity::Warning => format!("Warning: {:#}", error),
+            },
+            DiagnosticFormat::Rich => self.render_rich(severity, error, source),
+            DiagnosticFormat::Json => serde_json::json!({
+                "severity": severity.as_str(),
+                "code": error.code,
+                "message": error.message,
+                "file": error.filename,
+                "line": error.offset.map(|offset| offset.row),
+                "column": error.offset.map(|offset| offset.col),
+                "offset": error.offset.map(|offset| offset.offset),
+                "trace": error.trace.iter().map(|frame| serde_json::json!({
+                    "parselet": frame.parselet,
+                    "line": frame.source.map(|source| source.row),
+                    "column": frame.source.map(|source| source.col),
+                    "input_line": frame.reader.row,
+                    "input_column": frame.reader.col,
+                })).collect::<Vec<_>>(),
+            })
+            .to_string(),
+        }
+    }
+
+    fn render_rich(&self, severity: Severity, error: &Error, source: Option<&str>) -> String {
+        // Wraps text into an ANSI escape sequence, when colored
+        let paint = |code: &str, text: &str| {
+            if self.color {
+                format!("\x1b[{}m{}\x1b[0m", code, text)
+            } else {
+                text.to_string()
+            }
+        };
+
+        let mut ret = paint(
+            match severity {
+                Severity::Error => "1;31",
+                Severity::Warning => "1;33",
+            },
+            &match error.code {
+                Some(code) => format!("{}[{}]", severity.as_str(), code),
+                None => severity.as_str().to_string(),
+            },
+        );
+
+        ret.push_str(&paint("1", &format!(": {}", error.message)));
+
+        if let Some(offset) = error.offset {
+            ret.push_str(&paint(
+                "34",
+                &format!(
+                    "\n  --> {}:{}:{}",
+                    error.filename.as_deref().unwrap_or("<program>"),
+                    offset.row,
+                    offset.col
+                ),
+            ));
+        }
+
+        let source = match &error.filename {
+            Some(filename) => std::fs::read_to_string(filename).ok(),
+            None => source.map(|source| source.to_string()),
+        };
+
+        if let Some(excerpt) = source.and_then(|source| error.excerpt(&source)) {
+            for line in excerpt.lines() {
+                // Highlight the caret line
+                ret.push('\n');
+
+                if line.trim_end().ends_with(['^', '~']) {
+                    ret.push_str(&paint("1;31", line));
+                } else {
+                    ret.push_str(line);
+                }
+            }
+        }
+
+        if error.trace.len() > 1 {
+            for frame in &error.trace {
+                ret.push_str(&format!("\n  {}", frame));
+            }
+        }
+
+        ret
     }
 }
 
@@ -67,5 +293,8 @@ tokay_function!("error : @msg, collect=false", {
         }
     }
 
-    Error::new(Some(context.thread.reader.tell()), msg).into()
+    let reader = &context.thread.reader;
+    let mut error = Error::new(Some(reader.origin(&reader.tell(), false)), msg);
+    error.patch_filename(context.thread.reader.filename.as_deref());
+    error.into()
 });
@@ -0,0 +1,50 @@
+//! Example programs bundled with Tokay, which run on sample input
+use crate::{Error, Program, RefValue};
+
+/// An example program with its sample input, embedded from the examples directory.
+pub struct Example {
+    pub name: &'static str,
+    pub description: &'static str,
+    pub filename: &'static str,
+    pub program: &'static str,
+    pub input: &'static str,
+}
+
+/// All bundled examples, in the order they are presented.
+pub const EXAMPLES: &[Example] = &[
+    Example {
+        name: "calc",
+        description: "A calculator for expressions with operator precedence and parentheses",
+        filename: "examples/calc.tok",
+        program: include_str!("../examples/calc.tok"),
+        input: include_str!("../examples/calc.txt"),
+    },
+    Example {
+        name: "csv",
+        description: "Sums up the amounts of a CSV file of expenses per category",
+        filename: "examples/csv_summary.tok",
+        program: include_str!("../examples/csv_summary.tok"),
+        input: include_str!("../examples/csv_summary.csv"),
+    },
+    Example {
+        name: "log",
+        description: "Extracts the status codes from an access log and counts them",
+        filename: "examples/access_log.tok",
+        program: include_str!("../examples/access_log.tok"),
+        input: include_str!("../examples/access_log.txt"),
+    },
+];
+
+impl Example {
+    /// Find a bundled example by its name.
+    pub fn get(name: &str) -> Option<&'static Example> {
+        EXAMPLES.iter().find(|example| example.name == name)
+    }
+
+    /// Compiles the example program and runs it on its sample input.
+    pub fn run(&self) -> Result<Option<RefValue>, Vec<Error>> {
+        Program::from_source(self.program)?
+            .run_on_str(self.input)
+            .map_err(|error| vec![error])
+    }
+}
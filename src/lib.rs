@@ -12,8 +12,12 @@
 mod _builtins; // Generated builtin registry
 pub mod builtin;
 pub mod compiler;
+pub mod dispatch;
 pub mod error;
+pub mod examples;
 pub mod reader;
+#[cfg(feature = "serve")]
+pub mod serve;
 #[cfg(test)]
 pub mod test;
 mod utils;
@@ -21,7 +25,7 @@ pub mod value;
 pub mod vm;
 
 pub use compiler::Compiler;
-pub use error::Error;
+pub use error::{DiagnosticFormat, Diagnostics, Error, Severity, TraceFrame};
 pub use reader::Reader;
 pub use utils::run;
 pub use value::{Dict, List, Object, RefValue, Str, Value};
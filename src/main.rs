@@ -2,10 +2,17 @@
 use clap::Parser;
 use env_logger;
 use rustyline;
-use std::fs::{self, File};
-use std::io::{self, BufReader};
-use tokay::vm::Thread;
-use tokay::{Compiler, Object, Reader, RefValue};
+use std::io::{self, BufRead, IsTerminal};
+use tokay::builtin::escape::unescape;
+use tokay::compiler::{Cache, LintLevel, Unmatched};
+use tokay::dispatch::Grammars;
+use tokay::examples::{Example, EXAMPLES};
+use tokay::reader::Scrub;
+use tokay::value::{Lossy, Overflow};
+use tokay::vm::{Debugger, Profiler, ScanRange, Thread, TraceFormat, Tracer, HOT_REENTRIES};
+use tokay::{
+    Compiler, DiagnosticFormat, Diagnostics, Error, Object, Program, Reader, RefValue, Severity,
+};
 
 fn print_version() {
     println!("Tokay {}", env!("CARGO_PKG_VERSION"));
@@ -17,6 +24,7 @@ fn print_version() {
     author,
     version,
     about,
+    disable_help_subcommand = true,
     help_template = r#"{bin} {version}
 © 2024 by {author}
 {about}
@@ -33,6 +41,9 @@ When a PROGRAM is not specified, {bin} turns into an interactive REPL.
 Visit https://tokay.dev/ for help and further information."#
 )]
 struct Opts {
+    #[clap(subcommand)]
+    command: Option<Command>,
+
     /// Program to compile and run.
     #[clap(value_parser)]
     program: Option<String>,
@@ -41,6 +52,14 @@ struct Opts {
     #[clap(value_parser, last = true)]
     input: Vec<String>,
 
+    /// Runs every INPUT file by the PROGRAM of the first grammar whose PATTERN matches its file name, like '*.csv=csv.tok', or its first line when PATTERN starts with '^'. Can be given multiple times, instead of PROGRAM.
+    #[clap(long, value_name = "PATTERN=PROGRAM", value_parser)]
+    grammar: Vec<String>,
+
+    /// Adds DIR to the directories searched for files imported by PROGRAM.
+    #[clap(short = 'I', long, value_name = "DIR", value_parser)]
+    import_path: Vec<std::path::PathBuf>,
+
     /// Sets the debug level.
     #[clap(short, long, action = clap::ArgAction::Count)]
     debug: u8,
@@ -49,6 +68,152 @@ struct Opts {
     #[clap(short, long, action)]
     echo: bool,
 
+    /// Format of results printed by '--echo' and the REPL: plain text, JSON, or Tokay literals which can be read again.
+    #[clap(long, value_name = "FORMAT", value_parser = ["text", "json", "tokay"], default_value = "text")]
+    format: String,
+
+    /// Dump the abstract syntax tree of PROGRAM as JSON and exit.
+    #[clap(long, action)]
+    ast_json: bool,
+
+    /// Compile PROGRAM into a file which can be run directly later on, and exit.
+    #[clap(long, action)]
+    compile: bool,
+
+    /// Output file for '--compile', defaults to PROGRAM with extension '.tokc'.
+    #[clap(short, long, value_parser)]
+    output: Option<String>,
+
+    /// Always compile PROGRAM, instead of reusing a compiled program cached in '~/.cache/tokay'.
+    #[clap(long, action)]
+    no_cache: bool,
+
+    /// Explain why input didn't match.
+    #[clap(long, action)]
+    explain: bool,
+
+    /// Print a snapshot of the runtime state, like active parselets and globals, on a runtime error.
+    #[clap(long, action)]
+    post_mortem: bool,
+
+    /// Write the time spent in parselet call stacks as folded stacks into FILE, to render a flame graph.
+    /// Parselets frequently re-entered at the same input offset are reported.
+    #[clap(long, value_name = "FILE", value_parser)]
+    flamegraph: Option<String>,
+
+    /// Print the calls, accepted ratio, total and self time, and consumed bytes of every parselet on stderr after the run.
+    #[clap(long, action)]
+    profile: bool,
+
+    /// Trace parselet calls and token matches on stderr.
+    #[clap(long, action)]
+    trace: bool,
+
+    /// Format of traced events: text, or json with one event per line. Implies '--trace'.
+    #[clap(long, value_name = "FORMAT", value_parser = ["text", "json"])]
+    trace_format: Option<String>,
+
+    /// Debug PROGRAM interactively, breaking at parselet entries. Commands are read from stdin, so INPUT must be given.
+    #[clap(long, action)]
+    debugger: bool,
+
+    /// Stop scanning an INPUT after NUM matches.
+    #[clap(short, long, value_name = "NUM", value_parser)]
+    max_matches: Option<usize>,
+
+    /// Fail with an error when parselet calls are nested deeper than NUM levels. Calls nested deeper than the native stack allows always fail with an error.
+    #[clap(long, value_name = "NUM", value_parser)]
+    max_depth: Option<usize>,
+
+    /// Fail with an error when more than BYTES of input are backtracked in total.
+    #[clap(long, value_name = "BYTES", value_parser)]
+    max_backtrack: Option<usize>,
+
+    /// Fail with an error when more than NUM values allocated by the program are alive at once.
+    #[clap(long, value_name = "NUM", value_parser)]
+    max_values: Option<usize>,
+
+    /// Scan only matches starting within a byte range of INPUT, given as START..END.
+    #[clap(long, value_name = "RANGE", value_parser = parse_range, conflicts_with = "line_range")]
+    byte_range: Option<std::ops::Range<usize>>,
+
+    /// Scan only matches starting within a line range of INPUT, given as START..END.
+    #[clap(long, value_name = "RANGE", value_parser = parse_range)]
+    line_range: Option<std::ops::Range<usize>>,
+
+    /// Print each match with NUM lines of context after it.
+    #[clap(short = 'A', long, value_name = "NUM", value_parser)]
+    after_context: Option<usize>,
+
+    /// Print each match with NUM lines of context before it.
+    #[clap(short = 'B', long, value_name = "NUM", value_parser)]
+    before_context: Option<usize>,
+
+    /// Print each match with NUM lines of context before and after it.
+    #[clap(short = 'C', long, value_name = "NUM", value_parser)]
+    context: Option<usize>,
+
+    /// Highlight matches in lines printed with context; "auto" highlights on a terminal, unless NO_COLOR is set.
+    #[clap(long, value_name = "WHEN", value_parser = ["auto", "always", "never"], default_value = "auto")]
+    color: String,
+
+    /// Disable colors in diagnostics and highlighted matches.
+    #[clap(long, action)]
+    no_color: bool,
+
+    /// Format of errors and warnings: text, or JSON objects, one per line, for editor integrations.
+    #[clap(long, value_name = "FORMAT", value_parser = ["text", "json"], default_value = "text")]
+    error_format: String,
+
+    /// Result of optionals 'P?' not matching: void, an empty string, or no capture at all, which moves up following captures.
+    #[clap(long, value_name = "MODE", value_parser = ["void", "empty", "skip"], default_value = "void")]
+    unmatched: String,
+
+    /// Don't inline tiny parselets at their call sites. Implied by '--trace', '--flamegraph', '--profile' and '--debugger', which report parselet calls.
+    #[clap(long, action)]
+    no_inline: bool,
+
+    /// Run the program in sandbox mode, without builtins touching the environment like print(), and without imports.
+    #[clap(long, action)]
+    sandbox: bool,
+
+    /// Report findings of LINT as warnings, or of all lints by 'all'. LINT is one of capture-index, empty-repeat,
+    /// infinite-recursion, shadowed-name, unreachable-alternative or unused-constant.
+    #[clap(short = 'W', long, value_name = "LINT", value_parser)]
+    warn: Vec<String>,
+
+    /// Report findings of LINT as errors, which stop compilation, or of all lints by 'all'.
+    #[clap(short = 'D', long, value_name = "LINT", value_parser)]
+    deny: Vec<String>,
+
+    /// Ignore findings of LINT, or of all lints by 'all'.
+    #[clap(long, value_name = "LINT", value_parser)]
+    allow: Vec<String>,
+
+    /// Behavior of integer operations exceeding 64 bits: promote to big integers, fail with an error, wrap around or saturate.
+    #[clap(long, value_name = "MODE", value_parser = ["promote", "error", "wrap", "saturate"], default_value = "promote")]
+    overflow: String,
+
+    /// Behavior of conversions losing information, like "12abc" to 12 or 1.5 to 1: ignore, report a warning or fail with an error.
+    #[clap(long, value_name = "MODE", value_parser = ["ignore", "warn", "error"], default_value = "ignore")]
+    lossy: String,
+
+    /// Run the main parselet on every line of INPUT separately, like awk.
+    #[clap(long, action)]
+    lines: bool,
+
+    /// Like '--lines', but with records separated by SEP, which may contain escape sequences.
+    #[clap(long, value_name = "SEP", value_parser, conflicts_with = "lines")]
+    records: Option<String>,
+
+    /// Replace or skip invalid UTF-8 sequences and control characters in INPUT.
+    #[clap(long, value_parser = ["replace", "skip"])]
+    scrub: Option<String>,
+
+    /// Read INPUT as binary, every byte as one character, and capture bytes instead of strings.
+    #[clap(long, action)]
+    binary: bool,
+
     /// Accept only files as parameters, no string fallbacks.
     #[clap(short, long, action)]
     files: bool,
@@ -66,6 +231,183 @@ struct Opts {
     license: bool,
 }
 
+#[derive(clap::Subcommand)]
+enum Command {
+    /// List the bundled example programs, or run one by NAME on its sample input.
+    Examples {
+        /// Name of the example to run.
+        #[clap(value_parser)]
+        name: Option<String>,
+    },
+    /// Compile PROGRAM without running it, and print its diagnostics and statistics about its rules.
+    Check {
+        /// Program file to check.
+        #[clap(value_parser)]
+        program: String,
+    },
+    /// Run a local HTTP server, which runs grammars on input posted to '/run' as JSON.
+    #[cfg(feature = "serve")]
+    Serve {
+        /// Also serve a web-based visualizer of parse trees, matches and traces on '/'.
+        #[clap(long, action)]
+        visualize: bool,
+
+        /// Port to listen on at localhost.
+        #[clap(long, value_parser, default_value = "8080")]
+        port: u16,
+    },
+}
+
+/// Lists the bundled examples, or runs an example and prints it annotated with its results.
+fn examples(opts: &Opts, name: Option<&str>) {
+    let Some(name) = name else {
+        println!("Examples bundled with Tokay, run one with 'tokay examples NAME':\n");
+
+        for example in EXAMPLES {
+            println!("  {:<8}{}", example.name, example.description);
+        }
+
+        return;
+    };
+
+    let Some(example) = Example::get(name) else {
+        eprintln!(
+            "No example named '{}', run 'tokay examples' for a list",
+            name
+        );
+        std::process::exit(1);
+    };
+
+    println!("# {}: {}\n", example.name, example.description);
+    println!("# Program {}", example.filename);
+    print!("{}", example.program);
+    println!("\n# Input");
+    print!("{}", example.input);
+    println!("\n# Output");
+
+    match example.run() {
+        Ok(None) => {}
+        Ok(Some(value)) => println!("{}", value.repr()),
+        Err(errors) => {
+            print_errors(opts, &errors, Some(example.program));
+            std::process::exit(1);
+        }
+    }
+}
+
+/// Print a result in the format selected by '--format', where `text` renders plain text.
+fn print_result(opts: &Opts, value: &RefValue, text: impl FnOnce() -> String) {
+    match opts.format.as_str() {
+        "json" => match serde_json::to_string_pretty(value) {
+            Ok(json) => println!("{}", json),
+            Err(err) => eprintln!("Can't print result as JSON: {}", err),
+        },
+        "tokay" => match value.to_literal(Some(4)) {
+            Ok(literal) => println!("{}", literal),
+            Err(msg) => eprintln!("Can't print result as Tokay literal: {}", msg),
+        },
+        _ => println!("{}", text()),
+    }
+}
+
+/// Get the integer overflow behavior from the provided option
+fn get_overflow(opts: &Opts) -> Overflow {
+    match opts.overflow.as_str() {
+        "error" => Overflow::Error,
+        "wrap" => Overflow::Wrap,
+        "saturate" => Overflow::Saturate,
+        _ => Overflow::Promote,
+    }
+}
+
+/// Get the behavior of lossy conversions from the provided option
+fn get_lossy(opts: &Opts) -> Lossy {
+    match opts.lossy.as_str() {
+        "warn" => Lossy::Warn,
+        "error" => Lossy::Error,
+        _ => Lossy::Ignore,
+    }
+}
+
+/// Create a compiler configured by the provided options
+/// Compiles a program without running it, and prints its diagnostics and statistics.
+fn check(opts: &Opts, filename: &str) {
+    let reader = match Reader::from_file(filename) {
+        Ok(reader) => reader,
+        Err(err) => {
+            eprintln!("Can't open PROGRAM file '{}': {}", filename, err);
+            std::process::exit(1);
+        }
+    };
+
+    let mut compiler = get_compiler(opts);
+    let program = compiler.compile(reader);
+
+    print_warnings(opts, &compiler, None);
+
+    match program {
+        Ok(Some(program)) => println!("{}", program.stats()),
+        Ok(None) => {}
+        Err(errors) => {
+            print_errors(opts, &errors, None);
+            std::process::exit(1);
+        }
+    }
+}
+
+fn get_compiler(opts: &Opts) -> Compiler {
+    let mut compiler = Compiler::new();
+    compiler
+        .search_path
+        .extend(opts.import_path.iter().cloned());
+
+    compiler.unmatched = match opts.unmatched.as_str() {
+        "empty" => Unmatched::Empty,
+        "skip" => Unmatched::Skip,
+        _ => Unmatched::Void,
+    };
+
+    compiler.inline = !opts.no_inline
+        && !opts.trace
+        && opts.trace_format.is_none()
+        && opts.flamegraph.is_none()
+        && !opts.profile
+        && !opts.debugger;
+
+    compiler.sandbox = opts.sandbox;
+
+    for (lints, level) in [
+        (&opts.allow, LintLevel::Allow),
+        (&opts.warn, LintLevel::Warn),
+        (&opts.deny, LintLevel::Deny),
+    ] {
+        for lint in lints {
+            if let Err(msg) = compiler.set_lint(lint, level) {
+                eprintln!("{}", msg);
+                std::process::exit(1);
+            }
+        }
+    }
+
+    compiler
+}
+
+/// Get the diagnostics renderer from the provided options
+fn get_diagnostics(opts: &Opts) -> Diagnostics {
+    let terminal = io::stderr().is_terminal();
+
+    Diagnostics {
+        format: match opts.error_format.as_str() {
+            "json" => DiagnosticFormat::Json,
+            _ if terminal => DiagnosticFormat::Rich,
+            _ => DiagnosticFormat::Plain,
+        },
+        color: terminal
+            && !opts.no_color
+            && std::env::var_os("NO_COLOR").is_none_or(|var| var.is_empty()),
+    }
+}
+
 /// Create Readers from provided filesnames
 fn get_readers(opts: &Opts) -> Vec<Reader> {
     // Try getting files to run on program or repl
@@ -73,33 +415,85 @@ fn get_readers(opts: &Opts) -> Vec<Reader> {
 
     for filename in &opts.input {
         if filename == "-" && !opts.files {
-            readers.push(Reader::new(
-                Some(filename.to_string()),
-                Box::new(BufReader::new(io::stdin())),
-            ));
-        } else if let Ok(file) = File::open(filename) {
-            readers.push(Reader::new(
-                Some(filename.to_string()),
-                Box::new(BufReader::new(file)),
-            ));
-        } else if !opts.files {
-            readers.push(Reader::new(
-                None,
-                Box::new(io::Cursor::new(filename.clone())),
-            ));
+            readers.push(Reader::from_stdin());
         } else {
-            eprintln!("Can't open INPUT file '{}'", filename);
-            std::process::exit(1);
+            match Reader::from_file(filename) {
+                Ok(reader) => readers.push(reader),
+                Err(_) if !opts.files => readers.push(Reader::new(
+                    None,
+                    Box::new(io::Cursor::new(filename.clone())),
+                )),
+                Err(err) => {
+                    eprintln!("Can't open INPUT file '{}': {}", filename, err);
+                    std::process::exit(1);
+                }
+            }
         }
     }
 
     readers
 }
 
+/** Checks whether code is incomplete and requires further lines.
+
+This is the case for unterminated blocks, parentheses, brackets, strings and
+character-classes, or when the code ends with an escaped line-break. */
+fn is_incomplete(code: &str) -> bool {
+    let mut depth = 0;
+    let mut chars = code.char_indices();
+
+    // Skips chars until an unescaped delimiter; Returns false when the end of code was reached.
+    fn skip_until(chars: &mut std::str::CharIndices, delimiter: char) -> bool {
+        while let Some((_, ch)) = chars.next() {
+            match ch {
+                '\\' => {
+                    chars.next();
+                }
+                ch if ch == delimiter => return true,
+                _ => {}
+            }
+        }
+
+        false
+    }
+
+    while let Some((i, ch)) = chars.next() {
+        match ch {
+            '(' | '[' | '{' => depth += 1,
+            ')' | ']' | '}' => depth -= 1,
+            '#' => {
+                // Skip comment until end of line
+                if !chars.any(|(_, ch)| ch == '\n') {
+                    break;
+                }
+            }
+            '\\' => {
+                // Escaped line-break at the end of the code
+                if chars.next().is_none() {
+                    return true;
+                }
+            }
+            '"' | '\'' => {
+                if !skip_until(&mut chars, ch) {
+                    return true;
+                }
+            }
+            '<' if code[..i].ends_with("Char") || code[..i].ends_with("Chars") => {
+                if !skip_until(&mut chars, '>') {
+                    return true;
+                }
+            }
+            _ => {}
+        }
+    }
+
+    depth > 0
+}
+
 // Read-Eval-Print-Loop (REPL) for Tokay
 fn repl(opts: &Opts) -> rustyline::Result<()> {
     let mut globals: Vec<RefValue> = Vec::new();
-    let mut compiler = Compiler::new();
+    let mut compiler = get_compiler(opts);
 
     // todo: Implement a completer?
     let mut readline = rustyline::DefaultEditor::new()?;
@@ -110,8 +504,16 @@ fn repl(opts: &Opts) -> rustyline::Result<()> {
         readline.load_history(".tokayhist").ok();
     }
 
+    let mut code = String::new();
+
     loop {
-        let code = match readline.readline(">>> ") {
+        let line = match readline.readline(if code.is_empty() { ">>> " } else { "... " }) {
+            // Ctrl+C discards an unfinished input
+            Err(rustyline::error::ReadlineError::Interrupted) if !code.is_empty() => {
+                code.clear();
+                continue;
+            }
+
             Err(rustyline::error::ReadlineError::Interrupted)
             | Err(rustyline::error::ReadlineError::Eof) => break,
 
@@ -120,14 +522,27 @@ fn repl(opts: &Opts) -> rustyline::Result<()> {
                 break;
             }
 
-            Ok(code) => code,
+            Ok(line) => line,
         };
 
         // Stop when program is empty.
-        if code.trim().is_empty() {
+        if code.is_empty() && line.trim().is_empty() {
+            continue;
+        }
+
+        if !code.is_empty() {
+            code.push('\n');
+        }
+
+        code.push_str(&line);
+
+        // Continue prompting on unfinished input
+        if is_incomplete(&code) {
             continue;
         }
 
+        let code = std::mem::take(&mut code);
+
         //println!("code = {:?}", code);
 
         readline.add_history_entry(code.as_str())?;
@@ -143,34 +558,47 @@ fn repl(opts: &Opts) -> rustyline::Result<()> {
                 println!("<<< Debug switched off")
             }
             */
-            _ => match compiler.compile(Reader::new(None, Box::new(io::Cursor::new(code)))) {
-                Ok(None) => {}
-                Ok(Some(program)) => {
-                    let mut readers = get_readers(&opts);
-
-                    // In case no stream was specified and REPL fires up, read on an empty string.
-                    if readers.len() == 0 {
-                        readers.push(Reader::new(None, Box::new(io::Cursor::new(String::new()))));
-                    }
+            _ => {
+                let result =
+                    compiler.compile(Reader::new(None, Box::new(io::Cursor::new(code.clone()))));
 
-                    let mut thread = Thread::new(&program, readers.iter_mut().collect());
-                    thread.debug = compiler.debug;
-                    thread.globals = globals;
+                print_warnings(opts, &compiler, Some(&code));
 
-                    match thread.run() {
-                        Ok(Some(value)) => println!("{}", value.repr()),
-                        Err(error) => eprintln!("{}", error),
-                        _ => {}
-                    }
+                match result {
+                    Ok(None) => {}
+                    Ok(Some(program)) => {
+                        let mut readers = get_readers(&opts);
 
-                    globals = thread.globals;
-                }
-                Err(errors) => {
-                    for error in errors {
-                        eprintln!("{}", error);
+                        // In case no stream was specified and REPL fires up, read on an empty string.
+                        if readers.len() == 0 {
+                            readers
+                                .push(Reader::new(None, Box::new(io::Cursor::new(String::new()))));
+                        }
+
+                        let mut thread = Thread::new(&program, readers.iter_mut().collect());
+                        thread.debug = compiler.debug;
+                        thread.globals = globals;
+                        thread.options.overflow = get_overflow(&opts);
+                        thread.options.lossy = get_lossy(&opts);
+
+                        match thread.run() {
+                            Ok(Some(value)) => print_result(opts, &value, || value.repr()),
+                            Err(error) => eprintln!(
+                                "{}",
+                                get_diagnostics(opts).render(Severity::Error, &error, Some(&code))
+                            ),
+                            _ => {}
+                        }
+
+                        if let Some(code) = thread.exit {
+                            std::process::exit(code);
+                        }
+
+                        globals = thread.globals;
                     }
+                    Err(errors) => print_errors(opts, &errors, Some(&code)),
                 }
-            },
+            }
         }
     }
 
@@ -184,6 +612,293 @@ fn repl(opts: &Opts) -> rustyline::Result<()> {
     Ok(())
 }
 
+// Parse a range given as START..END, where START and END are optional.
+fn parse_range(range: &str) -> Result<std::ops::Range<usize>, String> {
+    let (start, end) = range
+        .split_once("..")
+        .ok_or_else(|| format!("Invalid range '{}', expecting START..END", range))?;
+
+    let bound = |bound: &str, default| {
+        if bound.is_empty() {
+            Ok(default)
+        } else {
+            bound
+                .parse::<usize>()
+                .map_err(|err| format!("Invalid range bound '{}': {}", bound, err))
+        }
+    };
+
+    Ok(bound(start, 0)?..bound(end, usize::MAX)?)
+}
+
+/** Prints errors of a compilation as diagnostics.
+
+When printing to a terminal, every error is followed by an excerpt of the source line it refers to,
+taken from its file, or from `source` when the program wasn't read from a file. */
+fn print_errors(opts: &Opts, errors: &[Error], source: Option<&str>) {
+    let diagnostics = get_diagnostics(opts);
+
+    for error in errors {
+        eprintln!("{}", diagnostics.render(Severity::Error, error, source));
+    }
+}
+
+/// Prints warnings raised by the compiler as diagnostics, unless quiet.
+fn print_warnings(opts: &Opts, compiler: &Compiler, source: Option<&str>) {
+    let warnings = compiler.take_warnings();
+
+    if opts.quiet {
+        return;
+    }
+
+    let diagnostics = get_diagnostics(opts);
+
+    for warning in warnings {
+        eprintln!(
+            "{}",
+            diagnostics.render(Severity::Warning, &warning, source)
+        );
+    }
+}
+
+/// Runs a program on readers, and prints its results and diagnostics as configured by the options.
+fn run(opts: &Opts, program: &Program, mut readers: Vec<Reader>, source: Option<&str>) {
+    let scrub = match opts.scrub.as_deref() {
+        Some("replace") => Scrub::Replace,
+        Some("skip") => Scrub::Skip,
+        _ => Scrub::Off,
+    };
+
+    let separator = if opts.lines {
+        Some("\n".to_string())
+    } else {
+        opts.records.as_deref().map(unescape)
+    };
+
+    if separator.as_deref() == Some("") {
+        eprintln!("Record separator must not be empty");
+        std::process::exit(1);
+    }
+
+    for reader in readers.iter_mut() {
+        reader.scrub = scrub;
+        reader.binary = opts.binary;
+
+        if let Some(separator) = &separator {
+            reader.split_records(separator);
+        }
+    }
+
+    let mut thread = Thread::new(program, readers.iter_mut().collect());
+    thread.options.explain = opts.explain;
+    thread.options.post_mortem = opts.post_mortem;
+    thread.options.max_matches = opts.max_matches;
+    thread.options.max_depth = opts.max_depth;
+    thread.options.max_backtrack = opts.max_backtrack;
+    thread.options.max_values = opts.max_values;
+    thread.options.args = opts.input.clone();
+
+    if opts.after_context.is_some() || opts.before_context.is_some() || opts.context.is_some() {
+        thread.options.context = Some((
+            opts.before_context.or(opts.context).unwrap_or(0),
+            opts.after_context.or(opts.context).unwrap_or(0),
+        ));
+
+        thread.options.color = match opts.color.as_str() {
+            _ if opts.no_color => false,
+            "always" => true,
+            "never" => false,
+            _ => {
+                io::stdout().is_terminal()
+                    && std::env::var_os("NO_COLOR").is_none_or(|var| var.is_empty())
+            }
+        };
+    }
+
+    thread.options.overflow = get_overflow(opts);
+    thread.options.lossy = get_lossy(opts);
+
+    thread.options.range = if let Some(range) = &opts.byte_range {
+        Some(ScanRange::Bytes(range.clone()))
+    } else {
+        opts.line_range.clone().map(ScanRange::Lines)
+    };
+
+    if opts.flamegraph.is_some() || opts.profile {
+        thread.profiler = Some(Profiler::new());
+    }
+
+    if opts.trace || opts.trace_format.is_some() {
+        let format = match opts.trace_format.as_deref() {
+            Some("json") => TraceFormat::Json,
+            _ => TraceFormat::Text,
+        };
+
+        thread.observers.push(Box::new(Tracer::new(
+            format,
+            Box::new(io::BufWriter::new(io::stderr())),
+        )));
+    }
+
+    if opts.debugger {
+        thread.debugger = Some(Debugger::new(
+            Box::new(io::BufReader::new(io::stdin())),
+            Box::new(io::stderr()),
+        ));
+    }
+
+    let result = thread.run();
+    let exit = thread.exit;
+
+    if let (Some(filename), Some(profiler)) = (&opts.flamegraph, &thread.profiler) {
+        if let Err(err) = std::fs::File::create(filename)
+            .and_then(|file| profiler.write_folded(io::BufWriter::new(file)))
+        {
+            eprintln!("Can't write flame graph file '{}': {}", filename, err);
+        }
+    }
+
+    if let (true, Some(profiler)) = (opts.profile, &thread.profiler) {
+        if let Err(err) = profiler.write_report(io::stderr()) {
+            eprintln!("Can't write profile: {}", err);
+        }
+    }
+
+    if let Some(profiler) = &thread.profiler {
+        for (name, reentries) in &profiler.reentries {
+            if !opts.quiet && *reentries >= HOT_REENTRIES {
+                eprintln!(
+                    "Hint: '{}' was re-entered {} times at the same input offset, consider flagging it with 'cache'",
+                    name, reentries
+                );
+            }
+        }
+    }
+
+    let diagnostics = get_diagnostics(opts);
+
+    if !opts.quiet {
+        for warning in &thread.warnings {
+            eprintln!(
+                "{}",
+                diagnostics.render(Severity::Warning, &Error::from(warning.clone()), None)
+            );
+        }
+    }
+
+    if thread.scrubbed() > 0 && !opts.quiet {
+        eprintln!(
+            "{} invalid sequences or control characters scrubbed from input",
+            thread.scrubbed()
+        );
+    }
+
+    if opts.explain {
+        // Explain the remaining failure in case of an error
+        if result.is_err() {
+            thread.explain();
+        }
+
+        for failure in &thread.failures {
+            eprintln!("{}", failure);
+        }
+    }
+
+    match result {
+        Ok(None) => {
+            if opts.echo && readers.len() > 1 {
+                print!("\n")
+            }
+        }
+        Ok(Some(value)) => {
+            if opts.echo {
+                print_result(opts, &value, || value.to_string())
+            }
+        }
+        Err(error) => {
+            eprintln!("{}", diagnostics.render(Severity::Error, &error, source));
+
+            if let Some(snapshot) = &thread.snapshot {
+                eprintln!("--- Post-mortem ---\n{}", snapshot);
+            }
+        }
+    }
+
+    // Stop with the exit code of a program stopped by exit()
+    if let Some(code) = exit {
+        std::process::exit(code);
+    }
+}
+
+/// Compiles the programs of the grammars, and runs every INPUT file by the one selected for it.
+fn dispatch(opts: &Opts) {
+    let mut grammars = Grammars::new();
+
+    for grammar in &opts.grammar {
+        let Some((pattern, filename)) = grammar.split_once('=') else {
+            eprintln!("Grammar '{}' must be given as PATTERN=PROGRAM", grammar);
+            std::process::exit(1);
+        };
+
+        let reader = match Reader::from_file(filename) {
+            Ok(reader) => reader,
+            Err(err) => {
+                eprintln!("Can't open PROGRAM file '{}': {}", filename, err);
+                std::process::exit(1);
+            }
+        };
+
+        let mut compiler = get_compiler(opts);
+        let program = compiler.compile(reader);
+
+        print_warnings(opts, &compiler, None);
+
+        match program {
+            Ok(Some(program)) => grammars.add(pattern, program),
+            Ok(None) => {}
+            Err(errors) => {
+                print_errors(opts, &errors, None);
+                std::process::exit(1);
+            }
+        }
+    }
+
+    let mut unmatched = false;
+
+    for filename in &opts.input {
+        // The first line is read for grammars sniffing the content
+        let head = match std::fs::File::open(filename) {
+            Ok(file) => {
+                let mut line = String::new();
+                let _ = io::BufReader::new(file).read_line(&mut line);
+                line.trim_end_matches(['\r', '\n']).to_string()
+            }
+            Err(err) => {
+                eprintln!("Can't open INPUT file '{}': {}", filename, err);
+                std::process::exit(1);
+            }
+        };
+
+        let Some(program) = grammars.select(filename, &head) else {
+            eprintln!("No grammar matches INPUT file '{}'", filename);
+            unmatched = true;
+            continue;
+        };
+
+        match Reader::from_file(filename) {
+            Ok(reader) => run(opts, program, vec![reader], None),
+            Err(err) => {
+                eprintln!("Can't open INPUT file '{}': {}", filename, err);
+                std::process::exit(1);
+            }
+        }
+    }
+
+    if unmatched {
+        std::process::exit(1);
+    }
+}
+
 fn main() -> rustyline::Result<()> {
     env_logger::init();
 
@@ -202,32 +917,70 @@ fn main() -> rustyline::Result<()> {
         std::process::exit(0);
     }
 
+    if let Some(Command::Examples { name }) = &opts.command {
+        examples(&opts, name.as_deref());
+        std::process::exit(0);
+    }
+
+    if let Some(Command::Check { program }) = &opts.command {
+        check(&opts, program);
+        std::process::exit(0);
+    }
+
+    #[cfg(feature = "serve")]
+    if let Some(Command::Serve { visualize, port }) = &opts.command {
+        let server = match tokay::serve::Server::bind(("127.0.0.1", *port), *visualize) {
+            Ok(server) => server,
+            Err(err) => {
+                eprintln!("Can't listen on port {}: {}", port, err);
+                std::process::exit(1);
+            }
+        };
+
+        if !opts.quiet {
+            if let Ok(url) = server.url() {
+                eprintln!("Tokay serving on {}", url);
+            }
+        }
+
+        if let Err(err) = server.run() {
+            eprintln!("Server failed: {}", err);
+            std::process::exit(1);
+        }
+
+        std::process::exit(0);
+    }
+
+    if !opts.grammar.is_empty() {
+        if opts.program.is_some() {
+            eprintln!("PROGRAM can't be used together with '--grammar', provide INPUT after '--'");
+            std::process::exit(1);
+        }
+
+        dispatch(&opts);
+        std::process::exit(0);
+    }
+
     // Read program, either from stdin, file or direct string.
     let mut program: Option<Reader> = None;
+    let mut source: Option<String> = None; // Source of a program directly given as string
+    let mut cached = false; // Program read from a file, whose compiled program is cached
 
     if let Some(prog) = &opts.program {
         if prog == "-" && !opts.files {
-            program = Some(Reader::new(
-                Some(prog.to_string()),
-                Box::new(BufReader::new(io::stdin())),
-            ));
+            program = Some(Reader::from_stdin());
         } else {
-            if let Some(meta) = fs::metadata(prog).ok() {
-                if !meta.is_dir() {
-                    if let Ok(file) = File::open(prog) {
-                        program = Some(Reader::new(
-                            Some(prog.to_string()),
-                            Box::new(BufReader::new(file)),
-                        ));
-                    }
+            match Reader::from_file(prog) {
+                Ok(reader) => {
+                    program = Some(reader);
+                    cached = !opts.no_cache;
                 }
-            }
-
-            if program.is_none() {
-                if !opts.files {
-                    program = Some(Reader::new(None, Box::new(io::Cursor::new(prog.clone()))))
-                } else {
-                    eprintln!("Can't open PROGRAM file '{}'", prog);
+                Err(_) if !opts.files => {
+                    program = Some(Reader::new(None, Box::new(io::Cursor::new(prog.clone()))));
+                    source = Some(prog.clone());
+                }
+                Err(err) => {
+                    eprintln!("Can't open PROGRAM file '{}': {}", prog, err);
                     std::process::exit(1);
                 }
             }
@@ -235,9 +988,81 @@ fn main() -> rustyline::Result<()> {
     }
 
     if let Some(program) = program {
-        let mut compiler = Compiler::new();
+        let mut compiler = get_compiler(&opts);
 
-        match compiler.compile(program) {
+        if opts.ast_json {
+            match compiler.parse(program) {
+                Ok(ast) => println!("{}", serde_json::to_string_pretty(&ast).unwrap()),
+                Err(errors) => {
+                    print_errors(&opts, &errors, source.as_deref());
+                    std::process::exit(1);
+                }
+            }
+
+            std::process::exit(0);
+        }
+
+        // Load a previously compiled program, or compile it from source.
+        let program = match opts.program.as_deref() {
+            Some(filename) if filename.ends_with(".tokc") => std::fs::read_to_string(filename)
+                .map_err(|err| {
+                    Error::from(format!("Can't open PROGRAM file '{}': {}", filename, err))
+                })
+                .and_then(|json| Program::from_json(&json))
+                .map(|mut program| {
+                    program.sandbox |= opts.sandbox;
+                    Some(program)
+                })
+                .map_err(|error| vec![error]),
+            _ => match Cache::default_dir() {
+                Some(dir) if cached => Cache::new(dir).compile(&mut compiler, program),
+                _ => compiler.compile(program),
+            },
+        };
+
+        print_warnings(&opts, &compiler, source.as_deref());
+
+        // Store the compiled program and exit?
+        if opts.compile {
+            let output = match (&opts.output, &opts.program) {
+                (Some(output), _) => output.clone(),
+                (None, Some(prog)) if std::path::Path::new(prog).is_file() => {
+                    std::path::Path::new(prog)
+                        .with_extension("tokc")
+                        .to_string_lossy()
+                        .to_string()
+                }
+                _ => {
+                    eprintln!("No OUTPUT file specified, use '-o'");
+                    std::process::exit(1);
+                }
+            };
+
+            let program = match program {
+                Ok(program) => program.expect("Compiler must return a program"),
+                Err(errors) => {
+                    print_errors(&opts, &errors, source.as_deref());
+                    std::process::exit(1);
+                }
+            };
+
+            match program.to_json() {
+                Ok(json) => {
+                    if let Err(err) = std::fs::write(&output, json) {
+                        eprintln!("Can't write OUTPUT file '{}': {}", output, err);
+                        std::process::exit(1);
+                    }
+                }
+                Err(error) => {
+                    eprintln!("{}", error);
+                    std::process::exit(1);
+                }
+            }
+
+            std::process::exit(0);
+        }
+
+        match program {
             Ok(None) => {}
             Ok(Some(program)) => {
                 let mut readers = get_readers(&opts);
@@ -273,8 +1098,13 @@ fn main() -> rustyline::Result<()> {
                                 Box::new(std::io::Cursor::new(code)),
                             )) {
                                 Ok(None) => {}
-                                Ok(Some(value)) => println!("{}", value.to_string()),
-                                Err(error) => eprintln!("{}", error),
+                                Ok(Some(value)) => {
+                                    print_result(&opts, &value, || value.to_string())
+                                }
+                                Err(error) => eprintln!(
+                                    "{}",
+                                    get_diagnostics(&opts).render(Severity::Error, &error, None)
+                                ),
                             }
                         }
 
@@ -282,13 +1112,15 @@ fn main() -> rustyline::Result<()> {
                         std::process::exit(0);
                     }
 
+                    if opts.debugger && program.main().is_consuming() {
+                        eprintln!("Debugger reads its commands from stdin, INPUT must be given");
+                        std::process::exit(1);
+                    }
+
                     readers.push(
                         // When program's main is consuming, read from stdin
                         if program.main().is_consuming() {
-                            Reader::new(
-                                Some("-".to_string()),
-                                Box::new(BufReader::new(io::stdin())),
-                            )
+                            Reader::from_stdin()
                         }
                         // otherwise just work on an empty input
                         else {
@@ -302,27 +1134,9 @@ fn main() -> rustyline::Result<()> {
                     std::process::exit(1);
                 }
 
-                let mut thread = Thread::new(&program, readers.iter_mut().collect());
-
-                match thread.run() {
-                    Ok(None) => {
-                        if opts.echo && readers.len() > 1 {
-                            print!("\n")
-                        }
-                    }
-                    Ok(Some(value)) => {
-                        if opts.echo {
-                            println!("{}", value.to_string())
-                        }
-                    }
-                    Err(error) => eprintln!("{}", error),
-                }
-            }
-            Err(errors) => {
-                for error in errors {
-                    eprintln!("{}", error);
-                }
+                run(&opts, &program, readers, source.as_deref());
             }
+            Err(errors) => print_errors(&opts, &errors, source.as_deref()),
         }
     } else {
         if opts.repl {
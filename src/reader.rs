@@ -1,10 +1,12 @@
 //! Universal low-level interface to let Tokay read input from different sources.
+use crate::value::{Bytes, Dict, Object, RefValue};
 use num_parse::PeekableIterator;
+use serde::{Deserialize, Serialize};
 use std::io::prelude::*;
 use std::io::BufReader;
 
 /// Position inside a reader, with row and column counting.
-#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Offset {
     // todo: Hold source filename information as well in the future?
     pub offset: usize,
@@ -14,15 +16,38 @@ pub struct Offset {
 
 pub type Range = std::ops::Range<usize>;
 
+/// Scrubbing of invalid UTF-8 sequences and control characters read from input
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum Scrub {
+    #[default]
+    Off, // Read input as is, invalid UTF-8 ends the input
+    Replace, // Replace invalid sequences and control characters by U+FFFD
+    Skip,    // Remove invalid sequences and control characters
+}
+
+/// Placeholder character representing a value in the buffer of a reader over values.
+const VALUE_CHAR: char = '\u{FFFC}';
+
+/// Default number of consumed characters kept on commit, to be examined by lookbehinds.
+pub const LOOKBEHIND: usize = 256;
+
 // Abstraction of a buffered Reader with internal buffering, offset counting and clean-up.
 pub struct Reader {
-    pub filename: Option<String>, // Source filename
-    reader: Box<dyn BufRead>,     // Reader object to read from
-    buffer: String,               // Internal buffer
-    peeked: char,                 // Currently peeked char
-    offset: Offset,               // Current offset
-    start: Offset,                // Offset of last commit
-    pub eof: bool,                // EOF marker
+    pub filename: Option<String>,  // Source filename
+    reader: Box<dyn BufRead>,      // Reader object to read from
+    buffer: String,                // Internal buffer
+    peeked: char,                  // Currently peeked char
+    offset: Offset,                // Current offset
+    start: Offset,                 // Offset of last commit
+    pub eof: bool,                 // EOF marker
+    pub streaming: bool,           // Discard consumed input on commit
+    pub lookbehind: usize,         // Consumed characters kept on commit for lookbehinds
+    values: Vec<RefValue>,         // Values read by a reader over values
+    pub scrub: Scrub,              // Scrubbing of invalid input
+    pub binary: bool,              // Read every byte as one character, captured as bytes
+    pub scrubbed: usize,           // Number of scrubbed invalid sequences and control characters
+    pub separator: Option<String>, // Record separator, when reading one record at a time
+    pub record: usize,             // Number of records read
 }
 
 impl Reader {
@@ -44,11 +69,154 @@ impl Reader {
                 col: 1,
             },
             eof: false,
+            streaming: false,
+            lookbehind: LOOKBEHIND,
+            values: Vec::new(),
+            scrub: Scrub::Off,
+            binary: false,
+            scrubbed: 0,
+            separator: None,
+            record: 0,
+        }
+    }
+
+    /** Creates a new reader over a list of values.
+
+    Every value is represented by one placeholder character in the reader's buffer, so that values
+    are consumed like characters and can be obtained with `peek_value()`. This allows to run a
+    parselet on the token values produced by another parselet. */
+    pub fn from_values(values: Vec<RefValue>) -> Self {
+        let mut reader = Self::new(None, Box::new(std::io::empty()));
+        reader.buffer = std::iter::repeat_n(VALUE_CHAR, values.len()).collect();
+        reader.values = values;
+        reader.eof = true;
+        reader
+    }
+
+    /** Creates a new reader on stdin.
+
+    The reader is created in streaming mode, so input is pulled incrementally and already consumed
+    input is discarded, keeping memory usage low even on large input streams. */
+    pub fn from_stdin() -> Self {
+        let mut reader = Self::new(Some("-".to_string()), Box::new(std::io::stdin()));
+        reader.streaming = true;
+        reader
+    }
+
+    /// Creates a new reader on a file.
+    pub fn from_file(filename: &str) -> std::io::Result<Self> {
+        let file = std::fs::File::open(filename)?;
+
+        if file.metadata()?.is_dir() {
+            return Err(std::io::Error::other("Is a directory"));
+        }
+
+        Ok(Self::new(Some(filename.to_string()), Box::new(file)))
+    }
+
+    /** Switches the reader into record mode, where records separated by `separator` are read one at
+    a time.
+
+    In record mode, the buffer only contains the current record without its separator, and the
+    reader is at EOF at the end of the record. Use `next_record()` to continue with the next one.
+    Streaming is disabled, as only one record is buffered anyway. */
+    pub fn split_records(&mut self, separator: &str) {
+        self.separator = Some(separator.to_string());
+        self.streaming = false;
+    }
+
+    /// Internal function for reading the next record in record mode.
+    fn read_record(&mut self) -> Option<usize> {
+        let separator = self.separator.as_deref()?.as_bytes().to_vec();
+        let mut bytes = Vec::new();
+
+        while !bytes.ends_with(&separator) {
+            match self.reader.read_until(*separator.last()?, &mut bytes) {
+                Ok(0) | Err(_) => break,
+                Ok(_) => {}
+            }
+        }
+
+        self.eof = true;
+        self.record += 1;
+
+        if bytes.is_empty() {
+            return None;
+        }
+
+        if bytes.ends_with(&separator) {
+            bytes.truncate(bytes.len() - separator.len());
+        }
+
+        let len = self.buffer.len();
+
+        if self.binary {
+            self.push_binary(&bytes);
+        } else if self.scrub != Scrub::Off {
+            self.push_scrubbed(&bytes);
+        } else {
+            // Invalid UTF-8 ends the input, like in read_line()
+            self.buffer.push_str(std::str::from_utf8(&bytes).ok()?);
+        }
+
+        Some(self.buffer.len() - len)
+    }
+
+    /** Continues with the next record in record mode.
+
+    Returns false when there are no more records, or the reader isn't in record mode. Offsets and
+    rows continue to count from the previous record, including its separator. */
+    pub fn next_record(&mut self) -> bool {
+        let Some(separator) = &self.separator else {
+            return false;
+        };
+
+        // The first record wasn't read on demand yet
+        if self.record == 0 {
+            return self.read_record().is_some();
+        }
+
+        let rows = (self.buffer.matches('\n').count() + separator.matches('\n').count()) as u32;
+
+        self.start = Offset {
+            offset: self.start.offset + self.buffer.len() + separator.len(),
+            row: self.start.row + rows,
+            col: 1,
+        };
+        self.offset = Offset {
+            offset: 0,
+            ..self.start
+        };
+        self.buffer.clear();
+
+        self.read_record().is_some()
+    }
+
+    /// Returns the current record in record mode, or the current line otherwise.
+    pub fn current_record(&self) -> &str {
+        if self.separator.is_some() {
+            &self.buffer
+        } else {
+            self.get_line(&self.offset).trim_end_matches('\r')
         }
     }
 
     /// Internal function for reading a line.
     fn read_line(&mut self) -> Option<usize> {
+        if self.separator.is_some() {
+            // Only the first record is read on demand, further ones by next_record()
+            return if self.record == 0 {
+                self.read_record()
+            } else {
+                self.eof = true;
+                None
+            };
+        }
+
+        if self.binary || self.scrub != Scrub::Off {
+            return self.read_line_bytes();
+        }
+
         if let Ok(n) = self.reader.read_line(&mut self.buffer) {
             if n == 0 {
                 self.eof = true;
@@ -62,6 +230,121 @@ impl Reader {
         }
     }
 
+    /** Internal function for reading a line in binary mode or with scrubbing.
+
+    In binary mode, every byte is pushed as one character. Otherwise, invalid UTF-8 sequences and
+    control characters other than tab, newline and carriage return are replaced or skipped,
+    according to the reader's scrub mode, and counted. */
+    fn read_line_bytes(&mut self) -> Option<usize> {
+        let mut bytes = Vec::new();
+
+        match self.reader.read_until(b'\n', &mut bytes) {
+            Ok(0) | Err(_) => {
+                self.eof = true;
+                return None;
+            }
+            Ok(_) => {}
+        }
+
+        let len = self.buffer.len();
+
+        if self.binary {
+            self.push_binary(&bytes);
+        } else {
+            self.push_scrubbed(&bytes);
+        }
+
+        Some(self.buffer.len() - len)
+    }
+
+    /// Internal function pushing bytes to the buffer in binary mode, each byte as the character of its value.
+    fn push_binary(&mut self, bytes: &[u8]) {
+        self.buffer
+            .extend(bytes.iter().map(|byte| char::from(*byte)));
+    }
+
+    /// Internal function pushing bytes to the buffer, scrubbing them according to the scrub mode.
+    fn push_scrubbed(&mut self, bytes: &[u8]) {
+        for chunk in bytes.utf8_chunks() {
+            for ch in chunk.valid().chars() {
+                if ch.is_control() && !matches!(ch, '\t' | '\n' | '\r') {
+                    self.scrubbed += 1;
+
+                    if self.scrub == Scrub::Replace {
+                        self.buffer.push(char::REPLACEMENT_CHARACTER);
+                    }
+                } else {
+                    self.buffer.push(ch);
+                }
+            }
+
+            if !chunk.invalid().is_empty() {
+                self.scrubbed += 1;
+
+                if self.scrub == Scrub::Replace {
+                    self.buffer.push(char::REPLACEMENT_CHARACTER);
+                }
+            }
+        }
+    }
+
+    /// Returns the value at the current offset of a reader over values.
+    pub fn peek_value(&self) -> Option<RefValue> {
+        if self.values.is_empty() {
+            return None;
+        }
+
+        self.values
+            .get((self.start.offset + self.offset.offset) / VALUE_CHAR.len_utf8())
+            .cloned()
+    }
+
+    /** Maps an offset to its absolute position in the original input.
+
+    On a reader over values, the offset is mapped to the position of the value in the text it was
+    produced from. Values providing the fields `offset`, `row` and `col`, like the ones produced by `ast()`, are
+    mapped to their start position; the end of the values is mapped to the `stop_offset`, `stop_row`
+    and `stop_col` fields of the last value. When `end` is true, the offset is mapped to the stop
+    position of the value before it instead, so that a range of values covers its original text.
+
+    When no such position is available, the offset itself is made absolute. */
+    pub fn origin(&self, offset: &Offset, end: bool) -> Offset {
+        fn position(value: &RefValue, prefix: &str) -> Option<Offset> {
+            let value = value.borrow();
+            let dict = value.object::<Dict>()?;
+            let field = |name: &str| {
+                dict.get_str(&format!("{}{}", prefix, name))?
+                    .to_usize()
+                    .ok()
+            };
+
+            Some(Offset {
+                offset: field("offset")?,
+                row: field("row")? as u32,
+                col: field("col")? as u32,
+            })
+        }
+
+        let idx = (self.start.offset + offset.offset) / VALUE_CHAR.len_utf8();
+
+        let position = if end && idx > 0 {
+            self.values
+                .get(idx - 1)
+                .and_then(|value| position(value, "stop_"))
+        } else if let Some(value) = self.values.get(idx) {
+            position(value, "")
+        } else {
+            self.values
+                .last()
+                .and_then(|value| position(value, "stop_"))
+        };
+
+        position.unwrap_or(Offset {
+            offset: self.start.offset + offset.offset,
+            ..*offset
+        })
+    }
+
     pub fn tell(&self) -> Offset {
         self.offset
     }
@@ -115,11 +398,152 @@ impl Reader {
         &self.buffer[range.start..range.end]
     }
 
-    /// Commits current input buffer and removes cached content
+    /** Get the consumed input before the current offset, up to max characters.
+
+    In streaming mode, consumed input is discarded on commit except of the last `lookbehind`
+    characters, so less input may be available. */
+    pub fn behind(&self, max: usize) -> &str {
+        let consumed = &self.buffer[..self.offset.offset];
+        &consumed[Self::tail(consumed, max)..]
+    }
+
+    /// Internal function returning the byte index of the last count characters of a str.
+    fn tail(s: &str, count: usize) -> usize {
+        if count == 0 {
+            return s.len();
+        }
+
+        s.char_indices()
+            .rev()
+            .take(count)
+            .last()
+            .map_or(s.len(), |(start, _)| start)
+    }
+
+    /// Get the value of a captured range, which is a str, or bytes in binary mode
+    pub fn get_value(&self, range: &Range) -> RefValue {
+        if self.binary {
+            RefValue::from(Bytes::from(
+                self.get(range)
+                    .chars()
+                    .map(|ch| ch as u8)
+                    .collect::<Vec<u8>>(),
+            ))
+        } else {
+            RefValue::from(self.get(range))
+        }
+    }
+
+    /// Get the buffered line containing offset
+    pub fn get_line(&self, offset: &Offset) -> &str {
+        let start = self.buffer[..offset.offset]
+            .rfind('\n')
+            .map_or(0, |start| start + 1);
+        let end = self.buffer[offset.offset..]
+            .find('\n')
+            .map_or(self.buffer.len(), |end| offset.offset + end);
+
+        &self.buffer[start..end]
+    }
+
+    /// Get the buffered line with the given row number, without its line break
+    pub fn get_row(&self, row: u32) -> Option<&str> {
+        // Start at the beginning of the current line
+        let mut start = self.buffer[..self.offset.offset]
+            .rfind('\n')
+            .map_or(0, |start| start + 1);
+        let mut current = self.offset.row;
+
+        while current > row {
+            if start == 0 {
+                return None;
+            }
+
+            start = self.buffer[..start - 1]
+                .rfind('\n')
+                .map_or(0, |start| start + 1);
+            current -= 1;
+        }
+
+        while current < row {
+            start += self.buffer[start..].find('\n')? + 1;
+            current += 1;
+        }
+
+        // There's no line behind a final line break
+        if start > 0 && start == self.buffer.len() {
+            return None;
+        }
+
+        let end = self.buffer[start..]
+            .find('\n')
+            .map_or(self.buffer.len(), |end| start + end);
+
+        Some(self.buffer[start..end].trim_end_matches('\r'))
+    }
+
+    /** Commits current input buffer, but keeps the current line and the given number of lines before.
+
+    This allows to obtain these lines with `get_row()` later on. When less lines are buffered,
+    nothing is removed. The last `lookbehind` characters consumed are always kept. */
+    pub fn commit_keep(&mut self, rows: u32) {
+        let mut cut = self.buffer[..self.offset.offset]
+            .rfind('\n')
+            .map_or(0, |start| start + 1);
+
+        for _ in 0..rows {
+            if cut == 0 {
+                return;
+            }
+
+            cut = self.buffer[..cut - 1]
+                .rfind('\n')
+                .map_or(0, |start| start + 1);
+        }
+
+        self.drain(cut.min(self.lookbehind_cut()));
+    }
+
+    /** Commits current input buffer and removes cached content, except of the last `lookbehind` characters
+
+    The buffer is only drained when the removed part is at least as long as the part kept, so
+    that the rest of a long line isn't moved on every commit. */
     pub fn commit(&mut self) {
-        self.buffer.drain(0..self.offset.offset);
-        self.start = self.offset;
-        self.offset.offset = 0; // reset offset to 0
+        let cut = self.lookbehind_cut();
+
+        if cut >= self.buffer.len() - cut {
+            self.drain(cut);
+        }
+    }
+
+    /// Internal function returning the byte index in front of the consumed input kept for lookbehinds.
+    fn lookbehind_cut(&self) -> usize {
+        Self::tail(&self.buffer[..self.offset.offset], self.lookbehind)
+    }
+
+    /// Internal function removing the buffer until cut, keeping the absolute start position.
+    fn drain(&mut self, cut: usize) {
+        if cut == 0 {
+            return;
+        }
+
+        let removed = &self.buffer[..cut];
+
+        self.start = match removed.rfind('\n') {
+            Some(pos) => Offset {
+                offset: self.start.offset + cut,
+                row: self.start.row + removed.matches('\n').count() as u32,
+                col: removed[pos + 1..].chars().count() as u32 + 1,
+            },
+            None => Offset {
+                offset: self.start.offset + cut,
+                row: self.start.row,
+                col: self.start.col + removed.chars().count() as u32,
+            },
+        };
+
+        self.buffer.drain(0..cut);
+        self.offset.offset -= cut;
     }
 
     /// Take one character accepted by callback
@@ -0,0 +1,326 @@
+//! Tiny local HTTP server running grammars on input, with an optional web-based visualizer
+use crate::vm::{RunOptions, Thread, TraceFormat, Tracer};
+use crate::{Compiler, DiagnosticFormat, Diagnostics, Reader, Severity};
+use std::cell::RefCell;
+use std::io::{self, BufRead, BufReader, Read, Write};
+use std::net::{TcpListener, TcpStream, ToSocketAddrs};
+use std::rc::Rc;
+use std::time::{Duration, Instant};
+
+/// Page of the visualizer, served on `/` when enabled
+pub const VISUALIZER: &str = include_str!("visualize.html");
+
+/// Maximum number of trace events reported for a run
+pub const REPORT_TRACE_LIMIT: usize = 10_000;
+
+/// Maximum size of a request body in bytes
+pub const REQUEST_BODY_LIMIT: usize = 4 * 1024 * 1024;
+
+/// Maximum time a run may take before it is cancelled
+pub const REPORT_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Maximum depth of parselet calls of a run
+pub const REPORT_MAX_DEPTH: usize = 1000;
+
+/// Maximum number of values allocated by a run and alive at once
+pub const REPORT_MAX_VALUES: usize = 100_000;
+
+/// Maximum time a connection may wait for reading a request or writing a response
+pub const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Hosts the server accepts requests for, and from
+const LOCAL_HOSTS: &[&str] = &["localhost", "127.0.0.1", "[::1]"];
+
+/// Writer collecting the JSON trace into a buffer shared with the report
+#[derive(Clone, Default)]
+struct TraceBuffer(Rc<RefCell<Vec<u8>>>);
+
+impl Write for TraceBuffer {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0.borrow_mut().extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/** Compiles a grammar and runs it on an input, and reports everything that happened as JSON.
+
+The report is an object with
+
+- `result`, the result of the run as JSON, or null,
+- `diagnostics`, the errors and warnings in the format of `--error-format json`,
+- `trace`, the events of `--trace-format json`, limited to `REPORT_TRACE_LIMIT` events,
+- `truncated`, which is true when events were left out of the trace.
+
+Grammars are compiled in sandbox mode, and runs are cancelled after `REPORT_TIMEOUT`, or when they
+exceed `REPORT_MAX_DEPTH` or `REPORT_MAX_VALUES`. */
+pub fn report(grammar: &str, input: &str) -> serde_json::Value {
+    report_with_timeout(grammar, input, REPORT_TIMEOUT)
+}
+
+/// Like `report()`, but cancels the run after `timeout`.
+pub fn report_with_timeout(grammar: &str, input: &str, timeout: Duration) -> serde_json::Value {
+    let diagnostics = Diagnostics {
+        format: DiagnosticFormat::Json,
+        color: false,
+    };
+
+    let diagnostic = |severity, error: &crate::Error| -> serde_json::Value {
+        serde_json::from_str(&diagnostics.render(severity, error, Some(grammar))).unwrap()
+    };
+
+    // Parselets are traced as called, so they aren't inlined
+    let mut compiler = Compiler::new();
+    compiler.inline = false;
+    compiler.sandbox = true;
+    let compiled = compiler.compile_from_str(grammar);
+
+    let mut report: Vec<serde_json::Value> = compiler
+        .take_warnings()
+        .iter()
+        .map(|warning| diagnostic(Severity::Warning, warning))
+        .collect();
+
+    let program = match compiled {
+        Ok(Some(program)) => program,
+        compiled => {
+            if let Err(errors) = compiled {
+                report.extend(
+                    errors
+                        .iter()
+                        .map(|error| diagnostic(Severity::Error, error)),
+                );
+            }
+
+            return serde_json::json!({
+                "result": null,
+                "diagnostics": report,
+                "trace": [],
+                "truncated": false,
+            });
+        }
+    };
+
+    let buffer = TraceBuffer::default();
+    let mut reader = Reader::new(None, Box::new(io::Cursor::new(input.to_string())));
+
+    let result = {
+        let mut thread = Thread::new(&program, vec![&mut reader]);
+        thread.options = RunOptions {
+            deadline: Some(Instant::now() + timeout),
+            max_depth: Some(REPORT_MAX_DEPTH),
+            max_values: Some(REPORT_MAX_VALUES),
+            ..Default::default()
+        };
+        thread.observers.push(Box::new(Tracer::new(
+            TraceFormat::Json,
+            Box::new(buffer.clone()),
+        )));
+        thread.run()
+    };
+
+    let result = match result {
+        Ok(Some(value)) => serde_json::to_value(&value).unwrap_or(serde_json::Value::Null),
+        Ok(None) => serde_json::Value::Null,
+        Err(error) => {
+            report.push(diagnostic(Severity::Error, &error));
+            serde_json::Value::Null
+        }
+    };
+
+    let buffer = buffer.0.borrow();
+    let lines = String::from_utf8_lossy(&buffer);
+    let mut lines = lines.lines();
+
+    let trace: Vec<serde_json::Value> = lines
+        .by_ref()
+        .take(REPORT_TRACE_LIMIT)
+        .filter_map(|line| serde_json::from_str(line).ok())
+        .collect();
+
+    serde_json::json!({
+        "result": result,
+        "diagnostics": report,
+        "trace": trace,
+        "truncated": lines.next().is_some(),
+    })
+}
+
+/** Tiny HTTP server for running grammars from a browser or other tools.
+
+Requests are handled one after another. `POST /run` takes a JSON object with `grammar` and
+`input`, and responds with the `report()` of the run. With the visualizer enabled, `GET /`
+serves a page which shows the parse tree, the match spans over the input and the trace timeline
+of a run.
+
+The server is meant for local use only. Requests must be addressed to a local host and, when sent
+by a browser, come from a local origin, so other web pages can't use it. Grammars run in sandbox
+mode with the limits of `report()`, and connections idle for longer than `timeout` are closed. */
+pub struct Server {
+    listener: TcpListener,
+    visualize: bool,       // Serve the visualizer page
+    pub timeout: Duration, // Read and write timeout of connections
+}
+
+impl Server {
+    /// Binds a server to an address
+    pub fn bind(addr: impl ToSocketAddrs, visualize: bool) -> io::Result<Self> {
+        Ok(Self {
+            listener: TcpListener::bind(addr)?,
+            visualize,
+            timeout: REQUEST_TIMEOUT,
+        })
+    }
+
+    /// Returns the URL the server can be reached at
+    pub fn url(&self) -> io::Result<String> {
+        Ok(format!("http://{}/", self.listener.local_addr()?))
+    }
+
+    /// Handles incoming requests until the listener fails
+    pub fn run(&self) -> io::Result<()> {
+        for stream in self.listener.incoming() {
+            let stream = stream?;
+
+            // A failing or idle connection doesn't stop the server
+            let _ = stream
+                .set_read_timeout(Some(self.timeout))
+                .and_then(|_| stream.set_write_timeout(Some(self.timeout)))
+                .and_then(|_| self.handle(stream));
+        }
+
+        Ok(())
+    }
+
+    /// Handles a single request on a connection
+    fn handle(&self, stream: TcpStream) -> io::Result<()> {
+        let mut reader = BufReader::new(stream.try_clone()?);
+        let mut stream = stream;
+
+        let mut line = String::new();
+        reader.read_line(&mut line)?;
+
+        let mut parts = line.split_whitespace();
+        let method = parts.next().unwrap_or("").to_string();
+        let path = parts.next().unwrap_or("").to_string();
+
+        // Headers, of which only the content length and type, the host and the origin are of interest
+        let mut length = 0;
+        let mut content_type = String::new();
+        let mut host = None;
+        let mut origin = None;
+
+        loop {
+            line.clear();
+
+            if reader.read_line(&mut line)? == 0 || line.trim().is_empty() {
+                break;
+            }
+
+            if let Some((name, value)) = line.split_once(':') {
+                let value = value.trim();
+
+                match name.trim().to_ascii_lowercase().as_str() {
+                    "content-length" => length = value.parse().unwrap_or(0),
+                    "content-type" => content_type = value.to_ascii_lowercase(),
+                    "host" => host = Some(value.to_string()),
+                    "origin" => origin = Some(value.to_string()),
+                    _ => {}
+                }
+            }
+        }
+
+        // Requests from other hosts or web pages are refused, so they can't run grammars
+        if !host.is_some_and(|host| is_local(&host))
+            || !origin.is_none_or(|origin| origin.strip_prefix("http://").is_some_and(is_local))
+        {
+            return respond(&mut stream, "403 Forbidden", "text/plain", "Forbidden");
+        }
+
+        match (method.as_str(), path.as_str()) {
+            ("GET", "/") if self.visualize => respond(
+                &mut stream,
+                "200 OK",
+                "text/html; charset=utf-8",
+                VISUALIZER,
+            ),
+            ("POST", "/run") if length > REQUEST_BODY_LIMIT => respond(
+                &mut stream,
+                "413 Payload Too Large",
+                "text/plain",
+                "Request body too large",
+            ),
+            ("POST", "/run")
+                if content_type.split(';').next().map(str::trim) != Some("application/json") =>
+            {
+                respond(
+                    &mut stream,
+                    "415 Unsupported Media Type",
+                    "text/plain",
+                    "Content-Type must be application/json",
+                )
+            }
+            ("POST", "/run") => {
+                let mut body = vec![0; length];
+                reader.read_exact(&mut body)?;
+
+                let request: serde_json::Value = match serde_json::from_slice(&body) {
+                    Ok(request) => request,
+                    Err(err) => {
+                        return respond(
+                            &mut stream,
+                            "400 Bad Request",
+                            "text/plain",
+                            &format!("Invalid JSON: {}", err),
+                        )
+                    }
+                };
+
+                let Some(grammar) = request["grammar"].as_str() else {
+                    return respond(
+                        &mut stream,
+                        "400 Bad Request",
+                        "text/plain",
+                        "Missing 'grammar'",
+                    );
+                };
+
+                let input = request["input"].as_str().unwrap_or("");
+
+                respond(
+                    &mut stream,
+                    "200 OK",
+                    "application/json",
+                    &report(grammar, input).to_string(),
+                )
+            }
+            _ => respond(&mut stream, "404 Not Found", "text/plain", "Not found"),
+        }
+    }
+}
+
+/// Checks if a host, with an optional port, is local
+fn is_local(host: &str) -> bool {
+    let name = match host.rsplit_once(':') {
+        Some((name, port)) if !port.ends_with(']') => name,
+        _ => host,
+    };
+
+    LOCAL_HOSTS.contains(&name)
+}
+
+/// Writes a complete response and closes the connection
+fn respond(stream: &mut TcpStream, status: &str, content_type: &str, body: &str) -> io::Result<()> {
+    write!(
+        stream,
+        "HTTP/1.1 {}\r\nContent-Type: {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        status,
+        content_type,
+        body.len(),
+        body
+    )?;
+    stream.flush()
+}
@@ -1,6 +1,8 @@
 //! Unit tests
+use crate::compiler::{LintLevel, Unmatched};
 use crate::utils::*;
 use crate::value;
+use crate::{Compiler, DiagnosticFormat, Diagnostics, Error, Reader, Severity};
 use tokay_macros;
 
 #[test]
@@ -75,6 +77,2089 @@ fn examples() {
         run(include_str!("../examples/factorial.tok"), ""),
         Ok(Some(value!(24)))
     );
+
+    assert_eq!(
+        run(
+            include_str!("../examples/diffstat.tok"),
+            concat!(
+                "--- a/hello.txt\n",
+                "+++ b/hello.txt\n",
+                "@@ -1,2 +1,2 @@\n",
+                " Hello\n",
+                "-World\n",
+                "+Tokay\n",
+            )
+        ),
+        Ok(Some(value!("b/hello.txt +1 -1")))
+    );
+
+    // Dicts with int keys can't be constructed by value!(), so the repr is compared
+    assert_eq!(
+        run(
+            include_str!("../examples/access_log.tok"),
+            concat!(
+                "127.0.0.1 - - [10/Oct/2000:13:55:36 -0700] \"GET / HTTP/1.0\" 200 2326\n",
+                "127.0.0.1 - - [10/Oct/2000:13:55:37 -0700] \"GET /x HTTP/1.0\" 404 -\n",
+                "127.0.0.1 - - [10/Oct/2000:13:55:38 -0700] \"GET / HTTP/1.0\" 200 12\n"
+            )
+        )
+        .map(|counts| crate::value::Object::repr(&counts.unwrap())),
+        Ok("(200 => 2 404 => 1)".to_string())
+    );
+}
+
+#[test]
+// Testing the builtin registry is sorted by name, as builtins and methods are looked up by binary search
+fn builtin_registry() {
+    use crate::_builtins::BUILTINS;
+    use crate::builtin::Builtin;
+
+    assert!(BUILTINS.windows(2).all(|pair| pair[0].name < pair[1].name));
+
+    assert_eq!(
+        Builtin::get("print").map(|builtin| builtin.name),
+        Some("print")
+    );
+    assert_eq!(Builtin::get("prin").map(|builtin| builtin.name), None);
+    assert_eq!(
+        Builtin::get_method("list", "push").map(|builtin| builtin.name),
+        Ok("list_push")
+    );
+    assert!(Builtin::get_method("list", "pus").is_err());
+}
+
+#[test]
+// Testing streaming mode of Reader, which discards already consumed input
+fn reader_streaming() {
+    let program = Compiler::new()
+        .compile_from_str("Int ($0, offset()[\"offset\"])")
+        .unwrap()
+        .unwrap();
+
+    let mut reader = Reader::new(None, Box::new(std::io::Cursor::new("1 22\n333")));
+    reader.streaming = true;
+
+    assert_eq!(
+        program.run_from_reader(reader).unwrap(),
+        Some(value!([["1", 1], ["22", 4], ["333", 8]]))
+    );
+}
+
+#[test]
+// Testing lookbehinds on streamed input, where consumed input is discarded on commit
+fn reader_streaming_lookbehind() {
+    let program = Compiler::new()
+        .compile_from_str("Behind<'ab', max: 2> Int | NotBehind<Alphanumeric> Word")
+        .unwrap()
+        .unwrap();
+
+    let mut reader = Reader::new(None, Box::new(std::io::Cursor::new("ab12 cd34 xab56")));
+    reader.streaming = true;
+
+    assert_eq!(
+        program.run_from_reader(reader).unwrap(),
+        Some(value!(["ab", 12, "cd", "xab", 56]))
+    );
+}
+
+#[test]
+// Testing word boundaries of network tokens on streamed input
+fn reader_streaming_net() {
+    let program = Compiler::new()
+        .compile_from_str("Ipv4 $1[\"address\"] | Url $1[\"url\"]")
+        .unwrap()
+        .unwrap();
+
+    let mut reader = Reader::new(
+        None,
+        Box::new(std::io::Cursor::new(
+            "1.2.3.4.5 10.0.0.1 1http://a.b http://c.d",
+        )),
+    );
+    reader.streaming = true;
+
+    assert_eq!(
+        program.run_from_reader(reader).unwrap(),
+        Some(value!(["10.0.0.1", "http://c.d"]))
+    );
+}
+
+#[test]
+// Testing prefix checks of timestamp and email tokens on streamed input
+fn reader_streaming_timestamp_email() {
+    let program = Compiler::new()
+        .compile_from_str("Timestamp $1[\"timestamp\"] | Email $1[\"local\"]")
+        .unwrap()
+        .unwrap();
+
+    let mut reader = Reader::new(
+        None,
+        Box::new(std::io::Cursor::new(
+            "12024-01-01 2024-02-02 a..b@c.de a.b@c.de",
+        )),
+    );
+    reader.streaming = true;
+
+    assert_eq!(
+        program.run_from_reader(reader).unwrap(),
+        Some(value!(["2024-02-02", "a.b"]))
+    );
+}
+
+#[test]
+// Test for memoization table eviction with a sliding window
+fn memo_window() {
+    use crate::vm::{MemoPolicy, RunOptions, Thread};
+
+    let program = Compiler::new()
+        .compile_from_str("Pair : @{ Int _ Int }\nPair | Int")
+        .unwrap()
+        .unwrap();
+
+    let src = "1 2 3 4 5 6 7 8 9 10 11 12 13 14 15 16 17 18 19 20 21";
+    let expected = program.run_from_str(src).unwrap();
+
+    let options = RunOptions {
+        memo: MemoPolicy::Window(8),
+        ..Default::default()
+    };
+
+    assert_eq!(
+        program
+            .run_from_reader_with_options(
+                Reader::new(None, Box::new(std::io::Cursor::new(src))),
+                options.clone()
+            )
+            .unwrap(),
+        expected
+    );
+
+    let mut reader = Reader::new(None, Box::new(std::io::Cursor::new(src)));
+    let mut thread = Thread::new(&program, vec![&mut reader]);
+    thread.options = options;
+    assert_eq!(thread.run().unwrap(), expected);
+
+    // Only the main parselet's result at offset 0 remains besides the entries inside the window
+    assert!(thread
+        .memo
+        .keys()
+        .all(|(offset, _)| *offset == 0 || *offset + 8 >= src.len()));
+}
+
+#[test]
+// Test for source filenames in error messages
+fn error_filename() {
+    let mut compiler = Compiler::new();
+
+    // Compile-time error
+    let errors = compiler
+        .compile(Reader::new(
+            Some("test.tok".to_string()),
+            Box::new(std::io::Cursor::new("x = 1\n\"abc")),
+        ))
+        .unwrap_err();
+
+    assert_eq!(
+        errors[0].to_string(),
+        "test.tok:2:5: Unclosed string, expecting '\"'"
+    );
+
+    let errors = compiler
+        .compile(Reader::new(
+            Some("test.tok".to_string()),
+            Box::new(std::io::Cursor::new("\nundefined_name")),
+        ))
+        .unwrap_err();
+
+    assert_eq!(
+        errors[0].to_string(),
+        "test.tok:2:1: Use of undefined name 'undefined_name'"
+    );
+
+    // Runtime error on input
+    let program = compiler
+        .compile_from_str("Int Expect<Int>")
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(
+        program
+            .run_from_reader(Reader::new(
+                Some("input.txt".to_string()),
+                Box::new(std::io::Cursor::new("1\nx"))
+            ))
+            .unwrap_err()
+            .to_string(),
+        "input.txt:1:2: Expecting Int, but got \"\\n\""
+    );
+
+    // Without filename
+    assert_eq!(
+        program.run_from_str("1\nx").unwrap_err().to_string(),
+        "Line 1, column 2: Expecting Int, but got \"\\n\""
+    );
+}
+
+#[test]
+// Test for explaining why input didn't match
+fn explain() {
+    use crate::vm::Thread;
+
+    let program = Compiler::new()
+        .compile_from_str("Pair : @{ Int _ '=' _ Int }\nPair")
+        .unwrap()
+        .unwrap();
+
+    let mut reader = Reader::new(None, Box::new(std::io::Cursor::new("1 = 2\n3 = x")));
+    let mut thread = Thread::new(&program, vec![&mut reader]);
+    thread.options.explain = true;
+    thread.run().unwrap();
+
+    let explanations: Vec<String> = thread
+        .failures
+        .iter()
+        .map(|failure| failure.to_string())
+        .collect();
+
+    assert_eq!(
+        explanations,
+        vec![
+            "Line 1, column 6: Input doesn't match, expecting Int\n  1 = 2\n       ^\n  in __main__ > Pair",
+            "Line 2, column 5: Input doesn't match, expecting Whitespaces or Int\n  3 = x\n      ^\n  in __main__ > Pair"
+        ]
+    );
+}
+
+#[test]
+// Test for the post-mortem snapshot taken on a runtime error
+fn post_mortem() {
+    use crate::vm::Thread;
+
+    let program = Compiler::new()
+        .compile_from_str("limit = 2\nCheck : @{ Int if $1 > limit 1 / 0 }\nCheck")
+        .unwrap()
+        .unwrap();
+
+    let mut reader = Reader::new(None, Box::new(std::io::Cursor::new("1\n3")));
+    let mut thread = Thread::new(&program, vec![&mut reader]);
+    thread.options.post_mortem = true;
+    assert!(thread.run().is_err());
+
+    let snapshot = thread.snapshot.unwrap();
+    assert_eq!(snapshot.parselet, "Check");
+    assert_eq!(snapshot.parselets, vec!["__main__", "Check"]);
+    assert_eq!((snapshot.offset.row, snapshot.offset.col), (2, 2));
+    assert_eq!(snapshot.globals, vec![value!(2)]);
+}
+
+#[test]
+// Test for salvaging partial results on error
+fn run_report() {
+    use crate::vm::Thread;
+
+    let program = Compiler::new()
+        .compile_from_str("Int _ ';' $1\n'!' error(\"Unexpected '!'\")")
+        .unwrap()
+        .unwrap();
+
+    let mut reader = Reader::new(None, Box::new(std::io::Cursor::new("1; 2; 3! 4;")));
+    let mut thread = Thread::new(&program, vec![&mut reader]);
+    let report = thread.run_report();
+
+    assert_eq!(
+        report.result.unwrap_err().to_string(),
+        "Line 1, column 9: Unexpected '!'"
+    );
+    assert_eq!(report.partial, Some(value!([1, 2])));
+    assert_eq!(report.offset.offset, 8);
+}
+
+#[test]
+// Test for accumulating values with emit()
+fn emit() {
+    use crate::vm::Thread;
+
+    let program = Compiler::new()
+        .compile_from_str("Int emit(\"ints\", $1)\nWord emit(\"words\", $1) $1.upper()")
+        .unwrap()
+        .unwrap();
+
+    let mut reader = Reader::new(None, Box::new(std::io::Cursor::new("a 1 b 2 3")));
+    let mut thread = Thread::new(&program, vec![&mut reader]);
+    let report = thread.run_report();
+
+    assert_eq!(report.result.unwrap(), Some(value!(["A", 1, "B", 2, 3])));
+    assert_eq!(
+        crate::RefValue::from(report.emitted),
+        value!(["words" => ["a", "b"], "ints" => [1, 2, 3]])
+    );
+
+    // Values emitted by rejected sequences are discarded
+    let program = Compiler::new()
+        .compile_from_str("Tagged : @{ Int emit(\"tagged\", $1) 'x' }\nTagged | Int")
+        .unwrap()
+        .unwrap();
+
+    let mut reader = Reader::new(None, Box::new(std::io::Cursor::new("1 2x 3")));
+    let mut thread = Thread::new(&program, vec![&mut reader]);
+    let report = thread.run_report();
+
+    assert_eq!(report.result.unwrap(), Some(value!([1, 2, 3])));
+    assert_eq!(
+        crate::RefValue::from(report.emitted),
+        value!(["tagged" => [2]])
+    );
+}
+
+#[test]
+// Test for scrubbing invalid UTF-8 sequences and control characters from input
+fn scrub() {
+    use crate::reader::Scrub;
+    use crate::vm::Thread;
+
+    let program = Compiler::new().compile_from_str("Word").unwrap().unwrap();
+
+    let input: &[u8] = b"ab\x01c\xffd\n";
+
+    for (scrub, result) in [
+        (Scrub::Replace, value!(["ab", "c", "d"])),
+        (Scrub::Skip, value!("abcd")),
+    ] {
+        let mut reader = Reader::new(None, Box::new(input));
+        reader.scrub = scrub;
+
+        let mut thread = Thread::new(&program, vec![&mut reader]);
+        let report = thread.run_report();
+
+        assert_eq!(report.result.unwrap(), Some(result));
+        assert_eq!(report.scrubbed, 2);
+    }
+}
+
+#[test]
+// Test for scrubbed characters being counted over all readers
+fn scrub_readers() {
+    use crate::reader::Scrub;
+    use crate::vm::Thread;
+
+    let program = Compiler::new().compile_from_str("Word").unwrap().unwrap();
+
+    let mut first = Reader::new(None, Box::new(&b"ab\x01c\n"[..]));
+    first.scrub = Scrub::Skip;
+    let mut second = Reader::new(None, Box::new(&b"d\x02ef\n"[..]));
+    second.scrub = Scrub::Skip;
+
+    let mut thread = Thread::new(&program, vec![&mut first, &mut second]);
+    let report = thread.run_report();
+
+    assert_eq!(report.result.unwrap(), Some(value!(["abc", "def"])));
+    assert_eq!(report.scrubbed, 2);
+}
+
+#[test]
+// Test for reading binary input, with length-prefixed records captured as bytes
+fn binary() {
+    use crate::value::{Bytes, RefValue};
+    use crate::vm::Thread;
+
+    let program = Compiler::new()
+        .compile_from_str(
+            "Record : @{ Bytes(2) Bytes($1.int()) }\nChar<\\x80-\\xff> 'PNG' Char<\\r\\n\\x1a>+ Record+ $4",
+        )
+        .unwrap()
+        .unwrap();
+
+    let input: &[u8] = b"\x89PNG\r\n\x1a\n\x00\x03abc\x00\x02\xff\xfe";
+
+    let mut reader = Reader::new(None, Box::new(input));
+    reader.binary = true;
+
+    let mut thread = Thread::new(&program, vec![&mut reader]);
+    let result = thread.run().unwrap().unwrap();
+
+    let record = |len: &[u8], data: &[u8]| {
+        value!([
+            (RefValue::from(Bytes::from(len.to_vec()))),
+            (RefValue::from(Bytes::from(data.to_vec())))
+        ])
+    };
+
+    assert_eq!(
+        result,
+        value!([
+            (record(b"\x00\x03", b"abc")),
+            (record(b"\x00\x02", b"\xff\xfe"))
+        ])
+    );
+}
+
+#[test]
+// Test for storing and loading compiled programs
+fn program_json() {
+    let program = Compiler::new()
+        .compile_from_str(
+            "Expr : @{ Expr Char<+-> Int ast(\"op\") ; Int }\nExpr _ ast_print($1) ; Word $1.upper()",
+        )
+        .unwrap()
+        .unwrap();
+
+    let json = program.to_json().unwrap();
+    let loaded = crate::Program::from_json(&json).unwrap();
+
+    for input in ["1+2-3 abc", "x 42"] {
+        assert_eq!(
+            loaded.run_on_str(input).unwrap(),
+            program.run_on_str(input).unwrap()
+        );
+    }
+
+    assert_eq!(loaded.to_json().unwrap(), json);
+    assert!(crate::Program::from_json(&json.replace(env!("CARGO_PKG_VERSION"), "0.0.0")).is_err());
+}
+
+#[test]
+// Test for the cache of compiled programs
+fn program_cache() {
+    use crate::compiler::Cache;
+
+    let dir = std::env::temp_dir().join(format!("tokay-cache-{}", std::process::id()));
+    let cache = Cache::new(&dir);
+
+    let reader = |src: &str| {
+        Reader::new(
+            Some("x.tok".to_string()),
+            Box::new(std::io::Cursor::new(src.to_string())),
+        )
+    };
+    let compile =
+        |compiler: &mut Compiler, src: &str| cache.compile(compiler, reader(src)).unwrap().unwrap();
+    let key = |compiler: &Compiler, src: &str| Cache::key(compiler, Some("x.tok"), src);
+
+    // Miss: the program is compiled and stored
+    let src = "Int $1 * 2";
+    assert!(cache.load(&key(&Compiler::new(), src)).is_none());
+    assert_eq!(
+        compile(&mut Compiler::new(), src).run_on_str("21"),
+        Ok(Some(value!(42)))
+    );
+    assert!(cache.load(&key(&Compiler::new(), src)).is_some());
+
+    // Hit: the stored program is loaded, which is proven by replacing it
+    let other = Compiler::new()
+        .compile_from_str("Int $1 * 3")
+        .unwrap()
+        .unwrap();
+    cache.store(&key(&Compiler::new(), src), &other).unwrap();
+    assert_eq!(
+        compile(&mut Compiler::new(), src).run_on_str("21"),
+        Ok(Some(value!(63)))
+    );
+
+    // Invalidation: other sources, filenames or compiler settings use other entries
+    assert_ne!(
+        key(&Compiler::new(), src),
+        key(&Compiler::new(), "Int $1 * 4")
+    );
+    assert_ne!(
+        key(&Compiler::new(), src),
+        Cache::key(&Compiler::new(), Some("y.tok"), src)
+    );
+
+    let mut compiler = Compiler::new();
+    compiler.sandbox = true;
+    assert_ne!(key(&Compiler::new(), src), key(&compiler, src));
+    assert_eq!(
+        compile(&mut compiler, src).run_on_str("21"),
+        Ok(Some(value!(42)))
+    );
+
+    // Invalidation: entries which can't be loaded, like from another Tokay version, are replaced
+    let path = dir.join(key(&Compiler::new(), src)).with_extension("tokc");
+    let json = std::fs::read_to_string(&path).unwrap();
+    std::fs::write(&path, json.replace(env!("CARGO_PKG_VERSION"), "0.0.0")).unwrap();
+    assert!(cache.load(&key(&Compiler::new(), src)).is_none());
+    assert_eq!(
+        compile(&mut Compiler::new(), src).run_on_str("21"),
+        Ok(Some(value!(42)))
+    );
+    assert!(cache.load(&key(&Compiler::new(), src)).is_some());
+
+    // Programs with warnings aren't stored
+    let mut compiler = Compiler::new();
+    compiler
+        .set_lint("all", crate::compiler::LintLevel::Warn)
+        .unwrap();
+    let src = "A : 'a'\nInt";
+    compile(&mut compiler, src);
+    assert_eq!(compiler.take_warnings().len(), 1);
+    assert!(cache.load(&key(&compiler, src)).is_none());
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+// Test for restricting the scan loop by a maximum number of matches and an input range
+fn scan_restrictions() {
+    use crate::vm::{RunOptions, ScanRange};
+
+    let program = Compiler::new().compile_from_str("Int").unwrap().unwrap();
+    let src = "a 1\nb 2\nc 3\nd 4\ne 5\n";
+
+    let run = |options| {
+        program
+            .run_from_reader_with_options(
+                Reader::new(None, Box::new(std::io::Cursor::new(src))),
+                options,
+            )
+            .unwrap()
+    };
+
+    assert_eq!(
+        run(RunOptions {
+            max_matches: Some(2),
+            ..Default::default()
+        }),
+        Some(value!([1, 2]))
+    );
+
+    assert_eq!(
+        run(RunOptions {
+            range: Some(ScanRange::Lines(2..4)),
+            ..Default::default()
+        }),
+        Some(value!([2, 3]))
+    );
+
+    assert_eq!(
+        run(RunOptions {
+            range: Some(ScanRange::Bytes(7..usize::MAX)),
+            max_matches: Some(1),
+            ..Default::default()
+        }),
+        Some(value!(3))
+    );
+}
+
+#[test]
+// Test for running the main parselet on every record of an input separately
+fn reader_records() {
+    let program = Compiler::new()
+        .compile_from_str("Word _ Int  (line_number() line_text() $1 $3)")
+        .unwrap()
+        .unwrap();
+
+    let run = |src: &'static str, separator| {
+        let mut reader = Reader::new(None, Box::new(std::io::Cursor::new(src)));
+        reader.split_records(separator);
+        program.run_from_reader(reader).unwrap()
+    };
+
+    // A match can't span over records
+    assert_eq!(
+        run("a 1\nb\n2\n\nc 3", "\n"),
+        Some(value!([[1, "a 1", "a", 1], [5, "c 3", "c", 3]]))
+    );
+
+    assert_eq!(
+        run("a 1;b\n2;;c 3 d 4", ";"),
+        Some(value!([
+            [1, "a 1", "a", 1],
+            [2, "b\n2", "b", 2],
+            [4, "c 3 d 4", "c", 3],
+            [4, "c 3 d 4", "d", 4]
+        ]))
+    );
+}
+
+#[test]
+// Test for rendering character classes and set operations on them
+fn ccl_render() {
+    use crate::value::Ccl;
+    use charclass::{charclass, CharClass};
+
+    let hex = charclass!['0' => '9', 'A' => 'F', 'a' => 'f'];
+    let vowels = charclass!['a', 'e', 'i', 'o', 'u'];
+    let lower = charclass!['a' => 'z'];
+
+    assert_eq!(Ccl(&hex).to_string(), "[0-9A-Fa-f]");
+    assert_eq!(Ccl(&Ccl(&hex).negate()).to_string(), "[^0-9A-Fa-f]");
+    assert_eq!(Ccl(&CharClass::new().negate()).to_string(), ".");
+    assert_eq!(Ccl(&charclass!['\n']).to_string(), "[\\n]");
+    assert_eq!(Ccl(&Ccl(&charclass!['\n']).negate()).to_string(), "[^\\n]");
+    assert_eq!(Ccl(&charclass!['\0' => ' ']).to_string(), "[\\x00- ]");
+    assert_eq!(
+        Ccl(&charclass!['-', ']', 'x', 'y']).to_string(),
+        "[\\-\\]xy]"
+    );
+
+    // Negating a class containing \0 twice results in the class itself
+    let control = charclass!['\0' => '\x1f'];
+    assert_eq!(Ccl(&Ccl(&control).negate()).negate(), control);
+
+    assert_eq!(
+        Ccl(&Ccl(&lower).difference(&vowels)).to_string(),
+        "[b-df-hj-np-tv-z]"
+    );
+    assert_eq!(Ccl(&Ccl(&hex).intersection(&lower)).to_string(), "[a-f]");
+    assert_eq!(Ccl(&Ccl(&vowels).union(&hex)).to_string(), "[0-9A-Fa-fiou]");
+    assert!(Ccl(&vowels).is_subset(&lower));
+    assert!(!Ccl(&hex).is_subset(&lower));
+}
+
+#[test]
+// Test for warning about a main parselet which can't make progress on input
+fn main_stalled() {
+    use crate::vm::Thread;
+
+    for (src, warned) in [("print(\"x\")", true), ("Int", false), ("begin 1", false)] {
+        let program = Compiler::new().compile_from_str(src).unwrap().unwrap();
+        let mut reader = Reader::new(None, Box::new(std::io::Cursor::new("a 1")));
+        let mut thread = Thread::new(&program, vec![&mut reader]);
+
+        thread.run().unwrap();
+        assert_eq!(thread.warnings.len(), warned as usize, "{}", src);
+    }
+}
+
+#[test]
+// Test for running the bundled examples on their sample input
+fn examples_bundled() {
+    use crate::examples::{Example, EXAMPLES};
+    use crate::value::Object;
+
+    for example in EXAMPLES {
+        assert!(example.run().is_ok(), "{}", example.name);
+    }
+
+    assert_eq!(
+        Example::get("log").unwrap().run().unwrap().unwrap().repr(),
+        "(200 => 2 404 => 2 302 => 1)"
+    );
+    assert!(Example::get("unknown").is_none());
+}
+
+#[test]
+// Test for rendering values in Tokay's literal syntax, and reading them again
+fn value_literal() {
+    let value = run(
+        r#"(a => 1 "b c" => (1.0, "x\ty\a", null, true) d => (, ) e => () "if" => (f => (1, )))"#,
+        "",
+    )
+    .unwrap()
+    .unwrap();
+
+    let literal = value.to_literal(None).unwrap();
+    assert_eq!(
+        literal,
+        r#"(a => 1 "b c" => (1.0, "x\ty\a", null, true) d => (, ) e => () "if" => (f => (1, )))"#
+    );
+    assert_eq!(run(&literal, ""), Ok(Some(value.clone())));
+    assert_eq!(crate::RefValue::from_literal(&literal), Ok(value.clone()));
+
+    let literal = value.to_literal(Some(2)).unwrap();
+    assert_eq!(
+        literal,
+        "(\n  a => 1\n  \"b c\" => (1.0, \"x\\ty\\a\", null, true)\n  d => (, )\n  e => ()\n  \"if\" => (\n    f => (1, )\n  )\n)"
+    );
+    assert_eq!(run(&literal, ""), Ok(Some(value.clone())));
+    assert_eq!(crate::RefValue::from_literal(&literal), Ok(value));
+
+    // Infinite floats have no literal syntax
+    assert!(crate::RefValue::from(f64::INFINITY)
+        .to_literal(None)
+        .is_err());
+}
+
+#[test]
+// Test for importing files from the compiler's search path
+fn import_search_path() {
+    let mut compiler = Compiler::new();
+    compiler.search_path.push("tests/import".into());
+
+    let program = compiler
+        .compile_from_str("import \"keyvalue.tok\" as kv\nkv::Pair accept kv::double($1[\"a\"])")
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(program.run_from_str("a = 21"), Ok(Some(value!(42))));
+
+    // Without the search path, the file isn't found
+    assert_eq!(
+        Compiler::new()
+            .compile_from_str("import \"keyvalue.tok\"")
+            .err()
+            .map(|errors| errors[0].to_string()),
+        Some("Line 1, column 1: Can't find 'keyvalue.tok' to import".to_string())
+    );
+}
+
+#[test]
+// Test for obtaining buffered lines of context around the reader's position
+fn reader_context() {
+    let mut reader = Reader::new(None, Box::new(std::io::Cursor::new("a\nbb\r\nccc\ndddd\n")));
+
+    while reader.next().is_some() {}
+
+    assert_eq!(reader.get_row(1), Some("a"));
+    assert_eq!(reader.get_row(2), Some("bb"));
+    assert_eq!(reader.get_row(4), Some("dddd"));
+    assert_eq!(reader.get_row(5), None);
+    assert_eq!(reader.get_row(0), None);
+
+    // Keep the line before the current one, without keeping input for lookbehinds
+    reader.lookbehind = 0;
+    reader.reset(crate::reader::Offset {
+        offset: 7,
+        row: 3,
+        col: 2,
+    });
+    reader.commit_keep(1);
+
+    assert_eq!(reader.get_row(1), None);
+    assert_eq!(reader.get_row(2), Some("bb"));
+    assert_eq!(reader.get_row(3), Some("ccc"));
+    assert_eq!(reader.tell().offset, 5);
+
+    // Keep everything when not enough lines are buffered
+    reader.commit_keep(5);
+    assert_eq!(reader.get_row(2), Some("bb"));
+}
+
+#[test]
+// Test for source excerpts of errors collected from several syntax errors
+fn error_excerpt() {
+    let src = "x = 1 +\ny = 2\n\tz = ]\nw = 3";
+    let errors = Compiler::new().compile_from_str(src).err().unwrap();
+
+    assert_eq!(
+        errors
+            .iter()
+            .map(|error| error.excerpt(src).unwrap())
+            .collect::<Vec<_>>(),
+        [
+            "  |\n1 | x = 1 +\n  |        ^",
+            "  |\n3 | \tz = ]\n  | \t    ^"
+        ]
+    );
+
+    // Words are underlined by a squiggle
+    let src = "v = Unknown";
+    let errors = Compiler::new().compile_from_str(src).err().unwrap();
+
+    assert_eq!(
+        errors[0].excerpt(src).unwrap(),
+        "  |\n1 | v = Unknown\n  |     ^~~~~~~"
+    );
+}
+
+#[test]
+// Test for rendering errors as rich and JSON diagnostics
+fn error_diagnostics() {
+    let src = "x = 1 +";
+    let errors = Compiler::new().compile_from_str(src).err().unwrap();
+
+    let rich = Diagnostics {
+        format: DiagnosticFormat::Rich,
+        color: false,
+    };
+
+    assert_eq!(
+        rich.render(Severity::Error, &errors[0], Some(src)),
+        "error[syntax]: Expecting <parselet MulDiv>, but got \"end-of-file\"\n  --> <program>:1:8\n  |\n1 | x = 1 +\n  |        ^"
+    );
+
+    let json = Diagnostics {
+        format: DiagnosticFormat::Json,
+        color: false,
+    };
+
+    let error = Error::new(None, "something odd".to_string());
+
+    assert_eq!(
+        json.render(Severity::Warning, &error, None),
+        r#"{"code":null,"column":null,"file":null,"line":null,"message":"something odd","offset":null,"severity":"warning","trace":[]}"#
+    );
+}
+
+#[test]
+// Test for the stack trace of runtime errors
+fn runtime_error_trace() {
+    let program = Compiler::new()
+        .compile_from_str("Inner : @{ Int error(\"bad\") }\nOuter : @{ Word Inner }\nOuter")
+        .unwrap()
+        .unwrap();
+
+    let error = program.run_on_str("abc123").unwrap_err();
+
+    assert_eq!(error.message, "bad");
+    assert_eq!(
+        error
+            .trace
+            .iter()
+            .map(|frame| (frame.parselet.as_str(), frame.reader.offset))
+            .collect::<Vec<_>>(),
+        [("Inner", 3), ("Outer", 0), ("__main__", 0)]
+    );
+}
+
+#[test]
+// Test for the compiler option configuring the result of unmatched optionals
+fn compiler_unmatched() {
+    let src = "''a'' ''b''? ''c'' repr($2)";
+
+    for (unmatched, result) in [
+        (Unmatched::Void, value!("void")),
+        (Unmatched::Empty, value!("\"\"")),
+        (Unmatched::Skip, value!("\"c\"")),
+    ] {
+        let mut compiler = Compiler::new();
+        compiler.unmatched = unmatched;
+
+        let program = compiler.compile_from_str(src).unwrap().unwrap();
+        assert_eq!(program.run_on_str("ac"), Ok(Some(result)));
+    }
+
+    // The modifier overrides the compiler option
+    let mut compiler = Compiler::new();
+    compiler.unmatched = Unmatched::Skip;
+
+    let program = compiler
+        .compile_from_str("''a'' ''b''?<empty> ''c'' $2")
+        .unwrap()
+        .unwrap();
+    assert_eq!(program.run_on_str("ac"), Ok(Some(value!(""))));
+}
+
+#[test]
+// Test for lints configured by their level
+fn compiler_lints() {
+    let src = "A : 'a'\nB : @{ print : 1 \n 'b' }\n_C : 'c'\nB";
+
+    // Unused constants and shadowed names aren't reported by default
+    let mut compiler = Compiler::new();
+    assert!(compiler.compile_from_str(src).is_ok());
+    assert!(compiler.take_warnings().is_empty());
+
+    let mut compiler = Compiler::new();
+    compiler.set_lint("all", LintLevel::Warn).unwrap();
+    assert!(compiler.compile_from_str(src).is_ok());
+    assert_eq!(
+        compiler
+            .take_warnings()
+            .iter()
+            .map(|warning| (warning.code.unwrap(), warning.to_string()))
+            .collect::<Vec<_>>(),
+        [
+            (
+                "shadowed-name",
+                "Line 2, column 8: Constant 'print' hides a builtin of the same name".to_string()
+            ),
+            (
+                "unused-constant",
+                "Line 2, column 8: Constant 'print' is never used".to_string()
+            ),
+            (
+                "unused-constant",
+                "Line 1, column 1: Constant 'A' is never used".to_string()
+            ),
+        ]
+    );
+
+    // Denied lints become errors
+    let mut compiler = Compiler::new();
+    compiler
+        .set_lint("unused-constant", LintLevel::Deny)
+        .unwrap();
+    let errors = compiler.compile_from_str(src).unwrap_err();
+    assert_eq!(errors.len(), 2);
+    assert!(errors
+        .iter()
+        .all(|error| error.code == Some("unused-constant")));
+
+    assert!(compiler.set_lint("unknown", LintLevel::Warn).is_err());
+}
+
+#[test]
+// Test for lints on the grammar of the finalized parselets
+fn compiler_grammar_lints() {
+    let src = "f : @x { f(x) }\nA : @{ 'a' | 'ab' }\nn = 0\nif n f(1)\nA";
+
+    let mut compiler = Compiler::new();
+    assert!(compiler.compile_from_str(src).is_ok());
+    assert_eq!(
+        compiler
+            .take_warnings()
+            .iter()
+            .map(|warning| warning.code.unwrap())
+            .collect::<Vec<_>>(),
+        ["infinite-recursion", "unreachable-alternative"]
+    );
+
+    // Denied findings stop compilation
+    let mut compiler = Compiler::new();
+    compiler
+        .set_lint("infinite-recursion", LintLevel::Deny)
+        .unwrap();
+    let errors = compiler.compile_from_str(src).unwrap_err();
+    assert_eq!(errors.len(), 1);
+    assert_eq!(
+        errors[0].to_string(),
+        "Line 1, column 5: Parselet 'f' calls itself in every alternative before consuming any input, so it never ends"
+    );
+    assert_eq!(compiler.take_warnings().len(), 1);
+}
+
+#[test]
+// Test for constant folding and dead branch elimination in intermediate code
+fn compiler_constant_folding() {
+    use crate::vm::Op;
+
+    let program = Compiler::new()
+        .compile_from_str(
+            "l = (1, \"a\" + \"b\", void, -3)\nif 2 > 1 && 3 * 4 == 12 l.push(4) else l.push(0)\nl",
+        )
+        .unwrap()
+        .unwrap();
+
+    #[cfg(feature = "static_expression_evaluation")]
+    assert!(!program.main().0.borrow().body.iter().any(|op| matches!(
+        op,
+        Op::UnaryOp(_) | Op::BinaryOp(_) | Op::MakeList(_) | Op::ForwardIfFalse(_)
+    )));
+
+    assert_eq!(program.run_on_str(""), Ok(Some(value!([1, "ab", (-3), 4]))));
+
+    // Preloaded lists are copied on every evaluation
+    let program = Compiler::new()
+        .compile_from_str(
+            "r = list(); for i in range(3) { l = (1, 2); l[0] += 1; l.push(i); r.push(l) }; r",
+        )
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(
+        program.run_on_str(""),
+        Ok(Some(value!([[2, 2, 0], [2, 2, 1], [2, 2, 2]])))
+    );
+}
+
+#[cfg(feature = "serve")]
+#[test]
+// Test for the JSON report of the local server and its visualizer
+fn serve_report() {
+    let report = crate::serve::report("Pair : @{ Int ',' Word }\nPair", "1,ä 2,b");
+
+    assert_eq!(report["result"], serde_json::json!([[1, "ä"], [2, "b"]]));
+    assert_eq!(report["truncated"], false);
+
+    let shifts: Vec<_> = report["trace"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .filter(|event| event["event"] == "shift")
+        .map(|event| {
+            (
+                event["offset"].as_u64().unwrap(),
+                event["end"].as_u64().unwrap(),
+            )
+        })
+        .collect();
+
+    // Offsets are in bytes
+    assert_eq!(shifts, [(0, 1), (1, 2), (2, 4), (5, 6), (6, 7), (7, 8)]);
+
+    let report = crate::serve::report("x = ", "");
+    assert_eq!(report["diagnostics"][0]["severity"], "error");
+    assert_eq!(report["diagnostics"][0]["line"], 1);
+    assert_eq!(report["result"], serde_json::Value::Null);
+}
+
+#[cfg(feature = "serve")]
+#[test]
+// Test for the limits of the local server's runs
+fn serve_report_limits() {
+    use std::time::Duration;
+
+    // Grammars are compiled in sandbox mode
+    let report = crate::serve::report("fwrite(\"x.txt\", \"x\")", "");
+    assert_eq!(
+        report["diagnostics"][0]["message"],
+        "Builtin 'fwrite' isn't available in sandbox mode"
+    );
+
+    // Endless runs are cancelled
+    let report = crate::serve::report_with_timeout("begin loop {}", "", Duration::from_millis(100));
+    assert_eq!(report["diagnostics"][0]["code"], "cancelled");
+    assert_eq!(report["result"], serde_json::Value::Null);
+
+    // Runs allocating endlessly are stopped
+    let report = crate::serve::report("l = list()\nloop { l.push(l.len()) }", "");
+    assert_eq!(report["diagnostics"][0]["code"], "limit");
+}
+
+#[cfg(feature = "serve")]
+#[test]
+// Test for the local server refusing requests from other hosts, origins and of other content types
+fn serve_requests() {
+    use std::io::{Read, Write};
+
+    let mut server = crate::serve::Server::bind("127.0.0.1:0", false).unwrap();
+    server.timeout = std::time::Duration::from_millis(100);
+    let addr = server.url().unwrap()["http://".len()..]
+        .trim_end_matches('/')
+        .to_string();
+    std::thread::spawn(move || server.run());
+
+    let request = |headers: &str| {
+        let body = r#"{"grammar": "Int", "input": "42"}"#;
+        let mut stream = std::net::TcpStream::connect(&addr).unwrap();
+        write!(
+            stream,
+            "POST /run HTTP/1.1\r\n{}Content-Length: {}\r\n\r\n{}",
+            headers,
+            body.len(),
+            body
+        )
+        .unwrap();
+
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+        response.lines().next().unwrap().to_string()
+    };
+
+    let json = "Content-Type: application/json\r\n";
+
+    // An idle connection doesn't block the server
+    let idle = std::net::TcpStream::connect(&addr).unwrap();
+
+    assert_eq!(
+        request(&format!("Host: {}\r\n{}", addr, json)),
+        "HTTP/1.1 200 OK"
+    );
+    assert_eq!(
+        request(&format!(
+            "Host: {}\r\nOrigin: http://{}\r\n{}",
+            addr, addr, json
+        )),
+        "HTTP/1.1 200 OK"
+    );
+    assert_eq!(
+        request(&format!(
+            "Host: {}\r\nOrigin: http://evil.example\r\n{}",
+            addr, json
+        )),
+        "HTTP/1.1 403 Forbidden"
+    );
+    assert_eq!(
+        request(&format!("Host: evil.example\r\n{}", json)),
+        "HTTP/1.1 403 Forbidden"
+    );
+    assert_eq!(request(json), "HTTP/1.1 403 Forbidden");
+    assert_eq!(
+        request(&format!("Host: {}\r\nContent-Type: text/plain\r\n", addr)),
+        "HTTP/1.1 415 Unsupported Media Type"
+    );
+
+    drop(idle);
+}
+
+#[test]
+// Test for inlining tiny parselets at their call sites
+fn compiler_inline() {
+    use crate::value::ParseletRef;
+
+    for (src, input, result) in [
+        (
+            "P : @{ Int ',' }\nQ : @{ P 'y' }\nQ+",
+            "1,y2,y",
+            value!([1, 2]),
+        ),
+        (
+            "Plus : @{ ''+'' }\nE : @{ E Plus Int | Int }\nE",
+            "1+2+3",
+            value!([[1, "+", 2], "+", 3]),
+        ),
+        ("T : @{ 'a' }\nT ''b''", "ab", value!("b")),
+    ] {
+        for inline in [true, false] {
+            let mut compiler = Compiler::new();
+            compiler.inline = inline;
+
+            let program = compiler.compile_from_str(src).unwrap().unwrap();
+            assert_eq!(program.run_on_str(input), Ok(Some(result.clone())));
+
+            // Inlined parselets aren't part of the program
+            let names: Vec<String> = program
+                .statics
+                .iter()
+                .filter_map(|value| {
+                    let value = value.borrow();
+                    value
+                        .object::<ParseletRef>()
+                        .map(|parselet| parselet.0.borrow().name.clone())
+                })
+                .collect();
+
+            for name in ["P", "Plus", "T"] {
+                assert!(!inline || !names.iter().any(|n| n == name));
+            }
+        }
+    }
+}
+
+#[test]
+// Test for constant aliases, which resolve to their target without a parselet of their own
+fn constant_alias() {
+    use crate::value::ParseletRef;
+
+    let mut compiler = Compiler::new();
+    compiler.inline = false;
+
+    let program = compiler
+        .compile_from_str("Val : Key\nKey : Label\nLabel : @{ Word | Int }\nKey '=' Val")
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(program.run_on_str("a=b"), Ok(Some(value!(["a", "b"]))));
+
+    let names: Vec<String> = program
+        .statics
+        .iter()
+        .filter_map(|value| {
+            value
+                .borrow()
+                .object::<ParseletRef>()
+                .map(|parselet| parselet.0.borrow().name.clone())
+        })
+        .collect();
+
+    assert_eq!(names, ["__main__", "Label"]);
+}
+
+#[test]
+// Test for spans of accepted rules, as used by editors
+fn program_spans() {
+    let mut compiler = Compiler::new();
+    compiler.inline = false;
+
+    let program = compiler
+        .compile_from_str(
+            "Num : @{ Int }\nName : @{ Word }\nItem : @{ Num ':' Name | Num }\nExpr : @{ Expr '+' Num | Num }\nLine : @{ Item ';' | Expr }\nLine+",
+        )
+        .unwrap()
+        .unwrap();
+
+    let spans = program.spans("1:a;2+3+4", None).unwrap();
+
+    assert_eq!(
+        spans
+            .iter()
+            .map(|span| (span.name.as_str(), span.start, span.end, span.depth))
+            .collect::<Vec<_>>(),
+        [
+            ("Line", 0, 4, 0),
+            ("Item", 0, 3, 1),
+            ("Num", 0, 1, 2),
+            ("Name", 2, 3, 2),
+            ("Line", 4, 9, 0),
+            ("Expr", 4, 9, 1),
+            ("Expr", 4, 7, 2),
+            ("Expr", 4, 5, 3),
+            ("Num", 4, 5, 4),
+            ("Num", 6, 7, 3),
+            ("Num", 8, 9, 2),
+        ]
+    );
+
+    // Spans are limited to a maximum depth
+    assert_eq!(program.spans("1:a;2+3+4", Some(1)).unwrap().len(), 4);
+}
+
+#[test]
+// Test for parselets called in tail position, which release the context of their caller
+fn parselet_tail_call() {
+    let program = Compiler::new()
+        .compile_from_str(
+            "Inner : @{ Int if $1 > 100 error(\"big\") }\nMiddle : @{ Inner }\nOuter : @{ Word Middle }\nOuter",
+        )
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(
+        program.run_on_str("abc12 d3"),
+        Ok(Some(value!([["abc", 12], ["d", 3]])))
+    );
+
+    // Middle and Outer are still part of the trace, although their contexts were released
+    let error = program.run_on_str("abc123").unwrap_err();
+
+    assert_eq!(
+        error
+            .trace
+            .iter()
+            .map(|frame| frame.parselet.as_str())
+            .collect::<Vec<_>>(),
+        ["Inner", "Middle", "Outer", "__main__"]
+    );
+
+    // Results of tail calls are collected with the captures of nested sequences taken before,
+    // and a rejection rewinds the input consumed before
+    let program = Compiler::new()
+        .compile_from_str("N : @{ 'x' ; Char<a> (Char<b> N) }\n(N | Char<a> Char<b> Char<a>)")
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(
+        program.run_on_str("ababx"),
+        Ok(Some(value!(["a", ["b", ["a", "b"]]])))
+    );
+    assert_eq!(
+        program.run_on_str("ababy"),
+        Ok(Some(value!(["a", "b", "a"])))
+    );
+
+    // Long chains of tail calls are run in a loop, so they don't overflow a small stack
+    let chain: Vec<String> = (0..500)
+        .map(|i| format!("P{} : @{{ P{} }}", i, i + 1))
+        .collect();
+    let src = format!("{}\nP500 : @{{ Int }}\nP0", chain.join("\n"));
+
+    let result = std::thread::Builder::new()
+        .stack_size(2 * 1024 * 1024)
+        .spawn(move || {
+            let mut compiler = Compiler::new();
+            compiler.inline = false;
+
+            let program = compiler.compile_from_str(&src).unwrap().unwrap();
+            program
+                .run_on_str("1 2")
+                .map(|value| value.map(|value| crate::value::Object::repr(&value)))
+                .map_err(|err| err.to_string())
+        })
+        .unwrap()
+        .join()
+        .unwrap();
+
+    assert_eq!(result, Ok(Some("(1, 2)".to_string())));
+
+    // Right-recursive calls after consuming input are run in a loop as well
+    let result = std::thread::Builder::new()
+        .stack_size(2 * 1024 * 1024)
+        .spawn(move || {
+            let program = Compiler::new()
+                .compile_from_str("L : @{ ''b'' ; 'a' L }\nL")
+                .unwrap()
+                .unwrap();
+
+            program
+                .run_on_str(&format!("{}b", "a".repeat(20_000)))
+                .map(|value| value.map(|value| crate::value::Object::repr(&value)))
+                .map_err(|err| err.to_string())
+        })
+        .unwrap()
+        .join()
+        .unwrap();
+
+    assert_eq!(result, Ok(Some("\"b\"".to_string())));
+}
+
+#[test]
+// Test for reporting parselets frequently re-entered when profiling, without memoizing them
+fn profiler_hot_reentries() {
+    use crate::vm::{Profiler, Thread, HOT_REENTRIES};
+
+    let alternatives: Vec<String> = (0..HOT_REENTRIES + 4)
+        .map(|i| format!("Uncached '{}'", i))
+        .collect();
+
+    // Uncached must be re-entered, so it isn't inlined
+    let mut compiler = Compiler::new();
+    compiler.inline = false;
+
+    let program = compiler
+        .compile_from_str(&format!(
+            "Cached : cache @{{ Word }}\nUncached : @{{ Int }}\nCached | {}",
+            alternatives.join(" | ")
+        ))
+        .unwrap()
+        .unwrap();
+
+    let mut reader = Reader::new(None, Box::new(std::io::Cursor::new("1 2 3")));
+    let mut thread = Thread::new(&program, vec![&mut reader]);
+    thread.profiler = Some(Profiler::new());
+
+    assert_eq!(thread.run(), Ok(None));
+
+    let profiler = thread.profiler.unwrap();
+    assert!(profiler.is_hot("Uncached"));
+    assert!(!profiler.is_hot("Cached"));
+
+    // Profiling doesn't memoize Uncached, so it is re-entered by every alternative at every offset
+    assert_eq!(profiler.reentries["Uncached"], 5 * (HOT_REENTRIES + 3));
+}
+
+#[test]
+// Test for profiling the time spent in parselet call stacks
+fn profiler_folded() {
+    use crate::vm::{Profiler, Thread};
+
+    // Inner and Outer shall show up in the call stacks
+    let mut compiler = Compiler::new();
+    compiler.inline = false;
+
+    let program = compiler
+        .compile_from_str("Inner : @{ Int }\nOuter : @{ Inner Word }\nOuter")
+        .unwrap()
+        .unwrap();
+
+    let mut reader = Reader::new(None, Box::new(std::io::Cursor::new("1a 2b")));
+    let mut thread = Thread::new(&program, vec![&mut reader]);
+    thread.profiler = Some(Profiler::new());
+
+    assert_eq!(thread.run(), Ok(Some(value!([[1, "a"], [2, "b"]]))));
+
+    let mut folded = Vec::new();
+    thread.profiler.unwrap().write_folded(&mut folded).unwrap();
+
+    let stacks: Vec<&str> = std::str::from_utf8(&folded)
+        .unwrap()
+        .lines()
+        .map(|line| line.rsplit_once(' ').unwrap().0)
+        .collect();
+
+    assert_eq!(
+        stacks,
+        ["__main__", "__main__;Outer", "__main__;Outer;Inner"]
+    );
+}
+
+#[test]
+// Test for the statistics of parselet calls collected by the profiler
+fn profiler_stats() {
+    use crate::vm::{Profiler, Thread};
+
+    let mut compiler = Compiler::new();
+    compiler.inline = false;
+
+    let program = compiler
+        .compile_from_str("Inner : @{ Int }\nOuter : @{ Inner Word }\nOuter")
+        .unwrap()
+        .unwrap();
+
+    let mut reader = Reader::new(None, Box::new(std::io::Cursor::new("1a 2b x")));
+    let mut thread = Thread::new(&program, vec![&mut reader]);
+    thread.profiler = Some(Profiler::new());
+
+    assert_eq!(thread.run(), Ok(Some(value!([[1, "a"], [2, "b"]]))));
+
+    let profiler = thread.profiler.unwrap();
+    let stats = |name: &str| {
+        let stats = &profiler.stats[name];
+        (stats.calls, stats.accepted, stats.consumed)
+    };
+
+    assert_eq!(stats("Outer"), (5, 2, 4));
+    assert_eq!(stats("Inner"), (5, 2, 2));
+    assert!(profiler.stats["Outer"].total >= profiler.stats["Outer"].own);
+
+    let mut report = Vec::new();
+    profiler.write_report(&mut report).unwrap();
+
+    let report = String::from_utf8(report).unwrap();
+    assert!(report.starts_with("Parselet"));
+    assert_eq!(report.lines().count(), 4);
+}
+
+#[test]
+// Test for tracing parselet calls and token matches as JSON events
+fn tracer_json() {
+    use crate::vm::{Thread, TraceFormat, Tracer};
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    // Output shared with the tracer, which owns its writer
+    #[derive(Clone, Default)]
+    struct Output(Rc<RefCell<Vec<u8>>>);
+
+    impl std::io::Write for Output {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0.borrow_mut().write(buf)
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    // Pair is traced as a call, not inlined
+    let mut compiler = Compiler::new();
+    compiler.inline = false;
+
+    let program = compiler
+        .compile_from_str("Pair : @{ Int ',' Word }\nPair")
+        .unwrap()
+        .unwrap();
+
+    let output = Output::default();
+
+    let mut reader = Reader::new(
+        None,
+        Box::new(std::io::Cursor::new(
+            "42,abcdefghijklmnopqrstuvwxyzabcdefghijklmnopqrstuvwxyzabcdefghijklmnopqrstuvwxyz",
+        )),
+    );
+    let mut thread = Thread::new(&program, vec![&mut reader]);
+    thread.observers.push(Box::new(Tracer::new(
+        TraceFormat::Json,
+        Box::new(output.clone()),
+    )));
+
+    assert!(thread.run().is_ok());
+    drop(thread);
+
+    let events: Vec<serde_json::Value> = std::str::from_utf8(&output.0.borrow())
+        .unwrap()
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect();
+
+    assert_eq!(
+        events
+            .iter()
+            .map(|event| format!(
+                "{} {}",
+                event["event"].as_str().unwrap(),
+                event["name"].as_str().unwrap()
+            ))
+            .collect::<Vec<_>>(),
+        [
+            "enter __main__",
+            "enter Pair",
+            "shift Int",
+            "shift ','",
+            "shift Word",
+            "exit Pair",
+            "exit __main__"
+        ]
+    );
+
+    assert_eq!(events[2]["offset"], 0);
+    assert_eq!(events[2]["end"], 2);
+    assert_eq!(events[2]["value"], "42");
+    assert_eq!(events[5]["status"], "accept");
+
+    // Values are truncated
+    let word = events[4]["value"].as_str().unwrap();
+    assert_eq!(word.chars().count(), crate::vm::TRACE_VALUE_LIMIT + 3);
+    assert!(word.ends_with("..."));
+}
+
+#[test]
+// Test for an observer registered by an embedder
+fn observer() {
+    use crate::reader::Offset;
+    use crate::value::{Object, RefValue};
+    use crate::vm::{Accept, Observer, Reject, Thread};
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    // Observer recording events, shared with the test
+    #[derive(Clone, Default)]
+    struct Recorder(Rc<RefCell<Vec<String>>>);
+
+    impl Observer for Recorder {
+        fn enter(&mut self, parselet: &str, _start: &Offset) {
+            self.0.borrow_mut().push(format!("enter {}", parselet));
+        }
+
+        fn leave(
+            &mut self,
+            parselet: &str,
+            _start: &Offset,
+            _end: &Offset,
+            result: &Result<Accept, Reject>,
+            _reader: &Reader,
+        ) {
+            self.0
+                .borrow_mut()
+                .push(format!("leave {} {}", parselet, result.is_ok()));
+        }
+
+        fn capture(
+            &mut self,
+            parselet: &str,
+            index: usize,
+            alias: Option<&RefValue>,
+            value: &RefValue,
+        ) {
+            self.0.borrow_mut().push(format!(
+                "capture {} {} {} {}",
+                parselet,
+                index,
+                alias.map_or("-".to_string(), |alias| alias.to_string()),
+                value.repr()
+            ));
+        }
+    }
+
+    let mut compiler = Compiler::new();
+    compiler.inline = false;
+
+    let program = compiler
+        .compile_from_str("Pair : @{ Int ',' w => Word $1 = $1 * 2 $w = \"x\" }\nPair")
+        .unwrap()
+        .unwrap();
+
+    let recorder = Recorder::default();
+
+    let mut reader = Reader::new(None, Box::new(std::io::Cursor::new("21,a")));
+    let mut thread = Thread::new(&program, vec![&mut reader]);
+    thread.observers.push(Box::new(recorder.clone()));
+
+    assert_eq!(
+        thread.run().unwrap().unwrap().repr(),
+        "(0 => 42 w => \"x\")"
+    );
+    drop(thread);
+
+    assert_eq!(
+        *recorder.0.borrow(),
+        [
+            "enter __main__",
+            "enter Pair",
+            "capture Pair 1 - 42",
+            "capture Pair 3 w \"x\"",
+            "leave Pair true",
+            "leave __main__ true"
+        ]
+    );
+}
+
+#[test]
+// Test for the debugger driven by scripted commands
+fn debugger() {
+    use crate::vm::{Debugger, Thread};
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    // Output shared with the debugger, which owns its writer
+    #[derive(Clone, Default)]
+    struct Output(Rc<RefCell<Vec<u8>>>);
+
+    impl std::io::Write for Output {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0.borrow_mut().write(buf)
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    let mut compiler = Compiler::new();
+    compiler.inline = false;
+
+    let program = compiler
+        .compile_from_str("Pair : @sep=',' { Int sep Word }\nPair")
+        .unwrap()
+        .unwrap();
+
+    let output = Output::default();
+
+    let mut reader = Reader::new(None, Box::new(std::io::Cursor::new("1,a 2,b")));
+    let mut thread = Thread::new(&program, vec![&mut reader]);
+    thread.debugger = Some(Debugger::new(
+        Box::new(std::io::Cursor::new("b Pair\nc\ns\nwhat\nq\n")),
+        Box::new(output.clone()),
+    ));
+
+    assert!(thread.run().is_ok());
+    drop(thread);
+
+    let output = String::from_utf8(output.0.borrow().clone()).unwrap();
+
+    // Breaks follow the prompt of the previous command
+    assert_eq!(
+        output
+            .lines()
+            .map(|line| line.trim_start_matches("(debug) "))
+            .filter(|line| line.starts_with("Break in"))
+            .collect::<Vec<_>>(),
+        [
+            "Break in __main__ at depth 0, 1:1",
+            "Break in Pair at depth 1, 1:1",
+            "Break in Pair at depth 1, 1:4"
+        ]
+    );
+
+    assert!(output.contains("Breakpoint set on Pair"));
+    assert!(output.contains("  sep = ','"));
+    assert!(output.contains("Pair accepted => (1, \"a\")"));
+    assert!(output.contains("Commands: "));
+}
+
+#[test]
+// Test for statistics of a compiled program
+fn program_stats() {
+    let program = Compiler::new()
+        .compile_from_str("Expr : @{ Expr '+' Int ; Int }\nMaybe : @{ 'a'? }\nExpr Maybe")
+        .unwrap()
+        .unwrap();
+
+    let stats = program.stats();
+
+    assert_eq!(stats.nullable, ["Maybe"]);
+    assert_eq!(stats.leftrec, ["Expr"]);
+    assert!(stats.rules >= 3 && stats.rules <= stats.parselets);
+    assert_eq!(stats.tokens, 2);
+    assert_eq!(stats.size, program.to_json().unwrap().len());
+}
+
+#[test]
+// Test for deduplication of identical parselets
+fn program_deduplicate() {
+    use crate::value::ParseletRef;
+
+    let program = Compiler::new()
+        .compile_from_str("'c' @{ Int ''x'' }+ ; 'd' @{ Int ''x'' }+ ; 'e' @{ Int ''y'' }+")
+        .unwrap()
+        .unwrap();
+
+    let names: Vec<String> = program
+        .statics
+        .iter()
+        .filter_map(|value| {
+            value
+                .borrow()
+                .object::<ParseletRef>()
+                .map(|parselet| parselet.0.borrow().name.clone())
+        })
+        .filter(|name| name.contains("__AnonymousParselet__"))
+        .collect();
+
+    // One anonymous parselet for each body, and one Pos and Repeat instance using each of them
+    assert_eq!(names.len(), 6);
+
+    assert_eq!(
+        program.run_on_str("c1x d2x e3y").unwrap(),
+        Some(value!([[1, "x"], [2, "x"], [3, "y"]]))
+    );
+}
+
+#[test]
+// Test for integer overflow behaviors
+fn overflow() {
+    use crate::value::{Object, Overflow};
+    use crate::vm::RunOptions;
+
+    let program = Compiler::new()
+        .compile_from_str("x = 9223372036854775807\n(x + 1, -x - 2, x * 2, -(-x - 1), x++, x)")
+        .unwrap()
+        .unwrap();
+
+    let run = |overflow| {
+        program.run_from_reader_with_options(
+            Reader::new(None, Box::new(std::io::Cursor::new(""))),
+            RunOptions {
+                overflow,
+                ..Default::default()
+            },
+        )
+    };
+
+    assert_eq!(
+        run(Overflow::Promote).unwrap().unwrap().repr(),
+        "(9223372036854775808, -9223372036854775809, 18446744073709551614, 9223372036854775808, 9223372036854775807, 9223372036854775808)"
+    );
+    assert_eq!(
+        run(Overflow::Wrap).unwrap().unwrap().repr(),
+        "(-9223372036854775808, 9223372036854775807, -2, -9223372036854775808, 9223372036854775807, -9223372036854775808)"
+    );
+    assert_eq!(
+        run(Overflow::Saturate).unwrap().unwrap().repr(),
+        "(9223372036854775807, -9223372036854775808, 9223372036854775807, 9223372036854775807, 9223372036854775807, 9223372036854775807)"
+    );
+    assert_eq!(
+        run(Overflow::Error).unwrap_err().to_string(),
+        "Line 2, column 2: Integer overflow, 9223372036854775808 exceeds 64 bits"
+    );
+}
+
+#[test]
+// Test for warnings and errors on lossy conversions
+fn lossy() {
+    use crate::value::{Lossy, Object};
+    use crate::vm::Thread;
+
+    let program = Compiler::new()
+        .compile_from_str(
+            "i = 1\nf = 7.5\ni += 2.5\n(i, int(\"12abc\"), float(\"1.5\"), f // 2, int(\"42\"))",
+        )
+        .unwrap()
+        .unwrap();
+
+    let run = |lossy| {
+        let mut reader = Reader::new(None, Box::new(std::io::Cursor::new("")));
+        let mut thread = Thread::new(&program, vec![&mut reader]);
+        thread.options.lossy = lossy;
+
+        (thread.run(), thread.warnings.clone())
+    };
+
+    let (result, warnings) = run(Lossy::Ignore);
+    assert_eq!(result.unwrap().unwrap().repr(), "(3, 12, 1.5, 3, 42)");
+    assert!(warnings.is_empty());
+
+    let (result, warnings) = run(Lossy::Warn);
+    assert_eq!(result.unwrap().unwrap().repr(), "(3, 12, 1.5, 3, 42)");
+    assert_eq!(
+        warnings,
+        [
+            "Line 3, column 6: Lossy conversion of 2.5 to int 2",
+            "Line 4, column 5: Lossy conversion of \"12abc\" to int 12",
+            "Line 4, column 33: Lossy conversion of 7.5 to int 7"
+        ]
+    );
+
+    let (result, _) = run(Lossy::Error);
+    assert_eq!(
+        result.unwrap_err().to_string(),
+        "Line 3, column 6: Lossy conversion of 2.5 to int 2"
+    );
+}
+
+#[test]
+// Test for selecting grammars by patterns on file names and content
+fn dispatch_grammars() {
+    use crate::dispatch::{glob, Grammars};
+    use crate::Program;
+
+    assert!(glob("*.log", "access.log"));
+    assert!(glob("a?c*", "abcdef"));
+    assert!(glob("*a*b", "xaxxab"));
+    assert!(glob("*", ""));
+    assert!(!glob("*.log", "access.log.1"));
+    assert!(!glob("a?c", "ac"));
+
+    let mut grammars = Grammars::new();
+    grammars.add("logs/*.log", Program::from_source("'log'").unwrap());
+    grammars.add("*.log", Program::from_source("'other'").unwrap());
+    grammars.add("^#!*", Program::from_source("'script'").unwrap());
+
+    let select = |filename, head| {
+        grammars
+            .select(filename, head)
+            .map(|program| program.run_on_str("log other script").unwrap())
+    };
+
+    assert_eq!(select("logs/a.log", ""), Some(Some(value!("log"))));
+    assert_eq!(select("var/logs/a.log", ""), Some(Some(value!("other"))));
+    assert_eq!(select("run", "#!/bin/sh"), Some(Some(value!("script"))));
+    assert_eq!(select("run", "echo"), None);
+}
+
+#[test]
+// Test for limits of the recursion depth, the backtracked input and the allocated values
+fn resource_limits() {
+    use crate::vm::RunOptions;
+
+    let run = |src: &str, input: &str, options: RunOptions| {
+        Compiler::new()
+            .compile_from_str(src)
+            .unwrap()
+            .unwrap()
+            .run_from_reader_with_options(
+                Reader::new(None, Box::new(std::io::Cursor::new(input.to_string()))),
+                options,
+            )
+    };
+
+    let nested = "X : @{ '(' X ')' ; Word }\nX";
+
+    assert_eq!(
+        run(nested, "((((x))))", RunOptions::default()),
+        Ok(Some(value!("x")))
+    );
+
+    let error = run(
+        nested,
+        "((((x))))",
+        RunOptions {
+            max_depth: Some(3),
+            ..Default::default()
+        },
+    )
+    .unwrap_err();
+
+    assert_eq!(error.code, Some("limit"));
+    assert!(error
+        .message
+        .starts_with("Resource limit exceeded, X() exceeds a depth of 3"));
+
+    // Recursion deeper than the native stack segments allow fails, even below the maximum depth
+    let deep = format!("{}x{}", "(".repeat(100_000), ")".repeat(100_000));
+
+    for max_depth in [None, Some(200_000)] {
+        let error = run(
+            nested,
+            &deep,
+            RunOptions {
+                max_depth,
+                ..Default::default()
+            },
+        )
+        .unwrap_err();
+
+        assert_eq!(error.code, Some("limit"));
+        assert!(error
+            .message
+            .starts_with("Resource limit exceeded, X() exceeds the native stack"));
+    }
+
+    let backtracking = "Word _ 'Z' | Word _";
+    let input = "aaaa bbbb cccc dddd";
+
+    assert!(run(backtracking, input, RunOptions::default()).is_ok());
+
+    let error = run(
+        backtracking,
+        input,
+        RunOptions {
+            max_backtrack: Some(12),
+            ..Default::default()
+        },
+    )
+    .unwrap_err();
+
+    assert_eq!(error.code, Some("limit"));
+    assert_eq!(
+        error.message,
+        "Resource limit exceeded, backtracked more than 12 bytes of input"
+    );
+
+    let allocating = "l = list()\ni = 0\nwhile i < 1000 { l.push(i); i += 1 }\nl.len";
+
+    assert_eq!(
+        run(
+            allocating,
+            "",
+            RunOptions {
+                max_values: Some(2000),
+                ..Default::default()
+            }
+        ),
+        Ok(Some(value!(1000)))
+    );
+
+    let error = run(
+        allocating,
+        "",
+        RunOptions {
+            max_values: Some(100),
+            ..Default::default()
+        },
+    )
+    .unwrap_err();
+
+    assert_eq!(error.code, Some("limit"));
+    assert_eq!(
+        error.message,
+        "Resource limit exceeded, more than 100 values allocated"
+    );
+
+    // Values aren't counted without a limit, so the result isn't
+    let allocated = crate::RefValue::allocated();
+    let result = run(allocating, "", RunOptions::default());
+    assert_eq!(crate::RefValue::allocated(), allocated);
+    assert_eq!(result, Ok(Some(value!(1000))));
+}
+
+#[test]
+// Test for cancelling runs by a timeout or a cancellation flag
+fn cancellation() {
+    use crate::vm::RunOptions;
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    let program = Compiler::new()
+        .compile_from_str("i = 0\nloop { i++ }")
+        .unwrap()
+        .unwrap();
+
+    let error = program
+        .run_with_timeout(
+            Reader::new(None, Box::new(std::io::Cursor::new(""))),
+            Duration::from_millis(50),
+        )
+        .unwrap_err();
+
+    assert_eq!(error.code, Some("cancelled"));
+    assert_eq!(error.message, "Cancelled, deadline exceeded");
+
+    // Cancelled from another thread
+    let cancel = Arc::new(AtomicBool::new(false));
+
+    let canceller = {
+        let cancel = cancel.clone();
+        std::thread::spawn(move || {
+            std::thread::sleep(Duration::from_millis(50));
+            cancel.store(true, Ordering::Relaxed);
+        })
+    };
+
+    let error = program
+        .run_from_reader_with_options(
+            Reader::new(None, Box::new(std::io::Cursor::new(""))),
+            RunOptions {
+                cancel: Some(cancel),
+                ..Default::default()
+            },
+        )
+        .unwrap_err();
+
+    canceller.join().unwrap();
+    assert_eq!(error.code, Some("cancelled"));
+    assert_eq!(error.message, "Cancelled");
+
+    // Runs finishing in time aren't affected
+    let program = Compiler::new().compile_from_str("Int").unwrap().unwrap();
+
+    assert_eq!(
+        program
+            .run_with_timeout(
+                Reader::new(None, Box::new(std::io::Cursor::new("1 2"))),
+                Duration::from_secs(60),
+            )
+            .unwrap(),
+        Some(value!([1, 2]))
+    );
+}
+
+#[test]
+// Test for sandbox mode, disabling builtins touching the environment and imports
+fn sandbox() {
+    let mut compiler = Compiler::new();
+    compiler.sandbox = true;
+
+    let errors = compiler.compile_from_str("x = 1\nprint(x)").unwrap_err();
+    assert_eq!(
+        errors[0].message,
+        "Builtin 'print' isn't available in sandbox mode"
+    );
+
+    let errors = compiler.compile_from_str("import \"x.tok\"").unwrap_err();
+    assert_eq!(errors[0].message, "Can't import 'x.tok' in sandbox mode");
+
+    // Other builtins are still available
+    let program = compiler
+        .compile_from_str("str(repr(Int) + \"!\")")
+        .unwrap()
+        .unwrap();
+    assert!(program.sandbox);
+    assert_eq!(program.run_from_str("42"), Ok(Some(value!("42!"))));
+
+    // Programs compiled without sandbox are sandboxed when they run
+    let mut program = crate::Program::from_source("print(Int)").unwrap();
+    program.sandbox = true;
+
+    let error = program.run_from_str("42").unwrap_err();
+    assert_eq!(error.message, "print() isn't available in sandbox mode");
+
+    // The sandbox mode is kept by serialization
+    let program = crate::Program::from_json(&program.to_json().unwrap()).unwrap();
+    assert!(program.sandbox);
+}
+
+#[test]
+// Test for the builtins reading, writing and appending files
+fn file_io() {
+    let path = std::env::temp_dir().join(format!("tokay-file-io-{}.txt", std::process::id()));
+    let path = path.to_str().unwrap();
+
+    let src = format!(
+        "path = {:?}\nfwrite(path, \"Hello\")\nfappend(path, 42)\nfappend(path, \"\\n\")\nfread(path)",
+        path
+    );
+
+    assert_eq!(
+        crate::Program::from_source(&src).unwrap().run_from_str(""),
+        Ok(Some(value!("Hello42\n")))
+    );
+
+    std::fs::remove_file(path).unwrap();
+
+    let error = crate::Program::from_source(&format!("fread({:?})", path))
+        .unwrap()
+        .run_from_str("")
+        .unwrap_err();
+
+    assert!(error
+        .message
+        .starts_with(&format!("fread() can't read '{}': ", path)));
+}
+
+#[test]
+// Test for the environment and arguments of the process, and stopping a program by exit
+fn process() {
+    use crate::vm::{RunOptions, Thread};
+
+    let program =
+        crate::Program::from_source("env(\"CARGO_PKG_NAME\") + env(\"TOKAY_NO_SUCH_VAR\")")
+            .unwrap();
+    assert_eq!(program.run_from_str(""), Ok(Some(value!("tokay"))));
+
+    let program = crate::Program::from_source("args()").unwrap();
+    assert_eq!(
+        program.run_from_reader_with_options(
+            Reader::new(None, Box::new(std::io::Cursor::new(""))),
+            RunOptions {
+                args: vec!["a".to_string(), "b".to_string()],
+                ..Default::default()
+            }
+        ),
+        Ok(Some(value!(["a", "b"])))
+    );
+
+    // exit unwinds the program, and sets its exit code
+    let program = crate::Program::from_source("Int { if $1 > 2 exit $1 * 10; $1 }").unwrap();
+
+    let mut reader = Reader::new(None, Box::new(std::io::Cursor::new("1 2 3 4")));
+    let mut thread = Thread::new(&program, vec![&mut reader]);
+    assert_eq!(thread.run(), Ok(None));
+    assert_eq!(thread.exit, Some(30));
+
+    let mut reader = Reader::new(None, Box::new(std::io::Cursor::new("1 2")));
+    let mut thread = Thread::new(&program, vec![&mut reader]);
+    assert_eq!(thread.run(), Ok(Some(value!([1, 2]))));
+    assert_eq!(thread.exit, None);
+}
+
+#[test]
+// Test for serde serialization and deserialization of values
+fn value_serde() {
+    let value = value!([
+        "a" => 1,
+        "b" => [true, false, null],
+        "c" => 2.5,
+        "d" => "Hello"
+    ]);
+
+    let json = serde_json::to_string(&value).unwrap();
+    assert_eq!(json, r#"{"a":1,"b":[true,false,null],"c":2.5,"d":"Hello"}"#);
+
+    let back: crate::RefValue = serde_json::from_str(&json).unwrap();
+    assert_eq!(back, value);
+
+    // AST from the compiler
+    let ast = Compiler::new()
+        .parse(Reader::new(None, Box::new(std::io::Cursor::new("42"))))
+        .unwrap();
+
+    let json = serde_json::to_value(&ast).unwrap();
+    assert_eq!(json["emit"], "main");
+    assert_eq!(json["children"]["emit"], "value_integer");
+    assert_eq!(json["children"]["value"], 42);
 }
 
 tokay_macros::tokay_tests!("tests/*.tok");
@@ -24,9 +24,9 @@ pub fn run(src: &str, input: &str) -> Result<Option<RefValue>, String> {
     }
 }
 
-/// Checks if an identifier defines a Tokay consumable.
+/// Checks if an identifier defines a Tokay consumable; Qualified names are checked by their last part.
 pub(crate) fn identifier_is_consumable(ident: &str) -> bool {
-    let ch = ident.chars().next().unwrap();
+    let ch = ident.rsplit("::").next().unwrap().chars().next().unwrap();
     ch.is_uppercase() || ch == '_'
 }
 
@@ -52,8 +52,8 @@ The provided code can either be a testcase or a path to a filename that contains
 
 There's also a special REPL test mode: If the first line in the testcase contains `#testmode:repl`,
 the code is fed to the Tokay REPL, and expected output is tested against each line specified. In this
-mode, it is important to specify multi-line definitions with the alternative `;` delimiter, otherwise
-a syntax error will occur (likewise in the normal REPL).
+mode, multi-line definitions are only continued on unterminated blocks, parentheses or strings, so
+other definitions must be specified with the alternative `;` delimiter (likewise in the normal REPL).
 */
 #[allow(dead_code)]
 pub(crate) fn testcase(code: &str) {
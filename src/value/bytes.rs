@@ -0,0 +1,244 @@
+//! Bytes object and the token consuming bytes from binary input
+use super::{BoxedObject, List, Object, RefValue, Str, Value};
+use crate::{Accept, Capture, Error, Reject};
+use num_bigint::{BigInt, Sign};
+use tokay_macros::{tokay_method, tokay_token};
+extern crate self as tokay;
+
+/** Bytes object type.
+
+Bytes are a sequence of byte values. They are captured from input read in binary mode, where every
+byte is read as one character, or constructed by `bytes()` from a str, a list of ints or other
+bytes. */
+#[derive(Clone, PartialEq, PartialOrd)]
+pub struct Bytes {
+    bytes: Vec<u8>,
+}
+
+impl Bytes {
+    /// Returns the bytes as slice.
+    pub fn as_slice(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    /// Converts a value into bytes, which is either bytes, a str or a list of ints.
+    fn from_value(value: &RefValue) -> Result<Self, String> {
+        let value = value.borrow();
+
+        if let Some(bytes) = value.object::<Bytes>() {
+            Ok(bytes.clone())
+        } else if let Some(string) = value.object::<Str>() {
+            Ok(Self::from(string.as_bytes().to_vec()))
+        } else if let Some(list) = value.object::<List>() {
+            let mut bytes = Vec::with_capacity(list.len());
+
+            for item in list.iter() {
+                match item
+                    .to_usize()
+                    .ok()
+                    .and_then(|byte| u8::try_from(byte).ok())
+                {
+                    Some(byte) => bytes.push(byte),
+                    None => return Err(format!("{} is not a byte value", item.repr())),
+                }
+            }
+
+            Ok(Self::from(bytes))
+        } else if value.is_void() {
+            Ok(Self::from(Vec::new()))
+        } else {
+            Err(format!("can't convert '{}' into bytes", value.name()))
+        }
+    }
+
+    tokay_method!("bytes : @value=void", {
+        match Self::from_value(&value) {
+            Ok(bytes) => Ok(RefValue::from(bytes)),
+            Err(msg) => Err(Error::from(format!("{} {}", __function, msg))),
+        }
+    });
+
+    tokay_method!("bytes_add : @bytes, append", {
+        // Operands may be swapped, so both are converted
+        match (Self::from_value(&bytes), Self::from_value(&append)) {
+            (Ok(mut bytes), Ok(append)) => {
+                bytes.bytes.extend(append.bytes);
+                Ok(RefValue::from(bytes))
+            }
+            (Err(msg), _) | (_, Err(msg)) => Err(Error::from(format!("{} {}", __function, msg))),
+        }
+    });
+
+    tokay_method!("bytes_decode : @bytes, encoding=void", {
+        let bytes = bytes.borrow();
+        let Some(bytes) = bytes.object::<Bytes>() else {
+            return Err(Error::from(format!("{} expects bytes", __function)));
+        };
+
+        let encoding = if encoding.is_void() {
+            "utf-8".to_string()
+        } else {
+            encoding.to_string()
+        };
+
+        match encoding.as_str() {
+            "utf-8" => match std::str::from_utf8(&bytes.bytes) {
+                Ok(string) => Ok(RefValue::from(string)),
+                Err(err) => Err(Error::from(format!(
+                    "{} invalid UTF-8 sequence at byte {}",
+                    __function,
+                    err.valid_up_to()
+                ))),
+            },
+            "latin1" => Ok(RefValue::from(
+                bytes
+                    .bytes
+                    .iter()
+                    .map(|byte| char::from(*byte))
+                    .collect::<String>(),
+            )),
+            encoding => Err(Error::from(format!(
+                "{} encoding '{}' is unknown, use 'utf-8' or 'latin1'",
+                __function, encoding
+            ))),
+        }
+    });
+
+    tokay_method!("bytes_get_item : @bytes, item, default=void", {
+        let bytes = bytes.borrow();
+        let Some(bytes) = bytes.object::<Bytes>() else {
+            return Ok(default);
+        };
+
+        // In case the item index is negative, calculate from the end
+        let item = item.to_i64()?;
+        let index = if item < 0 {
+            bytes.bytes.len() as i64 + item
+        } else {
+            item
+        };
+
+        match usize::try_from(index)
+            .ok()
+            .and_then(|index| bytes.bytes.get(index))
+        {
+            Some(byte) => Ok(RefValue::from(*byte as i64)),
+            None => Ok(default),
+        }
+    });
+
+    tokay_method!("bytes_hex : @bytes", {
+        let bytes = bytes.borrow();
+
+        Ok(RefValue::from(match bytes.object::<Bytes>() {
+            Some(bytes) => bytes
+                .bytes
+                .iter()
+                .map(|byte| format!("{:02x}", byte))
+                .collect::<String>(),
+            None => String::new(),
+        }))
+    });
+
+    tokay_method!("bytes_int : @bytes, little=false, signed=false", {
+        let bytes = bytes.borrow();
+        let Some(bytes) = bytes.object::<Bytes>() else {
+            return Err(Error::from(format!("{} expects bytes", __function)));
+        };
+
+        // Integers are read in big-endian byte order by default, like in network protocols
+        let mut bytes = bytes.bytes.clone();
+        if little.is_true() {
+            bytes.reverse();
+        }
+
+        Ok(RefValue::from(if signed.is_true() {
+            BigInt::from_signed_bytes_be(&bytes)
+        } else {
+            BigInt::from_bytes_be(Sign::Plus, &bytes)
+        }))
+    });
+
+    tokay_method!("bytes_len : @bytes", {
+        let bytes = bytes.borrow();
+
+        Ok(RefValue::from(match bytes.object::<Bytes>() {
+            Some(bytes) => bytes.bytes.len(),
+            None => 0,
+        }))
+    });
+}
+
+impl Object for Bytes {
+    fn severity(&self) -> u8 {
+        10
+    }
+
+    fn name(&self) -> &'static str {
+        "bytes"
+    }
+
+    fn repr(&self) -> String {
+        let mut ret = "bytes(\"".to_string();
+
+        for byte in &self.bytes {
+            match byte {
+                b'\\' => ret.push_str("\\\\"),
+                b'"' => ret.push_str("\\\""),
+                b'\n' => ret.push_str("\\n"),
+                b'\r' => ret.push_str("\\r"),
+                b'\t' => ret.push_str("\\t"),
+                0x20..=0x7e => ret.push(char::from(*byte)),
+                byte => ret.push_str(&format!("\\x{:02x}", byte)),
+            }
+        }
+
+        ret.push_str("\")");
+        ret
+    }
+
+    fn is_true(&self) -> bool {
+        !self.bytes.is_empty()
+    }
+
+    fn to_string(&self) -> String {
+        String::from_utf8_lossy(&self.bytes).into_owned()
+    }
+}
+
+impl std::fmt::Debug for Bytes {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.repr())
+    }
+}
+
+impl From<Vec<u8>> for Bytes {
+    fn from(bytes: Vec<u8>) -> Self {
+        Self { bytes }
+    }
+}
+
+impl From<Bytes> for RefValue {
+    fn from(bytes: Bytes) -> Self {
+        Value::Object(Box::new(bytes) as BoxedObject).into()
+    }
+}
+
+// Exactly count bytes, or characters when the input isn't read in binary mode
+tokay_token!("Bytes : @count=1", {
+    let reader = &mut context.thread.reader;
+    let start = reader.tell();
+
+    for _ in 0..count.to_usize()? {
+        if reader.next().is_none() {
+            reader.reset(start);
+            return Err(Reject::Next);
+        }
+    }
+
+    Ok(Accept::Push(Capture::Range(
+        reader.capture_from(&start),
+        None,
+        5,
+    )))
+});
@@ -0,0 +1,174 @@
+//! Rendering and set algebra of character classes
+use charclass::CharClass;
+
+/** View on a character class, rendering it in compact syntax like `[0-9A-Fa-f]`.
+
+Classes containing most characters are rendered negated, like `[^\n]`, and the class of all
+characters is rendered as `.`. Besides rendering, set operations on character classes are
+provided, which aren't available on `CharClass` itself. */
+#[derive(Clone, Copy)]
+pub struct Ccl<'ccl>(pub &'ccl CharClass);
+
+// Maps a code point to a char, surrogates are mapped to the next valid char
+fn to_char(n: u32) -> char {
+    char::from_u32(n).unwrap_or('\u{E000}')
+}
+
+impl Ccl<'_> {
+    /** Retrieve the ranges of the character class.
+
+    The ranges are not directly accessible, so they are determined by binary searches on the
+    character class: The end of a range is found by testing for containment, the end of a gap
+    between ranges by testing whether adding characters to the class adds all of them. */
+    pub fn ranges(&self) -> Vec<(char, char)> {
+        // Finds the last char from start, for which start..=end is completely inside or outside ccl
+        fn range_end(ccl: &CharClass, start: char, inside: bool) -> char {
+            let (mut lo, mut hi) = (start as u32, char::MAX as u32);
+
+            while lo < hi {
+                let mid = lo + (hi - lo).div_ceil(2);
+                let range = start..=to_char(mid);
+
+                let found = if inside {
+                    ccl.test(&range)
+                } else {
+                    ccl.clone().add(range.clone()) == *range.end() as u32 - start as u32 + 1
+                };
+
+                if found {
+                    lo = mid;
+                } else {
+                    hi = mid - 1;
+                }
+            }
+
+            to_char(lo)
+        }
+
+        let ccl = self.0;
+        let mut ranges = Vec::new();
+        let mut start = '\0';
+
+        loop {
+            let inside = ccl.test(&(start..=start));
+            let end = range_end(ccl, start, inside);
+
+            if inside {
+                ranges.push((start, end));
+            }
+
+            if end == char::MAX {
+                break;
+            }
+
+            start = to_char(end as u32 + 1);
+        }
+
+        ranges
+    }
+
+    /** Characters not contained in the class.
+
+    This is computed from the ranges, as `CharClass::negate()` fails on classes containing `\0`. */
+    pub fn negate(&self) -> CharClass {
+        let mut negated = CharClass::new();
+        let mut start = 0;
+
+        for (from, to) in self.ranges() {
+            if (from as u32) > start {
+                negated.add(to_char(start)..=to_char(from as u32 - 1));
+            }
+
+            start = to as u32 + 1;
+        }
+
+        if start <= char::MAX as u32 {
+            negated.add(to_char(start)..=char::MAX);
+        }
+
+        negated
+    }
+
+    /// Characters contained in this or the other class.
+    pub fn union(&self, other: &CharClass) -> CharClass {
+        self.0.clone() + other.clone()
+    }
+
+    /// Characters contained in both this and the other class.
+    pub fn intersection(&self, other: &CharClass) -> CharClass {
+        Ccl(&(self.negate() + Ccl(other).negate())).negate()
+    }
+
+    /// Characters contained in this class, but not in the other one.
+    pub fn difference(&self, other: &CharClass) -> CharClass {
+        self.intersection(&Ccl(other).negate())
+    }
+
+    /// Checks whether all characters of this class are contained in the other one.
+    pub fn is_subset(&self, other: &CharClass) -> bool {
+        self.difference(other).len() == 0
+    }
+}
+
+/// Renders a char within a character class, escaped like in Tokay code where required.
+fn render_char(ch: char) -> String {
+    match ch {
+        '\x07' => "\\a".to_string(),
+        '\x08' => "\\b".to_string(),
+        '\x0c' => "\\f".to_string(),
+        '\n' => "\\n".to_string(),
+        '\r' => "\\r".to_string(),
+        '\t' => "\\t".to_string(),
+        '\x0b' => "\\v".to_string(),
+        '\\' | ']' | '-' | '^' => format!("\\{}", ch),
+        // Control characters, other whitespace, private use areas and noncharacters
+        ch if ch.is_control()
+            || (ch.is_whitespace() && ch != ' ')
+            || matches!(ch as u32, 0xE000..=0xF8FF | 0xFFF0..=0xFFFF | 0xF0000..) =>
+        {
+            match ch as u32 {
+                n @ 0..=0xff => format!("\\x{:02x}", n),
+                n @ 0x100..=0xffff => format!("\\u{:04x}", n),
+                n => format!("\\U{:08x}", n),
+            }
+        }
+        ch => ch.to_string(),
+    }
+}
+
+impl std::fmt::Display for Ccl<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut ranges = self.ranges();
+
+        if ranges == [('\0', char::MAX)] {
+            return write!(f, ".");
+        }
+
+        write!(f, "[")?;
+
+        // Render classes containing the last character negated, when this is shorter
+        if ranges.last().is_some_and(|(_, end)| *end == char::MAX) {
+            let negated = Ccl(&self.negate()).ranges();
+
+            if negated.len() <= ranges.len() {
+                write!(f, "^")?;
+                ranges = negated;
+            }
+        }
+
+        for (start, end) in ranges {
+            write!(f, "{}", render_char(start))?;
+
+            if end > start {
+                // Adjacent characters are rendered without a dash
+                if end as u32 > start as u32 + 1 {
+                    write!(f, "-")?;
+                }
+
+                write!(f, "{}", render_char(end))?;
+            }
+        }
+
+        write!(f, "]")
+    }
+}
@@ -133,6 +133,9 @@ impl Dict {
         }
     });
 
+    // Iterating a dict yields its items as (key, value) pairs.
+    tokay_method!("dict_iter : @dict", Self::dict_items(vec![dict], None));
+
     // Method to retrieve or iterate the keys of a dict.
     tokay_method!("dict_keys : @dict, index=void", {
         // If index is void, create an iterator on keys.
@@ -193,6 +196,40 @@ impl Dict {
         }
     });
 
+    // Method to retrieve or iterate the values of a dict.
+    tokay_method!("dict_values : @dict, index=void", {
+        // If index is void, create an iterator on values.
+        if index.is_void() {
+            return Ok(RefValue::from(MethodIter::new_method_iter(
+                dict.clone(),
+                "values",
+                None,
+                "iinc",
+            )));
+        }
+
+        // Otherwise, borrow
+        let dict = dict.borrow();
+        if let Some(dict) = dict.object::<Dict>() {
+            if let Some((_, value)) = dict.get_index(index.to_usize()?) {
+                Ok(value.clone())
+            } else {
+                Ok(value!(void))
+            }
+        } else {
+            Err(Error::from(format!(
+                "{} only accepts '{}' as parameter, not '{}'",
+                __function,
+                "dict",
+                dict.name()
+            )))
+        }
+    });
+
+    tokay_method!("dict_get : @dict, key, default=void", {
+        Self::dict_get_item(vec![dict, key, default], None)
+    });
+
     tokay_method!("dict_get_item : @dict, item, default=void", {
         if !item.is_hashable() {
             return Err(Error::from(format!(
@@ -202,7 +239,6 @@ impl Dict {
             )));
         }
 
-        // todo: alias dict_get
         let dict = dict.borrow();
 
         if let Some(dict) = dict.object::<Dict>() {
@@ -292,6 +328,10 @@ impl Dict {
         Ok(dict)
     });
 
+    tokay_method!("dict_update : @dict, other", {
+        Self::dict_merge(vec![dict, other], None)
+    });
+
     tokay_method!("dict_push : @dict, key, value", {
         let dict = &mut *dict.borrow_mut();
 
@@ -337,6 +377,31 @@ impl Dict {
             )))
         }
     });
+
+    tokay_method!("dict_delete : @dict, key", {
+        {
+            let dict = &mut *dict.borrow_mut();
+
+            if let Some(dict) = dict.object_mut::<Dict>() {
+                if dict.shift_remove(&key).is_none() {
+                    return Err(Error::from(format!(
+                        "{} key {} not found in dict",
+                        __function,
+                        key.repr()
+                    )));
+                }
+            } else {
+                return Err(Error::from(format!(
+                    "{} only accepts '{}' as parameter, not '{}'",
+                    __function,
+                    "dict",
+                    dict.name()
+                )));
+            }
+        }
+
+        Ok(dict)
+    });
 }
 
 // Implement PartialOrd and PartialEq on our own,
@@ -63,6 +63,11 @@ impl Iter {
     });
 
     tokay_method!("iter_next : @iter", {
+        // Iterating void yields nothing
+        if iter.is_void() {
+            return Ok(iter);
+        }
+
         let mut iter = iter.borrow_mut();
 
         if let Some(iter) = iter.object_mut::<Iter>() {
@@ -1,5 +1,6 @@
 //! List object
 use super::{BoxedObject, Iter, Object, RefValue};
+use crate::vm::Reject;
 use tokay_macros::tokay_method;
 extern crate self as tokay;
 
@@ -71,6 +72,11 @@ impl List {
                     .call_method("collect", context, Vec::new())?
                     .unwrap());
             }
+            // A range is collected from its iter
+            else if args[0].is("range") {
+                let iter = args[0].call_method("iter", None, Vec::new())?.unwrap();
+                return Ok(iter.call_method("collect", context, Vec::new())?.unwrap());
+            }
             // Otherwise, create a list with one item
             else {
                 List::from(args[0].clone())
@@ -310,15 +316,111 @@ impl List {
         }
     });
 
-    tokay_method!("list_sort : @list", {
+    tokay_method!("list_insert : @list, index, item", {
+        Self::list_push(vec![list, item, index], None)
+    });
+
+    tokay_method!("list_remove : @list, item", {
+        if !list.is("list") {
+            list = Self::list(vec![list], None)?;
+        }
+
+        {
+            let mut list = list.borrow_mut();
+            let list = list.object_mut::<List>().unwrap();
+
+            match list.iter().position(|value| *value == item) {
+                Some(index) => {
+                    list.remove(index);
+                }
+                None => {
+                    return Err(
+                        format!("{} item {} not found in list", __function, item.repr()).into(),
+                    );
+                }
+            }
+        }
+
+        Ok(list)
+    });
+
+    tokay_method!("list_contains : @list, item", {
+        let list = List::from(list);
+        Ok(RefValue::from(list.contains(&item)))
+    });
+
+    tokay_method!("list_index : @list, item", {
+        let list = List::from(list);
+
+        // Returns -1 when item is not in list, like str_find()
+        Ok(match list.iter().position(|value| *value == item) {
+            Some(index) => RefValue::from(index),
+            None => crate::value!(-1),
+        })
+    });
+
+    tokay_method!("list_join : @list, sep=void", {
+        let sep = if sep.is_void() {
+            crate::value!("")
+        } else {
+            sep
+        };
+
+        super::Str::str_join(vec![sep, list], None)
+    });
+
+    tokay_method!("list_reverse : @list", {
         if !list.is("list") {
-            return Ok(Self::list(vec![list], None)?);
+            return Self::list(vec![list], None);
         }
 
         {
+            let mut list = list.borrow_mut();
+            let list = list.object_mut::<List>().unwrap();
+            list.reverse();
+        }
+
+        Ok(list)
+    });
+
+    tokay_method!("list_sort : @list, key=void", {
+        if !list.is("list") {
+            return Self::list(vec![list], None);
+        }
+
+        if key.is_void() {
             let mut list = list.borrow_mut();
             let list = list.object_mut::<List>().unwrap();
             list.sort();
+        } else {
+            if !key.is_callable(false) {
+                return Err(format!(
+                    "{} 'key' must be a callable accepting arguments",
+                    __function
+                )
+                .into());
+            }
+
+            // Obtain the keys first, as the key callable may access the list
+            let items = list.borrow().object::<List>().unwrap().list.clone();
+            let mut context = context;
+            let mut keyed = Vec::with_capacity(items.len());
+
+            for item in items {
+                let sort_key = match key.call(context.as_deref_mut(), vec![item.clone()], None) {
+                    Ok(accept) => accept.into_refvalue(),
+                    Err(Reject::Error(err)) => return Err(*err),
+                    Err(_) => crate::value!(void),
+                };
+
+                keyed.push((sort_key, item));
+            }
+
+            keyed.sort_by(|a, b| a.0.cmp(&b.0));
+
+            let mut list = list.borrow_mut();
+            let list = list.object_mut::<List>().unwrap();
+            list.list = keyed.into_iter().map(|(_, item)| item).collect();
         }
 
         Ok(list)
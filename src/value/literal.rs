@@ -0,0 +1,343 @@
+//! Rendering and parsing of values in Tokay's literal syntax
+use super::{Dict, List, Object, RefValue, Str, Value};
+use crate::builtin::escape::{escape, unescape};
+use num_bigint::BigInt;
+use std::str::FromStr;
+
+impl RefValue {
+    /** Renders the value in Tokay's literal syntax, so that it can be read by Tokay again.
+
+    In contrast to `repr()`, floats always keep their type, like `1.0`, and strings are escaped
+    completely. Dicts keep the order of their items. When `indent` is given, the items of dicts
+    are rendered on separate lines, indented by that many spaces per level.
+
+    Values without a literal syntax, like parselets or infinite floats, are rejected. */
+    pub fn to_literal(&self, indent: Option<usize>) -> Result<String, String> {
+        let mut ret = String::new();
+        render(self, indent, 0, &mut ret)?;
+        Ok(ret)
+    }
+
+    /** Parses a value from Tokay's literal syntax, as it is rendered by `to_literal()`.
+
+    Only literals are accepted: dicts, lists, strings, integers, floats, `true`, `false`, `void`
+    and `null`. Whitespace, line-breaks and comments are allowed between the items. Anything else,
+    like operators, variables or calls, is rejected, so the source is never executed. */
+    pub fn from_literal(src: &str) -> Result<RefValue, String> {
+        let mut parser = LiteralParser { src, pos: 0 };
+
+        let value = parser.value()?;
+        parser.skip();
+
+        if parser.pos < src.len() {
+            return Err(parser.error("Expecting end of input"));
+        }
+
+        Ok(value)
+    }
+}
+
+// Recursive descent parser for the restricted literal syntax
+struct LiteralParser<'src> {
+    src: &'src str,
+    pos: usize,
+}
+
+impl<'src> LiteralParser<'src> {
+    fn rest(&self) -> &'src str {
+        &self.src[self.pos..]
+    }
+
+    fn error(&self, msg: &str) -> String {
+        let consumed = &self.src[..self.pos];
+        let row = consumed.matches('\n').count() + 1;
+        let col = consumed.rsplit('\n').next().unwrap().chars().count() + 1;
+
+        format!("{} at line {}, column {}", msg, row, col)
+    }
+
+    // Skips whitespace, line-breaks and comments
+    fn skip(&mut self) {
+        loop {
+            let rest = self.rest();
+            let trimmed = rest.trim_start_matches([' ', '\t', '\r', '\n']);
+
+            let trimmed = if let Some(comment) = trimmed.strip_prefix('#') {
+                comment.trim_start_matches(|ch| ch != '\n')
+            } else if let Some(escaped) = trimmed.strip_prefix('\\') {
+                escaped
+            } else if trimmed.len() == rest.len() {
+                break;
+            } else {
+                trimmed
+            };
+
+            self.pos += rest.len() - trimmed.len();
+        }
+    }
+
+    fn eat(&mut self, token: &str) -> bool {
+        self.skip();
+
+        if self.rest().starts_with(token) {
+            self.pos += token.len();
+            true
+        } else {
+            false
+        }
+    }
+
+    // Identifiers are keywords, or aliases used as dict keys
+    fn identifier(&mut self) -> Option<&'src str> {
+        let rest = self.rest();
+
+        if !rest.starts_with(|ch: char| ch.is_ascii_alphabetic() || ch == '_') {
+            return None;
+        }
+
+        let len = rest
+            .find(|ch: char| !ch.is_ascii_alphanumeric() && ch != '_')
+            .unwrap_or(rest.len());
+
+        self.pos += len;
+        Some(&self.src[self.pos - len..self.pos])
+    }
+
+    fn number(&mut self) -> Result<RefValue, String> {
+        let rest = self.rest();
+        let digits = |s: &str| s.find(|ch: char| !ch.is_ascii_digit()).unwrap_or(s.len());
+
+        let mut len = usize::from(rest.starts_with('-'));
+        len += digits(&rest[len..]);
+
+        let is_float = rest[len..].starts_with('.');
+
+        if is_float {
+            len += 1 + digits(&rest[len + 1..]);
+        }
+
+        let number = &rest[..len];
+
+        if !number.contains(|ch: char| ch.is_ascii_digit()) {
+            return Err(self.error("Expecting a number"));
+        }
+
+        self.pos += len;
+
+        if is_float {
+            Ok(RefValue::from(f64::from_str(number).unwrap()))
+        } else {
+            Ok(RefValue::from(BigInt::from_str(number).unwrap()))
+        }
+    }
+
+    fn string(&mut self) -> Result<RefValue, String> {
+        let rest = &self.rest()[1..];
+        let mut escaped = false;
+
+        for (i, ch) in rest.char_indices() {
+            match ch {
+                '\\' if !escaped => escaped = true,
+                '"' if !escaped => {
+                    self.pos += i + 2;
+                    return Ok(RefValue::from(unescape(&rest[..i])));
+                }
+                _ => escaped = false,
+            }
+        }
+
+        Err(self.error("Unclosed string, expecting '\"'"))
+    }
+
+    // Parses a value, or a dict key which may be an alias; Aliases are reported by the flag
+    fn item(&mut self, key: bool) -> Result<(RefValue, bool), String> {
+        self.skip();
+
+        let start = self.pos;
+
+        if let Some(ident) = self.identifier() {
+            return match ident {
+                "true" => Ok((RefValue::from(true), false)),
+                "false" => Ok((RefValue::from(false), false)),
+                "void" => Ok((RefValue::from(Value::Void), false)),
+                "null" => Ok((RefValue::from(Value::Null), false)),
+                ident if key => Ok((RefValue::from(ident), true)),
+                ident => {
+                    let msg = format!("'{}' is not a literal", ident);
+                    self.pos = start;
+                    Err(self.error(&msg))
+                }
+            };
+        }
+
+        let value = match self.rest().chars().next() {
+            Some('(') => self.sequence(),
+            Some('"') => self.string(),
+            Some(ch) if ch == '-' || ch == '.' || ch.is_ascii_digit() => self.number(),
+            Some(_) => Err(self.error("Expecting a literal")),
+            None => Err(self.error("Expecting a literal, but input ended")),
+        };
+
+        value.map(|value| (value, false))
+    }
+
+    fn value(&mut self) -> Result<RefValue, String> {
+        self.item(false).map(|(value, _)| value)
+    }
+
+    // Parses a dict, a list or a value in brackets
+    fn sequence(&mut self) -> Result<RefValue, String> {
+        self.pos += 1;
+
+        // Empty dict and list
+        if self.eat(")") {
+            return Ok(RefValue::from(Dict::new()));
+        } else if self.eat(",") {
+            return if self.eat(")") {
+                Ok(RefValue::from(List::new()))
+            } else {
+                Err(self.error("Expecting ')'"))
+            };
+        }
+
+        let (first, alias) = self.item(true)?;
+
+        // Dict
+        if self.eat("=>") {
+            let mut dict = Dict::new();
+            dict.insert(first, self.value()?);
+
+            loop {
+                self.eat(",");
+
+                if self.eat(")") {
+                    return Ok(RefValue::from(dict));
+                }
+
+                let (key, _) = self.item(true)?;
+
+                if !self.eat("=>") {
+                    return Err(self.error("Expecting '=>'"));
+                }
+
+                dict.insert(key, self.value()?);
+            }
+        }
+
+        // An alias can only be used as key in dicts
+        if alias {
+            return Err(self.error("Expecting '=>'"));
+        }
+
+        // Value in brackets
+        if self.eat(")") {
+            return Ok(first);
+        }
+
+        // List
+        let mut list = vec![first];
+
+        while self.eat(",") {
+            if self.eat(")") {
+                return Ok(RefValue::from(list));
+            }
+
+            list.push(self.value()?);
+        }
+
+        if self.eat(")") {
+            Ok(RefValue::from(list))
+        } else {
+            Err(self.error("Expecting ',' or ')'"))
+        }
+    }
+}
+
+// Renders a dict key, which is written as an alias when possible
+fn render_key(key: &RefValue, ret: &mut String) -> Result<(), String> {
+    if let Some(key) = key.borrow().object::<Str>() {
+        if key.starts_with(|ch: char| ch.is_ascii_alphabetic() || ch == '_')
+            && key
+                .chars()
+                .all(|ch| ch.is_ascii_alphanumeric() || ch == '_')
+            && !crate::compiler::RESERVED_KEYWORDS.contains(&key.as_str())
+            && !crate::compiler::RESERVED_TOKENS.contains(&key.as_str())
+        {
+            ret.push_str(key.as_str());
+            return Ok(());
+        }
+    }
+
+    render(key, None, 0, ret)
+}
+
+fn render(
+    value: &RefValue,
+    indent: Option<usize>,
+    level: usize,
+    ret: &mut String,
+) -> Result<(), String> {
+    let value = value.borrow();
+
+    match &*value {
+        Value::Float(f) => {
+            if !f.is_finite() {
+                return Err(format!("float {} has no literal syntax", f));
+            }
+
+            let f = f.to_string();
+            ret.push_str(&f);
+
+            if !f.contains('.') {
+                ret.push_str(".0");
+            }
+        }
+        Value::Object(object) => {
+            if let Some(s) = object.as_any().downcast_ref::<Str>() {
+                ret.push('"');
+                ret.push_str(&escape(s.as_str(), "tokay")?);
+                ret.push('"');
+            } else if let Some(list) = object.as_any().downcast_ref::<List>() {
+                ret.push('(');
+
+                for (i, item) in list.iter().enumerate() {
+                    if i > 0 {
+                        ret.push_str(", ");
+                    }
+
+                    render(item, indent, level, ret)?;
+                }
+
+                // Lists with less than two items require a trailing comma
+                ret.push_str(if list.len() < 2 { ", )" } else { ")" });
+            } else if let Some(dict) = object.as_any().downcast_ref::<Dict>() {
+                ret.push('(');
+
+                for (i, (key, value)) in dict.iter().enumerate() {
+                    if let Some(indent) = indent {
+                        ret.push('\n');
+                        ret.push_str(&" ".repeat(indent * (level + 1)));
+                    } else if i > 0 {
+                        ret.push(' ');
+                    }
+
+                    render_key(key, ret)?;
+                    ret.push_str(" => ");
+                    render(value, indent, level + 1, ret)?;
+                }
+
+                if let (Some(indent), false) = (indent, dict.is_empty()) {
+                    ret.push('\n');
+                    ret.push_str(&" ".repeat(indent * level));
+                }
+
+                ret.push(')');
+            } else {
+                return Err(format!("{} has no literal syntax", object.name()));
+            }
+        }
+        value => ret.push_str(&value.repr()),
+    }
+
+    Ok(())
+}
@@ -1,23 +1,31 @@
 //! Tokay value and object representation
+pub mod bytes;
+mod ccl;
 pub mod dict;
 pub mod iter;
 pub mod list;
+mod literal;
 mod method;
 mod object;
 mod parselet;
 mod refvalue;
+mod serialize;
 pub mod str;
+pub mod time;
 pub mod token;
 pub mod value;
 
 pub use self::str::Str;
+pub use bytes::Bytes;
+pub use ccl::Ccl;
 pub use dict::Dict;
 pub use iter::*;
 pub use list::List;
 pub use method::Method;
-pub use object::{BoxedObject, Object};
+pub use object::{BoxedObject, ConversionError, Object};
 pub(crate) use parselet::{Parselet, ParseletRef};
-pub use refvalue::RefValue;
+pub use refvalue::{Lossy, Overflow, RefValue};
+pub use time::Time;
 pub use token::Token;
 pub use value::Value;
 
@@ -3,6 +3,60 @@ use crate::{Accept, Context, RefValue, Reject};
 use num_bigint::BigInt;
 use std::any::Any;
 
+// ConversionError
+// ----------------------------------------------------------------------------
+
+/// Error of a value that cannot be converted into a target type.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConversionError {
+    pub name: &'static str,     // Type name of the value
+    pub target: &'static str,   // Target type of the conversion
+    pub reason: Option<String>, // Optional reason why the conversion failed
+}
+
+impl ConversionError {
+    pub fn new(name: &'static str, target: &'static str) -> Self {
+        Self {
+            name,
+            target,
+            reason: None,
+        }
+    }
+
+    /// Conversion of a value which is out of the target's range
+    pub fn out_of_range(name: &'static str, target: &'static str, value: String) -> Self {
+        Self {
+            name,
+            target,
+            reason: Some(format!("{} is out of range", value)),
+        }
+    }
+}
+
+impl std::fmt::Display for ConversionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "`{}` cannot be converted to {}", self.name, self.target)?;
+
+        if let Some(reason) = &self.reason {
+            write!(f, ", {}", reason)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl From<ConversionError> for String {
+    fn from(error: ConversionError) -> Self {
+        error.to_string()
+    }
+}
+
+impl From<ConversionError> for crate::Error {
+    fn from(error: ConversionError) -> Self {
+        error.to_string().into()
+    }
+}
+
 // BoxedObject
 // ----------------------------------------------------------------------------
 
@@ -158,24 +212,30 @@ pub trait Object:
         false // by default, every object is never void
     }
 
-    /// Object as bool
+    /** Object as bool.
+
+    This is Tokay's truthiness protocol. Conditions of `if` and loops, the operators `!`, `&&`
+    and `||` as well as `bool()` all evaluate values by this method, so they always agree.
+
+    `void`, `null`, `false`, the int `0` and the float `0.0` are false, as well as the empty str,
+    list and dict. Any other value is true, unless its object implements this method otherwise. */
     fn is_true(&self) -> bool {
         true // by default, every object is boolean true
     }
 
     /// Object as i64
-    fn to_i64(&self) -> Result<i64, String> {
-        Err(format!("`{}` cannot be converted to i64", self.name()))
+    fn to_i64(&self) -> Result<i64, ConversionError> {
+        Err(ConversionError::new(self.name(), "i64"))
     }
 
     /// Object as f64
-    fn to_f64(&self) -> Result<f64, String> {
-        Err(format!("`{}` cannot be converted to f64", self.name()))
+    fn to_f64(&self) -> Result<f64, ConversionError> {
+        Err(ConversionError::new(self.name(), "f64"))
     }
 
     /// Object as usize
-    fn to_usize(&self) -> Result<usize, String> {
-        Err(format!("`{}` cannot be converted to usize", self.name()))
+    fn to_usize(&self) -> Result<usize, ConversionError> {
+        Err(ConversionError::new(self.name(), "usize"))
     }
 
     /// Object as String
@@ -184,8 +244,8 @@ pub trait Object:
     }
 
     /// Object as BigInt
-    fn to_bigint(&self) -> Result<BigInt, String> {
-        Err(format!("`{}` cannot be converted to BigInt", self.name()))
+    fn to_bigint(&self) -> Result<BigInt, ConversionError> {
+        Err(ConversionError::new(self.name(), "BigInt"))
     }
 
     /// Check whether the object is callable.
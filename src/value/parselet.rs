@@ -5,9 +5,19 @@ use std::rc::Rc;
 
 use super::{BoxedObject, Dict, Object, RefValue};
 
-use crate::error::Error;
+use crate::error::{Error, TraceFrame};
+use crate::reader::Offset;
 use crate::vm::*;
 
+/// Native stack which must remain for calling a parselet, otherwise a new stack segment is used
+const STACK_RED_ZONE: usize = 64 * 1024;
+
+/// Size of a native stack segment used by deeply nested parselet calls
+const STACK_SEGMENT_SIZE: usize = 1024 * 1024;
+
+/// Maximum number of native stack segments used by a thread, before nested calls fail
+const STACK_SEGMENTS: usize = 64;
+
 /** Parselet is the conceptual building block of a Tokay program.
 
 A parselet is like a function in ordinary programming languages, with the
@@ -19,20 +29,55 @@ and consuming builtins.
 
 Parselets support static program constructs being left-recursive, and extend
 the generated parse tree automatically until no more input can be consumed.
+
+Left-recursive parselets are detected by the compiler, either directly like
+`Expr : @{ Expr '+' Term ; Term }`, or indirectly across multiple parselets calling
+each other at the same input position. At runtime, such a parselet first inserts a
+failing memo entry as its seed, and then repeatedly re-runs its body, where every
+recursive call reads the previously memoized result, until the result doesn't grow
+anymore. Results of other parselets involved into the recursion are forgotten after
+every iteration, so they are re-evaluated with the grown seed.
+
+A call of another parselet in tail position, whose result would only be collected with the
+captures taken before, is run after the context of the calling parselet was released. Such calls
+are run in a loop by the outermost caller, so the stack doesn't grow on deeply nested calls, even
+on right-recursive parselets like `L : @{ 'b' ; 'a' L }`.
+
+By default, results of all consuming parselets are memoized. When a program flags any of its
+parselets with the `cache` keyword, only results of flagged and left-recursive parselets are
+memoized, to save memory. When profiling, parselets which are frequently re-entered at the same
+input position are reported as candidates for the `cache` keyword.
 */
 
 #[derive(Debug)]
 pub struct Parselet {
     pub name: String,                   // Parselet's name from source (for debugging)
     pub(crate) consuming: Option<bool>, // Indicator for consuming & left-recursion
+    pub(crate) memo: bool,              // Memoize results of a consuming parselet
+    pub(crate) nullable: bool,          // Body of a consuming parselet matches empty input
     pub(crate) severity: u8,            // Capture push severity
-    signature: Vec<(String, Option<usize>)>, // Argument signature with default arguments
+    pub(crate) signature: Vec<(String, Option<usize>)>, // Argument signature with default arguments
     pub(crate) locals: usize,           // Number of local variables present
     pub(crate) begin: Vec<Op>,          // Begin-operations
     pub(crate) end: Vec<Op>,            // End-operations
     pub(crate) body: Vec<Op>,           // Operations
 }
 
+/// A parselet which left a call in tail position to its caller, and awaits its result
+struct TailCaller {
+    id: usize,                   // Parselet id
+    name: String,                // Parselet's name
+    severity: u8,                // Capture push severity of the parselet
+    memo: bool,                  // Memoize the result
+    reader_start: Offset,        // Reader offset where the parselet started
+    source: Option<Offset>,      // Position of the call in the program source
+    side_effects: usize,         // Side effects counter when the parselet started
+    reads: usize,                // Reads of growing seeds when the parselet started
+    emit_start: usize,           // Values emitted before the parselet started
+    mark: Option<usize>,         // Mark of the parselet's span, when recorded
+    captures: Vec<Vec<Capture>>, // Captures the result is collected with, innermost first
+}
+
 impl Parselet {
     /// Creates a new parselet.
     pub(crate) fn new(
@@ -53,6 +98,8 @@ impl Parselet {
         let mut ret = Self {
             name: name.unwrap_or(String::new()),
             consuming,
+            memo: true,
+            nullable: false,
             severity,
             signature,
             locals,
@@ -73,16 +120,135 @@ impl Parselet {
     The main-parameter defines if the parselet behaves like a main loop or
     like subsequent parselet. */
     pub fn run(
+        &self,
+        thread: &mut Thread,
+        args: Vec<Capture>,
+        nargs: Option<Dict>,
+        main: bool,
+        depth: usize,
+    ) -> Result<Accept, Reject> {
+        // Continue on a new native stack segment when deep recursion runs short of stack, and fail
+        // when all segments are used, instead of overflowing the stack and aborting the process
+        if stacker::remaining_stack().is_some_and(|remaining| remaining < STACK_RED_ZONE) {
+            if thread.stack_segments >= STACK_SEGMENTS {
+                return Err(Thread::limit_exceeded(format!(
+                    "{}() exceeds the native stack at a depth of {} parselet calls",
+                    self.name, depth
+                )));
+            }
+
+            thread.stack_segments += 1;
+            let result = stacker::grow(STACK_SEGMENT_SIZE, || {
+                self.run(thread, args, nargs, main, depth)
+            });
+            thread.stack_segments -= 1;
+
+            return result;
+        }
+
+        let mut callers = Vec::new();
+        let mut result = self.run_once(thread, args, nargs, main, depth, &mut callers);
+
+        // Run parselets called in tail position within this loop, instead of nesting their calls
+        while let Some(target) = thread.tail_call.take() {
+            let parselet = target.borrow().object::<ParseletRef>().unwrap().0.clone();
+            let depth = depth + callers.len();
+            result =
+                parselet
+                    .borrow()
+                    .run_once(thread, Vec::new(), None, false, depth, &mut callers);
+        }
+
+        // Pass the result back through the callers, which left their call in tail position
+        while let Some(caller) = callers.pop() {
+            match &mut result {
+                // Collect the result with the captures the caller took before its call
+                Ok(Accept::Push(capture)) => {
+                    for mut captures in caller.captures {
+                        if !matches!(capture, Capture::Empty) {
+                            captures.push(std::mem::replace(capture, Capture::Empty));
+                        }
+
+                        *capture = Context::collect_values(
+                            captures,
+                            caller.severity,
+                            thread.reader,
+                            true,
+                            false,
+                        );
+                    }
+                }
+
+                // Rewind input consumed and values emitted by the caller before its call
+                Err(Reject::Next) => {
+                    thread.reader.reset(caller.reader_start);
+                    thread.emits.truncate(caller.emit_start);
+                }
+
+                // The caller is still part of the stack trace
+                Err(Reject::Error(err)) => err.trace.push(TraceFrame {
+                    parselet: caller.name.clone(),
+                    source: caller.source,
+                    reader: caller.reader_start,
+                }),
+
+                _ => {}
+            }
+
+            result = result.map(|accept| accept.into_push(caller.severity));
+
+            if thread.side_effects == caller.side_effects && caller.memo {
+                let reader_end = thread.reader.tell();
+                thread.memoize(
+                    caller.reader_start.offset,
+                    caller.id,
+                    reader_end,
+                    result.clone(),
+                    caller.reads,
+                );
+            }
+
+            Self::leave_spans(
+                thread,
+                caller.mark,
+                &caller.name,
+                caller.reader_start,
+                caller.id,
+                &result,
+            );
+        }
+
+        result
+    }
+
+    /** Run parselet once, without running a parselet it called in tail position.
+
+    Such a call is left in `Thread::tail_call`, and the parselet is added to callers, which then
+    await its result. */
+    fn run_once(
         &self,
         thread: &mut Thread,
         mut args: Vec<Capture>,
         mut nargs: Option<Dict>,
         main: bool,
         depth: usize,
+        callers: &mut Vec<TailCaller>,
     ) -> Result<Accept, Reject> {
         // Get unique parselet id from memory address
         let id = self as *const Parselet as usize;
 
+        thread.check_cancel()?;
+        thread.check_values()?;
+
+        if let Some(max) = thread.options.max_depth {
+            if depth > max {
+                return Err(Thread::limit_exceeded(format!(
+                    "{}() exceeds a depth of {} parselet calls",
+                    self.name, max
+                )));
+            }
+        }
+
         // When parselet is consuming, try to read previous result from cache.
         if self.consuming.is_some() {
             let reader_start = thread.reader.tell();
@@ -91,8 +257,17 @@ impl Parselet {
             // fixme: This doesn't recognize calls to the same parselet with same parameters,
             //        which might lead in unwanted results. This must be checked! It might become
             //        a problem when the Repeat<P>(min=0, max=void) generic parselet becomes available.
-            if let Some((reader_end, result)) = thread.memo.get(&(reader_start.offset, id)) {
+            if let Some((reader_end, result)) = thread.memo.get(reader_start.offset, id) {
                 thread.reader.reset(*reader_end);
+
+                for observer in thread.observers.iter_mut() {
+                    observer.memo(&self.name, &reader_start, reader_end, result, thread.reader);
+                }
+
+                if let (Some(spans), Ok(_)) = (&mut thread.spans, result) {
+                    spans.replay((reader_start.offset, id));
+                }
+
                 return result.clone();
             }
         }
@@ -101,12 +276,34 @@ impl Parselet {
             assert!(self.signature.is_empty());
         }
 
+        // Variable arguments are collected into a list by a *args-parameter,
+        // and remaining named arguments into a dict by a **nargs-parameter.
+        let varargs = self
+            .signature
+            .iter()
+            .position(|(name, _)| name.starts_with('*') && !name.starts_with("**"));
+        let varnargs = self
+            .signature
+            .iter()
+            .position(|(name, _)| name.starts_with("**"));
+
+        let rest = match varargs {
+            Some(index) if args.len() > index => Some(
+                args.drain(index..)
+                    .map(|mut capture| capture.extract(thread.reader))
+                    .collect::<Vec<_>>(),
+            ),
+            _ => None,
+        };
+
         let args_len = args.len();
 
+        // Parameters which can be provided by position, a **nargs-parameter is always the last one
+        let positional = varnargs.unwrap_or(self.signature.len());
+
         // Check for provided argument count bounds first
-        // todo: Not executed when *args-catchall is implemented
-        if args_len > self.signature.len() {
-            return Err(match self.signature.len() {
+        if varargs.is_none() && args_len > positional {
+            return Err(match positional {
                 0 => format!(
                     "{}() doesn't accept any arguments ({} given)",
                     self.name, args_len
@@ -117,13 +314,10 @@ impl Parselet {
                 ),
                 _ => format!(
                     "{}() expected at most {} arguments ({} given)",
-                    self.name,
-                    self.signature.len(),
-                    args_len
+                    self.name, positional, args_len
                 ),
             }
-            .into())
-            .into();
+            .into());
         }
 
         if main {
@@ -136,8 +330,17 @@ impl Parselet {
             args.resize(self.locals, Capture::Empty);
         }
 
+        if let Some(index) = varargs {
+            args[index] = Capture::Value(RefValue::from(rest.unwrap_or_default()), None, 0);
+        }
+
         // Set remaining parameters to their defaults
         for (i, arg) in (&self.signature[args_len..]).iter().enumerate() {
+            // Variable arguments are already set, or set below
+            if arg.0.starts_with('*') {
+                continue;
+            }
+
             // args parameters are previously pushed onto the stack.
             let var = &mut args[args_len + i];
 
@@ -168,8 +371,9 @@ impl Parselet {
         }
 
         // Check for remaining nargs
-        // todo: Not executed when **nargs-catchall is implemented
-        if let Some(mut nargs) = nargs {
+        if let Some(index) = varnargs {
+            args[index] = Capture::Value(RefValue::from(nargs.unwrap_or_else(Dict::new)), None, 0);
+        } else if let Some(mut nargs) = nargs {
             if let Some((name, _)) = nargs.pop() {
                 return Err(match nargs.len() {
                     0 => format!(
@@ -188,12 +392,41 @@ impl Parselet {
             }
         }
 
+        // Remember side effects counter, to not memoize results of parselets causing side effects
+        let side_effects = thread.side_effects;
+
+        // Remember reads of growing seeds, to forget results depending on them when they grow
+        let reads = thread.memo.reads();
+
         // Create a new conrext
         let mut context = Context::new(thread, self, depth, args);
 
+        if context.thread.options.explain || context.thread.options.post_mortem {
+            context.thread.parselets.push(self.name.clone());
+        }
+
         //println!("remaining {:?}", nargs);
         let reader_start = context.frame0().reader_start;
 
+        if let Some(profiler) = &mut context.thread.profiler {
+            profiler.enter(&self.name, reader_start.offset);
+        }
+
+        for observer in context.thread.observers.iter_mut() {
+            observer.enter(&self.name, &reader_start);
+        }
+
+        if let Some(mut debugger) = context.thread.debugger.take() {
+            debugger.enter(&context);
+            context.thread.debugger = Some(debugger);
+        }
+
+        let mark = context
+            .thread
+            .spans
+            .as_mut()
+            .map(|spans| spans.enter(&self.name));
+
         // Perform left-recursive execution
         let result = if let Some(true) = self.consuming {
             /*
@@ -211,15 +444,21 @@ impl Parselet {
             // Insert a fake memo entry to avoid endless recursion
             context
                 .thread
-                .memo
-                .insert((reader_start.offset, id), (reader_end, result.clone()));
+                .memoize(reader_start.offset, id, reader_end, result.clone(), reads);
+
+            // Track parselets memoized depending on the seed, after the fake entry above
+            let grow = context.thread.memo.grow(reader_start.offset, id);
 
             loop {
                 let loop_result = context.run(main);
 
+                // Forget results of parselets called in between, which depend on the current seed.
+                // This is required for indirect left recursion, where another parselet calls this one.
+                context.thread.memo.forget(reader_start.offset, grow);
+
                 match loop_result {
                     // Hard reject
-                    Err(Reject::Main) | Err(Reject::Error(_)) => {
+                    Err(Reject::Main) | Err(Reject::Error(_)) | Err(Reject::Exit(_)) => {
                         result = loop_result;
                         break;
                     }
@@ -243,8 +482,17 @@ impl Parselet {
                 // Save intermediate result in memo table
                 context
                     .thread
-                    .memo
-                    .insert((reader_start.offset, id), (reader_end, result.clone()));
+                    .memoize(reader_start.offset, id, reader_end, result.clone(), reads);
+
+                if let (Some(spans), Some(mark)) = (&mut context.thread.spans, mark) {
+                    spans.grow(
+                        (reader_start.offset, id),
+                        mark,
+                        &self.name,
+                        reader_start.offset,
+                        reader_end.offset,
+                    );
+                }
 
                 // Reset reader & stack
                 context.thread.reader.reset(reader_start);
@@ -254,17 +502,32 @@ impl Parselet {
                     .resize(context.frame0().capture_start, Capture::Empty);
             }
 
+            context.thread.memo.grown(reads);
             context.thread.reader.reset(reader_end);
 
+            if let (Some(spans), Some(mark)) = (&mut context.thread.spans, mark) {
+                spans.restore((reader_start.offset, id), mark, &self.name);
+            }
+
+            // Drop memoized result in case of side effects
+            if context.thread.side_effects != side_effects {
+                context.thread.memo.remove(reader_start.offset, id);
+            }
+
             result
         } else {
             let result = context.run(main);
 
-            if self.consuming.is_some() {
-                context.thread.memo.insert(
-                    (reader_start.offset, id),
-                    (context.thread.reader.tell(), result.clone()),
-                );
+            // The result of a tail call is memoized after the call was run below
+            if context.thread.tail_call.is_none()
+                && context.thread.side_effects == side_effects
+                && self.memoizes()
+            {
+                let reader_end = context.thread.reader.tell();
+
+                context
+                    .thread
+                    .memoize(reader_start.offset, id, reader_end, result.clone(), reads);
             }
 
             result
@@ -292,8 +555,98 @@ impl Parselet {
         }
         */
 
+        if context.thread.options.explain || context.thread.options.post_mortem {
+            context.thread.parselets.pop();
+        }
+
+        if let Some(profiler) = &mut context.thread.profiler {
+            profiler.leave(
+                result.is_ok(),
+                context
+                    .thread
+                    .reader
+                    .tell()
+                    .offset
+                    .saturating_sub(reader_start.offset),
+            );
+        }
+
+        if !context.thread.observers.is_empty() {
+            let thread = &mut *context.thread;
+            let reader_end = thread.reader.tell();
+
+            for observer in thread.observers.iter_mut() {
+                observer.leave(
+                    &self.name,
+                    &reader_start,
+                    &reader_end,
+                    &result,
+                    thread.reader,
+                );
+            }
+        }
+
+        if let Some(debugger) = &mut context.thread.debugger {
+            debugger.leave(&self.name, &result, context.thread.reader);
+        }
+
+        let emit_start = context.frame0().emit_start;
+        let source = context.source_offset;
+
+        // Release the context; a parselet called in tail position is run by the outermost caller
+        drop(context);
+
+        if thread.tail_call.is_some() {
+            callers.push(TailCaller {
+                id,
+                name: self.name.clone(),
+                severity: self.severity,
+                memo: self.memoizes(),
+                reader_start,
+                source,
+                side_effects,
+                reads,
+                emit_start,
+                mark,
+                captures: std::mem::take(&mut thread.tail_captures),
+            });
+        } else {
+            Self::leave_spans(thread, mark, &self.name, reader_start, id, &result);
+        }
+
         result
     }
+
+    // Records the span of a parselet which was left
+    fn leave_spans(
+        thread: &mut Thread,
+        mark: Option<usize>,
+        name: &str,
+        reader_start: Offset,
+        id: usize,
+        result: &Result<Accept, Reject>,
+    ) {
+        if let (Some(spans), Some(mark)) = (&mut thread.spans, mark) {
+            let key = (reader_start.offset, id);
+
+            spans.leave(
+                mark,
+                name,
+                reader_start.offset,
+                thread.reader.tell().offset,
+                result.is_ok(),
+            );
+
+            if result.is_ok() && thread.memo.contains(key.0, key.1) {
+                spans.memoize(key, mark);
+            }
+        }
+    }
+
+    // Checks if results of the parselet are memoized
+    fn memoizes(&self) -> bool {
+        self.consuming.is_some() && self.memo
+    }
 }
 
 impl From<Parselet> for RefValue {
@@ -322,7 +675,11 @@ impl Object for ParseletRef {
         let parselet = self.0.borrow();
 
         if without_arguments {
-            parselet.signature.len() == 0 || parselet.signature.iter().all(|arg| arg.1.is_some())
+            parselet.signature.is_empty()
+                || parselet
+                    .signature
+                    .iter()
+                    .all(|arg| arg.1.is_some() || arg.0.starts_with('*'))
         } else {
             true
         }
@@ -1,13 +1,54 @@
-use super::{BoxedObject, Dict, Method, Object, Str, Token, Value};
+use super::{BoxedObject, Bytes, ConversionError, Dict, Method, Object, Str, Time, Token, Value};
 use crate::builtin::{Builtin, BuiltinRef};
 use crate::value;
 use crate::{Accept, Context, Error, Reject};
-use num::{ToPrimitive, Zero};
+use num::{Signed, ToPrimitive, Zero};
 use num_bigint::BigInt;
-use std::cell::RefCell;
+use std::cell::{Cell, RefCell};
 use std::hash::{Hash, Hasher};
 use std::rc::Rc;
 
+/// Behavior of integer operations with results exceeding the range of 64-bit integers
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum Overflow {
+    #[default]
+    Promote, // Promote the result to an arbitrary precision integer
+    Error,    // Fail with an overflow error
+    Wrap,     // Wrap around the result in two's complement
+    Saturate, // Clamp the result to the smallest or largest 64-bit integer
+}
+
+impl Overflow {
+    /// Applies the overflow behavior to the result of an integer operation.
+    pub fn apply(self, int: BigInt) -> Result<BigInt, String> {
+        if self == Self::Promote || int.to_i64().is_some() {
+            return Ok(int);
+        }
+
+        match self {
+            Self::Promote => unreachable!(),
+            Self::Error => Err(format!("Integer overflow, {} exceeds 64 bits", int)),
+            Self::Wrap => Ok(BigInt::from(
+                (int & BigInt::from(u64::MAX)).to_u64().unwrap() as i64,
+            )),
+            Self::Saturate => Ok(BigInt::from(if int.is_negative() {
+                i64::MIN
+            } else {
+                i64::MAX
+            })),
+        }
+    }
+}
+
+/// Behavior of implicit conversions losing information, like "12abc" to 12 or 1.5 to 1
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum Lossy {
+    #[default]
+    Ignore, // Convert silently
+    Warn,  // Convert, but report a warning
+    Error, // Fail with a conversion error
+}
+
 #[derive(Clone, PartialEq, PartialOrd, Eq, Ord)]
 pub struct RefValue {
     value: Rc<RefCell<Value>>,
@@ -51,7 +92,57 @@ impl RefValue {
         builtin.call(context, args)
     }
 
+    /// Describes the information lost by converting this value into an int or float `target`.
+    pub fn lossy(&self, target: &str) -> Option<String> {
+        let lossy = match (&*self.borrow(), target) {
+            (Value::Float(float), "int") => float.is_finite() && float.fract() != 0.0,
+            (Value::Object(object), "int") if object.name() == "str" => {
+                object.to_string().trim().parse::<BigInt>().is_err()
+            }
+            (Value::Object(object), "float") if object.name() == "str" => {
+                object.to_string().parse::<f64>().is_err()
+            }
+            _ => false,
+        };
+
+        if !lossy {
+            return None;
+        }
+
+        let converted = match target {
+            "int" => self.to_bigint().ok()?.to_string(),
+            _ => value!(self.to_f64().ok()?).repr(),
+        };
+
+        Some(format!(
+            "Lossy conversion of {} to {} {}",
+            self.repr(),
+            target,
+            converted
+        ))
+    }
+
+    /// Describes the information lost by implicitly converting the operands of a binary operation.
+    pub fn lossy_operands(&self, operand: &RefValue, op: &str) -> Option<String> {
+        match op {
+            // Integer division truncates floats before dividing
+            "divi" => self.lossy("int").or_else(|| operand.lossy("int")),
+
+            // Inline operations on an int convert the operand to int
+            "iadd" | "isub" | "imul" if matches!(&*self.borrow(), Value::Int(_)) => {
+                operand.lossy("int")
+            }
+
+            _ => None,
+        }
+    }
+
     pub fn unary_op(self, op: &str) -> Result<RefValue, String> {
+        self.unary_op_overflow(op, Overflow::Promote)
+    }
+
+    /// Performs a unary operation, with integer results exceeding 64 bits handled by `overflow`.
+    pub fn unary_op_overflow(self, op: &str, overflow: Overflow) -> Result<RefValue, String> {
         let name = {
             let this = &mut *self.borrow_mut();
 
@@ -76,20 +167,28 @@ impl RefValue {
                 Value::Int(int) => {
                     // int fast lane neg, iinc, idec
                     match op {
-                        "neg" => return Ok(value!(-int.clone())),
+                        "neg" => return Ok(value!(overflow.apply(-int.clone())?)),
                         "not" => return Ok(value!(int.is_zero())),
                         "iinc" => {
-                            *int += 1;
+                            *int = overflow.apply(std::mem::take(int) + 1)?;
                             return Ok(self.clone());
                         }
                         "idec" => {
-                            *int -= 1;
+                            *int = overflow.apply(std::mem::take(int) - 1)?;
                             return Ok(self.clone());
                         }
                         _ => "int",
                     }
                 }
-                _ => "int", // fallback for any other type (void, null, bool)
+                Value::Bool(b) => {
+                    // bool fast lane not, neg
+                    match op {
+                        "not" => return Ok(value!(!*b)),
+                        "neg" => return Ok(value!(-(*b as i64))),
+                        _ => "bool",
+                    }
+                }
+                _ => "int", // fallback for any other type (void, null)
             }
         };
 
@@ -99,15 +198,29 @@ impl RefValue {
                 // default fallback for not
                 "not" => Ok(value!(!self.is_true())),
                 // default fallback for inline inc is an inline add by 1
-                "iinc" if name == "int" => Ok(self.binary_op(value!(1 as i64), "iadd")?),
+                "iinc" if name == "int" || name == "bool" => {
+                    Ok(self.binary_op_overflow(value!(1i64), "iadd", overflow)?)
+                }
                 // default fallback for inline dec is an inline sub by 1
-                "idec" if name == "int" => Ok(self.binary_op(value!(1 as i64), "isub")?),
+                "idec" if name == "int" || name == "bool" => {
+                    Ok(self.binary_op_overflow(value!(1i64), "isub", overflow)?)
+                }
                 _ => Err(notfound),
             },
         }
     }
 
     pub fn binary_op(self, operand: RefValue, op: &str) -> Result<RefValue, String> {
+        self.binary_op_overflow(operand, op, Overflow::Promote)
+    }
+
+    /// Performs a binary operation, with integer results exceeding 64 bits handled by `overflow`.
+    pub fn binary_op_overflow(
+        self,
+        operand: RefValue,
+        op: &str,
+        overflow: Overflow,
+    ) -> Result<RefValue, String> {
         let name = {
             // Operations starting with "i" are inline
             if op.starts_with("i") {
@@ -146,15 +259,15 @@ impl RefValue {
                         // Int inline fast-lane
                         (Value::Int(int), _) => match op {
                             "iadd" => {
-                                *int += that.to_i64()?;
+                                *int = overflow.apply(std::mem::take(int) + that.to_bigint()?)?;
                                 return Ok(self.clone());
                             }
                             "imul" => {
-                                *int *= that.to_i64()?;
+                                *int = overflow.apply(std::mem::take(int) * that.to_bigint()?)?;
                                 return Ok(self.clone());
                             }
                             "isub" => {
-                                *int -= that.to_i64()?;
+                                *int = overflow.apply(std::mem::take(int) - that.to_bigint()?)?;
                                 return Ok(self.clone());
                             }
                             _ => None,
@@ -217,9 +330,21 @@ impl RefValue {
                     },
 
                     (_, _) => match op {
-                        "add" => return Ok(value!(this.to_bigint()? + that.to_bigint()?)),
-                        "mul" => return Ok(value!(this.to_bigint()? * that.to_bigint()?)),
-                        "sub" => return Ok(value!(this.to_bigint()? - that.to_bigint()?)),
+                        "add" => {
+                            return Ok(value!(
+                                overflow.apply(this.to_bigint()? + that.to_bigint()?)?
+                            ))
+                        }
+                        "mul" => {
+                            return Ok(value!(
+                                overflow.apply(this.to_bigint()? * that.to_bigint()?)?
+                            ))
+                        }
+                        "sub" => {
+                            return Ok(value!(
+                                overflow.apply(this.to_bigint()? - that.to_bigint()?)?
+                            ))
+                        }
                         "div" | "divi" | "mod" => {
                             let dividend = this.to_bigint()?;
                             let divisor = that.to_bigint()?;
@@ -233,7 +358,7 @@ impl RefValue {
                             }
 
                             if op == "divi" {
-                                return Ok(value!(dividend / divisor));
+                                return Ok(value!(overflow.apply(dividend / divisor)?));
                             }
 
                             let modres = &dividend % &divisor;
@@ -243,7 +368,7 @@ impl RefValue {
                                 if op == "mod" {
                                     return Ok(value!(0));
                                 } else {
-                                    return Ok(value!(dividend / divisor));
+                                    return Ok(value!(overflow.apply(dividend / divisor)?));
                                 }
                             } else if op == "mod" {
                                 return Ok(value!(modres));
@@ -286,7 +411,9 @@ impl RefValue {
 
         // Perform expensive inline operation
         assert!(op.starts_with("i"));
-        let res = self.clone().binary_op(operand, &op[1..])?;
+        let res = self
+            .clone()
+            .binary_op_overflow(operand, &op[1..], overflow)?;
         *self.borrow_mut() = res.into();
         Ok(self)
     }
@@ -317,15 +444,15 @@ impl Object for RefValue {
         self.borrow().is_true()
     }
 
-    fn to_i64(&self) -> Result<i64, String> {
+    fn to_i64(&self) -> Result<i64, ConversionError> {
         self.borrow().to_i64()
     }
 
-    fn to_f64(&self) -> Result<f64, String> {
+    fn to_f64(&self) -> Result<f64, ConversionError> {
         self.borrow().to_f64()
     }
 
-    fn to_usize(&self) -> Result<usize, String> {
+    fn to_usize(&self) -> Result<usize, ConversionError> {
         self.borrow().to_usize()
     }
 
@@ -333,7 +460,7 @@ impl Object for RefValue {
         self.borrow().to_string()
     }
 
-    fn to_bigint(&self) -> Result<BigInt, String> {
+    fn to_bigint(&self) -> Result<BigInt, ConversionError> {
         self.borrow().to_bigint()
     }
 
@@ -381,8 +508,7 @@ impl Hash for RefValue {
         match &*self.borrow() {
             Value::Void => state.write_u8('V' as u8),
             Value::Null => state.write_u8('N' as u8),
-            Value::True => state.write_u8('T' as u8),
-            Value::False => state.write_u8('F' as u8),
+            Value::Bool(b) => state.write_u8(if *b { b'T' } else { b'F' }),
             Value::Int(i) => {
                 state.write_u8('i' as u8);
                 i.hash(state);
@@ -408,6 +534,16 @@ impl Hash for RefValue {
                     state.write_u8('t' as u8);
                     t.hash(state);
                 }
+                // ...Time
+                else if let Some(t) = o.as_any().downcast_ref::<Time>() {
+                    state.write_u8(b'd');
+                    t.timestamp().to_bits().hash(state);
+                }
+                // ...Bytes
+                else if let Some(b) = o.as_any().downcast_ref::<Bytes>() {
+                    state.write_u8(b'y');
+                    b.as_slice().hash(state);
+                }
                 // or otherwise use the object's id as hashable value
                 else {
                     state.write_u8('o' as u8);
@@ -447,18 +583,77 @@ impl std::fmt::Debug for RefValue {
     }
 }
 
-impl From<Value> for RefValue {
-    fn from(value: Value) -> Self {
+thread_local! {
+    // Number of values currently allocated on this thread, while they are counted
+    static ALLOCATED: Cell<usize> = const { Cell::new(0) };
+    // Values are only counted while a run limits them
+    static COUNTING: Cell<bool> = const { Cell::new(false) };
+}
+
+impl RefValue {
+    /** Returns the number of values currently allocated on the current thread.
+
+    While counting is enabled by `RefValue::count()`, every value allocated by `RefValue::from()`
+    is counted until its last reference is dropped. This is used to limit the values allocated by
+    a run with `RunOptions::max_values`, so values aren't counted otherwise. */
+    pub fn allocated() -> usize {
+        ALLOCATED.with(|count| count.get())
+    }
+
+    /// Enables or disables counting of values on the current thread, and returns the previous state.
+    /// The count starts from zero when counting is enabled.
+    pub fn count(enable: bool) -> bool {
+        let counting = COUNTING.with(|counting| counting.replace(enable));
+
+        if enable && !counting {
+            ALLOCATED.with(|count| count.set(0));
+        }
+
+        counting
+    }
+
+    // Counts a newly allocated value
+    fn alloc(value: Value) -> Self {
+        if COUNTING.with(|counting| counting.get()) {
+            ALLOCATED.with(|count| count.set(count.get() + 1));
+        }
+
         RefValue {
             value: Rc::new(RefCell::new(value)),
         }
     }
+
+    // Discounts a value which is released
+    fn release() {
+        ALLOCATED.with(|count| count.set(count.get().saturating_sub(1)));
+    }
+}
+
+impl Drop for RefValue {
+    fn drop(&mut self) {
+        if Rc::strong_count(&self.value) == 1 && COUNTING.with(|counting| counting.get()) {
+            Self::release();
+        }
+    }
+}
+
+impl From<Value> for RefValue {
+    fn from(value: Value) -> Self {
+        RefValue::alloc(value)
+    }
 }
 
 impl From<RefValue> for Value {
     fn from(value: RefValue) -> Self {
-        match std::rc::Rc::try_unwrap(value.value) {
-            Ok(value) => value.into_inner(),
+        // The value is released when it's unwrapped from its last reference
+        let inner = value.value.clone();
+        drop(value);
+
+        match Rc::try_unwrap(inner) {
+            Ok(value) => {
+                RefValue::release();
+                value.into_inner()
+            }
             Err(value) => value.borrow().clone(),
         }
     }
@@ -466,8 +661,6 @@ impl From<RefValue> for Value {
 
 impl From<BoxedObject> for RefValue {
     fn from(value: BoxedObject) -> Self {
-        RefValue {
-            value: Rc::new(RefCell::new(Value::Object(value))),
-        }
+        RefValue::alloc(Value::Object(value))
     }
 }
@@ -0,0 +1,125 @@
+//! Serde serialization and deserialization of values
+use super::{Bytes, Dict, List, Object, RefValue, Str, Time, Value};
+use num::ToPrimitive;
+use serde::de::{Deserialize, Deserializer, MapAccess, SeqAccess, Visitor};
+use serde::ser::{Serialize, SerializeMap, SerializeSeq, Serializer};
+
+impl Serialize for Value {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::Void | Self::Null => serializer.serialize_none(),
+            Self::Bool(b) => serializer.serialize_bool(*b),
+            Self::Int(i) => {
+                // Integers exceeding 64 bits are serialized as strings
+                if let Some(i) = i.to_i64() {
+                    serializer.serialize_i64(i)
+                } else {
+                    serializer.serialize_str(&i.to_string())
+                }
+            }
+            Self::Float(f) => serializer.serialize_f64(*f),
+            Self::Object(object) => {
+                if let Some(s) = object.as_any().downcast_ref::<Str>() {
+                    serializer.serialize_str(s.as_str())
+                } else if let Some(list) = object.as_any().downcast_ref::<List>() {
+                    let mut seq = serializer.serialize_seq(Some(list.len()))?;
+
+                    for item in list.iter() {
+                        seq.serialize_element(item)?;
+                    }
+
+                    seq.end()
+                } else if let Some(dict) = object.as_any().downcast_ref::<Dict>() {
+                    let mut map = serializer.serialize_map(Some(dict.len()))?;
+
+                    for (key, value) in dict.iter() {
+                        map.serialize_entry(&key.to_string(), value)?;
+                    }
+
+                    map.end()
+                } else if let Some(time) = object.as_any().downcast_ref::<Time>() {
+                    serializer.serialize_str(&time.to_string())
+                } else if let Some(bytes) = object.as_any().downcast_ref::<Bytes>() {
+                    serializer.serialize_bytes(bytes.as_slice())
+                } else {
+                    // Any other objects, like parselets or tokens, are serialized by their repr
+                    serializer.serialize_str(&object.repr())
+                }
+            }
+        }
+    }
+}
+
+impl Serialize for RefValue {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.borrow().serialize(serializer)
+    }
+}
+
+struct RefValueVisitor;
+
+impl<'de> Visitor<'de> for RefValueVisitor {
+    type Value = RefValue;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.write_str("a value")
+    }
+
+    fn visit_unit<E>(self) -> Result<RefValue, E> {
+        Ok(RefValue::from(Value::Null))
+    }
+
+    fn visit_none<E>(self) -> Result<RefValue, E> {
+        Ok(RefValue::from(Value::Null))
+    }
+
+    fn visit_some<D: Deserializer<'de>>(self, deserializer: D) -> Result<RefValue, D::Error> {
+        RefValue::deserialize(deserializer)
+    }
+
+    fn visit_bool<E>(self, value: bool) -> Result<RefValue, E> {
+        Ok(RefValue::from(value))
+    }
+
+    fn visit_i64<E>(self, value: i64) -> Result<RefValue, E> {
+        Ok(RefValue::from(value))
+    }
+
+    fn visit_u64<E>(self, value: u64) -> Result<RefValue, E> {
+        Ok(RefValue::from(Value::Int(value.into())))
+    }
+
+    fn visit_f64<E>(self, value: f64) -> Result<RefValue, E> {
+        Ok(RefValue::from(value))
+    }
+
+    fn visit_str<E>(self, value: &str) -> Result<RefValue, E> {
+        Ok(RefValue::from(value))
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<RefValue, A::Error> {
+        let mut list = List::new();
+
+        while let Some(item) = seq.next_element::<RefValue>()? {
+            list.push(item);
+        }
+
+        Ok(RefValue::from(list))
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<RefValue, A::Error> {
+        let mut dict = Dict::new();
+
+        while let Some((key, value)) = map.next_entry::<String, RefValue>()? {
+            dict.insert_str(&key, value);
+        }
+
+        Ok(RefValue::from(dict))
+    }
+}
+
+impl<'de> Deserialize<'de> for RefValue {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(RefValueVisitor)
+    }
+}
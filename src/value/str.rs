@@ -1,5 +1,5 @@
 //! String object
-use super::{BoxedObject, List, Object, RefValue};
+use super::{BoxedObject, ConversionError, Dict, List, Object, RefValue, Value};
 use crate::value;
 use num::{ToPrimitive, Zero};
 use num_bigint::{BigInt, Sign};
@@ -44,11 +44,11 @@ impl Object for Str {
         self.len() > 0
     }
 
-    fn to_i64(&self) -> Result<i64, String> {
+    fn to_i64(&self) -> Result<i64, ConversionError> {
         Ok(parse_int::<i64>(&self.string).unwrap_or(0))
     }
 
-    fn to_f64(&self) -> Result<f64, String> {
+    fn to_f64(&self) -> Result<f64, ConversionError> {
         // todo: JavaScript-style parseFloat-like behavior?
         match self.string.parse::<f64>() {
             Ok(f) => Ok(f),
@@ -56,7 +56,7 @@ impl Object for Str {
         }
     }
 
-    fn to_usize(&self) -> Result<usize, String> {
+    fn to_usize(&self) -> Result<usize, ConversionError> {
         Ok(parse_uint::<usize>(&self.string).unwrap_or(0))
     }
 
@@ -64,7 +64,7 @@ impl Object for Str {
         self.string.clone()
     }
 
-    fn to_bigint(&self) -> Result<BigInt, String> {
+    fn to_bigint(&self) -> Result<BigInt, ConversionError> {
         Ok(parse_int::<BigInt>(&self.string).unwrap_or(BigInt::zero()))
     }
 }
@@ -75,6 +75,194 @@ impl Str {
         &self.string
     }
 
+    /** Formats values into the string, which is used as format string.
+
+    Placeholders are written in curly braces, like `{}` for the next value, `{1}` for the value at
+    index 1, or `{name}` for the named value `name`. Literal braces are written as `{{` and `}}`.
+
+    A placeholder may specify a format after a colon, as `{:[[fill]align][+][0][width][.precision][type]}`:
+
+    - `align` is `<` for left, `>` for right and `^` for centered alignment within `width`,
+      padded by `fill`, which defaults to a space; Numbers are aligned right, anything else left.
+    - `+` prints the sign of positive numbers, `0` pads numbers with zeros after their sign.
+    - `precision` is the number of digits after the decimal point of floats, or the maximum
+      number of characters taken from other values.
+    - `type` is `d` for int, `x`, `X`, `o` or `b` for hexadecimal, octal or binary ints, `f` for
+      float, `e` for float in exponent notation, `s` for str or `?` for the value's repr. */
+    pub fn format(&self, args: &[RefValue], nargs: Option<&Dict>) -> Result<String, String> {
+        let mut ret = String::with_capacity(self.len());
+        let mut next = 0;
+        let mut chars = self.chars();
+
+        while let Some(ch) = chars.next() {
+            match ch {
+                '{' if chars.as_str().starts_with('{') => {
+                    chars.next();
+                    ret.push('{');
+                }
+                '}' if chars.as_str().starts_with('}') => {
+                    chars.next();
+                    ret.push('}');
+                }
+                '}' => return Err("format contains a single '}'".to_string()),
+                '{' => {
+                    let rest = chars.as_str();
+                    let end = rest
+                        .find('}')
+                        .ok_or_else(|| "format contains an unclosed '{'".to_string())?;
+                    let (field, spec) = rest[..end].split_once(':').unwrap_or((&rest[..end], ""));
+
+                    let value = if field.is_empty() {
+                        next += 1;
+                        args.get(next - 1)
+                    } else if let Ok(index) = field.parse::<usize>() {
+                        args.get(index)
+                    } else {
+                        nargs.and_then(|nargs| nargs.get_str(field))
+                    };
+
+                    let value = value.ok_or_else(|| {
+                        format!("placeholder '{{{}}}' has no value", &rest[..end])
+                    })?;
+
+                    ret.push_str(&Self::format_value(value, spec)?);
+                    chars = rest[end + 1..].chars();
+                }
+                ch => ret.push(ch),
+            }
+        }
+
+        Ok(ret)
+    }
+
+    // Renders a value by a format specification
+    fn format_value(value: &RefValue, spec: &str) -> Result<String, String> {
+        let mut spec: Vec<char> = spec.chars().collect();
+
+        // [[fill]align]
+        let (fill, align) = match spec.as_slice() {
+            [fill, align @ ('<' | '>' | '^'), ..] => {
+                let ret = (*fill, Some(*align));
+                spec.drain(..2);
+                ret
+            }
+            [align @ ('<' | '>' | '^'), ..] => {
+                let ret = (' ', Some(*align));
+                spec.remove(0);
+                ret
+            }
+            _ => (' ', None),
+        };
+
+        let sign = spec.first() == Some(&'+');
+        if sign {
+            spec.remove(0);
+        }
+
+        let zero = spec.first() == Some(&'0');
+        if zero {
+            spec.remove(0);
+        }
+
+        // Consumes a number from the start of the specification
+        let number = |spec: &mut Vec<char>| -> Option<usize> {
+            let len = spec.iter().take_while(|ch| ch.is_ascii_digit()).count();
+            let digits: String = spec.drain(..len).collect();
+            digits.parse().ok()
+        };
+
+        let width = number(&mut spec).unwrap_or(0);
+        let precision = if spec.first() == Some(&'.') {
+            spec.remove(0);
+            Some(number(&mut spec).ok_or_else(|| "precision requires digits".to_string())?)
+        } else {
+            None
+        };
+
+        let (numeric, text) = match spec.as_slice() {
+            ['?'] => (false, value.repr()),
+            [ty @ ('d' | 'x' | 'X' | 'o' | 'b')] => {
+                let int = value.to_bigint().map_err(|err| err.to_string())?;
+
+                let text = match ty {
+                    'x' => format!("{:x}", int),
+                    'X' => format!("{:X}", int),
+                    'o' => format!("{:o}", int),
+                    'b' => format!("{:b}", int),
+                    _ => int.to_string(),
+                };
+
+                (true, text)
+            }
+            [ty @ ('f' | 'e')] => {
+                let float = value.to_f64().map_err(|err| err.to_string())?;
+
+                let text = match (ty, precision) {
+                    ('e', Some(precision)) => format!("{:.*e}", precision, float),
+                    ('e', None) => format!("{:e}", float),
+                    (_, Some(precision)) => format!("{:.*}", precision, float),
+                    (_, None) => float.to_string(),
+                };
+
+                (true, text)
+            }
+            [] | ['s'] => match &*value.borrow() {
+                Value::Int(int) => (true, int.to_string()),
+                Value::Float(float) => match precision {
+                    Some(precision) => (true, format!("{:.*}", precision, float)),
+                    None => (true, value.to_string()),
+                },
+                _ => {
+                    let text = value.to_string();
+
+                    match precision {
+                        Some(precision) => (false, text.chars().take(precision).collect()),
+                        None => (false, text),
+                    }
+                }
+            },
+            ty => {
+                return Err(format!(
+                    "format type '{}' is unknown",
+                    ty.iter().collect::<String>()
+                ))
+            }
+        };
+
+        // Sign of positive numbers
+        let text = if numeric && sign && !text.starts_with('-') {
+            format!("+{}", text)
+        } else {
+            text
+        };
+
+        let len = text.chars().count();
+
+        if len >= width {
+            return Ok(text);
+        }
+
+        let padding = width - len;
+
+        // Numbers padded by zeros after their sign
+        if numeric && zero && align.is_none() {
+            let (sign, digits) = match text.strip_prefix(['+', '-']) {
+                Some(digits) => text.split_at(text.len() - digits.len()),
+                None => ("", text.as_str()),
+            };
+
+            return Ok(format!("{}{}{}", sign, "0".repeat(padding), digits));
+        }
+
+        let fill = |count: usize| fill.to_string().repeat(count);
+
+        Ok(match align.unwrap_or(if numeric { '>' } else { '<' }) {
+            '<' => text + &fill(padding),
+            '>' => fill(padding) + &text,
+            _ => fill(padding / 2) + &text + &fill(padding - padding / 2),
+        })
+    }
+
     tokay_method!("str : @value", Ok(RefValue::from(value.to_string())));
 
     tokay_method!("str_len : @s", {
@@ -135,6 +323,27 @@ impl Str {
         Ok(RefValue::from(string))
     });
 
+    tokay_method!("str_iadd : @s, append", {
+        // Append in-place when possible, to keep repeated concatenation linear.
+        if let (Ok(mut inner), Ok(to_append)) = (s.try_borrow_mut(), append.try_borrow()) {
+            if let Some(string) = inner.object_mut::<Str>() {
+                if let Some(append) = to_append.object::<Str>() {
+                    string.push_str(append.as_str());
+                } else {
+                    string.push_str(&to_append.to_string());
+                }
+
+                return Ok(s.clone());
+            }
+        }
+
+        // Otherwise, perform ordinary add first, then re-assign to s
+        let new = Self::str_add(vec![s.clone(), append], None)?;
+        *s.borrow_mut() = new.into();
+
+        Ok(s)
+    });
+
     tokay_method!("str_endswith : @s, postfix", {
         if !s.is("str") {
             s = RefValue::from(s.to_string());
@@ -162,6 +371,47 @@ impl Str {
         Ok(RefValue::from(count.to_string().repeat(s.to_usize()?)))
     });
 
+    tokay_method!("str_find : @s, sub, start=0", {
+        if !s.is("str") {
+            s = RefValue::from(s.to_string());
+        }
+
+        let string = s.borrow();
+        let string = string.object::<Str>().unwrap().as_str();
+        let start = start.to_usize()?;
+
+        // Skip start characters, and return the character position where sub was found
+        let offset = string
+            .char_indices()
+            .nth(start)
+            .map_or(string.len(), |(offset, _)| offset);
+
+        Ok(
+            if let Some(found) = string[offset..].find(&sub.to_string()) {
+                value!(start + string[offset..offset + found].chars().count())
+            } else {
+                value!(-1)
+            },
+        )
+    });
+
+    tokay_method!("str_format : @s, *args, **nargs", {
+        if !s.is("str") {
+            s = RefValue::from(s.to_string());
+        }
+
+        let string = s.borrow();
+
+        match string
+            .object::<Str>()
+            .unwrap()
+            .format(&args, nargs.as_ref())
+        {
+            Ok(string) => Ok(RefValue::from(string)),
+            Err(msg) => Err(format!("{} {}", __function, msg).into()),
+        }
+    });
+
     tokay_method!("str_join : @s, list", {
         let delimiter = s.to_string();
         let list = List::from(list);
@@ -212,6 +462,37 @@ impl Str {
         }))
     });
 
+    // Trims the given characters, or whitespace by default, from start and/or end of s
+    fn trim(s: RefValue, chars: RefValue, start: bool, end: bool) -> RefValue {
+        let string = s.to_string();
+
+        let string = if chars.is_void() {
+            match (start, end) {
+                (true, true) => string.trim(),
+                (true, false) => string.trim_start(),
+                _ => string.trim_end(),
+            }
+        } else {
+            let chars: Vec<char> = chars.to_string().chars().collect();
+
+            match (start, end) {
+                (true, true) => string.trim_matches(&chars[..]),
+                (true, false) => string.trim_start_matches(&chars[..]),
+                _ => string.trim_end_matches(&chars[..]),
+            }
+        };
+
+        RefValue::from(string)
+    }
+
+    tokay_method!("str_ltrim : @s, chars=void", {
+        Ok(Self::trim(s, chars, true, false))
+    });
+
+    tokay_method!("str_rtrim : @s, chars=void", {
+        Ok(Self::trim(s, chars, false, true))
+    });
+
     tokay_method!("str_lower : @s", {
         Ok(RefValue::from(s.to_string().to_lowercase()))
     });
@@ -269,6 +550,10 @@ impl Str {
         }))
     });
 
+    tokay_method!("str_trim : @s, chars=void", {
+        Ok(Self::trim(s, chars, true, true))
+    });
+
     tokay_method!("str_upper : @s", {
         Ok(RefValue::from(s.to_string().to_uppercase()))
     });
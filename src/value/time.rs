@@ -0,0 +1,626 @@
+//! Time object, a point in time with an UTC offset
+use super::{BoxedObject, Object, RefValue, Str, Value};
+use crate::builtin::timestamp::{days_in_month, parse_timestamp};
+use crate::Error;
+use std::cmp::Ordering;
+use std::sync::OnceLock;
+use std::time::{Instant, SystemTime, UNIX_EPOCH};
+use tokay_macros::{tokay_function, tokay_method};
+extern crate self as tokay;
+
+const WEEKDAYS: [&str; 7] = [
+    "Monday",
+    "Tuesday",
+    "Wednesday",
+    "Thursday",
+    "Friday",
+    "Saturday",
+    "Sunday",
+];
+
+const MONTHS: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+
+/// Days since 1970-01-01 of a date in the proleptic Gregorian calendar
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let yoe = year - era * 400;
+    let doy = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+
+    era * 146097 + doe - 719468
+}
+
+/// Date in the proleptic Gregorian calendar of days since 1970-01-01
+fn civil_from_days(days: i64) -> (i64, i64, i64) {
+    let days = days + 719468;
+    let era = days.div_euclid(146097);
+    let doe = days - era * 146097;
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+
+    (yoe + era * 400 + if month <= 2 { 1 } else { 0 }, month, day)
+}
+
+/// Expands the composed directives `%F` and `%T` of a format string
+fn expand(format: &str) -> String {
+    let mut ret = String::with_capacity(format.len());
+    let mut chars = format.chars();
+
+    while let Some(ch) = chars.next() {
+        match (ch, chars.clone().next()) {
+            ('%', Some('F')) => ret.push_str("%Y-%m-%d"),
+            ('%', Some('T')) => ret.push_str("%H:%M:%S"),
+            ('%', Some(next)) => {
+                ret.push('%');
+                ret.push(next);
+            }
+            _ => {
+                ret.push(ch);
+                continue;
+            }
+        }
+
+        chars.next();
+    }
+
+    ret
+}
+
+/// Components of a time, in the local time of its UTC offset
+#[derive(Debug, Clone, Copy)]
+struct Civil {
+    year: i64,
+    month: i64,
+    day: i64,
+    hour: i64,
+    minute: i64,
+    second: i64,
+    nanosecond: i64,
+    weekday: i64, // 0 is Monday
+    yearday: i64, // 1 is January 1st
+}
+
+/** Time object type.
+
+A time is a point in time given by seconds and nanoseconds since the Unix epoch, together with an
+offset to UTC in minutes, which is used for its components and formatting. Times are equal when
+they refer to the same point in time, regardless of their offsets. */
+#[derive(Debug, Clone)]
+pub struct Time {
+    secs: i64,   // Seconds since 1970-01-01T00:00:00Z
+    nanos: u32,  // Nanoseconds within the second
+    offset: i32, // Offset to UTC in minutes
+}
+
+impl Time {
+    /// Creates a time from seconds since the Unix epoch, in UTC.
+    pub fn from_secs(secs: f64) -> Option<Self> {
+        if !secs.is_finite() || secs.abs() >= i64::MAX as f64 {
+            return None;
+        }
+
+        let whole = secs.floor();
+
+        Some(Self {
+            secs: whole as i64,
+            nanos: (((secs - whole) * 1e9).round() as u32).min(999_999_999),
+            offset: 0,
+        })
+    }
+
+    /// Returns the current time, in UTC.
+    pub fn now() -> Self {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default();
+
+        Self {
+            secs: now.as_secs() as i64,
+            nanos: now.subsec_nanos(),
+            offset: 0,
+        }
+    }
+
+    /// Returns the seconds since the Unix epoch, including fractions.
+    pub fn timestamp(&self) -> f64 {
+        self.secs as f64 + self.nanos as f64 / 1e9
+    }
+
+    // Creates a time from the components of its local time and its UTC offset
+    fn from_civil(civil: &Civil, offset: i32) -> Self {
+        let days = days_from_civil(civil.year, civil.month, civil.day);
+
+        Self {
+            secs: days * 86400 + civil.hour * 3600 + civil.minute * 60 + civil.second
+                - offset as i64 * 60,
+            nanos: civil.nanosecond as u32,
+            offset,
+        }
+    }
+
+    // Computes the components of the local time
+    fn civil(&self) -> Civil {
+        let local = self.secs + self.offset as i64 * 60;
+        let days = local.div_euclid(86400);
+        let secs = local.rem_euclid(86400);
+        let (year, month, day) = civil_from_days(days);
+
+        Civil {
+            year,
+            month,
+            day,
+            hour: secs / 3600,
+            minute: secs / 60 % 60,
+            second: secs % 60,
+            nanosecond: self.nanos as i64,
+            weekday: (days + 3).rem_euclid(7),
+            yearday: days - days_from_civil(year, 1, 1) + 1,
+        }
+    }
+
+    // Formats the UTC offset as `+hh:mm`, or `+hhmm` when not `extended`
+    fn format_offset(&self, extended: bool) -> String {
+        let sign = if self.offset < 0 { '-' } else { '+' };
+        let offset = self.offset.abs();
+
+        if extended {
+            format!("{}{:02}:{:02}", sign, offset / 60, offset % 60)
+        } else {
+            format!("{}{:02}{:02}", sign, offset / 60, offset % 60)
+        }
+    }
+
+    /** Formats the time by a format string.
+
+    The directives `%Y` (year), `%y` (year without century), `%m` (month), `%d` (day), `%H`
+    (hour), `%M` (minute), `%S` (second), `%f` (microseconds), `%j` (day of the year), `%a`/`%A`
+    (weekday name), `%b`/`%B` (month name), `%z` (UTC offset `+hhmm`), `%s` (seconds since the
+    Unix epoch), `%F` (`%Y-%m-%d`), `%T` (`%H:%M:%S`) and `%%` are replaced, any other characters
+    are kept. */
+    pub fn format(&self, format: &str) -> Result<String, String> {
+        let civil = self.civil();
+        let format = expand(format);
+        let mut ret = String::new();
+        let mut chars = format.chars();
+
+        while let Some(ch) = chars.next() {
+            if ch != '%' {
+                ret.push(ch);
+                continue;
+            }
+
+            match chars.next() {
+                Some('Y') => ret.push_str(&format!("{:04}", civil.year)),
+                Some('y') => ret.push_str(&format!("{:02}", civil.year.rem_euclid(100))),
+                Some('m') => ret.push_str(&format!("{:02}", civil.month)),
+                Some('d') => ret.push_str(&format!("{:02}", civil.day)),
+                Some('H') => ret.push_str(&format!("{:02}", civil.hour)),
+                Some('M') => ret.push_str(&format!("{:02}", civil.minute)),
+                Some('S') => ret.push_str(&format!("{:02}", civil.second)),
+                Some('f') => ret.push_str(&format!("{:06}", civil.nanosecond / 1000)),
+                Some('j') => ret.push_str(&format!("{:03}", civil.yearday)),
+                Some('a') => ret.push_str(&WEEKDAYS[civil.weekday as usize][..3]),
+                Some('A') => ret.push_str(WEEKDAYS[civil.weekday as usize]),
+                Some('b') => ret.push_str(&MONTHS[civil.month as usize - 1][..3]),
+                Some('B') => ret.push_str(MONTHS[civil.month as usize - 1]),
+                Some('z') => ret.push_str(&self.format_offset(false)),
+                Some('s') => ret.push_str(&self.secs.to_string()),
+                Some('%') => ret.push('%'),
+                Some(ch) => return Err(format!("directive '%{}' is unknown", ch)),
+                None => return Err("format ends with a single '%'".to_string()),
+            }
+        }
+
+        Ok(ret)
+    }
+
+    /** Parses a time by a format string, with the directives of `format()`.
+
+    Names are matched case-insensitively. Components missing in the format default to the start
+    of 1970-01-01 in UTC. */
+    pub fn parse(s: &str, format: &str) -> Result<Self, String> {
+        let mut civil = Civil {
+            year: 1970,
+            month: 1,
+            day: 1,
+            hour: 0,
+            minute: 0,
+            second: 0,
+            nanosecond: 0,
+            weekday: 0,
+            yearday: 0,
+        };
+        let mut offset = 0;
+        let mut epoch = None;
+        let mut rest = s;
+
+        // Takes between one and `max` digits as number within `min..=max_value`
+        fn number(rest: &mut &str, max: usize, min: i64, max_value: i64) -> Option<i64> {
+            let len = rest
+                .chars()
+                .take(max)
+                .take_while(|ch| ch.is_ascii_digit())
+                .count();
+            let number = rest[..len]
+                .parse()
+                .ok()
+                .filter(|n| (min..=max_value).contains(n))?;
+            *rest = &rest[len..];
+            Some(number)
+        }
+
+        // Takes one of the names, or their abbreviations, returning its index
+        fn name(rest: &mut &str, names: &[&str]) -> Option<i64> {
+            for (i, name) in names.iter().enumerate() {
+                for len in [name.len(), 3] {
+                    if rest
+                        .get(..len)
+                        .is_some_and(|prefix| prefix.eq_ignore_ascii_case(&name[..len]))
+                    {
+                        *rest = &rest[len..];
+                        return Some(i as i64);
+                    }
+                }
+            }
+
+            None
+        }
+
+        let expanded = expand(format);
+        let mut chars = expanded.chars();
+        let mismatch = || format!("'{}' doesn't match format '{}'", s, format);
+
+        while let Some(ch) = chars.next() {
+            if ch != '%' {
+                rest = rest.strip_prefix(ch).ok_or_else(mismatch)?;
+                continue;
+            }
+
+            let directive = chars.next();
+            let parsed = match directive {
+                Some('Y') => {
+                    let sign = if let Some(tail) = rest.strip_prefix('-') {
+                        rest = tail;
+                        -1
+                    } else {
+                        1
+                    };
+
+                    number(&mut rest, 4, 0, 9999).map(|year| civil.year = sign * year)
+                }
+                Some('y') => number(&mut rest, 2, 0, 99)
+                    .map(|year| civil.year = if year < 69 { 2000 } else { 1900 } + year),
+                Some('m') => number(&mut rest, 2, 1, 12).map(|month| civil.month = month),
+                Some('d') => number(&mut rest, 2, 1, 31).map(|day| civil.day = day),
+                Some('H') => number(&mut rest, 2, 0, 23).map(|hour| civil.hour = hour),
+                Some('M') => number(&mut rest, 2, 0, 59).map(|minute| civil.minute = minute),
+                Some('S') => number(&mut rest, 2, 0, 60).map(|second| civil.second = second),
+                Some('f') => {
+                    let len = rest
+                        .chars()
+                        .take(9)
+                        .take_while(|ch| ch.is_ascii_digit())
+                        .count();
+                    let digits = format!("{:0<9}", &rest[..len]);
+                    rest = &rest[len..];
+
+                    (len > 0).then(|| civil.nanosecond = digits.parse().unwrap())
+                }
+                Some('j') => number(&mut rest, 3, 1, 366).map(|yearday| civil.yearday = yearday),
+                Some('a' | 'A') => name(&mut rest, &WEEKDAYS).map(|_| ()),
+                Some('b' | 'B') => name(&mut rest, &MONTHS).map(|month| civil.month = month + 1),
+                Some('z') => {
+                    if let Some(tail) = rest.strip_prefix(['Z', 'z']) {
+                        rest = tail;
+                        offset = 0;
+                        Some(())
+                    } else {
+                        let sign = match rest.chars().next() {
+                            Some('+') => 1,
+                            Some('-') => -1,
+                            _ => return Err(mismatch()),
+                        };
+                        rest = &rest[1..];
+
+                        number(&mut rest, 2, 0, 23).and_then(|hours| {
+                            rest = rest.strip_prefix(':').unwrap_or(rest);
+                            number(&mut rest, 2, 0, 59)
+                                .map(|minutes| offset = sign * (hours as i32 * 60 + minutes as i32))
+                        })
+                    }
+                }
+                Some('s') => {
+                    let sign = if let Some(tail) = rest.strip_prefix('-') {
+                        rest = tail;
+                        -1
+                    } else {
+                        1
+                    };
+
+                    number(&mut rest, 19, 0, i64::MAX).map(|secs| epoch = Some(sign * secs))
+                }
+                Some('%') => rest.strip_prefix('%').map(|tail| rest = tail),
+                Some(ch) => return Err(format!("directive '%{}' is unknown", ch)),
+                None => return Err("format ends with a single '%'".to_string()),
+            };
+
+            if parsed.is_none() {
+                return Err(mismatch());
+            }
+        }
+
+        if !rest.is_empty() {
+            return Err(mismatch());
+        }
+
+        if let Some(secs) = epoch {
+            return Ok(Self {
+                secs,
+                nanos: civil.nanosecond as u32,
+                offset,
+            });
+        }
+
+        // A day of the year replaces month and day
+        if civil.yearday > 0 {
+            let days = days_from_civil(civil.year, 1, 1) + civil.yearday - 1;
+            (_, civil.month, civil.day) = civil_from_days(days);
+        }
+
+        if civil.day > days_in_month(civil.year, civil.month) {
+            return Err(format!("'{}' is not a valid date", s));
+        }
+
+        Ok(Self::from_civil(&civil, offset))
+    }
+
+    // Creates a time from an RFC 3339 or ISO 8601 timestamp, like accepted by the Timestamp token
+    fn from_timestamp(s: &str) -> Option<Self> {
+        let (len, dict) = parse_timestamp(s.trim())?;
+
+        if len != s.trim().len() {
+            return None;
+        }
+
+        let get = |name| {
+            dict.get_str(name)
+                .and_then(|value| value.to_i64().ok())
+                .unwrap_or(0)
+        };
+
+        let civil = Civil {
+            year: get("year"),
+            month: get("month"),
+            day: get("day"),
+            hour: get("hour"),
+            minute: get("minute"),
+            second: get("second"),
+            nanosecond: get("nanosecond"),
+            weekday: 0,
+            yearday: 0,
+        };
+
+        Some(Self::from_civil(&civil, get("offset") as i32))
+    }
+
+    tokay_method!("time : @value=void, format=void", {
+        if value.is_void() {
+            return Ok(RefValue::from(Time::now()));
+        }
+
+        if value.is("time") {
+            return Ok(value);
+        }
+
+        let time = match &*value.borrow() {
+            Value::Object(object) if object.is("str") => {
+                let s = object.to_string();
+
+                if format.is_void() {
+                    Time::from_timestamp(&s)
+                        .ok_or_else(|| format!("'{}' is not a valid timestamp", s))
+                } else {
+                    Time::parse(&s, &format.to_string())
+                }
+            }
+            value => value
+                .to_f64()
+                .ok()
+                .and_then(Time::from_secs)
+                .ok_or_else(|| format!("{} can't be converted into a time", value.repr())),
+        };
+
+        match time {
+            Ok(time) => Ok(RefValue::from(time)),
+            Err(msg) => Err(Error::from(format!("{} {}", __function, msg))),
+        }
+    });
+
+    tokay_method!("time_format : @time, format=void", {
+        let time = time.borrow();
+        let time = time.object::<Time>().unwrap();
+
+        if format.is_void() {
+            return Ok(RefValue::from(time.to_string()));
+        }
+
+        match time.format(&format.to_string()) {
+            Ok(s) => Ok(RefValue::from(s)),
+            Err(msg) => Err(Error::from(format!("{} {}", __function, msg))),
+        }
+    });
+
+    tokay_method!("time_timestamp : @time", {
+        let time = time.borrow();
+        Ok(RefValue::from(time.object::<Time>().unwrap().timestamp()))
+    });
+
+    tokay_method!("time_utc : @time, offset=0", {
+        let offset = offset.to_i64()?;
+
+        if !(-1439..=1439).contains(&offset) {
+            return Err(format!("{} offset {} is out of range", __function, offset).into());
+        }
+
+        let time = time.borrow();
+
+        Ok(RefValue::from(Time {
+            offset: offset as i32,
+            ..time.object::<Time>().unwrap().clone()
+        }))
+    });
+
+    tokay_method!("time_get_item : @time, item, default=void", {
+        let time = time.borrow();
+        let time = time.object::<Time>().unwrap();
+        let civil = time.civil();
+
+        Ok(match item.to_string().as_str() {
+            "year" => RefValue::from(civil.year),
+            "month" => RefValue::from(civil.month),
+            "day" => RefValue::from(civil.day),
+            "hour" => RefValue::from(civil.hour),
+            "minute" => RefValue::from(civil.minute),
+            "second" => RefValue::from(civil.second),
+            "nanosecond" => RefValue::from(civil.nanosecond),
+            "weekday" => RefValue::from(civil.weekday),
+            "yearday" => RefValue::from(civil.yearday),
+            "offset" => RefValue::from(time.offset as i64),
+            _ => default,
+        })
+    });
+
+    tokay_method!("time_add : @time, seconds", {
+        // The operands may be swapped, like in `60 + time`
+        let (time, seconds) = if time.is("time") {
+            (time, seconds)
+        } else {
+            (seconds, time)
+        };
+
+        let time = time.borrow();
+        let time = time.object::<Time>().unwrap();
+
+        match Time::from_secs(time.timestamp() + seconds.to_f64()?) {
+            Some(sum) => Ok(RefValue::from(Time {
+                offset: time.offset,
+                ..sum
+            })),
+            None => Err(format!("{} result is out of range", __function).into()),
+        }
+    });
+
+    tokay_method!("time_sub : @time, other", {
+        if !time.is("time") {
+            return Err(
+                format!("{} can't subtract a time from {}", __function, time.repr()).into(),
+            );
+        }
+
+        let time = time.borrow();
+        let time = time.object::<Time>().unwrap();
+
+        // The difference of two times is a duration in seconds
+        if let Some(other) = other.borrow().object::<Time>() {
+            return Ok(RefValue::from(
+                (time.secs - other.secs) as f64 + (time.nanos as f64 - other.nanos as f64) / 1e9,
+            ));
+        }
+
+        match Time::from_secs(time.timestamp() - other.to_f64()?) {
+            Some(diff) => Ok(RefValue::from(Time {
+                offset: time.offset,
+                ..diff
+            })),
+            None => Err(format!("{} result is out of range", __function).into()),
+        }
+    });
+}
+
+impl Object for Time {
+    fn severity(&self) -> u8 {
+        15
+    }
+
+    fn name(&self) -> &'static str {
+        "time"
+    }
+
+    fn repr(&self) -> String {
+        format!("time({})", Str::from(self.to_string()).repr())
+    }
+
+    fn to_string(&self) -> String {
+        let civil = self.civil();
+        let mut ret = format!(
+            "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}",
+            civil.year, civil.month, civil.day, civil.hour, civil.minute, civil.second
+        );
+
+        if self.nanos > 0 {
+            let fraction = format!("{:09}", self.nanos);
+            ret.push('.');
+            ret.push_str(fraction.trim_end_matches('0'));
+        }
+
+        if self.offset == 0 {
+            ret.push('Z');
+        } else {
+            ret.push_str(&self.format_offset(true));
+        }
+
+        ret
+    }
+
+    fn to_f64(&self) -> Result<f64, super::ConversionError> {
+        Ok(self.timestamp())
+    }
+
+    fn to_i64(&self) -> Result<i64, super::ConversionError> {
+        Ok(self.secs)
+    }
+}
+
+impl PartialEq for Time {
+    fn eq(&self, other: &Self) -> bool {
+        (self.secs, self.nanos) == (other.secs, other.nanos)
+    }
+}
+
+impl PartialOrd for Time {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some((self.secs, self.nanos).cmp(&(other.secs, other.nanos)))
+    }
+}
+
+impl From<Time> for RefValue {
+    fn from(value: Time) -> Self {
+        Value::Object(Box::new(value) as BoxedObject).into()
+    }
+}
+
+// Start of the monotonic clock, on its first use
+static CLOCK: OnceLock<Instant> = OnceLock::new();
+
+// Seconds elapsed on a monotonic clock, to measure durations
+tokay_function!("clock : @", {
+    let start = CLOCK.get_or_init(Instant::now);
+    RefValue::from(start.elapsed().as_secs_f64()).into()
+});
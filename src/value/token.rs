@@ -1,24 +1,28 @@
 //! Token callables represented by Value::Token
-use super::{BoxedObject, Dict, Object, RefValue};
+use super::{BoxedObject, Ccl, Dict, Object, RefValue};
 use crate::vm::*;
 use charclass::{charclass, CharClass};
 use num_bigint::BigInt;
 use num_parse::*;
+use std::sync::OnceLock;
 use tokay_macros::tokay_token;
+use unicode_general_category::get_general_category;
 extern crate self as tokay;
 
 // todo: The entire Token enum could be split into separate objects.
 
 #[derive(Debug, Clone, Hash, PartialEq, PartialOrd)]
 pub enum Token {
-    Empty,                              // Matches the empty word
-    EOF,                                // Matches End of File
-    Char(CharClass),                    // Matches one character from a character class
-    BuiltinChar(fn(ch: char) -> bool),  // Matches one character from a callback function
-    Chars(CharClass),                   // Matches multiple characters from a character class
-    BuiltinChars(fn(ch: char) -> bool), // Matches multiple characters from a callback function
-    Match(String),                      // Match a string
-    Touch(String),                      // Match a string with zero severity
+    Empty,                                        // Matches the empty word
+    EOF,                                          // Matches End of File
+    Char(CharClass),                              // Matches one character from a character class
+    BuiltinChar(&'static str, fn(char) -> bool),  // Matches one character from a named callback
+    Chars(CharClass),                             // Matches many characters from a character class
+    BuiltinChars(&'static str, fn(char) -> bool), // Matches many characters from a named callback
+    Match(String),                                // Match a string
+    Touch(String),                                // Match a string with zero severity
+    MatchCaseless(String),                        // Match a string ignoring case
+    TouchCaseless(String),                        // Match a string ignoring case with zero severity
 }
 
 impl Token {
@@ -26,30 +30,34 @@ impl Token {
     pub fn builtin(ident: &str) -> Option<Token> {
         fn builtin_ccl(ident: &str) -> Option<Token> {
             Some(match ident {
-                "Alphabetic" => Token::BuiltinChar(|c| c.is_alphabetic()),
-                "Alphanumeric" => Token::BuiltinChar(|c| c.is_alphanumeric()),
-                "Ascii" => Token::BuiltinChar(|c| c.is_ascii()),
+                "Alphabetic" => Token::BuiltinChar("Alphabetic", |c| c.is_alphabetic()),
+                "Alphanumeric" => Token::BuiltinChar("Alphanumeric", |c| c.is_alphanumeric()),
+                "Ascii" => Token::BuiltinChar("Ascii", |c| c.is_ascii()),
                 "AsciiAlphabetic" => Token::Char(charclass!['A' => 'Z', 'a' => 'z']),
                 "AsciiAlphanumeric" => Token::Char(charclass!['A' => 'Z', 'a' => 'z', '0' => '9']),
-                "AsciiControl" => Token::BuiltinChar(|c| c.is_ascii_control()),
+                "AsciiControl" => Token::BuiltinChar("AsciiControl", |c| c.is_ascii_control()),
                 "AsciiDigit" => Token::Char(charclass!['0' => '9']),
                 "AsciiGraphic" => Token::Char(charclass!['!' => '~']),
                 "AsciiHexdigit" => Token::Char(charclass!['0' => '9', 'A' => 'F', 'a' => 'f']),
                 "AsciiLowercase" => Token::Char(charclass!['a' => 'z']),
-                "AsciiPunctuation" => Token::BuiltinChar(|c| c.is_ascii_punctuation()),
+                "AsciiPunctuation" => {
+                    Token::BuiltinChar("AsciiPunctuation", |c| c.is_ascii_punctuation())
+                }
                 "AsciiUppercase" => Token::Char(charclass!['A' => 'Z']),
-                "AsciiWhitespace" => Token::Char(charclass!['A' => 'Z', 'a' => 'z']),
-                "Control" => Token::BuiltinChar(|c| c.is_control()),
-                "Digit" => Token::BuiltinChar(|c| c.is_digit(10)),
-                "Lowercase" => Token::BuiltinChar(|c| c.is_lowercase()),
-                "Numeric" => Token::BuiltinChar(|c| c.is_numeric()),
-                "Uppercase" => Token::BuiltinChar(|c| c.is_uppercase()),
-                "Whitespace" => Token::BuiltinChar(|c| c.is_whitespace()),
+                "AsciiWhitespace" => {
+                    Token::BuiltinChar("AsciiWhitespace", |c| c.is_ascii_whitespace())
+                }
+                "Control" => Token::BuiltinChar("Control", |c| c.is_control()),
+                "Digit" => Token::BuiltinChar("Digit", |c| c.is_digit(10)),
+                "Lowercase" => Token::BuiltinChar("Lowercase", |c| c.is_lowercase()),
+                "Numeric" => Token::BuiltinChar("Numeric", |c| c.is_numeric()),
+                "Uppercase" => Token::BuiltinChar("Uppercase", |c| c.is_uppercase()),
+                "Whitespace" => Token::BuiltinChar("Whitespace", |c| c.is_whitespace()),
 
                 // Any identifier attached with an "s" will be checked for Token+
                 ident if ident.len() > 1 && ident.ends_with("s") => {
                     match builtin_ccl(&ident[..ident.len() - 1]) {
-                        Some(Token::BuiltinChar(f)) => Token::BuiltinChars(f),
+                        Some(Token::BuiltinChar(name, f)) => Token::BuiltinChars(name, f),
                         Some(Token::Char(c)) => Token::Chars(c),
                         _ => return None,
                     }
@@ -64,6 +72,92 @@ impl Token {
             ident => builtin_ccl(ident),
         }
     }
+
+    /** Retrieve the character class for a Unicode general category.
+
+    The category is given by its abbreviation, like `Nd`, or by its major class, like `L`. */
+    pub fn ccl_category(category: &str) -> Option<CharClass> {
+        // Ranges of characters sharing the same category, determined once as lookups are costly
+        static RANGES: OnceLock<Vec<(char, char, &'static str)>> = OnceLock::new();
+
+        let ranges = RANGES.get_or_init(|| {
+            let mut ranges: Vec<(char, char, &'static str)> = Vec::new();
+
+            for ch in (0..=char::MAX as u32).filter_map(char::from_u32) {
+                let category = get_general_category(ch).abbreviation();
+
+                match ranges.last_mut() {
+                    Some((_, to, last)) if *last == category && *to as u32 + 1 == ch as u32 => {
+                        *to = ch
+                    }
+                    _ => ranges.push((ch, ch, category)),
+                }
+            }
+
+            ranges
+        });
+
+        let mut ccl = CharClass::new();
+
+        for (from, to, _) in ranges
+            .iter()
+            .filter(|(_, _, name)| name.starts_with(category))
+        {
+            ccl.add(*from..=*to);
+        }
+
+        if category.is_empty() || ccl.len() == 0 {
+            return None;
+        }
+
+        Some(ccl)
+    }
+
+    /** Retrieve the character class for a regex-style shorthand escape in a character class.
+
+    `\d` matches ASCII digits like the `Digit` token, `\w` matches Unicode alphanumerics and the
+    underscore, and `\s` matches Unicode whitespace. The uppercase variants are negated. */
+    pub fn ccl_shorthand(shorthand: char) -> Option<CharClass> {
+        let ccl = match shorthand.to_ascii_lowercase() {
+            'd' => charclass!['0' => '9'],
+            'w' => ccl_from_fn(|ch| ch.is_alphanumeric() || ch == '_'),
+            's' => ccl_from_fn(char::is_whitespace),
+            _ => return None,
+        };
+
+        Some(if shorthand.is_uppercase() {
+            ccl.negate()
+        } else {
+            ccl
+        })
+    }
+}
+
+// Collects all characters matching a predicate into a character class
+fn ccl_from_fn(f: impl Fn(char) -> bool) -> CharClass {
+    let mut ccl = CharClass::new();
+    let mut range: Option<(char, char)> = None;
+
+    for ch in (0..=char::MAX as u32).filter_map(char::from_u32) {
+        if !f(ch) {
+            continue;
+        }
+
+        match &mut range {
+            Some((_, to)) if *to as u32 + 1 == ch as u32 => *to = ch,
+            _ => {
+                if let Some((from, to)) = range.replace((ch, ch)) {
+                    ccl.add(from..=to);
+                }
+            }
+        }
+    }
+
+    if let Some((from, to)) = range {
+        ccl.add(from..=to);
+    }
+
+    ccl
 }
 
 impl Object for Token {
@@ -75,11 +169,14 @@ impl Object for Token {
         match self {
             Token::Empty => "Empty".to_string(),
             Token::EOF => "EOF".to_string(),
-            Token::Char(ccl) => format!("{:?}", ccl),
-            Token::Chars(ccl) => format!("{:?}+", ccl),
-            Token::BuiltinChar(_) | Token::BuiltinChars(_) => "<token builtin fn>".to_string(),
+            Token::Char(ccl) => Ccl(ccl).to_string(),
+            Token::Chars(ccl) => format!("{}+", Ccl(ccl)),
+            Token::BuiltinChar(name, _) => name.to_string(),
+            Token::BuiltinChars(name, _) => format!("{}s", name),
             Token::Touch(s) => format!("'{}'", s),
             Token::Match(s) => format!("''{}''", s),
+            Token::TouchCaseless(s) => format!("'{}'i", s),
+            Token::MatchCaseless(s) => format!("''{}''i", s),
         }
     }
 
@@ -96,8 +193,11 @@ impl Object for Token {
             Token::Empty => true,
             Token::EOF => false,
             Token::Char(ccl) | Token::Chars(ccl) => ccl.len() == 0, //True shouldn't be possible here by definition!
-            Token::BuiltinChar(_) | Token::BuiltinChars(_) => true,
-            Token::Match(s) | Token::Touch(s) => s.len() == 0, //True shouldn't be possible here by definition!
+            Token::BuiltinChar(..) | Token::BuiltinChars(..) => true,
+            Token::Match(s)
+            | Token::Touch(s)
+            | Token::MatchCaseless(s)
+            | Token::TouchCaseless(s) => s.is_empty(), //True shouldn't be possible here by definition!
         }
     }
 
@@ -132,7 +232,7 @@ impl Object for Token {
 
                 Err(Reject::Next)
             }
-            Token::BuiltinChar(f) => {
+            Token::BuiltinChar(_, f) => {
                 if let Some(ch) = reader.once(f) {
                     return Ok(Accept::Push(Capture::Range(
                         reader.capture_last(ch.len_utf8()),
@@ -163,7 +263,7 @@ impl Object for Token {
                     Err(Reject::Next)
                 }
             }
-            Token::BuiltinChars(f) => {
+            Token::BuiltinChars(_, f) => {
                 let start = reader.tell();
 
                 while let Some(ch) = reader.peek() {
@@ -215,6 +315,29 @@ impl Object for Token {
                     Err(Reject::Next)
                 }
             }
+            Token::MatchCaseless(string) | Token::TouchCaseless(string) => {
+                let start = reader.tell();
+
+                for ch in string.chars() {
+                    match reader.peek() {
+                        Some(c) if c.to_lowercase().eq(ch.to_lowercase()) => reader.next(),
+                        _ => {
+                            reader.reset(start);
+                            return Err(Reject::Next);
+                        }
+                    };
+                }
+
+                Ok(Accept::Push(Capture::Range(
+                    reader.capture_from(&start),
+                    None,
+                    if matches!(self, Token::TouchCaseless(_)) {
+                        0
+                    } else {
+                        5
+                    },
+                )))
+            }
         }
     }
 }
@@ -318,6 +441,135 @@ tokay_token!("Float : @with_signs=true", {
     )))
 });
 
+// Matching integers or floats, with optional fractional part and exponent
+tokay_token!("Number : @with_signs=true", {
+    let reader = &mut context.thread.reader;
+    let start = reader.tell();
+
+    // Sign
+    if with_signs.is_true() {
+        reader.once(|ch: char| ch == '-' || ch == '+');
+    }
+
+    // Integer part
+    let has_int = reader.span(|ch: char| ch.is_ascii_digit()).is_some();
+    let mut is_float = false;
+
+    // Decimal point and fractional part
+    let before_point = reader.tell();
+
+    if reader.once(|ch: char| ch == '.').is_some() {
+        if reader.span(|ch: char| ch.is_ascii_digit()).is_some() || has_int {
+            is_float = true;
+        } else {
+            reader.reset(before_point);
+        }
+    }
+
+    if !has_int && !is_float {
+        reader.reset(start);
+        return Err(Reject::Next);
+    }
+
+    // Exponential notation, only taken when followed by digits
+    let before_exponent = reader.tell();
+
+    if reader.once(|ch: char| ch == 'e' || ch == 'E').is_some() {
+        reader.once(|ch: char| ch == '-' || ch == '+');
+
+        if reader.span(|ch: char| ch.is_ascii_digit()).is_some() {
+            is_float = true;
+        } else {
+            reader.reset(before_exponent);
+        }
+    }
+
+    let number = reader.get(&reader.capture_from(&start));
+
+    Ok(Accept::Push(Capture::Value(
+        if is_float {
+            crate::value!(number.parse::<f64>().unwrap())
+        } else {
+            crate::value!(number.parse::<BigInt>().unwrap())
+        },
+        None,
+        5,
+    )))
+});
+
+// Matching quoted strings, resulting in their unescaped content
+tokay_token!("QuotedString : @quotes=void", {
+    let quotes = if quotes.is_void() {
+        "\"'".to_string()
+    } else {
+        quotes.to_string()
+    };
+
+    let reader = &mut context.thread.reader;
+    let start = reader.tell();
+
+    let Some(quote) = reader.once(|ch| quotes.contains(ch)) else {
+        return Err(Reject::Next);
+    };
+
+    let mut string = String::new();
+
+    loop {
+        match reader.next() {
+            Some(ch) if ch == quote => break,
+            Some('\\') => match reader.next() {
+                Some('n') => string.push('\n'),
+                Some('r') => string.push('\r'),
+                Some('t') => string.push('\t'),
+                Some('0') => string.push('\0'),
+                Some(ch @ ('x' | 'u')) => {
+                    // \xHH and \uHHHH, or \u{H..} with up to six hex digits
+                    let braced = ch == 'u' && reader.once(|ch| ch == '{').is_some();
+                    let max = if braced {
+                        6
+                    } else if ch == 'x' {
+                        2
+                    } else {
+                        4
+                    };
+                    let mut code = String::new();
+
+                    while code.len() < max {
+                        match reader.once(|ch| ch.is_ascii_hexdigit()) {
+                            Some(ch) => code.push(ch),
+                            None => break,
+                        }
+                    }
+
+                    let valid = (braced || code.len() == max)
+                        && (!braced || reader.once(|ch| ch == '}').is_some());
+
+                    match u32::from_str_radix(&code, 16).ok().and_then(char::from_u32) {
+                        Some(ch) if valid => string.push(ch),
+                        _ => {
+                            reader.reset(start);
+                            return Err(Reject::Next);
+                        }
+                    }
+                }
+                Some(ch) => string.push(ch),
+                None => {
+                    reader.reset(start);
+                    return Err(Reject::Next);
+                }
+            },
+            Some(ch) => string.push(ch),
+            None => {
+                // Unterminated string
+                reader.reset(start);
+                return Err(Reject::Next);
+            }
+        }
+    }
+
+    Ok(Accept::Push(Capture::Value(crate::value!(string), None, 5)))
+});
+
 // Words, optionally with limited length
 tokay_token!("Word : @min=1 max=void", {
     let reader = &mut context.thread.reader;
@@ -341,3 +593,26 @@ tokay_token!("Word : @min=1 max=void", {
         Err(Reject::Next)
     }
 });
+
+// Matching a token value of a given kind and optional value, when reading from a list of values
+tokay_token!("Kind : @kind, value=void", {
+    let reader = &mut context.thread.reader;
+
+    if let Some(token) = reader.peek_value() {
+        let matches = token
+            .borrow()
+            .object::<Dict>()
+            .map(|dict| {
+                dict.get_str("emit") == Some(&kind)
+                    && (value.is_void() || dict.get_str("value") == Some(&value))
+            })
+            .unwrap_or(false);
+
+        if matches {
+            reader.next();
+            return Ok(Accept::Push(Capture::Value(token, None, 5)));
+        }
+    }
+
+    Err(Reject::Next)
+});
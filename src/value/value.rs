@@ -1,9 +1,9 @@
 //! Tokay value
-use super::{BoxedObject, Dict, Object, RefValue};
-use crate::{Accept, Context, Error, Reject};
+use super::{BoxedObject, ConversionError, Dict, Object, RefValue};
+use crate::{Accept, Context, Reject};
 use tokay_macros::tokay_method;
 extern crate self as tokay;
-use num::{ToPrimitive, Zero};
+use num::{FromPrimitive, ToPrimitive, Zero};
 use num_bigint::BigInt;
 use std::any::Any;
 use std::cmp::Ordering;
@@ -11,10 +11,9 @@ use std::cmp::Ordering;
 #[derive(Debug, Clone, PartialEq)]
 pub enum Value {
     // Atomics
-    Void,  // void
-    Null,  // null
-    True,  // true
-    False, // false
+    Void,       // void
+    Null,       // null
+    Bool(bool), // true or false
 
     // Numerics
     Int(BigInt), // int
@@ -57,23 +56,31 @@ impl Value {
     // Constructors
     tokay_method!("bool : @value", Ok(RefValue::from(value.is_true())));
     tokay_method!("int : @value", {
-        if let Ok(value) = value.to_bigint() {
-            Ok(RefValue::from(value))
-        } else {
-            Err(Error::from(format!(
-                "`{}` cannot be converted to int",
-                value.name()
-            )))
+        if let Some(context) = context {
+            context.lossy(value.lossy("int"))?;
+        }
+
+        match value.to_bigint() {
+            Ok(value) => Ok(RefValue::from(value)),
+            Err(err) => Err(ConversionError {
+                target: "int",
+                ..err
+            }
+            .into()),
         }
     });
     tokay_method!("float : @value", {
-        if let Ok(value) = value.to_f64() {
-            Ok(RefValue::from(value))
-        } else {
-            Err(Error::from(format!(
-                "`{}` cannot be converted to float",
-                value.name()
-            )))
+        if let Some(context) = context {
+            context.lossy(value.lossy("float"))?;
+        }
+
+        match value.to_f64() {
+            Ok(value) => Ok(RefValue::from(value)),
+            Err(err) => Err(ConversionError {
+                target: "float",
+                ..err
+            }
+            .into()),
         }
     });
 
@@ -106,7 +113,7 @@ impl Object for Value {
         match self {
             Self::Void => "void",
             Self::Null => "null",
-            Self::True | Self::False => "bool",
+            Self::Bool(_) => "bool",
             Self::Int(_) => "int",
             Self::Float(_) => "float",
             Self::Object(o) => o.name(),
@@ -115,8 +122,7 @@ impl Object for Value {
 
     fn repr(&self) -> String {
         match self {
-            Self::True => "true".to_string(),
-            Self::False => "false".to_string(),
+            Self::Bool(b) => b.to_string(),
             Self::Int(i) => format!("{}", i),
             Self::Float(f) => format!("{}", f),
             Self::Object(o) => o.repr(),
@@ -130,7 +136,7 @@ impl Object for Value {
 
     fn is_true(&self) -> bool {
         match self {
-            Self::True => true,
+            Self::Bool(b) => *b,
             Self::Int(i) => !i.is_zero(),
             Self::Float(f) => *f != 0.0,
             Self::Object(o) => o.is_true(),
@@ -138,33 +144,55 @@ impl Object for Value {
         }
     }
 
-    fn to_i64(&self) -> Result<i64, String> {
+    fn to_i64(&self) -> Result<i64, ConversionError> {
         match self {
-            Self::True => Ok(1),
-            Self::Int(i) => Ok(i.to_i64().or(Some(0)).unwrap()),
-            Self::Float(f) => Ok(*f as i64),
+            Self::Bool(b) => Ok(*b as i64),
+            Self::Int(i) => i
+                .to_i64()
+                .ok_or_else(|| ConversionError::out_of_range("int", "i64", i.to_string())),
+            Self::Float(f) => {
+                // Floats are truncated, but must be in range
+                if f.is_finite() && *f > i64::MIN as f64 - 1.0 && *f < i64::MAX as f64 + 1.0 {
+                    Ok(*f as i64)
+                } else {
+                    Err(ConversionError::out_of_range("float", "i64", f.to_string()))
+                }
+            }
             Self::Object(o) => o.to_i64(),
             _ => Ok(0),
         }
     }
 
-    fn to_f64(&self) -> Result<f64, String> {
+    fn to_f64(&self) -> Result<f64, ConversionError> {
         match self {
-            Self::True => Ok(1.0),
-            Self::Int(i) => Ok(i.to_f64().or(Some(0.0)).unwrap()),
+            Self::Bool(b) => Ok(*b as i64 as f64),
+            Self::Int(i) => i
+                .to_f64()
+                .ok_or_else(|| ConversionError::out_of_range("int", "f64", i.to_string())),
             Self::Float(f) => Ok(*f),
             Self::Object(o) => o.to_f64(),
             _ => Ok(0.0),
         }
     }
 
-    fn to_usize(&self) -> Result<usize, String> {
+    fn to_usize(&self) -> Result<usize, ConversionError> {
         match self {
-            Self::True => Ok(1),
+            Self::Bool(b) => Ok(*b as usize),
             Self::Int(i) => i
                 .to_usize()
-                .ok_or("Cannot convert BigInt to usize".to_string()),
-            Self::Float(f) => Ok(*f as usize),
+                .ok_or_else(|| ConversionError::out_of_range("int", "usize", i.to_string())),
+            Self::Float(f) => {
+                // Floats are truncated, but must be in range
+                if f.is_finite() && *f > -1.0 && *f < usize::MAX as f64 + 1.0 {
+                    Ok(*f as usize)
+                } else {
+                    Err(ConversionError::out_of_range(
+                        "float",
+                        "usize",
+                        f.to_string(),
+                    ))
+                }
+            }
             Self::Object(o) => o.to_usize(),
             _ => Ok(0),
         }
@@ -178,11 +206,12 @@ impl Object for Value {
         }
     }
 
-    fn to_bigint(&self) -> Result<BigInt, String> {
+    fn to_bigint(&self) -> Result<BigInt, ConversionError> {
         match self {
-            Self::True => Ok(BigInt::from(1)),
+            Self::Bool(b) => Ok(BigInt::from(*b as i64)),
             Self::Int(i) => Ok(i.clone()),
-            Self::Float(f) => Ok(BigInt::from(*f as i64)),
+            Self::Float(f) => BigInt::from_f64(*f)
+                .ok_or_else(|| ConversionError::out_of_range("float", "BigInt", f.to_string())),
             Self::Object(o) => o.to_bigint(),
             _ => Ok(BigInt::from(0)),
         }
@@ -284,7 +313,7 @@ impl Ord for Value {
 
 impl From<bool> for RefValue {
     fn from(value: bool) -> Self {
-        RefValue::from(if value { Value::True } else { Value::False })
+        RefValue::from(Value::Bool(value))
     }
 }
 
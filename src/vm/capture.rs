@@ -33,12 +33,13 @@ impl Capture {
 
     /** Extracts a value from a capture.
 
-    In case the capture is a range, the range is extracted as a string from the reader. */
+    In case the capture is a range, the range is extracted as a string from the reader, or as bytes
+    when the reader is in binary mode. */
     pub(crate) fn extract(&mut self, reader: &Reader) -> RefValue {
         match self {
             Capture::Empty => crate::value!(void),
             Capture::Range(range, alias, severity) => {
-                let value = RefValue::from(reader.get(range));
+                let value = reader.get_value(range);
                 *self = Capture::Value(value.clone(), alias.clone(), *severity);
                 value
             }
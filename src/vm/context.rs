@@ -1,7 +1,8 @@
 //! Contexts and stack frames for parselet calls.
 use super::*;
-use crate::reader::Offset;
-use crate::value::{Dict, List, Object, Parselet, RefValue, Value};
+use crate::error::{Error, TraceFrame};
+use crate::reader::{Offset, Reader};
+use crate::value::{Dict, List, Lossy, Object, Parselet, RefValue, Value};
 use std::iter::FromIterator;
 
 /** Representation of a stack-frame based on current context. */
@@ -10,6 +11,7 @@ pub struct Frame {
     pub fuse: Option<usize>,  // optional fuse
     pub capture_start: usize, // capture start
     pub reader_start: Offset, // reader start
+    pub emit_start: usize,    // emit start
 }
 
 impl std::fmt::Display for Frame {
@@ -67,6 +69,7 @@ impl<'program, 'reader, 'thread, 'parselet> Context<'program, 'reader, 'thread,
             fuse: None,
             capture_start: stack.len(),
             reader_start: reader_start.clone(),
+            emit_start: thread.emits.len(),
         };
 
         // Create Context
@@ -86,6 +89,15 @@ impl<'program, 'reader, 'thread, 'parselet> Context<'program, 'reader, 'thread,
         }
     }
 
+    /// Returns the frame describing this context within a stack trace.
+    pub fn trace_frame(&self) -> TraceFrame {
+        TraceFrame {
+            parselet: self.parselet.name.clone(),
+            source: self.source_offset,
+            reader: self.frame0().reader_start,
+        }
+    }
+
     /// Print debug output with context depth indention
     #[inline]
     pub fn log(&self, msg: &str) {
@@ -140,12 +152,91 @@ impl<'program, 'reader, 'thread, 'parselet> Context<'program, 'reader, 'thread,
     fn reset(&mut self, offset: Option<Offset>) {
         self.stack.truncate(self.frame.capture_start); // Truncate stack
         self.var = Capture::Empty; // Reset $0
+        self.frame.emit_start = self.thread.emits.len(); // Keep values emitted so far
 
         if let Some(offset) = offset {
             self.frame.reader_start = offset; // Set reader start to provided position
         }
     }
 
+    /** Resets the reader to the start of the current frame, and discards the spans behind it.
+
+    Fails when the input backtracked in total exceeds `RunOptions::max_backtrack`. */
+    #[inline]
+    pub(crate) fn rewind(&mut self) -> Result<(), Reject> {
+        let backtracked =
+            (self.thread.reader.tell().offset).saturating_sub(self.frame.reader_start.offset);
+
+        self.thread.reader.reset(self.frame.reader_start);
+
+        if let Some(spans) = &mut self.thread.spans {
+            spans.rewind(self.frame.reader_start.offset);
+        }
+
+        self.thread.backtracked += backtracked;
+
+        match self.thread.options.max_backtrack {
+            Some(max) if self.thread.backtracked > max => Err(Thread::limit_exceeded(format!(
+                "backtracked more than {} bytes of input",
+                max
+            ))),
+            _ => Ok(()),
+        }
+    }
+
+    /// Reports a lossy conversion as warning or error, as configured by the run options
+    pub fn lossy(&mut self, loss: Option<String>) -> Result<(), Error> {
+        let Some(loss) = loss else {
+            return Ok(());
+        };
+
+        match self.thread.options.lossy {
+            Lossy::Ignore => Ok(()),
+            Lossy::Warn => {
+                self.thread
+                    .warnings
+                    .push(Error::new(self.source_offset, loss).to_string());
+                Ok(())
+            }
+            Lossy::Error => Err(Error::new(self.source_offset, loss)),
+        }
+    }
+
+    /// Takes a snapshot of the current state
+    pub fn snapshot(&self) -> Snapshot {
+        let reader = &self.thread.reader;
+
+        let mut frames: Vec<&Frame> = self.frames.iter().collect();
+        frames.push(&self.frame);
+
+        let frames = frames
+            .iter()
+            .enumerate()
+            .map(|(i, frame)| SnapshotFrame {
+                captures: frames
+                    .get(i + 1)
+                    .map_or(self.stack.len(), |next| next.capture_start)
+                    .saturating_sub(frame.capture_start),
+                start: reader.origin(&frame.reader_start, false),
+            })
+            .collect();
+
+        Snapshot {
+            parselet: self.parselet.name.clone(),
+            depth: self.depth,
+            filename: reader.filename.clone(),
+            offset: reader.origin(&reader.tell(), false),
+            parselets: if self.thread.parselets.is_empty() {
+                vec![self.parselet.name.clone()]
+            } else {
+                self.thread.parselets.clone()
+            },
+            frames,
+            captures: self.stack.len() - self.frame0().capture_start,
+            globals: self.thread.globals.clone(),
+        }
+    }
+
     /// Return top-level frame
     pub fn frame0(&self) -> &Frame {
         if self.frames.is_empty() {
@@ -155,6 +246,65 @@ impl<'program, 'reader, 'thread, 'parselet> Context<'program, 'reader, 'thread,
         }
     }
 
+    /** Checks if a call at instruction pointer `ip` is in tail position.
+
+    This is the case when the context's result would be the called parselet's result, only collected
+    with captures taken before, and no frame, loop, end-block or left-recursion would process
+    the result or a rejection. */
+    pub fn is_tail_position(&self, ip: usize) -> bool {
+        self.depth > 0
+            && self.parselet.consuming != Some(true)
+            && self.parselet.end.is_empty()
+            && matches!(self.var, Capture::Empty)
+            && self.loops.is_empty()
+            && std::iter::once(&self.frame)
+                .chain(self.frames.iter())
+                .all(|frame| frame.fuse.is_none_or(|fuse| fuse <= ip))
+    }
+
+    /** Takes the captures a call in tail position at instruction pointer `ip` of `ops` is collected
+    with, as done by the ops following the call.
+
+    Returns the significant captures of every collecting frame, starting with the innermost one,
+    to be collected with the called parselet's result. */
+    pub(in crate::vm) fn take_tail_captures(&mut self, ops: &[Op], ip: usize) -> Vec<Vec<Capture>> {
+        let mut captures = Vec::new();
+        let mut end = self.stack.len();
+        let mut frames = self.frames.len(); // Number of frames below the current one
+        let mut ip = ip + 1;
+
+        while let Some(op) = ops.get(ip) {
+            match op {
+                Op::Collect => {
+                    let start = if frames == self.frames.len() {
+                        self.frame.capture_start
+                    } else {
+                        self.frames[frames].capture_start
+                    };
+
+                    captures.push(
+                        self.stack
+                            .drain(start..end)
+                            .filter(|item| !(matches!(item, Capture::Empty)))
+                            .collect(),
+                    );
+
+                    end = start;
+                    ip += 1;
+                }
+                Op::Close => {
+                    frames -= 1;
+                    ip += 1;
+                }
+                Op::Offset(_) => ip += 1,
+                Op::Forward(goto) => ip += goto,
+                op => unreachable!("{:?} doesn't follow a call in tail position", op),
+            }
+        }
+
+        captures
+    }
+
     /// Return mutable top-level frame
     pub fn frame0_mut(&mut self) -> &mut Frame {
         if self.frames.is_empty() {
@@ -212,6 +362,10 @@ impl<'program, 'reader, 'thread, 'parselet> Context<'program, 'reader, 'thread,
     /** Set a capture to a RefValue by index. */
     pub fn set_capture(&mut self, pos: usize, value: RefValue) {
         if pos == 0 {
+            for observer in self.thread.observers.iter_mut() {
+                observer.capture(&self.parselet.name, 0, None, &value);
+            }
+
             self.var = Capture::from(value);
             return;
         }
@@ -230,6 +384,8 @@ impl<'program, 'reader, 'thread, 'parselet> Context<'program, 'reader, 'thread,
             Capture::Range(_, alias, _) => *capture = Capture::Value(value, alias.clone(), 5),
             Capture::Value(capture_value, ..) => *capture_value = value,
         }
+
+        self.observe_capture(pos);
     }
 
     /** Set a capture to a RefValue by name. */
@@ -248,11 +404,28 @@ impl<'program, 'reader, 'thread, 'parselet> Context<'program, 'reader, 'thread,
                     }
                     Capture::Value(capture_value, ..) => *capture_value = value,
                 }
+
+                self.observe_capture(capture_start + i);
                 break;
             }
         }
     }
 
+    /// Notifies the observers about a capture set at a position of the stack
+    fn observe_capture(&mut self, pos: usize) {
+        if self.thread.observers.is_empty() {
+            return;
+        }
+
+        if let Capture::Value(value, alias, _) = &self.stack[pos] {
+            let index = pos - self.frame0().capture_start + 1;
+
+            for observer in self.thread.observers.iter_mut() {
+                observer.capture(&self.parselet.name, index, alias.as_ref(), value);
+            }
+        }
+    }
+
     /** Collect captures from a capture_start and turn them either into a dict or list object capture.
 
     Any items with a severity of at least 1 are being collected, but higher severities always win.
@@ -272,6 +445,24 @@ impl<'program, 'reader, 'thread, 'parselet> Context<'program, 'reader, 'thread,
         copy: bool,           // When true: Copy values instead of draining them from the stack
         sequence: bool, // Sequence mode; true: Determine dict, list or inherit type fitting best; false: Always dict
         debug: bool,    // Print debug information
+    ) -> Capture {
+        self.collect_captures(capture_start, copy, sequence, false, debug)
+    }
+
+    /** Collect captures from a capture_start into a list, even when there are none or only one.
+
+    Captures with an alias are still collected into a dict. */
+    pub fn collect_list(&mut self, capture_start: usize, debug: bool) -> Capture {
+        self.collect_captures(capture_start, false, true, true, debug)
+    }
+
+    fn collect_captures(
+        &mut self,
+        capture_start: usize,
+        copy: bool,
+        sequence: bool,
+        list_only: bool, // Sequence mode always results in a list
+        debug: bool,
     ) -> Capture {
         // Early abort when capture_start is behind stack len
         if capture_start > self.stack.len() {
@@ -304,15 +495,46 @@ impl<'program, 'reader, 'thread, 'parselet> Context<'program, 'reader, 'thread,
             }
         }
 
+        let capture = Self::collect_values(
+            captures,
+            self.parselet.severity,
+            self.thread.reader,
+            sequence,
+            list_only,
+        );
+
+        if debug {
+            self.log(&format!("collected = {:?}", capture));
+        }
+
+        capture
+    }
+
+    /** Collects significant captures into a value without a context; `captures` must not contain
+    any empty captures.
+
+    This is used by `collect()`, and to collect the result of a parselet called in tail position
+    with the captures its caller left pending before the call. */
+    pub(crate) fn collect_values(
+        captures: Vec<Capture>,
+        severity: u8,    // Parselet severity
+        reader: &Reader, // Reader to extract ranges from
+        sequence: bool,
+        list_only: bool,
+    ) -> Capture {
         // Early abort when no valuable captures had been taken
         if captures.len() == 0 {
+            if list_only {
+                return Capture::Value(RefValue::from(List::new()), None, severity);
+            }
+
             return Capture::Empty;
         }
 
         // Capture inheritance is only possible when there is only one capture available
         let mut list = List::new(); // List collector
         let mut dict = Dict::new(); // Dict collector
-        let mut max = self.parselet.severity; // Require at least parselet severity level
+        let mut max = severity; // Require at least parselet severity level
         let mut idx = 0; // Keep the order for dicts
 
         // Collect any significant captures and values
@@ -330,7 +552,7 @@ impl<'program, 'reader, 'thread, 'parselet> Context<'program, 'reader, 'thread,
 
                     // fixme: This line is the only difference between the Capture::Range and Capture::Value branch.
                     //        This is totally ugly and should be reworked.
-                    let value = RefValue::from(self.thread.reader.get(&range));
+                    let value = reader.get_value(&range);
 
                     if let Some(alias) = alias {
                         // Move list items into dict when this is the first entry
@@ -395,13 +617,9 @@ impl<'program, 'reader, 'thread, 'parselet> Context<'program, 'reader, 'thread,
             };
         }
 
-        if debug {
-            self.log(&format!("list = {:?}", list));
-            self.log(&format!("dict = {:?}", dict));
-        }
-
         if dict.is_empty() && sequence {
             match list.len() {
+                _ if list_only => Capture::Value(RefValue::from(list), None, max),
                 0 => Capture::Empty,
                 1 => Capture::Value(list.pop().unwrap(), None, max),
                 _ => Capture::Value(RefValue::from(list), None, max),
@@ -452,6 +670,13 @@ impl<'program, 'reader, 'thread, 'parselet> Context<'program, 'reader, 'thread,
                 if let Some(source_offset) = self.source_offset {
                     err.patch_offset(source_offset);
                 }
+
+                err.trace.push(self.trace_frame());
+
+                // Take a snapshot of the innermost context for the post-mortem report
+                if self.thread.options.post_mortem && self.thread.snapshot.is_none() {
+                    self.thread.snapshot = Some(self.snapshot());
+                }
             }
 
             _ => {}
@@ -469,7 +694,10 @@ impl<'program, 'reader, 'thread, 'parselet> Context<'program, 'reader, 'thread,
         // Debugging
         if self.debug < 3 {
             //println!("{:?}", self.parselet.name);
-            if let Ok(inspect) = std::env::var("TOKAY_INSPECT") {
+            // The environment is only read once, as parselets are run very often
+            static INSPECT: std::sync::OnceLock<Option<String>> = std::sync::OnceLock::new();
+
+            if let Some(inspect) = INSPECT.get_or_init(|| std::env::var("TOKAY_INSPECT").ok()) {
                 for name in inspect.split(" ") {
                     if self.parselet.name.starts_with(name) {
                         self.debug = 6;
@@ -544,12 +772,15 @@ impl<'program, 'reader, 'thread, 'parselet> Context<'program, 'reader, 'thread,
 
     __main__-parselets are executed differently, as they handle unrecognized input as whitespace or gap,
     by skipping over it. __main__ parselets do also operate on multiple input Readers by sequence inside
-    of the Context's thread.
+    of the Context's thread. Readers in record mode are scanned record by record.
     */
     fn run_as_main(&mut self) -> Result<Accept, Reject> {
         // collected results
         let mut results = List::new();
 
+        // Set when the main parselet didn't make progress on input
+        let mut stalled = false;
+
         // Begin
         match self.execute("main begin", &self.parselet.begin) {
             Ok(Accept::Next) | Err(Reject::Skip) | Ok(Accept::Push(Capture::Empty)) => {}
@@ -565,32 +796,107 @@ impl<'program, 'reader, 'thread, 'parselet> Context<'program, 'reader, 'thread,
         };
 
         loop {
+            // Skip input before the range to be scanned
+            if let Some(range) = &self.thread.options.range {
+                while range.is_before(&self.thread.reader.origin(&self.thread.reader.tell(), false))
+                    && self.thread.reader.next().is_some()
+                {}
+            }
+
             self.reset(Some(self.thread.reader.tell()));
 
+            // Number of matches, which are main body runs that consumed input
+            let mut matches = 0;
+
+            // Restart printing matches with context
+            self.thread.context_row = 0;
+            self.thread.context_until = 0;
+            self.thread.context_matches.clear();
+
             // Body
             loop {
+                let mut value = None;
+
                 match self.execute("main body", &self.parselet.body) {
-                    Err(Reject::Next)
-                    | Err(Reject::Skip)
-                    | Ok(Accept::Next)
-                    | Ok(Accept::Push(Capture::Empty)) => {}
+                    Err(Reject::Next) => {
+                        // Discard values emitted by the rejected body
+                        self.thread.emits.truncate(self.frame.emit_start);
+                    }
+                    Err(Reject::Skip) | Ok(Accept::Next) | Ok(Accept::Push(Capture::Empty)) => {}
                     Ok(Accept::Push(mut capture)) => {
                         let res = capture.extract(&self.thread.reader);
                         if !res.is_void() {
+                            value = Some(res.clone());
                             results.push(res);
                         }
                     }
                     Ok(Accept::Repeat) => {}
                     Ok(accept) => return Ok(accept.into_push(self.parselet.severity)),
+                    Err(Reject::Error(error)) => {
+                        // Salvage results collected so far
+                        if !results.is_empty() {
+                            self.thread.partial = Some(RefValue::from(results));
+                        }
+
+                        return Err(Reject::Error(error));
+                    }
                     other => return other,
                 }
 
                 if self.frame.reader_start == self.thread.reader.tell() {
+                    // Explain why input didn't match
+                    if self.thread.options.explain {
+                        self.thread.explain();
+                    }
+
                     // Skip one character if nothing was consumed
-                    self.thread.reader.next();
+                    let skipped = self.thread.reader.next().is_some();
+
+                    // A main parselet not consuming any input can't make progress on it
+                    if skipped
+                        && self.parselet.consuming.is_none()
+                        && !self.parselet.body.is_empty()
+                        && !stalled
+                    {
+                        self.thread.warnings.push(format!(
+                            "Parselet '{}' doesn't consume any input and runs once for every character; use tokens like Char, Word or Int to read input",
+                            self.parselet.name
+                        ));
+                        stalled = true;
+                    }
 
                     // Drop all memoizations
                     self.thread.memo.clear();
+                } else {
+                    // Forget failures when input was consumed
+                    self.thread.failure = None;
+                    matches += 1;
+
+                    // Print match with context
+                    self.thread.context_match(
+                        self.frame.reader_start,
+                        self.thread.reader.tell(),
+                        value,
+                    );
+                }
+
+                // Print pending lines of context which were passed
+                self.thread.context_flush(self.thread.reader.tell().row);
+
+                // In streaming mode, discard consumed input, as main never backtracks behind this point
+                if self.thread.reader.streaming {
+                    if let Some(keep) = self.thread.context_keep() {
+                        self.thread.reader.commit_keep(keep);
+                    } else {
+                        self.thread.reader.commit();
+                    }
+                    self.thread.memo.clear();
+                    self.reader_start = self.thread.reader.tell();
+                }
+
+                // Main never enters input behind this point again
+                if let Some(profiler) = &mut self.thread.profiler {
+                    profiler.commit();
                 }
 
                 // Reset capture stack for loop repeat
@@ -600,6 +906,29 @@ impl<'program, 'reader, 'thread, 'parselet> Context<'program, 'reader, 'thread,
                 if self.thread.reader.eof() {
                     break;
                 }
+
+                // Break when maximum number of matches was reached
+                if matches >= self.thread.options.max_matches.unwrap_or(usize::MAX) {
+                    break;
+                }
+
+                // Break when the range to be scanned was left
+                if let Some(range) = &self.thread.options.range {
+                    if range
+                        .is_behind(&self.thread.reader.origin(&self.thread.reader.tell(), false))
+                    {
+                        break;
+                    }
+                }
+            }
+
+            // Print remaining lines of context
+            self.thread.context_flush(u32::MAX);
+
+            // In record mode, continue with the next record of the current reader
+            if self.thread.reader.next_record() {
+                self.thread.memo.clear();
+                continue;
             }
 
             if self.thread.readers.is_empty() {
@@ -607,6 +936,7 @@ impl<'program, 'reader, 'thread, 'parselet> Context<'program, 'reader, 'thread,
             }
 
             // Change reader within thread, and continue
+            self.thread.scrubbed += self.thread.reader.scrubbed;
             self.thread.reader = self.thread.readers.remove(0);
 
             // Drop all memoizations
@@ -643,3 +973,13 @@ impl<'program, 'reader, 'thread, 'parselet> Context<'program, 'reader, 'thread,
         }
     }
 }
+
+impl Drop for Context<'_, '_, '_, '_> {
+    // Collect the stack trace while unwinding a panic, which is turned into an error by Thread::run
+    fn drop(&mut self) {
+        if std::thread::panicking() {
+            let frame = self.trace_frame();
+            self.thread.panic_trace.push(frame);
+        }
+    }
+}
@@ -0,0 +1,200 @@
+//! Interactive debugger stepping through the parselet calls of a running program
+use super::*;
+use crate::reader::Reader;
+use crate::value::{Object, RefValue};
+use std::collections::BTreeSet;
+use std::io::{BufRead, Write};
+
+/// Mode of the debugger, deciding on the next break
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum DebugMode {
+    Step,        // Break at the next parselet entry
+    Next(usize), // Break at the next parselet entry not deeper than the given depth
+    Continue,    // Break only at breakpoints
+}
+
+/** Interactive debugger breaking at parselet entries.
+
+At every break, the debugger shows the parselet, an excerpt of the input at the current reader
+position, the captures and variables of the parselet, and then reads commands from its input:
+
+- `s`, `step`: run until the next parselet entry
+- `n`, `next`: run until the next parselet entry which isn't called by the current parselet
+- `c`, `continue`: run until a breakpoint is reached
+- `b NAME`, `break NAME`: set a breakpoint on the entry of parselet NAME
+- `d NAME`, `delete NAME`: delete the breakpoint on parselet NAME
+- `p`, `print`: show the state again
+- `q`, `quit`: stop debugging and run the program to its end
+- `h`, `help`: list the commands
+
+On the end of its input, the debugger continues like on `continue`. While stepping, parselets
+returning are reported with their status and result.
+*/
+pub struct Debugger {
+    input: Box<dyn BufRead>,       // Input commands are read from
+    output: Box<dyn Write>,        // Output the state is written to
+    mode: DebugMode,               // Current mode
+    breakpoints: BTreeSet<String>, // Names of parselets to break at
+    depth: usize,                  // Current depth of parselet calls
+}
+
+impl Debugger {
+    pub fn new(input: Box<dyn BufRead>, output: Box<dyn Write>) -> Self {
+        Self {
+            input,
+            output,
+            mode: DebugMode::Step,
+            breakpoints: BTreeSet::new(),
+            depth: 0,
+        }
+    }
+
+    /// Called on a parselet's entry, breaks when required
+    pub fn enter(&mut self, context: &Context) {
+        let brk = match self.mode {
+            DebugMode::Step => true,
+            DebugMode::Next(depth) => self.depth <= depth,
+            DebugMode::Continue => false,
+        } || self.breakpoints.contains(&context.parselet.name);
+
+        if brk {
+            // The debugger output is best effort, a failing output doesn't stop the program
+            let _ = self.show(context);
+            self.prompt(context);
+        }
+
+        self.depth += 1;
+    }
+
+    /// Called when a parselet returns, reports the result while stepping
+    pub fn leave(&mut self, parselet: &str, result: &Result<Accept, Reject>, reader: &Reader) {
+        self.depth = self.depth.saturating_sub(1);
+
+        if self.mode != DebugMode::Step {
+            return;
+        }
+
+        let status = match result {
+            Ok(Accept::Push(capture) | Accept::Return(capture)) => match capture {
+                Capture::Empty => "accepted".to_string(),
+                capture => format!(
+                    "accepted => {}",
+                    capture.clone().extract(reader).borrow().repr()
+                ),
+            },
+            Ok(_) => "accepted".to_string(),
+            Err(Reject::Next | Reject::Main) => "rejected".to_string(),
+            Err(Reject::Skip) => "skipped".to_string(),
+            Err(Reject::Error(error)) => format!("error: {}", error.message),
+            Err(Reject::Exit(code)) => format!("exited with code {}", code),
+        };
+
+        let _ = writeln!(self.output, "{} {}", parselet, status);
+    }
+
+    /// Writes the state of the parselet at its entry
+    fn show(&mut self, context: &Context) -> std::io::Result<()> {
+        let reader = &context.thread.reader;
+        let offset = reader.tell();
+        let origin = reader.origin(&offset, false);
+        let line = reader.get_line(&offset);
+
+        writeln!(
+            self.output,
+            "Break in {} at depth {}, {}:{}",
+            context.parselet.name, self.depth, origin.row, origin.col
+        )?;
+        writeln!(self.output, "  {}", line)?;
+        writeln!(self.output, "  {:>1$}", "^", offset.col as usize)?;
+
+        // Variables of the main parselet are globals, otherwise locals on the stack
+        let locals: Vec<RefValue> = if context.depth == 0 {
+            context.thread.globals.clone()
+        } else {
+            context.stack[..context.parselet.locals.min(context.stack.len())]
+                .iter()
+                .map(|capture| capture.clone().extract(reader))
+                .collect()
+        };
+
+        writeln!(self.output, "Variables:")?;
+        for (i, value) in locals.iter().enumerate() {
+            match context.parselet.signature.get(i) {
+                Some((name, _)) => writeln!(self.output, "  {} = {}", name, value.repr())?,
+                None => writeln!(self.output, "  {:>3}: {}", i, value.repr())?,
+            }
+        }
+
+        writeln!(self.output, "Captures:")?;
+        for (i, capture) in context.stack[context.frame0().capture_start..]
+            .iter()
+            .enumerate()
+        {
+            writeln!(
+                self.output,
+                "  {:>3}: {}",
+                i,
+                capture.clone().extract(reader).borrow().repr()
+            )?;
+        }
+
+        Ok(())
+    }
+
+    /// Reads and performs commands until the program shall continue
+    fn prompt(&mut self, context: &Context) {
+        loop {
+            let _ = write!(self.output, "(debug) ");
+            let _ = self.output.flush();
+
+            let mut line = String::new();
+            if !matches!(self.input.read_line(&mut line), Ok(n) if n > 0) {
+                self.mode = DebugMode::Continue;
+                return;
+            }
+
+            let mut args = line.split_whitespace();
+
+            match (args.next(), args.next()) {
+                (Some("s" | "step"), None) => {
+                    self.mode = DebugMode::Step;
+                    return;
+                }
+                (Some("n" | "next"), None) => {
+                    self.mode = DebugMode::Next(self.depth);
+                    return;
+                }
+                (Some("c" | "continue"), None) => {
+                    self.mode = DebugMode::Continue;
+                    return;
+                }
+                (Some("b" | "break"), Some(name)) => {
+                    self.breakpoints.insert(name.to_string());
+                    let _ = writeln!(self.output, "Breakpoint set on {}", name);
+                }
+                (Some("d" | "delete"), Some(name)) => {
+                    if self.breakpoints.remove(name) {
+                        let _ = writeln!(self.output, "Breakpoint on {} deleted", name);
+                    } else {
+                        let _ = writeln!(self.output, "No breakpoint on {}", name);
+                    }
+                }
+                (Some("p" | "print"), None) => {
+                    let _ = self.show(context);
+                }
+                (Some("q" | "quit"), None) => {
+                    self.breakpoints.clear();
+                    self.mode = DebugMode::Continue;
+                    return;
+                }
+                (None, _) => {}
+                _ => {
+                    let _ = writeln!(
+                        self.output,
+                        "Commands: s(tep), n(ext), c(ontinue), b(reak) NAME, d(elete) NAME, p(rint), q(uit), h(elp)"
+                    );
+                }
+            }
+        }
+    }
+}
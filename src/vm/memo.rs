@@ -0,0 +1,157 @@
+//! Packrat memoization table of a thread
+use super::*;
+use crate::reader::Offset;
+use std::collections::{BTreeSet, HashMap};
+
+/// Memoized end offset and result of a parselet
+pub type Memoized = (Offset, Result<Accept, Reject>);
+
+/** Packrat memoization table, holding results of parselets by reader offset and parselet id.
+
+Results are looked up by a hash map. Only with a `MemoPolicy::Window`, the keys are additionally
+kept ordered by reader offset, so that entries which moved out of the window can be evicted at once.
+
+Left-recursive parselets growing their seed forget results which depend on the seed, see `grow()`. */
+#[derive(Default)]
+pub struct Memo {
+    table: HashMap<(usize, usize), (Memoized, bool)>, // Results, and if they depend on seeds
+    order: BTreeSet<(usize, usize)>, // Keys ordered by reader offset, only with a window
+    seeds: Vec<((usize, usize), usize)>, // Keys of growing seeds, with their own reads
+    reads: usize,                    // Reads of growing seeds and results depending on them
+    grown: Vec<(usize, usize)>,      // Keys memoized depending on growing seeds
+}
+
+impl Memo {
+    /// Returns the memoized result of parselet `id` at reader offset `start`.
+    #[inline]
+    pub fn get(&mut self, start: usize, id: usize) -> Option<&Memoized> {
+        let key = (start, id);
+
+        if !self.seeds.is_empty() {
+            if let Some((_, reads)) = self.seeds.iter_mut().find(|(seed, _)| *seed == key) {
+                *reads += 1;
+                self.reads += 1;
+            } else if matches!(self.table.get(&key), Some((_, true))) {
+                self.reads += 1;
+            }
+        }
+
+        self.table.get(&key).map(|(memoized, _)| memoized)
+    }
+
+    /// Returns the number of reads of growing seeds so far, to be passed to `insert()` later on.
+    #[inline]
+    pub fn reads(&self) -> usize {
+        self.reads
+    }
+
+    /// Checks for a memoized result of parselet `id` at reader offset `start`.
+    #[inline]
+    pub fn contains(&self, start: usize, id: usize) -> bool {
+        self.table.contains_key(&(start, id))
+    }
+
+    /// Returns the keys of all memoized results, as reader offset and parselet id.
+    pub fn keys(&self) -> impl Iterator<Item = &(usize, usize)> {
+        self.table.keys()
+    }
+
+    /** Memoizes the result of parselet `id` started at reader offset `start`, evicting results
+    which moved out of the window of `policy`.
+
+    `reads` is the number of reads of growing seeds when the parselet started. When seeds were
+    read since, the result depends on them and is forgotten when they grow. */
+    pub fn insert(
+        &mut self,
+        start: usize,
+        id: usize,
+        memoized: Memoized,
+        policy: MemoPolicy,
+        reads: usize,
+    ) {
+        let key = (start, id);
+
+        if let MemoPolicy::Window(window) = policy {
+            if let Some(threshold) = start.checked_sub(window) {
+                if matches!(self.order.first(), Some((offset, _)) if *offset < threshold) {
+                    let keep = self.order.split_off(&(threshold, 0));
+
+                    for key in std::mem::replace(&mut self.order, keep) {
+                        self.table.remove(&key);
+                    }
+                }
+            }
+
+            self.order.insert(key);
+        }
+
+        // A seed doesn't depend on itself, see `grown()`
+        let dependent = self.reads > reads && !self.seeds.iter().any(|(seed, _)| *seed == key);
+
+        if dependent {
+            self.grown.push(key);
+        }
+
+        self.table.insert(key, (memoized, dependent));
+    }
+
+    /// Removes the memoized result of parselet `id` at reader offset `start`.
+    pub fn remove(&mut self, start: usize, id: usize) {
+        if self.table.remove(&(start, id)).is_some() && !self.order.is_empty() {
+            self.order.remove(&(start, id));
+        }
+    }
+
+    /// Removes all memoized results.
+    pub fn clear(&mut self) {
+        self.table.clear();
+        self.order.clear();
+        self.grown.clear();
+    }
+
+    /** Starts growing the seed of left-recursive parselet `id` at reader offset `start`, and
+    returns a mark for `forget()`.
+
+    Every call must be followed by a call to `grown()` when growing has finished. */
+    pub fn grow(&mut self, start: usize, id: usize) -> usize {
+        self.seeds.push(((start, id), 0));
+        self.grown.len()
+    }
+
+    /** Forgets all results at reader offset `start` memoized depending on growing seeds since `mark`.
+
+    This is used by left-recursive parselets growing their seed, as results of other parselets
+    involved into the recursion depend on the seed and become outdated with every grown result.
+    Results memoized at other offsets are kept, but no longer tracked, as they don't belong to the
+    recursion; seeds growing at the same time are either at `start` or before it. */
+    pub fn forget(&mut self, start: usize, mark: usize) {
+        for index in mark..self.grown.len() {
+            let (offset, id) = self.grown[index];
+
+            if offset == start {
+                self.remove(offset, id);
+            }
+        }
+
+        self.grown.truncate(mark);
+    }
+
+    /** Finishes growing the seed started last by `grow()`.
+
+    Reads of the seed itself are no longer counted, so that only a seed which read other seeds
+    still growing depends on them. `reads` is taken from `reads()` when the parselet started. */
+    pub fn grown(&mut self, reads: usize) {
+        let (key, own) = self.seeds.pop().unwrap();
+        self.reads -= own;
+
+        if self.seeds.is_empty() {
+            self.grown.clear();
+        } else if self.reads > reads {
+            self.grown.push(key);
+
+            if let Some((_, dependent)) = self.table.get_mut(&key) {
+                *dependent = true;
+            }
+        }
+    }
+}
@@ -2,15 +2,30 @@
 mod accept;
 mod capture;
 mod context;
+mod debugger;
+mod memo;
+mod observer;
 mod op;
+mod profiler;
 mod program;
 mod reject;
+mod serialize;
+mod snapshot;
+mod spans;
 mod thread;
+mod tracer;
 
 pub use accept::*;
 pub use capture::*;
 pub use context::*;
+pub use debugger::*;
+pub use memo::*;
+pub use observer::*;
 pub(crate) use op::*;
+pub use profiler::*;
 pub use program::*;
 pub use reject::*;
+pub use snapshot::*;
+pub use spans::*;
 pub use thread::*;
+pub use tracer::*;
@@ -0,0 +1,69 @@
+//! Observers of the events of a running program
+use super::*;
+use crate::reader::{Offset, Reader};
+use crate::value::RefValue;
+
+/** Observer of the events of a running program.
+
+Embedders implement this trait to follow the run of a program, and register it in
+`Thread::observers`. Every callback does nothing by default, so only the events of interest have
+to be implemented. The events are
+
+- `enter` when a parselet is called,
+- `leave` when a parselet returns, with its result, which holds the returned value,
+- `memo` when a parselet's result is taken from the memoization table,
+- `shift` when a token matched input, with the matched value,
+- `capture` when a capture of a parselet is set, like by `$1 = 42`.
+
+Offsets are reader offsets of the input. Results are passed along with the reader, so that captured
+ranges of the input can be extracted. The `Tracer` is an observer writing every event into an
+output.
+*/
+#[allow(unused_variables)]
+pub trait Observer {
+    /// Called when a parselet is called at an input offset
+    fn enter(&mut self, parselet: &str, start: &Offset) {}
+
+    /// Called when a parselet returns a result
+    fn leave(
+        &mut self,
+        parselet: &str,
+        start: &Offset,
+        end: &Offset,
+        result: &Result<Accept, Reject>,
+        reader: &Reader,
+    ) {
+    }
+
+    /// Called when a parselet's result is taken from the memoization table
+    fn memo(
+        &mut self,
+        parselet: &str,
+        start: &Offset,
+        end: &Offset,
+        result: &Result<Accept, Reject>,
+        reader: &Reader,
+    ) {
+    }
+
+    /// Called when a token matched input
+    fn shift(
+        &mut self,
+        token: &str,
+        start: &Offset,
+        end: &Offset,
+        result: &Result<Accept, Reject>,
+        reader: &Reader,
+    ) {
+    }
+
+    /// Called when the capture at index, with an optional alias, of a parselet is set to a value
+    fn capture(
+        &mut self,
+        parselet: &str,
+        index: usize,
+        alias: Option<&RefValue>,
+        value: &RefValue,
+    ) {
+    }
+}
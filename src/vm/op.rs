@@ -1,11 +1,39 @@
 use super::*;
 use crate::reader::Offset;
 use crate::value;
-use crate::value::{Dict, List, Object, RefValue, Str, Value};
+use crate::value::{Dict, List, Lossy, Object, ParseletRef, RefValue, Str, Value};
+use serde::{Deserialize, Serialize};
 use std::io;
 use std::io::prelude::*;
 use std::rc::Rc;
 
+// Serialization of operator names, which are mapped back to static strings on deserialization
+mod operator {
+    use serde::de::Error;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub type Name = &'static str;
+
+    const OPERATORS: &[&str] = &[
+        "add", "div", "divi", "eq", "gt", "gteq", "iadd", "idiv", "idivi", "imod", "imul", "isub",
+        "lt", "lteq", "mod", "mul", "neq", "sub", "idec", "iinc", "neg", "not",
+    ];
+
+    pub fn serialize<S: Serializer>(op: &Name, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(op)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Name, D::Error> {
+        let op = String::deserialize(deserializer)?;
+
+        OPERATORS
+            .iter()
+            .find(|name| **name == op)
+            .copied()
+            .ok_or_else(|| D::Error::custom(format!("Unknown operator '{}'", op)))
+    }
+}
+
 // --- Op ----------------------------------------------------------------------
 
 /**
@@ -13,7 +41,8 @@ Atomic operations.
 
 Specifies all atomic level VM code operations to run the Tokay VM.
 */
-#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize, Deserialize)]
+#[allow(clippy::enum_variant_names)] // UnaryOp and BinaryOp are named after their operands
 pub(crate) enum Op {
     Nop,
     Offset(Box<Offset>), // Source offset position for debugging
@@ -27,6 +56,7 @@ pub(crate) enum Op {
     ResetCapture, // Reset captures
     Close,        // Close frame
     Collect,      // Collect stack values from current frame
+    CollectList,  // Collect stack values from current frame into a list in any case
     // InCollect,    // Same as collect, but degrate the parselet level (5) (fixme: This is temporary!)
     Fuse(usize), // Set frame fuse to relative forward address
 
@@ -58,11 +88,12 @@ pub(crate) enum Op {
     Exit,       // Exit with 0
 
     // Call
-    CallOrCopy,          // Load and eventually call stack element without parameters
-    Call,                // Call stack element without parameters
-    CallArg(usize),      // Call stack element with sequential parameters
-    CallArgNamed(usize), // Call stack element with sequential and named parameters
-    CallStatic(usize),   // Call static element without parameters
+    CallOrCopy,            // Load and eventually call stack element without parameters
+    Call,                  // Call stack element without parameters
+    CallArg(usize),        // Call stack element with sequential parameters
+    CallArgNamed(usize),   // Call stack element with sequential and named parameters
+    CallStatic(usize),     // Call static element without parameters
+    CallStaticTail(usize), // Call static element without parameters in tail position
     CallStaticArg(Box<(usize, usize)>), // Call static element with sequential parameters
     CallStaticArgNamed(Box<(usize, usize)>), // Call static element with sequential and named parameters
 
@@ -92,6 +123,8 @@ pub(crate) enum Op {
     StoreCaptureHold,            // Store capture with evaluated index and keep tos
     StoreItem,                   // Store item
     StoreItemHold,               // Store item and push item reference to tos
+    StoreAttr,                   // Store attr
+    StoreAttrHold,               // Store attr and push attr reference to tos
 
     MakeAlias,       // Make key-value-Capture from last two stack items
     MakeList(usize), // Make a List from specified amount of items on stack
@@ -105,11 +138,24 @@ pub(crate) enum Op {
     Copy(usize), // copy indexed element as TOS
     Swap(usize), // swap indexed element with TOS
 
-    UnaryOp(&'static str),  // Operation with one operand
-    BinaryOp(&'static str), // Operation with two operands
+    UnaryOp(#[serde(with = "operator")] operator::Name), // Operation with one operand
+    BinaryOp(#[serde(with = "operator")] operator::Name), // Operation with two operands
 }
 
 impl Op {
+    /// Remaps the address of a static referenced by this operation.
+    pub(in crate::vm) fn remap_static(&mut self, map: impl Fn(usize) -> usize) {
+        match self {
+            Op::CallStatic(addr) | Op::CallStaticTail(addr) | Op::LoadStatic(addr) => {
+                *addr = map(*addr)
+            }
+            Op::CallStaticArg(addr_args) | Op::CallStaticArgNamed(addr_args) => {
+                addr_args.0 = map(addr_args.0)
+            }
+            _ => {}
+        }
+    }
+
     /** Runs a sequence of Ops on a given Context.
 
     This function is the heart of the Tokay VM, and executes the individual instructions.
@@ -206,6 +252,7 @@ impl Op {
                         fuse: if *fuse > 0 { Some(ip + *fuse) } else { None },
                         capture_start: context.stack.len(),
                         reader_start: context.thread.reader.tell(),
+                        emit_start: context.thread.emits.len(),
                     };
 
                     Ok(Accept::Next)
@@ -224,12 +271,12 @@ impl Op {
 
                 Op::Reset => {
                     context.stack.truncate(context.frame.capture_start);
-                    context.thread.reader.reset(context.frame.reader_start);
+                    context.rewind()?;
                     Ok(Accept::Next)
                 }
 
                 Op::ResetReader => {
-                    context.thread.reader.reset(context.frame.reader_start);
+                    context.rewind()?;
                     Ok(Accept::Next)
                 }
 
@@ -250,6 +297,10 @@ impl Op {
                     context.thread.debug > 5,
                 ))),
 
+                Op::CollectList => Ok(Accept::Push(
+                    context.collect_list(context.frame.capture_start, context.thread.debug > 5),
+                )),
+
                 /*
                 Op::InCollect => {
                     let mut capture =
@@ -307,6 +358,9 @@ impl Op {
                 }
 
                 Op::Continue => {
+                    context.thread.check_cancel()?;
+                    context.thread.check_values()?;
+
                     let current = context
                         .loops
                         .last()
@@ -390,10 +444,8 @@ impl Op {
                     state = Err(Reject::Next);
                     break;
                 }
-                Op::LoadExit => {
-                    std::process::exit(context.pop().to_i64()? as i32);
-                }
-                Op::Exit => std::process::exit(0),
+                Op::LoadExit => Err(Reject::Exit(context.pop().to_i64()? as i32)),
+                Op::Exit => Err(Reject::Exit(0)),
 
                 // Calls
                 Op::CallOrCopy => {
@@ -440,8 +492,48 @@ impl Op {
                     }
                 }
 
-                Op::CallStatic(addr) => {
-                    context.thread.program.statics[*addr].call_direct(context, 0, None)
+                Op::CallStatic(addr) | Op::CallStaticTail(addr) => {
+                    let target = &context.thread.program.statics[*addr];
+
+                    // Leave the call of a parselet to the calling parselet when this context
+                    // would only collect its result, so its context can be released.
+                    if matches!(op, Op::CallStaticTail(_))
+                        && target.borrow().object::<ParseletRef>().is_some()
+                        && context.is_tail_position(ip)
+                    {
+                        context.thread.tail_call = Some(target.clone());
+                        context.thread.tail_captures = context.take_tail_captures(ops, ip);
+                        Ok(Accept::Return(Capture::Empty))
+                    } else {
+                        let start = context.thread.reader.tell();
+                        let result = target.call_direct(context, 0, None);
+
+                        // Notify observers about token matches
+                        if result.is_ok()
+                            && !context.thread.observers.is_empty()
+                            && target.is_consuming()
+                            && target.borrow().object::<ParseletRef>().is_none()
+                        {
+                            let thread = &mut *context.thread;
+                            let end = thread.reader.tell();
+                            let token = target.repr();
+
+                            for observer in thread.observers.iter_mut() {
+                                observer.shift(&token, &start, &end, &result, thread.reader);
+                            }
+                        }
+
+                        // Record failed token matches to explain why input didn't match
+                        if context.thread.options.explain
+                            && matches!(result, Err(Reject::Next))
+                            && target.is_consuming()
+                            && target.borrow().object::<ParseletRef>().is_none()
+                        {
+                            context.thread.fail(target.repr());
+                        }
+
+                        result
+                    }
                 }
 
                 Op::CallStaticArg(addr_args) => {
@@ -469,8 +561,20 @@ impl Op {
 
                 // Variables and values
                 Op::LoadStatic(addr) => {
-                    let value = &context.thread.program.statics[*addr];
-                    context.push(value.borrow().clone().into())
+                    let value = context.thread.program.statics[*addr].borrow();
+
+                    // Preloaded lists are copied with their items, as both may be modified
+                    if let Some(list) = value.object::<List>() {
+                        let mut copy = List::with_capacity(list.len());
+                        for item in list.iter() {
+                            copy.push(RefValue::from(item.borrow().clone()));
+                        }
+                        drop(value);
+                        context.push(RefValue::from(copy))
+                    } else {
+                        let value = value.clone().into();
+                        context.push(value)
+                    }
                 }
                 Op::Push0 => context.push(value!(0i64)),
                 Op::Push1 => context.push(value!(1i64)),
@@ -525,11 +629,23 @@ impl Op {
                 Op::LoadAttr => {
                     let attr = context.pop();
                     let attr = attr.borrow();
+                    let attr = attr.object::<Str>().unwrap().as_str();
                     let value = context.pop();
 
-                    match value.create_method(attr.object::<Str>().unwrap().as_str()) {
+                    match value.create_method(attr) {
                         Ok(value) => context.push(value),
-                        Err(err) => err.into(),
+                        Err(err) => {
+                            // Dict items can be accessed as attributes, unless shadowed by a method
+                            let item = value
+                                .borrow()
+                                .object::<Dict>()
+                                .and_then(|dict| dict.get_str(attr).cloned());
+
+                            match item {
+                                Some(item) => context.push(item),
+                                None => err.into(),
+                            }
+                        }
                     }
                 }
 
@@ -621,6 +737,34 @@ impl Op {
                     }
                 }
 
+                Op::StoreAttr | Op::StoreAttrHold => {
+                    let attr = context.pop();
+                    let object = context.pop();
+                    let value = context.pop();
+
+                    // Only dicts accept attribute assignment, by using the attribute name as key
+                    if !object.is("dict") {
+                        Err(Reject::from(format!(
+                            "Cannot assign attribute '{}' to '{}'",
+                            attr.to_string(),
+                            object.name()
+                        )))
+                    } else {
+                        match object.call_method("set_item", Some(context), vec![attr, value]) {
+                            Ok(value) => {
+                                let value = value.unwrap(); // setitem must always return a value!
+
+                                if matches!(op, Op::StoreAttrHold) {
+                                    context.push(value)
+                                } else {
+                                    Ok(Accept::Next)
+                                }
+                            }
+                            Err(msg) => Err(Reject::from(msg)),
+                        }
+                    }
+                }
+
                 Op::MakeAlias => {
                     let name = context.pop();
 
@@ -629,8 +773,9 @@ impl Op {
                             *alias = Some(name);
                         }
 
+                        // An empty capture, e.g. of an unmatched optional, becomes null
                         empty => {
-                            *empty = Capture::Value(value!(null), Some(name), 10);
+                            *empty = Capture::Value(value!(null), Some(name), 5);
                         }
                     }
 
@@ -651,11 +796,18 @@ impl Op {
                 }
 
                 Op::MakeDict(count) => {
-                    let mut dict = Dict::new();
+                    let mut items = Vec::with_capacity(*count);
 
                     for _ in 0..*count {
                         let key = context.pop();
                         let value = context.pop();
+                        items.push((key, value));
+                    }
+
+                    // Keep the order in which the items were pushed
+                    let mut dict = Dict::new();
+
+                    for (key, value) in items.into_iter().rev() {
                         dict.insert(key, value);
                     }
 
@@ -716,13 +868,22 @@ impl Op {
 
                 Op::UnaryOp(op) => {
                     let value = context.pop();
-                    context.push(value.unary_op(op)?)
+                    context.push(value.unary_op_overflow(op, context.thread.options.overflow)?)
                 }
 
                 Op::BinaryOp(op) => {
                     let last = context.pop();
                     let first = context.pop();
-                    context.push(first.binary_op(last, op)?)
+
+                    if context.thread.options.lossy != Lossy::Ignore {
+                        context.lossy(first.lossy_operands(&last, op))?;
+                    }
+
+                    context.push(first.binary_op_overflow(
+                        last,
+                        op,
+                        context.thread.options.overflow,
+                    )?)
                 }
             };
 
@@ -741,7 +902,8 @@ impl Op {
                 }
                 Err(Reject::Next) if context.frames.len() > 0 => loop {
                     context.stack.truncate(context.frame.capture_start);
-                    context.thread.reader.reset(context.frame.reader_start);
+                    context.thread.emits.truncate(context.frame.emit_start);
+                    context.rewind()?;
 
                     if let Some(fuse) = context.frame.fuse {
                         if fuse > ip {
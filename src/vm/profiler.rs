@@ -0,0 +1,156 @@
+//! Profiler collecting the time spent in parselets
+use std::collections::{BTreeMap, HashSet};
+use std::time::{Duration, Instant};
+
+/** Profiler measuring the time spent in parselet call stacks.
+
+The time spent in a parselet itself, without the time spent in parselets it called, is summed up
+per call stack. These folded stacks can be written in the format used by `flamegraph.pl` and
+`inferno-flamegraph` to render a flame graph.
+
+Per parselet, the profiler furthermore sums up the calls, their results, the total time including
+called parselets, the time spent in the parselet itself, and the bytes of input consumed.
+
+The profiler also counts how often parselets are re-entered at the same input offset.
+Parselets re-entered at least `HOT_REENTRIES` times are considered hot, and are candidates for the
+`cache` keyword. The profiler is purely observational, so profiling doesn't change how a program runs.
+*/
+#[derive(Debug, Default)]
+pub struct Profiler {
+    stack: Vec<(Instant, Duration)>, // Start and time spent in called parselets of active parselets
+    names: Vec<String>,              // Names of active parselets
+    path: Vec<String>,               // Names of active parselets, as used in folded stacks
+    pub folded: BTreeMap<String, Duration>, // Time spent by folded call stack
+    pub stats: BTreeMap<String, ParseletStats>, // Statistics by parselet
+    entered: HashSet<(String, usize)>, // Parselets and input offsets entered since last commit
+    pub reentries: BTreeMap<String, usize>, // Number of re-entries at the same offset by parselet
+}
+
+/// Statistics of the calls of a parselet
+#[derive(Debug, Default, Clone)]
+pub struct ParseletStats {
+    pub calls: usize,    // Number of calls
+    pub accepted: usize, // Number of calls which accepted
+    pub total: Duration, // Time spent in outermost calls, including called parselets
+    pub own: Duration,   // Time spent in the parselet itself
+    pub consumed: usize, // Bytes of input consumed by accepted calls
+    active: usize,       // Number of calls currently running
+}
+
+/// Number of re-entries at the same input offset which make a parselet hot
+pub const HOT_REENTRIES: usize = 8;
+
+impl Profiler {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Enter a parselet with the given name at an input offset
+    pub fn enter(&mut self, name: &str, offset: usize) {
+        if !self.entered.insert((name.to_string(), offset)) {
+            *self.reentries.entry(name.to_string()).or_default() += 1;
+        }
+
+        let stats = self.stats.entry(name.to_string()).or_default();
+        stats.calls += 1;
+        stats.active += 1;
+
+        // Names must not contain the stack separator
+        self.names.push(name.to_string());
+        self.path.push(name.replace([';', ' '], "_"));
+        self.stack.push((Instant::now(), Duration::ZERO));
+    }
+
+    /// Checks if the parselet with the given name is frequently re-entered at the same offset
+    pub fn is_hot(&self, name: &str) -> bool {
+        self.reentries
+            .get(name)
+            .is_some_and(|reentries| *reentries >= HOT_REENTRIES)
+    }
+
+    /// Forget the input offsets entered, as input before the current offset isn't entered again
+    pub fn commit(&mut self) {
+        self.entered.clear();
+    }
+
+    /// Leave the most recently entered parselet, which accepted or rejected after consuming input
+    pub fn leave(&mut self, accepted: bool, consumed: usize) {
+        let (Some((start, called)), Some(name)) = (self.stack.pop(), self.names.pop()) else {
+            return;
+        };
+
+        let elapsed = start.elapsed();
+        let own = elapsed.saturating_sub(called);
+
+        *self.folded.entry(self.path.join(";")).or_default() += own;
+        self.path.pop();
+
+        let stats = self.stats.get_mut(&name).unwrap();
+        stats.active -= 1;
+        stats.own += own;
+
+        // Recursive calls are already part of the total time of the outermost call
+        if stats.active == 0 {
+            stats.total += elapsed;
+        }
+
+        if accepted {
+            stats.accepted += 1;
+            stats.consumed += consumed;
+        }
+
+        if let Some((_, called)) = self.stack.last_mut() {
+            *called += elapsed;
+        }
+    }
+
+    /// Write a report of the statistics by parselet, ordered by their own time, as a table.
+    pub fn write_report(&self, mut output: impl std::io::Write) -> std::io::Result<()> {
+        let mut stats: Vec<(&String, &ParseletStats)> = self.stats.iter().collect();
+        stats.sort_by(|a, b| b.1.own.cmp(&a.1.own).then(a.0.cmp(b.0)));
+
+        let width = stats
+            .iter()
+            .map(|(name, _)| name.len())
+            .max()
+            .unwrap_or_default()
+            .max("Parselet".len());
+
+        writeln!(
+            output,
+            "{:<width$} {:>10} {:>9} {:>12} {:>12} {:>12}",
+            "Parselet",
+            "Calls",
+            "Accepted",
+            "Total ms",
+            "Self ms",
+            "Bytes",
+            width = width
+        )?;
+
+        for (name, stats) in stats {
+            writeln!(
+                output,
+                "{:<width$} {:>10} {:>8.1}% {:>12.3} {:>12.3} {:>12}",
+                name,
+                stats.calls,
+                stats.accepted as f64 * 100.0 / stats.calls as f64,
+                stats.total.as_secs_f64() * 1000.0,
+                stats.own.as_secs_f64() * 1000.0,
+                stats.consumed,
+                width = width
+            )?;
+        }
+
+        Ok(())
+    }
+
+    /// Write folded stacks weighted by nanoseconds, one per line.
+    pub fn write_folded(&self, mut output: impl std::io::Write) -> std::io::Result<()> {
+        for (stack, time) in &self.folded {
+            writeln!(output, "{} {}", stack, time.as_nanos())?;
+        }
+
+        Ok(())
+    }
+}
@@ -1,9 +1,47 @@
 use super::*;
+use crate::compiler::Compiler;
 use crate::error::Error;
 use crate::reader::Reader;
-use crate::value::{ParseletRef, RefValue};
-use std::fs::File;
-use std::io;
+use crate::value::{ParseletRef, RefValue, Token};
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+/// Statistics of a compiled program
+#[derive(Debug, Clone, Default)]
+pub struct ProgramStats {
+    pub parselets: usize,      // Number of parselets
+    pub rules: usize,          // Number of consuming parselets
+    pub tokens: usize,         // Number of distinct tokens
+    pub nullable: Vec<String>, // Names of rules which may match empty input
+    pub leftrec: Vec<String>,  // Names of left-recursive rules
+    pub ops: usize,            // Number of operations of all parselets
+    pub statics: usize,        // Number of statics
+    pub size: usize,           // Size of the compiled program in bytes
+}
+
+impl std::fmt::Display for ProgramStats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "Parselets: {} ({} rules)", self.parselets, self.rules)?;
+        writeln!(f, "Tokens: {}", self.tokens)?;
+
+        for (what, names) in [
+            ("Nullable rules", &self.nullable),
+            ("Left-recursive rules", &self.leftrec),
+        ] {
+            if names.is_empty() {
+                writeln!(f, "{}: none", what)?;
+            } else {
+                writeln!(f, "{}: {}", what, names.join(", "))?;
+            }
+        }
+
+        write!(
+            f,
+            "Size: {} operations, {} statics, {} bytes compiled",
+            self.ops, self.statics, self.size
+        )
+    }
+}
 
 /** Programs are containers holding statics and a pointer to the main parselet.
 
@@ -11,12 +49,151 @@ A program is the result of a successful compiler run. */
 #[derive(Debug)]
 pub struct Program {
     pub(crate) statics: Vec<RefValue>, // Static values referenced by this program
+    pub sandbox: bool,                 // Fail on builtins touching the environment
 }
 
 impl Program {
     pub fn new(statics: Vec<RefValue>) -> Self {
         //println!("Program with {} statics in total", statics.len());
-        Self { statics }
+        Self {
+            statics,
+            sandbox: false,
+        }
+    }
+
+    /** Compiles a Tokay program from a source string.
+
+    ```
+    use tokay::{Program, value};
+
+    let program = Program::from_source("Int ($1 * 2)").unwrap();
+    assert_eq!(program.run_on_str("1 2").unwrap(), Some(value!([2, 4])));
+    ```
+    */
+    pub fn from_source(src: &str) -> Result<Self, Vec<Error>> {
+        Ok(Compiler::new()
+            .compile_from_str(src)?
+            .expect("Compiler must return a program"))
+    }
+
+    /** Deduplicates structurally identical parselets, and returns the number of removed statics.
+
+    Parselets are identical when they only differ in source offsets, and in the addresses of
+    statics which are identical themselves. This is mostly the case for inline parselets with
+    equal bodies. The first of identical parselets is kept, and all references are remapped to it,
+    so that its memoized results are shared as well. */
+    pub(crate) fn deduplicate(&mut self) -> usize {
+        let mut map: Vec<usize> = (0..self.statics.len()).collect();
+
+        // Resolve the address of the parselet a static was merged into
+        fn resolve(map: &[usize], mut addr: usize) -> usize {
+            while map[addr] != addr {
+                addr = map[addr];
+            }
+
+            addr
+        }
+
+        // Merge identical parselets until nothing changes; merging a parselet may turn
+        // parselets referring to it into identical ones.
+        loop {
+            let mut seen = HashMap::new();
+            let mut changes = false;
+
+            for addr in 0..self.statics.len() {
+                if map[addr] != addr {
+                    continue;
+                }
+
+                let static_ = self.statics[addr].borrow();
+                let Some(parselet) = static_.object::<ParseletRef>() else {
+                    continue;
+                };
+                let parselet = parselet.0.borrow();
+
+                let ops = |ops: &[Op]| -> Vec<Op> {
+                    ops.iter()
+                        .filter(|op| !matches!(op, Op::Offset(_)))
+                        .map(|op| {
+                            let mut op = op.clone();
+                            op.remap_static(|addr| resolve(&map, addr));
+                            op
+                        })
+                        .collect()
+                };
+
+                let key = (
+                    parselet.name.clone(),
+                    parselet.consuming,
+                    parselet.memo,
+                    parselet.nullable,
+                    parselet.severity,
+                    parselet
+                        .signature
+                        .iter()
+                        .map(|(name, default)| {
+                            (name.clone(), default.map(|addr| resolve(&map, addr)))
+                        })
+                        .collect::<Vec<_>>(),
+                    parselet.locals,
+                    ops(&parselet.begin),
+                    ops(&parselet.end),
+                    ops(&parselet.body),
+                );
+
+                if let Some(first) = seen.get(&key) {
+                    map[addr] = *first;
+                    changes = true;
+                } else {
+                    seen.insert(key, addr);
+                }
+            }
+
+            if !changes {
+                break;
+            }
+        }
+
+        // Assign new addresses to the remaining statics
+        let mut addrs = vec![0; self.statics.len()];
+        let mut statics = Vec::new();
+
+        for addr in 0..self.statics.len() {
+            if map[addr] == addr {
+                addrs[addr] = statics.len();
+                statics.push(self.statics[addr].clone());
+            }
+        }
+
+        let removed = self.statics.len() - statics.len();
+
+        if removed == 0 {
+            return 0;
+        }
+
+        let remap = |addr: usize| addrs[resolve(&map, addr)];
+
+        for static_ in &statics {
+            if let Some(parselet) = static_.borrow().object::<ParseletRef>() {
+                let parselet = &mut *parselet.0.borrow_mut();
+
+                for (_, default) in parselet.signature.iter_mut() {
+                    *default = default.map(remap);
+                }
+
+                for op in parselet
+                    .begin
+                    .iter_mut()
+                    .chain(parselet.end.iter_mut())
+                    .chain(parselet.body.iter_mut())
+                {
+                    op.remap_static(remap);
+                }
+            }
+        }
+
+        self.statics = statics;
+        removed
     }
 
     /// Returns a reference to the program's main parselet.
@@ -32,6 +209,47 @@ impl Program {
         panic!("No main parselet found")
     }
 
+    /** Returns statistics of the program.
+
+    Rules are consuming parselets, which are reported as nullable when their body matches empty
+    input, like a sequence of optional items, and as left-recursive when they call themselves
+    before consuming input. The size is the length
+    of the program's JSON representation, as written by `to_json()`. */
+    pub fn stats(&self) -> ProgramStats {
+        let mut stats = ProgramStats {
+            statics: self.statics.len(),
+            size: self.to_json().map_or(0, |json| json.len()),
+            ..Default::default()
+        };
+
+        for value in &self.statics {
+            let value = value.borrow();
+
+            if value.object::<Token>().is_some() {
+                stats.tokens += 1;
+            } else if let Some(parselet) = value.object::<ParseletRef>() {
+                let parselet = parselet.0.borrow();
+
+                stats.parselets += 1;
+                stats.ops += parselet.begin.len() + parselet.end.len() + parselet.body.len();
+
+                if let Some(leftrec) = parselet.consuming {
+                    stats.rules += 1;
+
+                    if parselet.nullable {
+                        stats.nullable.push(parselet.name.clone());
+                    }
+
+                    if leftrec {
+                        stats.leftrec.push(parselet.name.clone());
+                    }
+                }
+            }
+        }
+
+        stats
+    }
+
     pub fn dump(&self) {
         for i in 0..self.statics.len() {
             println!("{} => {:#?}", i, self.statics[i]);
@@ -42,6 +260,41 @@ impl Program {
         Thread::new(self, vec![&mut reader]).run()
     }
 
+    pub fn run_from_reader_with_options(
+        &self,
+        mut reader: Reader,
+        options: RunOptions,
+    ) -> Result<Option<RefValue>, Error> {
+        reader.scrub = options.scrub;
+
+        let mut thread = Thread::new(self, vec![&mut reader]);
+        thread.options = options;
+        thread.run()
+    }
+
+    /** Runs the program on a reader, and cancels the run when it takes longer than `timeout`.
+
+    A cancelled run fails with an error of code `cancelled`. To cancel a run on demand, like from
+    another thread, set `RunOptions::cancel` instead. */
+    pub fn run_with_timeout(
+        &self,
+        reader: Reader,
+        timeout: Duration,
+    ) -> Result<Option<RefValue>, Error> {
+        self.run_from_reader_with_options(
+            reader,
+            RunOptions {
+                deadline: Some(Instant::now() + timeout),
+                ..Default::default()
+            },
+        )
+    }
+
+    /// Runs the program on a list of values, like tokens produced by another program.
+    pub fn run_from_values(&self, values: Vec<RefValue>) -> Result<Option<RefValue>, Error> {
+        self.run_from_reader(Reader::from_values(values))
+    }
+
     pub fn run_from_str(&self, src: &'static str) -> Result<Option<RefValue>, Error> {
         self.run_from_reader(Reader::new(None, Box::new(std::io::Cursor::new(src))))
     }
@@ -50,16 +303,37 @@ impl Program {
         self.run_from_reader(Reader::new(None, Box::new(std::io::Cursor::new(src))))
     }
 
+    /// Runs the program on an input string.
+    pub fn run_on_str(&self, input: &str) -> Result<Option<RefValue>, Error> {
+        self.run_from_string(input.to_owned())
+    }
+
+    /** Runs the program on an input string, and returns the spans of all accepted rules.
+
+    Spans are ordered by their start, with enclosing spans before the spans within, and limited to
+    rules nested up to `max_depth` levels, when given. This is meant for editors highlighting and
+    folding input by a grammar. Parselets inlined by the compiler have no spans, so programs should
+    be compiled with `Compiler::inline` disabled to get spans of all rules. */
+    pub fn spans(&self, input: &str, max_depth: Option<usize>) -> Result<Vec<Span>, Error> {
+        let mut reader = Reader::new(None, Box::new(std::io::Cursor::new(input.to_owned())));
+        let mut thread = Thread::new(self, vec![&mut reader]);
+        thread.spans = Some(Spans::new());
+        thread.run()?;
+
+        Ok(thread.spans.take().unwrap().into_spans(max_depth))
+    }
+
     pub fn run_from_file(&self, filename: &str) -> Result<Option<RefValue>, Error> {
         if filename == "-" {
-            self.run_from_reader(Reader::new(Some("-".to_string()), Box::new(io::stdin())))
-        } else if let Ok(file) = File::open(filename) {
-            self.run_from_reader(Reader::new(Some(filename.to_string()), Box::new(file)))
+            self.run_from_reader(Reader::from_stdin())
         } else {
-            Err(Error::new(
-                None,
-                format!("Unable to read from filename '{}'", filename),
-            ))
+            match Reader::from_file(filename) {
+                Ok(reader) => self.run_from_reader(reader),
+                Err(err) => Err(Error::new(
+                    None,
+                    format!("Unable to read from filename '{}': {}", filename, err),
+                )),
+            }
         }
     }
 }
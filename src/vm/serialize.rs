@@ -0,0 +1,265 @@
+//! Serialization of compiled programs into an intermediate representation
+use super::*;
+use crate::builtin::{Builtin, BuiltinRef};
+use crate::error::Error;
+use crate::value::{Ccl, Dict, List, Object, Parselet, ParseletRef, RefValue, Str, Token, Value};
+use charclass::CharClass;
+use serde::{Deserialize, Serialize};
+
+/// Intermediate representation of a static value of a program
+#[derive(Serialize, Deserialize)]
+enum Static {
+    Void,
+    Null,
+    True,
+    False,
+    Int(String), // Integers are stored as strings, as they might exceed 64 bits
+    Float(f64),
+    Str(String),
+    List(Vec<Static>),
+    Dict(Vec<(Static, Static)>),
+    Token(StaticToken),
+    Builtin(String),
+    Parselet(StaticParselet),
+}
+
+/// Intermediate representation of a token
+#[derive(Serialize, Deserialize)]
+enum StaticToken {
+    Empty,
+    Eof,
+    Char(Vec<(char, char)>),
+    BuiltinChar(String),
+    Chars(Vec<(char, char)>),
+    BuiltinChars(String),
+    Match(String),
+    Touch(String),
+    MatchCaseless(String),
+    TouchCaseless(String),
+}
+
+/// Intermediate representation of a parselet
+#[derive(Serialize, Deserialize)]
+struct StaticParselet {
+    name: String,
+    consuming: Option<bool>,
+    memo: bool,
+    #[serde(default)]
+    nullable: bool,
+    severity: u8,
+    signature: Vec<(String, Option<usize>)>,
+    locals: usize,
+    begin: Vec<Op>,
+    end: Vec<Op>,
+    body: Vec<Op>,
+}
+
+/// Intermediate representation of a program, tagged with the Tokay version it was compiled with
+#[derive(Serialize, Deserialize)]
+struct StaticProgram {
+    tokay: String,
+    statics: Vec<Static>,
+    #[serde(default)]
+    sandbox: bool,
+}
+
+/// Build a character class from ranges
+fn ccl_from_ranges(ranges: Vec<(char, char)>) -> CharClass {
+    let mut ccl = CharClass::new();
+
+    for (start, end) in ranges {
+        ccl.add(start..=end);
+    }
+
+    ccl
+}
+
+impl Static {
+    fn from_value(value: &RefValue) -> Result<Self, Error> {
+        let value = value.borrow();
+
+        Ok(match &*value {
+            Value::Void => Self::Void,
+            Value::Null => Self::Null,
+            Value::Bool(true) => Self::True,
+            Value::Bool(false) => Self::False,
+            Value::Int(i) => Self::Int(i.to_string()),
+            Value::Float(f) => Self::Float(*f),
+            Value::Object(object) => {
+                let object = object.as_any();
+
+                if let Some(s) = object.downcast_ref::<Str>() {
+                    Self::Str(s.as_str().to_string())
+                } else if let Some(list) = object.downcast_ref::<List>() {
+                    Self::List(
+                        list.iter()
+                            .map(Self::from_value)
+                            .collect::<Result<_, _>>()?,
+                    )
+                } else if let Some(dict) = object.downcast_ref::<Dict>() {
+                    Self::Dict(
+                        dict.iter()
+                            .map(|(key, value)| {
+                                Ok((Self::from_value(key)?, Self::from_value(value)?))
+                            })
+                            .collect::<Result<_, Error>>()?,
+                    )
+                } else if let Some(token) = object.downcast_ref::<Token>() {
+                    Self::Token(match token {
+                        Token::Empty => StaticToken::Empty,
+                        Token::EOF => StaticToken::Eof,
+                        Token::Char(ccl) => StaticToken::Char(Ccl(ccl).ranges()),
+                        Token::BuiltinChar(name, _) => StaticToken::BuiltinChar(name.to_string()),
+                        Token::Chars(ccl) => StaticToken::Chars(Ccl(ccl).ranges()),
+                        Token::BuiltinChars(name, _) => StaticToken::BuiltinChars(name.to_string()),
+                        Token::Match(s) => StaticToken::Match(s.clone()),
+                        Token::Touch(s) => StaticToken::Touch(s.clone()),
+                        Token::MatchCaseless(s) => StaticToken::MatchCaseless(s.clone()),
+                        Token::TouchCaseless(s) => StaticToken::TouchCaseless(s.clone()),
+                    })
+                } else if let Some(builtin) = object.downcast_ref::<BuiltinRef>() {
+                    Self::Builtin(builtin.0.name.to_string())
+                } else if let Some(parselet) = object.downcast_ref::<ParseletRef>() {
+                    let parselet = parselet.0.borrow();
+
+                    Self::Parselet(StaticParselet {
+                        name: parselet.name.clone(),
+                        consuming: parselet.consuming,
+                        memo: parselet.memo,
+                        nullable: parselet.nullable,
+                        severity: parselet.severity,
+                        signature: parselet.signature.clone(),
+                        locals: parselet.locals,
+                        begin: parselet.begin.clone(),
+                        end: parselet.end.clone(),
+                        body: parselet.body.clone(),
+                    })
+                } else {
+                    return Err(format!("Cannot serialize static value {}", value.repr()).into());
+                }
+            }
+        })
+    }
+
+    fn into_value(self) -> Result<RefValue, Error> {
+        Ok(match self {
+            Self::Void => RefValue::from(Value::Void),
+            Self::Null => RefValue::from(Value::Null),
+            Self::True => RefValue::from(Value::Bool(true)),
+            Self::False => RefValue::from(Value::Bool(false)),
+            Self::Int(i) => RefValue::from(Value::Int(
+                i.parse()
+                    .map_err(|_| Error::from(format!("Invalid integer '{}'", i)))?,
+            )),
+            Self::Float(f) => RefValue::from(f),
+            Self::Str(s) => RefValue::from(s),
+            Self::List(items) => {
+                let mut list = List::new();
+
+                for item in items {
+                    list.push(item.into_value()?);
+                }
+
+                RefValue::from(list)
+            }
+            Self::Dict(items) => {
+                let mut dict = Dict::new();
+
+                for (key, value) in items {
+                    dict.insert(key.into_value()?, value.into_value()?);
+                }
+
+                RefValue::from(dict)
+            }
+            Self::Token(token) => {
+                let builtin = |name: String| {
+                    Token::builtin(&name)
+                        .ok_or_else(|| Error::from(format!("Token '{}' not found", name)))
+                };
+
+                RefValue::from(match token {
+                    StaticToken::Empty => Token::Empty,
+                    StaticToken::Eof => Token::EOF,
+                    StaticToken::Char(ranges) => Token::Char(ccl_from_ranges(ranges)),
+                    StaticToken::BuiltinChar(name) => builtin(name)?,
+                    StaticToken::Chars(ranges) => Token::Chars(ccl_from_ranges(ranges)),
+                    StaticToken::BuiltinChars(name) => builtin(name + "s")?,
+                    StaticToken::Match(s) => Token::Match(s),
+                    StaticToken::Touch(s) => Token::Touch(s),
+                    StaticToken::MatchCaseless(s) => Token::MatchCaseless(s),
+                    StaticToken::TouchCaseless(s) => Token::TouchCaseless(s),
+                })
+            }
+            Self::Builtin(name) => RefValue::from(
+                Builtin::get(&name)
+                    .ok_or_else(|| Error::from(format!("Builtin '{}' not found", name)))?,
+            ),
+            Self::Parselet(parselet) => {
+                if parselet.signature.len() > parselet.locals {
+                    return Err(format!("Parselet '{}' is corrupted", parselet.name).into());
+                }
+
+                let mut ret = Parselet::new(
+                    Some(parselet.name),
+                    parselet.consuming,
+                    parselet.severity,
+                    parselet.signature,
+                    parselet.locals,
+                    parselet.begin,
+                    parselet.end,
+                    parselet.body,
+                );
+                ret.memo = parselet.memo;
+                ret.nullable = parselet.nullable;
+
+                RefValue::from(ret)
+            }
+        })
+    }
+}
+
+impl Program {
+    /** Serializes the compiled program into a JSON intermediate representation.
+
+    The representation can be stored and loaded with `Program::from_json()` later on, to avoid
+    compiling the program again. It is bound to the Tokay version it was created with. */
+    pub fn to_json(&self) -> Result<String, Error> {
+        let program = StaticProgram {
+            tokay: env!("CARGO_PKG_VERSION").to_string(),
+            statics: self
+                .statics
+                .iter()
+                .map(Static::from_value)
+                .collect::<Result<_, _>>()?,
+            sandbox: self.sandbox,
+        };
+
+        serde_json::to_string(&program).map_err(|err| Error::from(err.to_string()))
+    }
+
+    /// Loads a program from its JSON intermediate representation created by `Program::to_json()`.
+    pub fn from_json(json: &str) -> Result<Self, Error> {
+        let program: StaticProgram =
+            serde_json::from_str(json).map_err(|err| Error::from(err.to_string()))?;
+
+        if program.tokay != env!("CARGO_PKG_VERSION") {
+            return Err(format!(
+                "Program was compiled by Tokay {}, but this is Tokay {}",
+                program.tokay,
+                env!("CARGO_PKG_VERSION")
+            )
+            .into());
+        }
+
+        let mut loaded = Self::new(
+            program
+                .statics
+                .into_iter()
+                .map(Static::into_value)
+                .collect::<Result<_, _>>()?,
+        );
+        loaded.sandbox = program.sandbox;
+
+        Ok(loaded)
+    }
+}
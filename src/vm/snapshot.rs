@@ -0,0 +1,113 @@
+//! Snapshots of the state of a running program
+use crate::reader::Offset;
+use crate::value;
+use crate::value::{Dict, List, Object, RefValue};
+
+/// A frame within a snapshot
+#[derive(Debug, Clone)]
+pub struct SnapshotFrame {
+    pub captures: usize, // Number of captures taken within the frame
+    pub start: Offset,   // Reader offset where the frame started
+}
+
+/** Snapshot of the state of a running program.
+
+A snapshot is taken from the context of the currently running parselet. It can be turned into
+a value, e.g. to be inspected by a program, or printed for humans.
+
+The names of all active parselets are only known when they are tracked, which is the case for
+`RunOptions::explain` and `RunOptions::post_mortem`; otherwise, only the current parselet is listed.
+*/
+#[derive(Debug, Clone)]
+pub struct Snapshot {
+    pub parselet: String,           // Name of the current parselet
+    pub depth: usize,               // Recursion depth of the current parselet
+    pub filename: Option<String>,   // Input filename
+    pub offset: Offset,             // Current reader offset
+    pub parselets: Vec<String>,     // Names of the active parselets, outermost first
+    pub frames: Vec<SnapshotFrame>, // Frames of the current parselet, outermost first
+    pub captures: usize,            // Number of captures on the stack of the current parselet
+    pub globals: Vec<RefValue>,     // Global variables
+}
+
+impl From<&Snapshot> for RefValue {
+    fn from(snapshot: &Snapshot) -> Self {
+        let offset = |offset: &Offset| {
+            value!([
+                "offset" => (offset.offset),
+                "row" => (offset.row as usize),
+                "col" => (offset.col as usize)
+            ])
+        };
+
+        let mut frames = List::new();
+
+        for frame in &snapshot.frames {
+            frames.push(value!([
+                "captures" => (frame.captures),
+                "start" => (offset(&frame.start))
+            ]));
+        }
+
+        let mut ret = Dict::new();
+        ret.insert_str("parselet", value!(snapshot.parselet.clone()));
+        ret.insert_str("depth", value!(snapshot.depth));
+        ret.insert_str(
+            "filename",
+            match &snapshot.filename {
+                Some(filename) => value!(filename.clone()),
+                None => value!(void),
+            },
+        );
+        ret.insert_str("offset", offset(&snapshot.offset));
+        ret.insert_str(
+            "parselets",
+            RefValue::from(
+                snapshot
+                    .parselets
+                    .iter()
+                    .map(|name| value!(name.clone()))
+                    .collect::<Vec<RefValue>>(),
+            ),
+        );
+        ret.insert_str("frames", RefValue::from(frames));
+        ret.insert_str("captures", value!(snapshot.captures));
+        ret.insert_str("globals", RefValue::from(snapshot.globals.clone()));
+
+        RefValue::from(ret)
+    }
+}
+
+impl std::fmt::Display for Snapshot {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "In {} at depth {}, ", self.parselet, self.depth)?;
+
+        if let Some(filename) = &self.filename {
+            write!(f, "{}:", filename)?;
+        }
+
+        writeln!(
+            f,
+            "{}:{} (offset {})",
+            self.offset.row, self.offset.col, self.offset.offset
+        )?;
+
+        writeln!(f, "Parselets: {}", self.parselets.join(" > "))?;
+
+        writeln!(f, "Frames:")?;
+        for (i, frame) in self.frames.iter().enumerate() {
+            writeln!(
+                f,
+                "  {:>3}: {} captures, started at {}:{}",
+                i, frame.captures, frame.start.row, frame.start.col
+            )?;
+        }
+
+        write!(f, "Globals:")?;
+        for (i, global) in self.globals.iter().enumerate() {
+            write!(f, "\n  {:>3}: {}", i, global.repr())?;
+        }
+
+        Ok(())
+    }
+}
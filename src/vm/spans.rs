@@ -0,0 +1,158 @@
+//! Spans of accepted parselet invocations, as used by editors for highlighting and folding
+use std::collections::HashMap;
+
+/// Span of input accepted by a parselet invocation
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Span {
+    pub name: String, // Name of the parselet
+    pub start: usize, // Byte offset where the span starts
+    pub end: usize,   // Byte offset where the span ends
+    pub depth: usize, // Nesting level, starting with 0 for outermost spans
+}
+
+/** Recorder of the spans of accepted parselet invocations during a run.
+
+Spans are recorded when a parselet returns, and are discarded again when their parselet or any
+parselet around it rejects, or when the reader backtracks before their end. Memoized results
+replay the spans they had recorded.
+
+Only rules get a span of their own: the main parselet and instances of generic parselets, like the
+repetitions of `P+`, are left out, as well as empty spans. */
+#[derive(Debug, Default)]
+pub struct Spans {
+    spans: Vec<Span>,                         // Recorded spans, ordered by their end
+    memo: HashMap<(usize, usize), Vec<Span>>, // Spans of memoized results, with relative depth
+    depth: usize,                             // Current nesting level of rules
+}
+
+impl Spans {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Checks if a parselet is a rule, which gets a span of its own
+    pub fn is_rule(name: &str) -> bool {
+        !name.starts_with("__") && !name.contains('<')
+    }
+
+    /// Enters a parselet, and returns the mark to record or discard its spans on leave
+    pub fn enter(&mut self, name: &str) -> usize {
+        if Self::is_rule(name) {
+            self.depth += 1;
+        }
+
+        self.spans.len()
+    }
+
+    /// Leaves a parselet, recording its span when it accepted, otherwise discarding all spans within
+    pub fn leave(&mut self, mark: usize, name: &str, start: usize, end: usize, accepted: bool) {
+        if !accepted {
+            self.spans.truncate(mark);
+        }
+
+        if Self::is_rule(name) {
+            self.depth -= 1;
+
+            if accepted && end > start {
+                self.spans.push(Span {
+                    name: name.to_string(),
+                    start,
+                    end,
+                    depth: self.depth,
+                });
+            }
+        }
+    }
+
+    /// Discards all spans ending behind an offset, as the reader backtracked to it
+    pub fn rewind(&mut self, offset: usize) {
+        while self.spans.last().is_some_and(|span| span.end > offset) {
+            self.spans.pop();
+        }
+    }
+
+    /// Remembers the spans since mark for a memoized result, after its parselet was left
+    pub fn memoize(&mut self, key: (usize, usize), mark: usize) {
+        let spans = self.relative(mark, self.depth);
+        self.memo.insert(key, spans);
+    }
+
+    /// Replays the spans of a memoized result at the current nesting level
+    pub fn replay(&mut self, key: (usize, usize)) {
+        if let Some(spans) = self.memo.get(&key) {
+            let depth = self.depth;
+            self.spans.extend(spans.iter().map(|span| Span {
+                depth: span.depth + depth,
+                ..span.clone()
+            }));
+        }
+    }
+
+    /** Remembers the spans of a grown seed of a left-recursive parselet, which is still running,
+    for the recursive calls of the next iteration. The spans since mark are discarded afterwards. */
+    pub fn grow(&mut self, key: (usize, usize), mark: usize, name: &str, start: usize, end: usize) {
+        let depth = self.depth - Self::is_rule(name) as usize;
+        let mut spans = self.relative(mark, depth);
+
+        if Self::is_rule(name) && end > start {
+            spans.push(Span {
+                name: name.to_string(),
+                start,
+                end,
+                depth: 0,
+            });
+        }
+
+        self.memo.insert(key, spans);
+        self.spans.truncate(mark);
+    }
+
+    /// Restores the spans within the finally grown seed of a left-recursive parselet, before it is left
+    pub fn restore(&mut self, key: (usize, usize), mark: usize, name: &str) {
+        self.spans.truncate(mark);
+
+        if let Some(spans) = self.memo.get(&key) {
+            let depth = self.depth - Self::is_rule(name) as usize;
+
+            // The parselet's own span is recorded again on leave
+            self.spans.extend(
+                spans
+                    .iter()
+                    .filter(|span| !Self::is_rule(name) || span.depth > 0)
+                    .map(|span| Span {
+                        depth: span.depth + depth,
+                        ..span.clone()
+                    }),
+            );
+        }
+    }
+
+    /// Returns the spans since mark with their depth relative to a nesting level
+    fn relative(&self, mark: usize, depth: usize) -> Vec<Span> {
+        self.spans[mark..]
+            .iter()
+            .map(|span| Span {
+                depth: span.depth - depth,
+                ..span.clone()
+            })
+            .collect()
+    }
+
+    /// Returns the recorded spans up to a maximum depth, ordered by start, enclosing spans first
+    pub fn into_spans(self, max_depth: Option<usize>) -> Vec<Span> {
+        let mut spans: Vec<Span> = self
+            .spans
+            .into_iter()
+            .filter(|span| max_depth.is_none_or(|max_depth| span.depth <= max_depth))
+            .collect();
+
+        spans.sort_by(|a, b| {
+            a.start
+                .cmp(&b.start)
+                .then(b.end.cmp(&a.end))
+                .then(a.depth.cmp(&b.depth))
+        });
+
+        spans
+    }
+}
@@ -1,9 +1,106 @@
 //! Runtime thread withing a VM program.
 use super::*;
-use crate::reader::{Offset, Reader};
-use crate::value::RefValue;
+use crate::error::TraceFrame;
+use crate::reader::{Offset, Reader, Scrub};
+use crate::value::{Dict, List, Lossy, Overflow, RefValue};
 use crate::{Error, Object};
-use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::Instant;
+
+/// Eviction policy for the packrat memoization table
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum MemoPolicy {
+    #[default]
+    Unbounded, // Keep all memoized results until the input is committed
+    Window(usize), // Evict memoized results starting more than n bytes before the current reader offset
+}
+
+/// Range of an input to be scanned by the main parselet
+#[derive(Debug, Clone, PartialEq)]
+pub enum ScanRange {
+    Bytes(std::ops::Range<usize>), // Range of byte offsets
+    Lines(std::ops::Range<usize>), // Range of line numbers, starting at 1
+}
+
+impl ScanRange {
+    /// Checks if an absolute reader offset is located before the range.
+    pub fn is_before(&self, offset: &Offset) -> bool {
+        match self {
+            Self::Bytes(range) => offset.offset < range.start,
+            Self::Lines(range) => (offset.row as usize) < range.start,
+        }
+    }
+
+    /// Checks if an absolute reader offset is located behind the range.
+    pub fn is_behind(&self, offset: &Offset) -> bool {
+        match self {
+            Self::Bytes(range) => offset.offset >= range.end,
+            Self::Lines(range) => offset.row as usize >= range.end,
+        }
+    }
+}
+
+/// Options to configure the execution of a Thread
+#[derive(Debug, Clone, Default)]
+pub struct RunOptions {
+    pub memo: MemoPolicy,                // Memoization table eviction policy
+    pub explain: bool,                   // Collect failures explaining why input didn't match
+    pub scrub: Scrub,                    // Scrubbing of invalid input
+    pub max_matches: Option<usize>,      // Stop scanning an input after this number of matches
+    pub range: Option<ScanRange>,        // Scan only matches starting within this range of an input
+    pub context: Option<(usize, usize)>, // Print matches with lines of context before and after
+    pub color: bool,                     // Highlight matches in printed lines
+    pub overflow: Overflow,              // Behavior of integer operations exceeding 64 bits
+    pub lossy: Lossy,                    // Behavior of implicit conversions losing information
+    pub post_mortem: bool,               // Take a snapshot of the state on a runtime error
+    pub max_depth: Option<usize>,        // Maximum depth of parselet calls
+    pub max_backtrack: Option<usize>,    // Maximum bytes of input backtracked in total
+    pub max_values: Option<usize>,       // Maximum values allocated by the run and alive at once
+    pub cancel: Option<Arc<AtomicBool>>, // Cancels the run when set, like from another thread
+    pub deadline: Option<Instant>,       // Cancels the run when this point in time has passed
+    pub args: Vec<String>,               // Arguments of the program, returned by args()
+}
+
+/// Farthest failed token match, explaining why input didn't match
+#[derive(Debug, Clone)]
+pub struct Failure {
+    pub offset: Offset,           // Reader offset where matching failed
+    pub expected: Vec<String>,    // Tokens expected at this offset
+    pub stack: Vec<String>,       // Parselets active at the first failed match
+    pub filename: Option<String>, // Input filename
+    pub line: String,             // Input line containing the failure
+}
+
+impl std::fmt::Display for Failure {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(filename) = &self.filename {
+            write!(f, "{}:{}:{}: ", filename, self.offset.row, self.offset.col)?;
+        } else {
+            write!(f, "Line {}, column {}: ", self.offset.row, self.offset.col)?;
+        }
+
+        writeln!(
+            f,
+            "Input doesn't match, expecting {}",
+            self.expected.join(" or ")
+        )?;
+        writeln!(f, "  {}", self.line)?;
+        writeln!(f, "  {:>1$}", "^", self.offset.col as usize)?;
+        write!(f, "  in {}", self.stack.join(" > "))
+    }
+}
+
+/// Report of a thread's run, including partial results salvaged on error
+#[derive(Debug)]
+pub struct RunReport {
+    pub result: Result<Option<RefValue>, Error>, // Result of the run
+    pub partial: Option<RefValue>,               // Results collected before an error occurred
+    pub offset: Offset,                          // Reader offset reached
+    pub emitted: Dict,                           // Values accumulated by emit()
+    pub scrubbed: usize,   // Invalid sequences and control characters scrubbed
+    pub exit: Option<i32>, // Exit code, when the program stopped by exit
+}
 
 /** Thread which is executing a VM program.
 
@@ -15,9 +112,33 @@ pub struct Thread<'program, 'reader> {
     pub reader: &'reader mut Reader,       // Current reader
     pub readers: Vec<&'reader mut Reader>, // List of readers
 
-    pub memo: HashMap<(usize, usize), (Offset, Result<Accept, Reject>)>, // parselet memoization table
-    pub globals: Vec<RefValue>,                                          // Global variables
-    pub debug: u8,                                                       // Debug level
+    pub memo: Memo,                        // parselet memoization table
+    pub globals: Vec<RefValue>,            // Global variables
+    pub debug: u8,                         // Debug level
+    pub options: RunOptions,               // Run options
+    pub parselets: Vec<String>,            // Active parselets
+    pub failure: Option<Failure>,          // Farthest failure
+    pub failures: Vec<Failure>,            // Explained failures
+    pub warnings: Vec<String>,             // Warnings on the run
+    pub partial: Option<RefValue>,         // Salvaged results
+    pub emits: Vec<(RefValue, RefValue)>,  // Emitted keys and values
+    pub side_effects: usize,               // Side effects counter
+    pub backtracked: usize,                // Bytes of input backtracked
+    pub stack_segments: usize,             // Native stack segments used by deep recursion
+    pub scrubbed: usize,                   // Scrubbed by readers already consumed
+    pub exit: Option<i32>,                 // Exit code, when the program stopped by exit
+    pub allocated: usize,                  // Values allocated before the run
+    pub profiler: Option<Profiler>,        // Profiler, when enabled
+    pub observers: Vec<Box<dyn Observer>>, // Observers of events
+    pub debugger: Option<Debugger>,        // Interactive debugger, when enabled
+    pub spans: Option<Spans>,              // Spans of accepted parselets, when recorded
+    pub snapshot: Option<Snapshot>,        // Snapshot of the state at the first runtime error
+    pub panic_trace: Vec<TraceFrame>,      // Stack trace collected while unwinding a panic
+    pub tail_call: Option<RefValue>,       // Parselet called in tail position, run by its caller
+    pub tail_captures: Vec<Vec<Capture>>,  // Captures the tail call's result is collected with
+    pub context_row: u32,                  // Last row printed as match or context
+    pub context_until: u32,                // Last row to be printed as context after a match
+    pub context_matches: Vec<(Offset, Offset, Option<RefValue>)>, // Matches with lines not printed yet
 }
 
 impl<'program, 'reader> Thread<'program, 'reader> {
@@ -28,24 +149,156 @@ impl<'program, 'reader> Thread<'program, 'reader> {
             program,
             reader: readers.remove(0), // first reader becomes current reader
             readers,                   // other readers are kept for later use
-            memo: HashMap::new(),
+            memo: Memo::default(),
             globals: Vec::new(),
             debug: if let Ok(level) = std::env::var("TOKAY_DEBUG") {
                 level.parse::<u8>().unwrap_or_default()
             } else {
                 0
             },
+            options: RunOptions::default(),
+            parselets: Vec::new(),
+            failure: None,
+            failures: Vec::new(),
+            warnings: Vec::new(),
+            partial: None,
+            emits: Vec::new(),
+            side_effects: 0,
+            backtracked: 0,
+            stack_segments: 0,
+            scrubbed: 0,
+            exit: None,
+            allocated: 0,
+            profiler: None,
+            observers: Vec::new(),
+            debugger: None,
+            spans: None,
+            snapshot: None,
+            panic_trace: Vec::new(),
+            tail_call: None,
+            tail_captures: Vec::new(),
+            context_row: 0,
+            context_until: 0,
+            context_matches: Vec::new(),
         }
     }
 
-    pub fn run(&mut self) -> Result<Option<RefValue>, Error> {
-        match self
-            .program
-            .main()
-            .0
-            .borrow()
-            .run(self, Vec::new(), None, true, 0)
+    /// Records a failed match of an expected token at the current reader offset.
+    pub fn fail(&mut self, expected: String) {
+        // Failures within lookaheads aren't expectations
+        if self
+            .parselets
+            .iter()
+            .any(|name| name.starts_with("Peek<") || name.starts_with("Not<"))
         {
+            return;
+        }
+
+        let offset = self.reader.tell();
+
+        if let Some(failure) = &mut self.failure {
+            if failure.offset.offset > offset.offset {
+                return;
+            }
+
+            if failure.offset.offset == offset.offset {
+                if !failure.expected.contains(&expected) {
+                    failure.expected.push(expected);
+                }
+
+                // Keep only the parselets shared by all failed matches
+                let shared = failure
+                    .stack
+                    .iter()
+                    .zip(self.parselets.iter())
+                    .take_while(|(a, b)| a == b)
+                    .count();
+
+                failure.stack.truncate(shared);
+                return;
+            }
+        }
+
+        self.failure = Some(Failure {
+            offset,
+            expected: vec![expected],
+            stack: self.parselets.clone(),
+            filename: self.reader.filename.clone(),
+            line: String::new(),
+        });
+    }
+
+    /// Takes the farthest failure as explanation why input didn't match.
+    ///
+    /// Failures not beyond an already explained failure are dropped.
+    pub fn explain(&mut self) {
+        if let Some(mut failure) = self.failure.take() {
+            if let Some(last) = self.failures.last() {
+                if (failure.offset.row, failure.offset.col) <= (last.offset.row, last.offset.col) {
+                    return;
+                }
+            }
+
+            failure.line = self.reader.get_line(&failure.offset).to_string();
+            self.failures.push(failure);
+        }
+    }
+
+    /** Memoizes the result of parselet `id` started at reader offset `start`, according to the
+    memoization policy of the run.
+
+    `reads` is taken from `Memo::reads()` when the parselet started. */
+    pub fn memoize(
+        &mut self,
+        start: usize,
+        id: usize,
+        end: Offset,
+        result: Result<Accept, Reject>,
+        reads: usize,
+    ) {
+        self.memo
+            .insert(start, id, (end, result), self.options.memo, reads);
+    }
+
+    /** Run the program's main parselet on this thread.
+
+    Runtime errors are returned with a stack trace of the parselets involved. Panics occurring
+    during execution are caught and returned as an internal error with a stack trace as well.
+    When the program stops by `exit`, no result is returned and its exit code is set in `exit`. */
+    pub fn run(&mut self) -> Result<Option<RefValue>, Error> {
+        let main = self.program.main();
+
+        // Values are only counted when they are limited
+        let counting = self.options.max_values.is_some() && !RefValue::count(true);
+        self.allocated = RefValue::allocated();
+
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            main.0.borrow().run(self, Vec::new(), None, true, 0)
+        }));
+
+        if counting {
+            RefValue::count(false);
+        }
+
+        let result = match result {
+            Ok(result) => result,
+            Err(panic) => {
+                let message = if let Some(message) = panic.downcast_ref::<&str>() {
+                    message.to_string()
+                } else if let Some(message) = panic.downcast_ref::<String>() {
+                    message.clone()
+                } else {
+                    "unknown cause".to_string()
+                };
+
+                let mut error = Error::new(None, format!("Internal error: {}", message));
+                error.code = Some("internal");
+                error.trace = std::mem::take(&mut self.panic_trace);
+                return Err(error);
+            }
+        };
+
+        match result {
             Ok(Accept::Push(Capture::Value(value, ..))) => {
                 if value.is_void() {
                     Ok(None)
@@ -54,8 +307,262 @@ impl<'program, 'reader> Thread<'program, 'reader> {
                 }
             }
             Ok(_) => Ok(None),
-            Err(Reject::Error(error)) => Err(*error),
-            Err(other) => Err(Error::new(None, format!("Runtime error {:?}", other))),
+            Err(Reject::Exit(code)) => {
+                self.exit = Some(code);
+                Ok(None)
+            }
+            Err(Reject::Error(mut error)) => {
+                error.patch_code("runtime");
+                Err(*error)
+            }
+            Err(other) => {
+                let mut error = Error::new(None, format!("Runtime error {:?}", other));
+                error.code = Some("runtime");
+                Err(error)
+            }
+        }
+    }
+
+    /** Fails when the run was cancelled by `RunOptions::cancel`, or its `RunOptions::deadline` has passed.
+
+    This is checked at safe points, which are parselet calls and iterations of loops. */
+    #[inline]
+    pub(crate) fn check_cancel(&self) -> Result<(), Reject> {
+        let message = if self
+            .options
+            .cancel
+            .as_ref()
+            .is_some_and(|cancel| cancel.load(Ordering::Relaxed))
+        {
+            "Cancelled"
+        } else if self
+            .options
+            .deadline
+            .is_some_and(|deadline| Instant::now() >= deadline)
+        {
+            "Cancelled, deadline exceeded"
+        } else {
+            return Ok(());
+        };
+
+        let mut error = Error::new(None, message.to_string());
+        error.code = Some("cancelled");
+        Err(error.into())
+    }
+
+    /** Fails when more values than `RunOptions::max_values` allocated by the run are alive.
+
+    Values allocated before the run, like the program's statics, aren't counted.
+    This is checked at the same safe points as `check_cancel()`. */
+    #[inline]
+    pub(crate) fn check_values(&self) -> Result<(), Reject> {
+        match self.options.max_values {
+            Some(max) if RefValue::allocated().saturating_sub(self.allocated) > max => Err(
+                Self::limit_exceeded(format!("more than {} values allocated", max)),
+            ),
+            _ => Ok(()),
+        }
+    }
+
+    /// Creates the error of an exceeded resource limit, which isn't caught by the program
+    pub(crate) fn limit_exceeded(message: String) -> Reject {
+        let mut error = Error::new(None, format!("Resource limit exceeded, {}", message));
+        error.code = Some("limit");
+        error.into()
+    }
+
+    /// Returns the last row covered by a match; a match ending at a line break doesn't cover the next row.
+    fn context_last(start: &Offset, end: &Offset) -> u32 {
+        if end.col == 1 && end.row > start.row {
+            end.row - 1
+        } else {
+            end.row
+        }
+    }
+
+    /** Prints a match from start to end, including lines of context.
+
+    This is only done when enabled by `RunOptions::context`. Like grep, match lines are prefixed by
+    their row number and ":", context lines by their row number and "-", and non-adjacent groups of
+    lines are separated by "--". The value of a match is printed behind its lines.
+
+    Match lines are printed as soon as they are complete, so that all matches within a line can be
+    highlighted when `RunOptions::color` is enabled. */
+    pub fn context_match(&mut self, start: Offset, end: Offset, value: Option<RefValue>) {
+        let Some((before, after)) = self.options.context else {
+            return;
+        };
+
+        // Print any complete lines up to the match
+        self.context_flush(start.row);
+
+        let from = start
+            .row
+            .saturating_sub(before as u32)
+            .max(self.context_row + 1)
+            .max(1);
+
+        if self.context_row > 0 && from > self.context_row + 1 {
+            println!("--");
+        }
+
+        for row in from..start.row {
+            if let Some(line) = self.reader.get_row(row) {
+                println!("{}-{}", row, line);
+            }
+        }
+
+        self.context_row = self.context_row.max(start.row - 1);
+
+        let last = Self::context_last(&start, &end);
+
+        self.context_matches.push((start, end, value));
+        self.context_until = last + after as u32;
+    }
+
+    /// Prints pending match lines and lines of context after the last match, up to the row before the given one.
+    pub fn context_flush(&mut self, row: u32) {
+        while self.context_row + 1 < row {
+            let next = self.context_row + 1;
+
+            // Collect the spans of matches within the next row
+            let spans: Vec<(usize, usize)> = self
+                .context_matches
+                .iter()
+                .filter(|(start, end, _)| {
+                    start.row <= next && Self::context_last(start, end) >= next
+                })
+                .map(|(start, end, _)| {
+                    (
+                        if start.row == next {
+                            start.col as usize - 1
+                        } else {
+                            0
+                        },
+                        if end.row == next {
+                            end.col as usize - 1
+                        } else {
+                            usize::MAX
+                        },
+                    )
+                })
+                .collect();
+
+            if spans.is_empty() && next > self.context_until {
+                break;
+            }
+
+            match self.reader.get_row(next) {
+                Some(line) if spans.is_empty() => println!("{}-{}", next, line),
+                Some(line) if self.options.color => {
+                    let mut colored = String::new();
+                    let len = line.chars().count();
+                    let inside = |col| spans.iter().any(|(from, to)| col >= *from && col < *to);
+
+                    for (col, ch) in line.chars().enumerate() {
+                        if inside(col) && (col == 0 || !inside(col - 1)) {
+                            colored.push_str("\x1b[1;31m");
+                        }
+
+                        colored.push(ch);
+
+                        if inside(col) && (col + 1 == len || !inside(col + 1)) {
+                            colored.push_str("\x1b[0m");
+                        }
+                    }
+
+                    println!("{}:{}", next, colored)
+                }
+                Some(line) => println!("{}:{}", next, line),
+                None if spans.is_empty() => break,
+                None => {}
+            }
+
+            self.context_row = next;
+
+            // Print the values of matches ending within this row
+            let (done, pending): (Vec<_>, Vec<_>) = std::mem::take(&mut self.context_matches)
+                .into_iter()
+                .partition(|(start, end, _)| Self::context_last(start, end) <= next);
+
+            self.context_matches = pending;
+
+            for (_, _, value) in done {
+                if let Some(value) = value {
+                    println!("=> {}", value.repr());
+                }
+            }
+        }
+    }
+
+    /// Returns the number of lines before the current one, which must be kept for printing context.
+    pub fn context_keep(&self) -> Option<u32> {
+        match self.options.context {
+            Some((before, _)) => {
+                let row = self.reader.tell().row;
+                let mut keep = (before as u32).min(row - 1);
+
+                // Keep pending match lines and lines of context after the last match
+                if self.context_row < self.context_until || !self.context_matches.is_empty() {
+                    keep = keep.max(row - (self.context_row + 1).min(row));
+                }
+
+                Some(keep)
+            }
+            None => None,
+        }
+    }
+
+    /// Returns the number of invalid sequences and control characters scrubbed from all readers.
+    pub fn scrubbed(&self) -> usize {
+        self.scrubbed
+            + self.reader.scrubbed
+            + self
+                .readers
+                .iter()
+                .map(|reader| reader.scrubbed)
+                .sum::<usize>()
+    }
+
+    /// Accumulates the emitted values into lists by their keys, and clears them.
+    pub fn emitted(&mut self) -> Dict {
+        let mut emitted = Dict::new();
+
+        for (key, value) in self.emits.drain(..) {
+            let list = emitted
+                .entry(key)
+                .or_insert_with(|| RefValue::from(List::new()));
+
+            list.borrow_mut().object_mut::<List>().unwrap().push(value);
+        }
+
+        emitted
+    }
+
+    /** Runs the program like run(), and reports partial results in case of an error.
+
+    This allows to display whatever structure was recovered from malformed input. */
+    pub fn run_report(&mut self) -> RunReport {
+        self.partial = None;
+        let result = self.run();
+
+        let start = self.reader.start();
+        let offset = self.reader.tell();
+
+        RunReport {
+            partial: if result.is_err() {
+                self.partial.take()
+            } else {
+                None
+            },
+            result,
+            offset: Offset {
+                offset: start.offset + offset.offset,
+                ..offset
+            },
+            emitted: self.emitted(),
+            scrubbed: self.scrubbed(),
+            exit: self.exit,
         }
     }
 }
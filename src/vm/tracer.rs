@@ -0,0 +1,195 @@
+//! Tracer writing the events of a running program line by line
+use super::*;
+use crate::reader::{Offset, Reader};
+use crate::value::Object;
+use std::io::Write;
+
+/// Format of trace events
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum TraceFormat {
+    #[default]
+    Text, // Human-readable lines, indented by call depth
+    Json, // One JSON object per line
+}
+
+/// Maximum number of characters of a value's representation in a trace event
+pub const TRACE_VALUE_LIMIT: usize = 64;
+
+/** Tracer observing a running program, and writing an event for every parselet call and token match.
+
+The events are
+
+- `enter` when a parselet is called,
+- `exit` when a parselet returns, with its status and result,
+- `memo` when a parselet's result is taken from the memoization table,
+- `shift` when a token matched input, with the matched value.
+
+Every event has the name of the parselet or token, and the offset, row and column in the input where it starts. `exit`, `memo` and
+`shift` additionally have the offset where they end. Values are written as their representation,
+truncated to `TRACE_VALUE_LIMIT` characters.
+*/
+pub struct Tracer {
+    format: TraceFormat,    // Format of events
+    output: Box<dyn Write>, // Output events are written to
+    depth: usize,           // Current depth of parselet calls
+}
+
+impl Tracer {
+    pub fn new(format: TraceFormat, output: Box<dyn Write>) -> Self {
+        Self {
+            format,
+            output,
+            depth: 0,
+        }
+    }
+
+    /// Describes a result by its status and the representation of its value
+    fn describe(
+        result: &Result<Accept, Reject>,
+        reader: &Reader,
+    ) -> (&'static str, Option<String>) {
+        match result {
+            Ok(Accept::Push(capture) | Accept::Return(capture)) => (
+                "accept",
+                match capture {
+                    Capture::Empty => None,
+                    capture => Some(Self::truncate(
+                        capture.clone().extract(reader).borrow().repr(),
+                    )),
+                },
+            ),
+            Ok(_) => ("accept", None),
+            Err(Reject::Next | Reject::Main) => ("reject", None),
+            Err(Reject::Skip) => ("skip", None),
+            Err(Reject::Error(error)) => ("error", Some(Self::truncate(error.message.clone()))),
+            Err(Reject::Exit(code)) => ("stop", Some(code.to_string())),
+        }
+    }
+
+    /// Truncates a representation to `TRACE_VALUE_LIMIT` characters
+    fn truncate(repr: String) -> String {
+        match repr.char_indices().nth(TRACE_VALUE_LIMIT) {
+            Some((idx, _)) => format!("{}...", &repr[..idx]),
+            None => repr,
+        }
+    }
+
+    fn write(
+        &mut self,
+        event: &str,
+        name: &str,
+        start: &Offset,
+        end: Option<&Offset>,
+        status: Option<&str>,
+        value: Option<String>,
+    ) {
+        // Trace output is best effort, a failing output doesn't stop the program
+        let _ = match self.format {
+            TraceFormat::Text => {
+                let mut line = format!(
+                    "{}{} {} @{}:{}",
+                    "  ".repeat(self.depth),
+                    event,
+                    name,
+                    start.row,
+                    start.col
+                );
+
+                if let Some(end) = end {
+                    line.push_str(&format!("-{}:{}", end.row, end.col));
+                }
+
+                if let Some(status) = status {
+                    line.push_str(&format!(" {}", status));
+                }
+
+                if let Some(value) = &value {
+                    line.push_str(&format!(" => {}", value));
+                }
+
+                writeln!(self.output, "{}", line)
+            }
+            TraceFormat::Json => {
+                let mut json = serde_json::json!({
+                    "event": event,
+                    "name": name,
+                    "depth": self.depth,
+                    "offset": start.offset,
+                    "row": start.row,
+                    "col": start.col,
+                });
+
+                let object = json.as_object_mut().unwrap();
+
+                if let Some(end) = end {
+                    object.insert("end".to_string(), end.offset.into());
+                }
+
+                if let Some(status) = status {
+                    object.insert("status".to_string(), status.into());
+                }
+
+                if let Some(value) = value {
+                    object.insert("value".to_string(), value.into());
+                }
+
+                writeln!(self.output, "{}", json)
+            }
+        };
+    }
+}
+
+impl Observer for Tracer {
+    /// Writes an `enter` event for a parselet called at an input offset
+    fn enter(&mut self, parselet: &str, start: &Offset) {
+        self.write("enter", parselet, start, None, None, None);
+        self.depth += 1;
+    }
+
+    /// Writes an `exit` event for a parselet returning a result
+    fn leave(
+        &mut self,
+        parselet: &str,
+        start: &Offset,
+        end: &Offset,
+        result: &Result<Accept, Reject>,
+        reader: &Reader,
+    ) {
+        self.depth = self.depth.saturating_sub(1);
+
+        let (status, value) = Self::describe(result, reader);
+        self.write("exit", parselet, start, Some(end), Some(status), value);
+    }
+
+    /// Writes a `memo` event for a parselet result taken from the memoization table
+    fn memo(
+        &mut self,
+        parselet: &str,
+        start: &Offset,
+        end: &Offset,
+        result: &Result<Accept, Reject>,
+        reader: &Reader,
+    ) {
+        let (status, value) = Self::describe(result, reader);
+        self.write("memo", parselet, start, Some(end), Some(status), value);
+    }
+
+    /// Writes a `shift` event for a token which matched input
+    fn shift(
+        &mut self,
+        token: &str,
+        start: &Offset,
+        end: &Offset,
+        result: &Result<Accept, Reject>,
+        reader: &Reader,
+    ) {
+        let (_, value) = Self::describe(result, reader);
+        self.write("shift", token, start, Some(end), None, value);
+    }
+}
+
+impl Drop for Tracer {
+    fn drop(&mut self) {
+        let _ = self.output.flush();
+    }
+}